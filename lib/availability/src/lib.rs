@@ -0,0 +1,337 @@
+//! Annotates Rust bindings with the OS versions that introduced the bound interface.
+//!
+//! Apple's SDK headers declare the availability of an interface with attributes like
+//! `API_AVAILABLE(macos(10.14), ios(12.0))`. The [`macro@availability`] attribute is the
+//! equivalent for Rust bindings:
+//!
+//! ```ignore
+//! extern "C" {
+//!     #[availability(macos = "10.14", ios = "12.0", tvos = "12.0", watchos = "5.0")]
+//!     pub fn dispatch_workloop_create(label: *const c_char) -> dispatch_workloop_t;
+//! }
+//! ```
+//!
+//! The attribute appends an `# Availability` section to the item's documentation and, for each
+//! platform on which the interface was introduced after the oldest deployment target supported by
+//! the Rust toolchain, gates the item on a deployment target Cargo feature of the crate using the
+//! attribute. The feature is named for the platform and version (e.g. `macos_10_14`) and must be
+//! declared by the crate. Features are additive: enabling a feature exposes the interfaces
+//! introduced in that version, so crates enable the features for the newest versions by default and
+//! apps that deploy to older OS versions disable default features to hide the interfaces that are
+//! not available at run time.
+//!
+//! The gate for a platform is only applied when compiling for that platform.
+
+use core::cmp::Ordering;
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// An OS on which an interface may be available.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Platform {
+    Ios,
+    Macos,
+    Tvos,
+    Visionos,
+    Watchos,
+}
+
+impl Platform {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ios" => Some(Self::Ios),
+            "macos" => Some(Self::Macos),
+            "tvos" => Some(Self::Tvos),
+            "visionos" => Some(Self::Visionos),
+            "watchos" => Some(Self::Watchos),
+            _ => None,
+        }
+    }
+
+    /// The name of the platform in the Cargo feature and `target_os` value.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Ios => "ios",
+            Self::Macos => "macos",
+            Self::Tvos => "tvos",
+            Self::Visionos => "visionos",
+            Self::Watchos => "watchos",
+        }
+    }
+
+    /// The name of the platform in documentation.
+    const fn display_name(self) -> &'static str {
+        match self {
+            Self::Ios => "iOS",
+            Self::Macos => "macOS",
+            Self::Tvos => "tvOS",
+            Self::Visionos => "visionOS",
+            Self::Watchos => "watchOS",
+        }
+    }
+
+    /// The oldest deployment target supported by the Rust toolchain. Interfaces introduced in or
+    /// before this version are always available, so they are documented but not gated.
+    const fn minimum_deployment_target(self) -> Version {
+        match self {
+            Self::Ios | Self::Tvos => Version(10, 0),
+            Self::Macos => Version(10, 12),
+            Self::Visionos => Version(1, 0),
+            Self::Watchos => Version(5, 0),
+        }
+    }
+}
+
+/// A `major.minor` OS version.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct Version(u32, u32);
+
+impl Version {
+    fn parse(s: &str) -> Option<Self> {
+        let (major, minor) = s.split_once('.').unwrap_or((s, "0"));
+        Some(Self(major.parse().ok()?, minor.parse().ok()?))
+    }
+}
+
+/// The version in which an interface was introduced on a platform.
+#[derive(Clone, Copy, Debug)]
+struct Introduced {
+    platform: Platform,
+    version: Version,
+}
+
+impl Introduced {
+    /// Returns the deployment target Cargo feature that gates the interface, or [`None`] if the
+    /// interface is available on every deployment target supported by the Rust toolchain.
+    fn feature(&self) -> Option<String> {
+        match self.version.cmp(&self.platform.minimum_deployment_target()) {
+            Ordering::Greater => Some(format!(
+                "{}_{}_{}",
+                self.platform.name(),
+                self.version.0,
+                self.version.1
+            )),
+            Ordering::Equal | Ordering::Less => None,
+        }
+    }
+}
+
+/// Documents the OS versions that introduced the annotated binding, and gates the binding on the
+/// crate's deployment target features.
+///
+/// The arguments are a comma-separated list of `platform = "major.minor"` pairs, where `platform`
+/// is one of `ios`, `macos`, `tvos`, `visionos`, or `watchos`. Platforms on which the interface is
+/// not available should be omitted.
+///
+/// See the [crate documentation](crate) for a description of the deployment target features.
+// LINT: Procedural macros are invoked by the compiler, not inlined into callers.
+#[allow(clippy::missing_inline_in_public_items)]
+#[proc_macro_attribute]
+pub fn availability(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let introduced = match parse_args(attr) {
+        Ok(introduced) => introduced,
+        Err((span, message)) => return compile_error(span, &message),
+    };
+
+    let mut doc = vec![String::new(), "# Availability".to_owned(), String::new()];
+    let mut sorted = introduced.clone();
+    sorted.sort_by_key(|i| i.platform.display_name());
+    for i in &sorted {
+        let requires = i.feature().map_or_else(String::new, |feature| {
+            format!(" (requires the `{feature}` feature)")
+        });
+        doc.push(format!(
+            "* {}: {}.{}{requires}",
+            i.platform.display_name(),
+            i.version.0,
+            i.version.1
+        ));
+    }
+
+    let mut output = TokenStream::new();
+    for line in doc {
+        output.extend(attribute(
+            [
+                ident("doc"),
+                punct('='),
+                TokenTree::Literal(Literal::string(&line)),
+            ]
+            .into_iter()
+            .collect(),
+        ));
+    }
+
+    let gates = introduced
+        .iter()
+        .filter_map(|i| {
+            let feature = i.feature()?;
+            // any(not(target_os = "platform"), feature = "platform_major_minor")
+            let not = group(
+                Delimiter::Parenthesis,
+                name_value("target_os", i.platform.name()),
+            );
+            let any = group(
+                Delimiter::Parenthesis,
+                [ident("not"), not, punct(',')]
+                    .into_iter()
+                    .chain(name_value("feature", &feature))
+                    .collect(),
+            );
+            Some([ident("any"), any, punct(',')])
+        })
+        .flatten()
+        .collect::<TokenStream>();
+    if !gates.is_empty() {
+        let all = group(Delimiter::Parenthesis, gates);
+        let cfg = group(
+            Delimiter::Parenthesis,
+            [ident("all"), all].into_iter().collect(),
+        );
+        output.extend(attribute([ident("cfg"), cfg].into_iter().collect()));
+    }
+
+    output.extend(item);
+    output
+}
+
+type Error = (Span, String);
+
+fn parse_args(attr: TokenStream) -> Result<Vec<Introduced>, Error> {
+    let mut introduced = Vec::<Introduced>::new();
+    let mut tokens = attr.into_iter();
+
+    while let Some(token) = tokens.next() {
+        let TokenTree::Ident(name) = token else {
+            return Err((token.span(), "expected a platform name".to_owned()));
+        };
+        let platform = Platform::from_name(&name.to_string()).ok_or_else(|| {
+            (
+                name.span(),
+                "expected one of `ios`, `macos`, `tvos`, `visionos`, or `watchos`".to_owned(),
+            )
+        })?;
+        if introduced.iter().any(|i| i.platform == platform) {
+            return Err((
+                name.span(),
+                format!("duplicate platform `{}`", platform.name()),
+            ));
+        }
+
+        match tokens.next() {
+            Some(TokenTree::Punct(p)) if p.as_char() == '=' => {}
+            other => return Err((span_of(other.as_ref(), &name), "expected `=`".to_owned())),
+        }
+
+        let version = match tokens.next() {
+            Some(TokenTree::Literal(literal)) => {
+                let s = literal.to_string();
+                s.strip_prefix('"')
+                    .and_then(|s| s.strip_suffix('"'))
+                    .and_then(Version::parse)
+                    .ok_or_else(|| {
+                        (
+                            literal.span(),
+                            "expected a version string like \"10.14\"".to_owned(),
+                        )
+                    })?
+            }
+            other => {
+                return Err((
+                    span_of(other.as_ref(), &name),
+                    "expected a version string like \"10.14\"".to_owned(),
+                ))
+            }
+        };
+        introduced.push(Introduced { platform, version });
+
+        match tokens.next() {
+            None => break,
+            Some(TokenTree::Punct(p)) if p.as_char() == ',' => {}
+            Some(other) => return Err((other.span(), "expected `,`".to_owned())),
+        }
+    }
+
+    if introduced.is_empty() {
+        return Err((
+            Span::call_site(),
+            "expected at least one platform".to_owned(),
+        ));
+    }
+    Ok(introduced)
+}
+
+fn span_of(token: Option<&TokenTree>, fallback: &Ident) -> Span {
+    token.map_or_else(|| fallback.span(), TokenTree::span)
+}
+
+fn attribute(contents: TokenStream) -> TokenStream {
+    [punct('#'), group(Delimiter::Bracket, contents)]
+        .into_iter()
+        .collect()
+}
+
+fn compile_error(span: Span, message: &str) -> TokenStream {
+    let mut literal = Literal::string(message);
+    literal.set_span(span);
+    let args = group(Delimiter::Parenthesis, TokenTree::Literal(literal).into());
+    [ident("compile_error"), punct('!'), args, punct(';')]
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
+
+fn group(delimiter: Delimiter, stream: TokenStream) -> TokenTree {
+    TokenTree::Group(Group::new(delimiter, stream))
+}
+
+fn ident(s: &str) -> TokenTree {
+    TokenTree::Ident(Ident::new(s, Span::call_site()))
+}
+
+fn name_value(name: &str, value: &str) -> TokenStream {
+    [
+        ident(name),
+        punct('='),
+        TokenTree::Literal(Literal::string(value)),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn punct(c: char) -> TokenTree {
+    TokenTree::Punct(Punct::new(c, Spacing::Alone))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feature() {
+        let introduced = |platform, version| Introduced {
+            platform,
+            version: Version::parse(version).unwrap_or(Version(0, 0)),
+        };
+
+        assert_eq!(
+            introduced(Platform::Macos, "10.14").feature().as_deref(),
+            Some("macos_10_14")
+        );
+        assert_eq!(
+            introduced(Platform::Ios, "12").feature().as_deref(),
+            Some("ios_12_0")
+        );
+        assert_eq!(introduced(Platform::Macos, "10.12").feature(), None);
+        assert_eq!(introduced(Platform::Watchos, "2.0").feature(), None);
+    }
+
+    #[test]
+    fn version() {
+        assert_eq!(Version::parse("10.14"), Some(Version(10, 14)));
+        assert_eq!(Version::parse("17"), Some(Version(17, 0)));
+        assert_eq!(Version::parse("10.x"), None);
+        assert!(Version(10, 9) < Version(10, 14));
+    }
+}
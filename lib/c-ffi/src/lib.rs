@@ -1,5 +1,9 @@
 //! Convenience utilities for implementing *-sys crates and Rust bindings.
 
+mod weak;
+
+pub use weak::{WeakFn, WeakStatic};
+
 /// Defines a type to represent an opaque C struct for use in defining an FFI.
 ///
 /// By default, the type is `!Send` and `!Sync`, but these traits may be implemented if supported by
@@ -7,17 +11,139 @@
 ///
 /// The type is also `!Unpin` as it is illogical to move out of an opaque struct.
 ///
+/// The identifier may be followed by a comma-separated list of options:
+///
+/// * `Send` and `Sync` implement the respective trait. The macro's caller asserts the foreign type
+///   supports it.
+/// * `Debug` implements [`Debug`] by printing the address of the instance, as its contents are
+///   unknown.
+/// * `type_id: fn() -> $ty = $getter` defines an associated constant `TYPE_ID` that refers to the
+///   foreign function returning the type's run time identifier (e.g., `CFStringGetTypeID`).
+///
+/// ```ignore
+/// c_ffi::opaque_type!(
+///     /// An immutable point in time.
+///     __CFDate, Debug, Send, Sync, type_id: fn() -> CFTypeID = CFDateGetTypeID
+/// );
+/// ```
+///
 /// See [The Rustonomicon][] for more information.
 ///
+/// [`Debug`]: core::fmt::Debug
 /// [The Rustonomicon]: https://doc.rust-lang.org/nomicon/ffi.html#representing-opaque-structs
 #[macro_export]
 macro_rules! opaque_type {
-    ($(#[$doc:meta])* $ident:ident) => {
+    ($(#[$doc:meta])* $ident:ident $(, $($option:tt)*)?) => {
         $(#[$doc])*
         #[repr(C)]
         pub struct $ident {
             _data: [u8; 0],
             _marker: core::marker::PhantomData<(*const u8, core::marker::PhantomPinned)>,
         }
-    }
+
+        $crate::__opaque_type_options!($ident $(, $($option)*)?);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __opaque_type_options {
+    ($ident:ident $(,)?) => {};
+    ($ident:ident, Debug $($rest:tt)*) => {
+        impl core::fmt::Debug for $ident {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let ptr: *const Self = self;
+                f.debug_tuple(stringify!($ident)).field(&ptr).finish()
+            }
+        }
+
+        $crate::__opaque_type_options!($ident $($rest)*);
+    };
+    ($ident:ident, Send $($rest:tt)*) => {
+        // SAFETY: The macro's caller asserts the foreign type may be sent across threads.
+        unsafe impl Send for $ident {}
+
+        $crate::__opaque_type_options!($ident $($rest)*);
+    };
+    ($ident:ident, Sync $($rest:tt)*) => {
+        // SAFETY: The macro's caller asserts the foreign type may be shared across threads.
+        unsafe impl Sync for $ident {}
+
+        $crate::__opaque_type_options!($ident $($rest)*);
+    };
+    ($ident:ident, type_id: fn() -> $ty:ty = $getter:path $(, $($rest:tt)*)?) => {
+        impl $ident {
+            /// The foreign function that returns the run time identifier of the type.
+            pub const TYPE_ID: unsafe extern "C" fn() -> $ty = $getter;
+        }
+
+        $crate::__opaque_type_options!($ident $(, $($rest)*)?);
+    };
+}
+
+/// Declares functions and statics that are resolved at run time, so a binary can be deployed to OS
+/// versions that predate them.
+///
+/// Each declaration becomes a `static` [`WeakFn`] or [`WeakStatic`] of the same name. Test whether
+/// the interface exists with `is_available()`, or access it with `get()`:
+///
+/// ```ignore
+/// c_ffi::weak_extern! {
+///     pub fn dispatch_workloop_create(label: *const c_char) -> dispatch_workloop_t;
+///     pub static kCFErrorLocalizedFailureKey: CFStringConstant;
+/// }
+///
+/// if let Some(create) = dispatch_workloop_create.get() {
+///     let workloop = unsafe { create(label) };
+/// }
+/// ```
+///
+/// Like the `extern` block it replaces, the declarations must match the C interface exactly. As the
+/// declarations are `static` items, the type of a static must be [`Sync`]; a pointer may be wrapped
+/// in a `#[repr(transparent)]` type that implements [`Sync`] if the pointee is immutable.
+#[macro_export]
+macro_rules! weak_extern {
+    () => {};
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $arg_ty:ty),* $(,)?) $(-> $ret:ty)?;
+        $($rest:tt)*
+    ) => {
+        $(#[$attr])*
+        #[allow(non_upper_case_globals)]
+        $vis static $name: $crate::WeakFn<unsafe extern "C" fn($($arg_ty),*) $(-> $ret)?> = {
+            let name = $crate::__weak_extern_name!($name);
+            // SAFETY: The macro's caller asserts the declaration matches the C interface.
+            unsafe { $crate::WeakFn::new(name) }
+        };
+        $crate::weak_extern!($($rest)*);
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis static $name:ident: $ty:ty;
+        $($rest:tt)*
+    ) => {
+        $(#[$attr])*
+        #[allow(non_upper_case_globals)]
+        $vis static $name: $crate::WeakStatic<$ty> = {
+            let name = $crate::__weak_extern_name!($name);
+            // SAFETY: The macro's caller asserts the declaration matches the C interface.
+            unsafe { $crate::WeakStatic::new(name) }
+        };
+        $crate::weak_extern!($($rest)*);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __weak_extern_name {
+    ($name:ident) => {
+        // SAFETY: The identifier cannot contain a nul byte.
+        unsafe {
+            ::core::ffi::CStr::from_bytes_with_nul_unchecked(
+                concat!(stringify!($name), "\0").as_bytes(),
+            )
+        }
+    };
 }
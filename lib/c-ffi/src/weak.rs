@@ -0,0 +1,213 @@
+//! Symbols resolved at run time, for calling interfaces that may not exist on the OS version the
+//! binary is running on.
+//!
+//! Stable Rust cannot emit weak imports (the equivalent of `__attribute__((weak_import))`), so a
+//! [`WeakFn`] or [`WeakStatic`] looks up its symbol in the process's loaded images with `dlsym`
+//! the first time it's accessed, and caches the result. A symbol that cannot be found is
+//! unavailable, just like a weakly linked symbol whose address is `NULL`.
+
+use core::ffi::{c_char, c_void, CStr};
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+use core::mem;
+use core::ptr;
+use core::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+
+/// Instructs `dlsym` to search every image loaded in the process.
+#[cfg(target_vendor = "apple")]
+const RTLD_DEFAULT: *mut c_void = ptr::null_mut::<c_void>().wrapping_sub(2);
+
+/// Instructs `dlsym` to search every image loaded in the process.
+#[cfg(not(target_vendor = "apple"))]
+const RTLD_DEFAULT: *mut c_void = ptr::null_mut();
+
+extern "C" {
+    fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+}
+
+/// The name and lazily resolved address of a symbol.
+struct Symbol {
+    name: &'static CStr,
+    address: AtomicPtr<c_void>,
+    resolved: AtomicBool,
+}
+
+impl Symbol {
+    const fn new(name: &'static CStr) -> Self {
+        Self {
+            name,
+            address: AtomicPtr::new(ptr::null_mut()),
+            resolved: AtomicBool::new(false),
+        }
+    }
+
+    fn address(&self) -> *mut c_void {
+        if self.resolved.load(Ordering::Acquire) {
+            return self.address.load(Ordering::Relaxed);
+        }
+
+        // SAFETY: `name` is a valid C string. Concurrent resolution is benign as every thread
+        // resolves the same address.
+        let address = unsafe { dlsym(RTLD_DEFAULT, self.name.as_ptr()) };
+        self.address.store(address, Ordering::Relaxed);
+        self.resolved.store(true, Ordering::Release);
+        address
+    }
+
+    fn fmt(&self, f: &mut Formatter<'_>, ty: &str) -> fmt::Result {
+        f.debug_struct(ty)
+            .field("name", &self.name)
+            .field("address", &self.address())
+            .finish()
+    }
+}
+
+/// A function that is resolved the first time it's accessed. Use [`weak_extern!`] to declare one.
+///
+/// [`weak_extern!`]: crate::weak_extern
+pub struct WeakFn<F> {
+    symbol: Symbol,
+    _marker: PhantomData<F>,
+}
+
+impl<F> WeakFn<F>
+where
+    F: Copy,
+{
+    /// Declares the function named `name`.
+    ///
+    /// # Safety
+    ///
+    /// `F` must be an `unsafe extern "C" fn` pointer type whose signature matches the function
+    /// exported under `name`.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn new(name: &'static CStr) -> Self {
+        Self {
+            symbol: Symbol::new(name),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the function, or [`None`] if it's not available on this OS version.
+    #[inline]
+    pub fn get(&self) -> Option<F> {
+        let address = self.symbol.address();
+        // SAFETY: The caller of [`Self::new`] asserted `F` is a function pointer, which has the
+        // same size as a data pointer on all supported targets, with a matching signature.
+        (!address.is_null()).then(|| unsafe { mem::transmute_copy(&address) })
+    }
+
+    /// Returns `true` if the function is available on this OS version.
+    #[inline]
+    pub fn is_available(&self) -> bool {
+        !self.symbol.address().is_null()
+    }
+}
+
+impl<F> Debug for WeakFn<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.symbol.fmt(f, "WeakFn")
+    }
+}
+
+/// A static that is resolved the first time it's accessed. Use [`weak_extern!`] to declare one.
+///
+/// [`weak_extern!`]: crate::weak_extern
+pub struct WeakStatic<T> {
+    symbol: Symbol,
+    _marker: PhantomData<*const T>,
+}
+
+impl<T> WeakStatic<T> {
+    /// Declares the static named `name`.
+    ///
+    /// # Safety
+    ///
+    /// The static exported under `name` must be a valid, immutable `T`.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn new(name: &'static CStr) -> Self {
+        Self {
+            symbol: Symbol::new(name),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the static, or [`None`] if it's not available on this OS version.
+    #[inline]
+    pub fn get(&self) -> Option<&'static T> {
+        let address = self.symbol.address().cast::<T>();
+        // SAFETY: The caller of [`Self::new`] asserted the symbol is a valid, immutable `T`, and
+        // an image's statics live as long as the process (images exporting system interfaces are
+        // never unloaded).
+        unsafe { address.cast_const().as_ref() }
+    }
+
+    /// Returns `true` if the static is available on this OS version.
+    #[inline]
+    pub fn is_available(&self) -> bool {
+        !self.symbol.address().is_null()
+    }
+}
+
+impl<T> Debug for WeakStatic<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.symbol.fmt(f, "WeakStatic")
+    }
+}
+
+// SAFETY: A `WeakStatic<T>` only hands out shared references to the static, which may be used on
+// any thread if `T` is `Sync`.
+unsafe impl<T> Send for WeakStatic<T> where T: Sync {}
+
+// SAFETY: See above.
+unsafe impl<T> Sync for WeakStatic<T> where T: Sync {}
+
+#[cfg(test)]
+mod tests {
+    use super::{WeakFn, WeakStatic};
+    use core::ffi::{c_char, c_void};
+
+    crate::weak_extern! {
+        fn strlen(s: *const c_char) -> usize;
+        fn c_ffi_missing_function();
+        static c_ffi_missing_static: u8;
+    }
+
+    #[test]
+    fn missing() {
+        assert!(!c_ffi_missing_function.is_available());
+        assert!(c_ffi_missing_function.get().is_none());
+        assert!(!c_ffi_missing_static.is_available());
+        assert!(c_ffi_missing_static.get().is_none());
+    }
+
+    #[test]
+    fn resolve_function() {
+        assert!(strlen.is_available());
+        let f = strlen.get().expect("strlen");
+        // SAFETY: The argument is a valid C string.
+        assert_eq!(unsafe { f(b"weak\0".as_ptr().cast()) }, 4_usize);
+
+        let name = crate::__weak_extern_name!(strlen);
+        // SAFETY: The signature matches the C interface.
+        let weak = unsafe { WeakFn::<unsafe extern "C" fn(*const c_char) -> usize>::new(name) };
+        assert!(weak.is_available());
+    }
+
+    #[test]
+    fn resolve_static() {
+        #[cfg(target_vendor = "apple")]
+        let name = crate::__weak_extern_name!(__stdinp);
+        #[cfg(not(target_vendor = "apple"))]
+        let name = crate::__weak_extern_name!(stdin);
+
+        // SAFETY: The C library exports the standard input stream as a `FILE *`.
+        let stdin = unsafe { WeakStatic::<*mut c_void>::new(name) };
+        assert!(stdin.is_available());
+        assert!(!stdin.get().expect("stdin").is_null());
+    }
+}
@@ -124,6 +124,74 @@ impl CFRange {
     pub const fn is_empty(&self) -> bool {
         self.length == 0
     }
+
+    /// Converts `range` into a [`CFRange`], clamping its bounds to `0..=to` (and [`CFIndex::MAX`])
+    /// instead of failing. A range whose start is greater than its end is converted into an empty
+    /// range at its start.
+    #[inline]
+    #[must_use]
+    pub fn saturating_from_range_bounds(range: impl RangeBounds<usize>, to: usize) -> Self {
+        let to = to.min(CFIndex::MAX.unsigned_abs());
+
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        }
+        .min(to);
+
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => to,
+        }
+        .clamp(start, to);
+
+        // LINT: Both bounds were clamped to `CFIndex::MAX`, and `end >= start`.
+        #[allow(clippy::as_conversions, clippy::cast_possible_wrap)]
+        Self {
+            location: start as CFIndex,
+            length: end.wrapping_sub(start) as CFIndex,
+        }
+    }
+
+    /// Returns the index one past the last item in the range, or [`None`] if the range's
+    /// `location` plus its `length` overflows [`CFIndex`].
+    #[inline]
+    #[must_use]
+    pub const fn checked_end(&self) -> Option<CFIndex> {
+        self.location.checked_add(self.length)
+    }
+
+    /// Returns the range moved by `delta` items, or [`None`] if the range's new location would be
+    /// negative or its new end overflows [`CFIndex`].
+    #[inline]
+    #[must_use]
+    pub fn checked_offset(&self, delta: CFIndex) -> Option<Self> {
+        let location = self.location.checked_add(delta).filter(|&l| l >= 0)?;
+        let range = Self {
+            location,
+            length: self.length,
+        };
+        range.checked_end().map(|_| range)
+    }
+
+    /// Returns the range of items contained by both `self` and `other`, or [`None`] if the ranges
+    /// do not overlap or either range is invalid. Adjacent ranges intersect in an empty range.
+    #[inline]
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if self.location < 0 || self.length < 0 || other.location < 0 || other.length < 0 {
+            return None;
+        }
+
+        let location = self.location.max(other.location);
+        let end = self.checked_end()?.min(other.checked_end()?);
+        // UB: Cannot overflow as both values are non-negative.
+        let length = end.wrapping_sub(location);
+
+        (length >= 0).then_some(Self { location, length })
+    }
 }
 
 impl TryFrom<Range<usize>> for CFRange {
@@ -329,3 +329,97 @@ fn try_from_range_signed_overflow() {
         }))
     );
 }
+
+#[test]
+fn saturating_from_range_bounds() {
+    assert_eq!(
+        CFRange::saturating_from_range_bounds(5..100, 10),
+        CFRange {
+            location: 5,
+            length: 5
+        }
+    );
+    assert_eq!(
+        CFRange::saturating_from_range_bounds(20.., 10),
+        CFRange {
+            location: 10,
+            length: 0
+        }
+    );
+    assert_eq!(
+        CFRange::saturating_from_range_bounds(8..2, 10),
+        CFRange {
+            location: 8,
+            length: 0
+        }
+    );
+    assert_eq!(
+        CFRange::saturating_from_range_bounds(..=usize::MAX, usize::MAX),
+        CFRange {
+            location: 0,
+            length: CFIndex::MAX
+        }
+    );
+}
+
+#[test]
+fn checked_offset() {
+    let range = CFRange {
+        location: 5,
+        length: 10,
+    };
+
+    assert_eq!(range.checked_end(), Some(15));
+    assert_eq!(
+        range.checked_offset(-5),
+        Some(CFRange {
+            location: 0,
+            length: 10
+        })
+    );
+    assert_eq!(range.checked_offset(-6), None);
+    assert_eq!(range.checked_offset(CFIndex::MAX - 14), None);
+}
+
+#[test]
+fn intersection() {
+    let range = CFRange {
+        location: 5,
+        length: 10,
+    };
+
+    assert_eq!(
+        range.intersection(&CFRange {
+            location: 10,
+            length: 10
+        }),
+        Some(CFRange {
+            location: 10,
+            length: 5
+        })
+    );
+    assert_eq!(
+        range.intersection(&CFRange {
+            location: 15,
+            length: 1
+        }),
+        Some(CFRange {
+            location: 15,
+            length: 0
+        })
+    );
+    assert_eq!(
+        range.intersection(&CFRange {
+            location: 16,
+            length: 1
+        }),
+        None
+    );
+    assert_eq!(
+        range.intersection(&CFRange {
+            location: kCFNotFound,
+            length: 0
+        }),
+        None
+    );
+}
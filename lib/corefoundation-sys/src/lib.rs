@@ -9,7 +9,24 @@
 mod bindings;
 mod sys;
 
+pub mod weak;
+
 pub use bindings::range::{TryFromCFRangeError, TryFromRangeError};
+pub use sys::array::*;
 pub use sys::base::*;
+pub use sys::character_set::*;
+pub use sys::data::*;
+pub use sys::date::*;
+pub use sys::dictionary::*;
+pub use sys::error::*;
+pub use sys::file_descriptor::*;
+pub use sys::locale::*;
+pub use sys::mach_port::*;
+pub use sys::message_port::*;
+pub use sys::number::*;
+pub use sys::run_loop::*;
+pub use sys::stream::*;
 pub use sys::string::*;
 pub use sys::string_encoding_ext::*;
+pub use sys::string_tokenizer::*;
+pub use sys::time_zone::*;
@@ -10,18 +10,38 @@ use c_ffi::opaque_type;
 /// Defines an opaque type to act as a proxy for a Core Foundation object type.
 ///
 /// By default, the type is `!Send` and `!Sync`, but these traits may be implemented if supported by
-/// the object type.
+/// the object type. If `type_id` follows the pointer types, the type's `TYPE_ID` constant refers to
+/// the given `CF*GetTypeID` function.
 macro_rules! declare_cf_type {
-    ($struct:ident, $ref:ident) => {
-        $crate::sys::opaque_type!($struct);
+    ($struct:ident, $ref:ident, $mutable_ref:ident, type_id: $type_id:path) => {
+        declare_cf_type!($struct, $ref, type_id: $type_id);
+        pub type $mutable_ref = *mut $struct;
+    };
+    ($struct:ident, $ref:ident, type_id: $type_id:path) => {
+        $crate::sys::opaque_type!($struct, Debug, type_id: fn() -> $crate::CFTypeID = $type_id);
         pub type $ref = *const $struct;
     };
-    ($struct:ident, $ref:ident, $mutable_ref:ident) => {
-        declare_cf_type!($struct, $ref);
-        pub type $mutable_ref = *mut $struct;
+    ($struct:ident, $ref:ident) => {
+        $crate::sys::opaque_type!($struct, Debug);
+        pub type $ref = *const $struct;
     };
 }
 
+pub(crate) mod array;
 pub(crate) mod base;
+pub(crate) mod character_set;
+pub(crate) mod data;
+pub(crate) mod date;
+pub(crate) mod dictionary;
+pub(crate) mod error;
+pub(crate) mod file_descriptor;
+pub(crate) mod locale;
+pub(crate) mod mach_port;
+pub(crate) mod message_port;
+pub(crate) mod number;
+pub(crate) mod run_loop;
+pub(crate) mod stream;
 pub(crate) mod string;
 pub(crate) mod string_encoding_ext;
+pub(crate) mod string_tokenizer;
+pub(crate) mod time_zone;
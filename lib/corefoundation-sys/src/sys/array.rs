@@ -0,0 +1,124 @@
+use crate::{
+    c_void, Boolean, CFAllocatorRef, CFComparatorFunction, CFIndex, CFRange, CFStringRef, CFTypeID,
+};
+
+pub type CFArrayRetainCallBack =
+    extern "C" fn(allocator: CFAllocatorRef, value: *const c_void) -> *const c_void;
+pub type CFArrayReleaseCallBack = extern "C" fn(allocator: CFAllocatorRef, value: *const c_void);
+pub type CFArrayCopyDescriptionCallBack = extern "C" fn(value: *const c_void) -> CFStringRef;
+pub type CFArrayEqualCallBack =
+    extern "C" fn(value1: *const c_void, value2: *const c_void) -> Boolean;
+
+/// Structure containing the callbacks of a `CFArray`.
+///
+/// Any callback may be [`None`], in which case the array does not retain or release its values,
+/// describes them by their address, and compares them by pointer equality.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CFArrayCallBacks {
+    pub version: CFIndex,
+    pub retain: Option<CFArrayRetainCallBack>,
+    pub release: Option<CFArrayReleaseCallBack>,
+    pub copyDescription: Option<CFArrayCopyDescriptionCallBack>,
+    pub equal: Option<CFArrayEqualCallBack>,
+}
+
+/// Type of the callback function used by the apply functions of `CFArray`s.
+pub type CFArrayApplierFunction = extern "C" fn(value: *const c_void, context: *mut c_void);
+
+declare_cf_type!(__CFArray, CFArrayRef, CFMutableArrayRef, type_id: CFArrayGetTypeID);
+
+extern "C" {
+    /// Predefined [`CFArrayCallBacks`] structure containing a set of callbacks appropriate for use
+    /// when the values in a `CFArray` are all CF types.
+    pub static kCFTypeArrayCallBacks: CFArrayCallBacks;
+
+    pub fn CFArrayGetTypeID() -> CFTypeID;
+
+    pub fn CFArrayCreate(
+        allocator: CFAllocatorRef,
+        values: *const *const c_void,
+        numValues: CFIndex,
+        callBacks: *const CFArrayCallBacks,
+    ) -> CFArrayRef;
+    pub fn CFArrayCreateCopy(allocator: CFAllocatorRef, theArray: CFArrayRef) -> CFArrayRef;
+    pub fn CFArrayCreateMutable(
+        allocator: CFAllocatorRef,
+        capacity: CFIndex,
+        callBacks: *const CFArrayCallBacks,
+    ) -> CFMutableArrayRef;
+    pub fn CFArrayCreateMutableCopy(
+        allocator: CFAllocatorRef,
+        capacity: CFIndex,
+        theArray: CFArrayRef,
+    ) -> CFMutableArrayRef;
+
+    pub fn CFArrayGetCount(theArray: CFArrayRef) -> CFIndex;
+    pub fn CFArrayGetCountOfValue(
+        theArray: CFArrayRef,
+        range: CFRange,
+        value: *const c_void,
+    ) -> CFIndex;
+    pub fn CFArrayContainsValue(
+        theArray: CFArrayRef,
+        range: CFRange,
+        value: *const c_void,
+    ) -> Boolean;
+    pub fn CFArrayGetValueAtIndex(theArray: CFArrayRef, idx: CFIndex) -> *const c_void;
+    pub fn CFArrayGetValues(theArray: CFArrayRef, range: CFRange, values: *mut *const c_void);
+    pub fn CFArrayApplyFunction(
+        theArray: CFArrayRef,
+        range: CFRange,
+        applier: CFArrayApplierFunction,
+        context: *mut c_void,
+    );
+    pub fn CFArrayGetFirstIndexOfValue(
+        theArray: CFArrayRef,
+        range: CFRange,
+        value: *const c_void,
+    ) -> CFIndex;
+    pub fn CFArrayGetLastIndexOfValue(
+        theArray: CFArrayRef,
+        range: CFRange,
+        value: *const c_void,
+    ) -> CFIndex;
+    pub fn CFArrayBSearchValues(
+        theArray: CFArrayRef,
+        range: CFRange,
+        value: *const c_void,
+        comparator: CFComparatorFunction,
+        context: *mut c_void,
+    ) -> CFIndex;
+
+    pub fn CFArrayAppendValue(theArray: CFMutableArrayRef, value: *const c_void);
+    pub fn CFArrayInsertValueAtIndex(
+        theArray: CFMutableArrayRef,
+        idx: CFIndex,
+        value: *const c_void,
+    );
+    pub fn CFArraySetValueAtIndex(theArray: CFMutableArrayRef, idx: CFIndex, value: *const c_void);
+    pub fn CFArrayRemoveValueAtIndex(theArray: CFMutableArrayRef, idx: CFIndex);
+    pub fn CFArrayRemoveAllValues(theArray: CFMutableArrayRef);
+    pub fn CFArrayReplaceValues(
+        theArray: CFMutableArrayRef,
+        range: CFRange,
+        newValues: *mut *const c_void,
+        newCount: CFIndex,
+    );
+    pub fn CFArrayExchangeValuesAtIndices(
+        theArray: CFMutableArrayRef,
+        idx1: CFIndex,
+        idx2: CFIndex,
+    );
+    pub fn CFArraySortValues(
+        theArray: CFMutableArrayRef,
+        range: CFRange,
+        comparator: CFComparatorFunction,
+        context: *mut c_void,
+    );
+    pub fn CFArrayAppendArray(
+        theArray: CFMutableArrayRef,
+        otherArray: CFArrayRef,
+        otherRange: CFRange,
+    );
+}
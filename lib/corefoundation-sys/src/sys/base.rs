@@ -5,6 +5,11 @@ pub type UInt8 = u8;
 pub type UniChar = u16;
 pub type UTF32Char = u32;
 
+/// A pointer to a Pascal string: a length byte followed by up to 255 bytes of characters.
+pub type StringPtr = *mut u8;
+pub type ConstStringPtr = *const u8;
+pub type ConstStr255Param = *const u8;
+
 pub type CFTypeID = usize;
 pub type CFOptionFlags = usize;
 pub type CFHashCode = usize;
@@ -13,7 +18,21 @@ pub type CFIndex = isize;
 /// Base "type" of all "CF objects", and polymorphic functions on them
 pub type CFTypeRef = *const c_void;
 
-declare_cf_type!(__CFString, CFStringRef, CFMutableStringRef);
+declare_cf_type!(__CFString, CFStringRef, CFMutableStringRef, type_id: crate::CFStringGetTypeID);
+
+/// Values returned from comparison functions.
+pub type CFComparisonResult = CFIndex;
+
+pub const kCFCompareLessThan: CFComparisonResult = -1;
+pub const kCFCompareEqualTo: CFComparisonResult = 0;
+pub const kCFCompareGreaterThan: CFComparisonResult = 1;
+
+/// A prototype for a function callback used for comparisons, such as in sorting.
+pub type CFComparatorFunction = extern "C" fn(
+    val1: *const c_void,
+    val2: *const c_void,
+    context: *mut c_void,
+) -> CFComparisonResult;
 
 /// Constant used by some functions to indicate failed searches.
 pub const kCFNotFound: CFIndex = -1;
@@ -58,21 +77,24 @@ extern "C" {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(C)]
 pub struct CFAllocatorContext {
-    version: CFIndex,
-    info: *mut c_void,
-    retain: extern "C" fn(info: *const c_void) -> *const c_void,
-    release: extern "C" fn(info: *const c_void),
-    copyDescription: extern "C" fn(info: *const c_void) -> CFStringRef,
-    allocate:
+    pub version: CFIndex,
+    pub info: *mut c_void,
+    pub retain: Option<extern "C" fn(info: *const c_void) -> *const c_void>,
+    pub release: Option<extern "C" fn(info: *const c_void)>,
+    pub copyDescription: Option<extern "C" fn(info: *const c_void) -> CFStringRef>,
+    pub allocate:
         extern "C" fn(allocSize: CFIndex, hint: CFOptionFlags, info: *mut c_void) -> *mut c_void,
-    reallocate: extern "C" fn(
-        ptr: *mut c_void,
-        newsize: CFIndex,
-        hint: CFOptionFlags,
-        info: *mut c_void,
-    ) -> *mut c_void,
-    deallocate: extern "C" fn(ptr: *mut c_void, info: *mut c_void),
-    preferredSize: extern "C" fn(size: CFIndex, hint: CFOptionFlags, info: *mut c_void) -> CFIndex,
+    pub reallocate: Option<
+        extern "C" fn(
+            ptr: *mut c_void,
+            newsize: CFIndex,
+            hint: CFOptionFlags,
+            info: *mut c_void,
+        ) -> *mut c_void,
+    >,
+    pub deallocate: Option<extern "C" fn(ptr: *mut c_void, info: *mut c_void)>,
+    pub preferredSize:
+        Option<extern "C" fn(size: CFIndex, hint: CFOptionFlags, info: *mut c_void) -> CFIndex>,
 }
 
 extern "C" {
@@ -81,8 +103,12 @@ extern "C" {
         context: &CFAllocatorContext,
     ) -> CFAllocatorRef;
 
+    pub fn CFGetTypeID(cf: CFTypeRef) -> CFTypeID;
+
     pub fn CFRetain(cf: CFTypeRef) -> CFTypeRef;
     pub fn CFRelease(cf: CFTypeRef);
+    pub fn CFGetRetainCount(cf: CFTypeRef) -> CFIndex;
+
     pub fn CFEqual(cf1: CFTypeRef, cf2: CFTypeRef) -> Boolean;
     pub fn CFHash(cf: CFTypeRef) -> CFHashCode;
     pub fn CFCopyDescription(cf: CFTypeRef) -> CFStringRef;
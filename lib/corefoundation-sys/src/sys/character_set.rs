@@ -0,0 +1,118 @@
+use crate::{
+    Boolean, CFAllocatorRef, CFDataRef, CFIndex, CFRange, CFStringRef, CFTypeID, UTF32Char, UniChar,
+};
+
+declare_cf_type!(
+    __CFCharacterSet,
+    CFCharacterSetRef,
+    CFMutableCharacterSetRef,
+    type_id: CFCharacterSetGetTypeID
+);
+
+/// Type of the predefined `CFCharacterSet` selector values.
+pub type CFCharacterSetPredefinedSet = CFIndex;
+
+/// Control character set (Unicode General Category Cc and Cf)
+pub const kCFCharacterSetControl: CFCharacterSetPredefinedSet = 1;
+/// Whitespace character set (Unicode General Category Zs and U0009 CHARACTER TABULATION)
+pub const kCFCharacterSetWhitespace: CFCharacterSetPredefinedSet = 2;
+/// Whitespace and Newline character set (Unicode General Category Z*, U000A ~ U000D, and U0085)
+pub const kCFCharacterSetWhitespaceAndNewline: CFCharacterSetPredefinedSet = 3;
+/// Decimal digit character set
+pub const kCFCharacterSetDecimalDigit: CFCharacterSetPredefinedSet = 4;
+/// Letter character set (Unicode General Category L* & M*)
+pub const kCFCharacterSetLetter: CFCharacterSetPredefinedSet = 5;
+/// Lowercase character set (Unicode General Category Ll)
+pub const kCFCharacterSetLowercaseLetter: CFCharacterSetPredefinedSet = 6;
+/// Uppercase character set (Unicode General Category Lu and Lt)
+pub const kCFCharacterSetUppercaseLetter: CFCharacterSetPredefinedSet = 7;
+/// Non-base character set (Unicode General Category M*)
+pub const kCFCharacterSetNonBase: CFCharacterSetPredefinedSet = 8;
+/// Canonically decomposable character set
+pub const kCFCharacterSetDecomposable: CFCharacterSetPredefinedSet = 9;
+/// Alpha Numeric character set (Unicode General Category L*, M*, & N*)
+pub const kCFCharacterSetAlphaNumeric: CFCharacterSetPredefinedSet = 10;
+/// Punctuation character set (Unicode General Category P*)
+pub const kCFCharacterSetPunctuation: CFCharacterSetPredefinedSet = 11;
+/// Illegal character set
+pub const kCFCharacterSetIllegal: CFCharacterSetPredefinedSet = 12;
+/// Titlecase character set (Unicode General Category Lt)
+pub const kCFCharacterSetCapitalizedLetter: CFCharacterSetPredefinedSet = 13;
+/// Symbol character set (Unicode General Category S*)
+pub const kCFCharacterSetSymbol: CFCharacterSetPredefinedSet = 14;
+/// Newline character set (U000A ~ U000D, U0085, U2028, and U2029)
+pub const kCFCharacterSetNewline: CFCharacterSetPredefinedSet = 15;
+
+extern "C" {
+    pub fn CFCharacterSetGetTypeID() -> CFTypeID;
+
+    /// Returns a predefined `CFCharacterSet` instance. The returned object follows the get rule.
+    pub fn CFCharacterSetGetPredefined(
+        theSetIdentifier: CFCharacterSetPredefinedSet,
+    ) -> CFCharacterSetRef;
+
+    /// `theRange` is specified in UTF-32 code points.
+    pub fn CFCharacterSetCreateWithCharactersInRange(
+        alloc: CFAllocatorRef,
+        theRange: CFRange,
+    ) -> CFCharacterSetRef;
+    pub fn CFCharacterSetCreateWithCharactersInString(
+        alloc: CFAllocatorRef,
+        theString: CFStringRef,
+    ) -> CFCharacterSetRef;
+    pub fn CFCharacterSetCreateWithBitmapRepresentation(
+        alloc: CFAllocatorRef,
+        theData: CFDataRef,
+    ) -> CFCharacterSetRef;
+    pub fn CFCharacterSetCreateInvertedSet(
+        alloc: CFAllocatorRef,
+        theSet: CFCharacterSetRef,
+    ) -> CFCharacterSetRef;
+
+    pub fn CFCharacterSetIsSupersetOfSet(
+        theSet: CFCharacterSetRef,
+        theOtherset: CFCharacterSetRef,
+    ) -> Boolean;
+    pub fn CFCharacterSetHasMemberInPlane(theSet: CFCharacterSetRef, thePlane: CFIndex) -> Boolean;
+
+    pub fn CFCharacterSetCreateMutable(alloc: CFAllocatorRef) -> CFMutableCharacterSetRef;
+    pub fn CFCharacterSetCreateCopy(
+        alloc: CFAllocatorRef,
+        theSet: CFCharacterSetRef,
+    ) -> CFCharacterSetRef;
+    pub fn CFCharacterSetCreateMutableCopy(
+        alloc: CFAllocatorRef,
+        theSet: CFCharacterSetRef,
+    ) -> CFMutableCharacterSetRef;
+
+    pub fn CFCharacterSetIsCharacterMember(theSet: CFCharacterSetRef, theChar: UniChar) -> Boolean;
+    pub fn CFCharacterSetIsLongCharacterMember(
+        theSet: CFCharacterSetRef,
+        theChar: UTF32Char,
+    ) -> Boolean;
+
+    pub fn CFCharacterSetCreateBitmapRepresentation(
+        alloc: CFAllocatorRef,
+        theSet: CFCharacterSetRef,
+    ) -> CFDataRef;
+
+    pub fn CFCharacterSetAddCharactersInRange(theSet: CFMutableCharacterSetRef, theRange: CFRange);
+    pub fn CFCharacterSetRemoveCharactersInRange(
+        theSet: CFMutableCharacterSetRef,
+        theRange: CFRange,
+    );
+    pub fn CFCharacterSetAddCharactersInString(
+        theSet: CFMutableCharacterSetRef,
+        theString: CFStringRef,
+    );
+    pub fn CFCharacterSetRemoveCharactersInString(
+        theSet: CFMutableCharacterSetRef,
+        theString: CFStringRef,
+    );
+    pub fn CFCharacterSetUnion(theSet: CFMutableCharacterSetRef, theOtherSet: CFCharacterSetRef);
+    pub fn CFCharacterSetIntersect(
+        theSet: CFMutableCharacterSetRef,
+        theOtherSet: CFCharacterSetRef,
+    );
+    pub fn CFCharacterSetInvert(theSet: CFMutableCharacterSetRef);
+}
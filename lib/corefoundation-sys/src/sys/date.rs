@@ -0,0 +1,33 @@
+use crate::{c_void, CFAllocatorRef, CFComparisonResult, CFTypeID};
+
+/// Time intervals are measured in seconds.
+pub type CFTimeInterval = f64;
+
+/// Absolute time is measured in seconds relative to the absolute reference date of Jan 1 2001
+/// 00:00:00 GMT.
+pub type CFAbsoluteTime = CFTimeInterval;
+
+declare_cf_type!(__CFDate, CFDateRef, type_id: CFDateGetTypeID);
+
+extern "C" {
+    /// The number of seconds between the absolute reference date and Jan 1 1970 00:00:00 GMT.
+    pub static kCFAbsoluteTimeIntervalSince1970: CFTimeInterval;
+    /// The number of seconds between the absolute reference date and Jan 1 1904 00:00:00 GMT.
+    pub static kCFAbsoluteTimeIntervalSince1904: CFTimeInterval;
+
+    pub fn CFAbsoluteTimeGetCurrent() -> CFAbsoluteTime;
+
+    pub fn CFDateGetTypeID() -> CFTypeID;
+
+    pub fn CFDateCreate(allocator: CFAllocatorRef, at: CFAbsoluteTime) -> CFDateRef;
+    pub fn CFDateGetAbsoluteTime(theDate: CFDateRef) -> CFAbsoluteTime;
+    pub fn CFDateGetTimeIntervalSinceDate(
+        theDate: CFDateRef,
+        otherDate: CFDateRef,
+    ) -> CFTimeInterval;
+    pub fn CFDateCompare(
+        theDate: CFDateRef,
+        otherDate: CFDateRef,
+        context: *mut c_void,
+    ) -> CFComparisonResult;
+}
@@ -0,0 +1,125 @@
+use crate::{c_void, Boolean, CFAllocatorRef, CFHashCode, CFIndex, CFStringRef, CFTypeID};
+
+pub type CFDictionaryRetainCallBack =
+    extern "C" fn(allocator: CFAllocatorRef, value: *const c_void) -> *const c_void;
+pub type CFDictionaryReleaseCallBack =
+    extern "C" fn(allocator: CFAllocatorRef, value: *const c_void);
+pub type CFDictionaryCopyDescriptionCallBack = extern "C" fn(value: *const c_void) -> CFStringRef;
+pub type CFDictionaryEqualCallBack =
+    extern "C" fn(value1: *const c_void, value2: *const c_void) -> Boolean;
+pub type CFDictionaryHashCallBack = extern "C" fn(value: *const c_void) -> CFHashCode;
+
+/// Structure containing the callbacks for keys of a `CFDictionary`.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CFDictionaryKeyCallBacks {
+    pub version: CFIndex,
+    pub retain: Option<CFDictionaryRetainCallBack>,
+    pub release: Option<CFDictionaryReleaseCallBack>,
+    pub copyDescription: Option<CFDictionaryCopyDescriptionCallBack>,
+    pub equal: Option<CFDictionaryEqualCallBack>,
+    pub hash: Option<CFDictionaryHashCallBack>,
+}
+
+/// Structure containing the callbacks for values of a `CFDictionary`.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CFDictionaryValueCallBacks {
+    pub version: CFIndex,
+    pub retain: Option<CFDictionaryRetainCallBack>,
+    pub release: Option<CFDictionaryReleaseCallBack>,
+    pub copyDescription: Option<CFDictionaryCopyDescriptionCallBack>,
+    pub equal: Option<CFDictionaryEqualCallBack>,
+}
+
+/// Type of the callback function used by the apply functions of `CFDictionary`s.
+pub type CFDictionaryApplierFunction =
+    extern "C" fn(key: *const c_void, value: *const c_void, context: *mut c_void);
+
+declare_cf_type!(
+    __CFDictionary,
+    CFDictionaryRef,
+    CFMutableDictionaryRef,
+    type_id: CFDictionaryGetTypeID
+);
+
+extern "C" {
+    /// Predefined [`CFDictionaryKeyCallBacks`] structure containing a set of callbacks appropriate
+    /// for use when the keys of a `CFDictionary` are all CF types.
+    pub static kCFTypeDictionaryKeyCallBacks: CFDictionaryKeyCallBacks;
+
+    /// Predefined [`CFDictionaryKeyCallBacks`] structure containing a set of callbacks appropriate
+    /// for use when the keys of a `CFDictionary` are all `CFString`s, which may be mutable and need
+    /// to be copied in order to serve as constant keys for the values in the dictionary.
+    pub static kCFCopyStringDictionaryKeyCallBacks: CFDictionaryKeyCallBacks;
+
+    /// Predefined [`CFDictionaryValueCallBacks`] structure containing a set of callbacks
+    /// appropriate for use when the values in a `CFDictionary` are all CF types.
+    pub static kCFTypeDictionaryValueCallBacks: CFDictionaryValueCallBacks;
+
+    pub fn CFDictionaryGetTypeID() -> CFTypeID;
+
+    pub fn CFDictionaryCreate(
+        allocator: CFAllocatorRef,
+        keys: *const *const c_void,
+        values: *const *const c_void,
+        numValues: CFIndex,
+        keyCallBacks: *const CFDictionaryKeyCallBacks,
+        valueCallBacks: *const CFDictionaryValueCallBacks,
+    ) -> CFDictionaryRef;
+    pub fn CFDictionaryCreateCopy(
+        allocator: CFAllocatorRef,
+        theDict: CFDictionaryRef,
+    ) -> CFDictionaryRef;
+    pub fn CFDictionaryCreateMutable(
+        allocator: CFAllocatorRef,
+        capacity: CFIndex,
+        keyCallBacks: *const CFDictionaryKeyCallBacks,
+        valueCallBacks: *const CFDictionaryValueCallBacks,
+    ) -> CFMutableDictionaryRef;
+    pub fn CFDictionaryCreateMutableCopy(
+        allocator: CFAllocatorRef,
+        capacity: CFIndex,
+        theDict: CFDictionaryRef,
+    ) -> CFMutableDictionaryRef;
+
+    pub fn CFDictionaryGetCount(theDict: CFDictionaryRef) -> CFIndex;
+    pub fn CFDictionaryGetCountOfKey(theDict: CFDictionaryRef, key: *const c_void) -> CFIndex;
+    pub fn CFDictionaryGetCountOfValue(theDict: CFDictionaryRef, value: *const c_void) -> CFIndex;
+    pub fn CFDictionaryContainsKey(theDict: CFDictionaryRef, key: *const c_void) -> Boolean;
+    pub fn CFDictionaryContainsValue(theDict: CFDictionaryRef, value: *const c_void) -> Boolean;
+    pub fn CFDictionaryGetValue(theDict: CFDictionaryRef, key: *const c_void) -> *const c_void;
+    pub fn CFDictionaryGetValueIfPresent(
+        theDict: CFDictionaryRef,
+        key: *const c_void,
+        value: *mut *const c_void,
+    ) -> Boolean;
+    pub fn CFDictionaryGetKeysAndValues(
+        theDict: CFDictionaryRef,
+        keys: *mut *const c_void,
+        values: *mut *const c_void,
+    );
+    pub fn CFDictionaryApplyFunction(
+        theDict: CFDictionaryRef,
+        applier: CFDictionaryApplierFunction,
+        context: *mut c_void,
+    );
+
+    pub fn CFDictionaryAddValue(
+        theDict: CFMutableDictionaryRef,
+        key: *const c_void,
+        value: *const c_void,
+    );
+    pub fn CFDictionarySetValue(
+        theDict: CFMutableDictionaryRef,
+        key: *const c_void,
+        value: *const c_void,
+    );
+    pub fn CFDictionaryReplaceValue(
+        theDict: CFMutableDictionaryRef,
+        key: *const c_void,
+        value: *const c_void,
+    );
+    pub fn CFDictionaryRemoveValue(theDict: CFMutableDictionaryRef, key: *const c_void);
+    pub fn CFDictionaryRemoveAllValues(theDict: CFMutableDictionaryRef);
+}
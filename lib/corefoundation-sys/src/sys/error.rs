@@ -0,0 +1,74 @@
+use crate::{c_void, CFAllocatorRef, CFDictionaryRef, CFIndex, CFStringRef, CFTypeID};
+use availability::availability;
+
+/// Type of the domain of a `CFError`.
+pub type CFErrorDomain = CFStringRef;
+
+declare_cf_type!(__CFError, CFErrorRef, type_id: CFErrorGetTypeID);
+
+extern "C" {
+    /// POSIX errno values.
+    pub static kCFErrorDomainPOSIX: CFErrorDomain;
+    /// Mac OS error codes.
+    pub static kCFErrorDomainOSStatus: CFErrorDomain;
+    /// Mach error codes.
+    pub static kCFErrorDomainMach: CFErrorDomain;
+    /// Cocoa error codes.
+    pub static kCFErrorDomainCocoa: CFErrorDomain;
+
+    /// Key to identify the end user-presentable description in `userInfo`.
+    pub static kCFErrorLocalizedDescriptionKey: CFStringRef;
+    /// Key to identify the end user-presentable failing operation ("what failed") description in
+    /// `userInfo`.
+    #[availability(macos = "10.13", ios = "11.0", tvos = "11.0", watchos = "4.0")]
+    pub static kCFErrorLocalizedFailureKey: CFStringRef;
+    /// Key to identify the end user-presentable failure reason ("why it failed") in `userInfo`.
+    pub static kCFErrorLocalizedFailureReasonKey: CFStringRef;
+    /// Key to identify the end user-presentable recovery suggestion in `userInfo`.
+    pub static kCFErrorLocalizedRecoverySuggestionKey: CFStringRef;
+    /// Key to identify the description in the `userInfo` dictionary. Should be a complete sentence
+    /// if possible. Should not contain domain name or error code.
+    pub static kCFErrorDescriptionKey: CFStringRef;
+    /// Key to identify the underlying error in `userInfo`.
+    pub static kCFErrorUnderlyingErrorKey: CFStringRef;
+    /// Key to identify associated URL in `userInfo`.
+    pub static kCFErrorURLKey: CFStringRef;
+    /// Key to identify associated file path in `userInfo`.
+    pub static kCFErrorFilePathKey: CFStringRef;
+
+    pub fn CFErrorGetTypeID() -> CFTypeID;
+
+    /// Creates a new `CFError`. `domain` and `code` are required; `userInfo` may be `NULL`.
+    pub fn CFErrorCreate(
+        allocator: CFAllocatorRef,
+        domain: CFErrorDomain,
+        code: CFIndex,
+        userInfo: CFDictionaryRef,
+    ) -> CFErrorRef;
+
+    /// Creates a new `CFError` without having to create an intermediate `userInfo` dictionary.
+    pub fn CFErrorCreateWithUserInfoKeysAndValues(
+        allocator: CFAllocatorRef,
+        domain: CFErrorDomain,
+        code: CFIndex,
+        userInfoKeys: *const *const c_void,
+        userInfoValues: *const *const c_void,
+        numUserInfoValues: CFIndex,
+    ) -> CFErrorRef;
+
+    pub fn CFErrorGetDomain(err: CFErrorRef) -> CFErrorDomain;
+    pub fn CFErrorGetCode(err: CFErrorRef) -> CFIndex;
+
+    /// Returns `CFError` user info dictionary. Never returns `NULL`; if there is no `userInfo`, an
+    /// empty dictionary is returned.
+    pub fn CFErrorCopyUserInfo(err: CFErrorRef) -> CFDictionaryRef;
+
+    /// Returns a human-presentable description of the error. Never returns `NULL`.
+    pub fn CFErrorCopyDescription(err: CFErrorRef) -> CFStringRef;
+
+    /// Returns a human-presentable failure reason for the error. May return `NULL`.
+    pub fn CFErrorCopyFailureReason(err: CFErrorRef) -> CFStringRef;
+
+    /// Returns a human presentable recovery suggestion for the error. May return `NULL`.
+    pub fn CFErrorCopyRecoverySuggestion(err: CFErrorRef) -> CFStringRef;
+}
@@ -0,0 +1,60 @@
+use crate::{
+    Boolean, CFAllocatorRef, CFIndex, CFOptionFlags, CFRunLoopSourceRef, CFStringRef, CFTypeID,
+};
+use core::ffi::{c_int, c_void};
+
+pub type CFFileDescriptorNativeDescriptor = c_int;
+
+declare_cf_type!(
+    __CFFileDescriptor,
+    CFFileDescriptorRef,
+    type_id: CFFileDescriptorGetTypeID
+);
+
+pub const kCFFileDescriptorReadCallBack: CFOptionFlags = 1 << 0;
+pub const kCFFileDescriptorWriteCallBack: CFOptionFlags = 1 << 1;
+
+pub type CFFileDescriptorCallBack =
+    extern "C" fn(f: CFFileDescriptorRef, callBackTypes: CFOptionFlags, info: *mut c_void);
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CFFileDescriptorContext {
+    pub version: CFIndex,
+    pub info: *mut c_void,
+    pub retain: Option<extern "C" fn(info: *mut c_void) -> *mut c_void>,
+    pub release: Option<extern "C" fn(info: *mut c_void)>,
+    pub copyDescription: Option<extern "C" fn(info: *mut c_void) -> CFStringRef>,
+}
+
+extern "C" {
+    pub fn CFFileDescriptorGetTypeID() -> CFTypeID;
+
+    pub fn CFFileDescriptorCreate(
+        allocator: CFAllocatorRef,
+        fd: CFFileDescriptorNativeDescriptor,
+        closeOnInvalidate: Boolean,
+        callout: CFFileDescriptorCallBack,
+        context: &CFFileDescriptorContext,
+    ) -> CFFileDescriptorRef;
+
+    pub fn CFFileDescriptorGetNativeDescriptor(
+        f: CFFileDescriptorRef,
+    ) -> CFFileDescriptorNativeDescriptor;
+    pub fn CFFileDescriptorGetContext(
+        f: CFFileDescriptorRef,
+        context: *mut CFFileDescriptorContext,
+    );
+
+    pub fn CFFileDescriptorEnableCallBacks(f: CFFileDescriptorRef, callBackTypes: CFOptionFlags);
+    pub fn CFFileDescriptorDisableCallBacks(f: CFFileDescriptorRef, callBackTypes: CFOptionFlags);
+
+    pub fn CFFileDescriptorInvalidate(f: CFFileDescriptorRef);
+    pub fn CFFileDescriptorIsValid(f: CFFileDescriptorRef) -> Boolean;
+
+    pub fn CFFileDescriptorCreateRunLoopSource(
+        allocator: CFAllocatorRef,
+        f: CFFileDescriptorRef,
+        order: CFIndex,
+    ) -> CFRunLoopSourceRef;
+}
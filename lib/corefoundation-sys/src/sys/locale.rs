@@ -0,0 +1,25 @@
+use crate::{CFAllocatorRef, CFStringRef, CFTypeID};
+
+declare_cf_type!(__CFLocale, CFLocaleRef, type_id: CFLocaleGetTypeID);
+
+/// A string that identifies a locale, e.g., `en_US`.
+pub type CFLocaleIdentifier = CFStringRef;
+
+extern "C" {
+    pub fn CFLocaleGetTypeID() -> CFTypeID;
+
+    /// Returns the generic root locale, which has no language or region. The returned object
+    /// follows the get rule.
+    pub fn CFLocaleGetSystem() -> CFLocaleRef;
+
+    /// Returns a copy of the logical locale for the current user.
+    pub fn CFLocaleCopyCurrent() -> CFLocaleRef;
+
+    pub fn CFLocaleCreate(
+        allocator: CFAllocatorRef,
+        localeIdentifier: CFLocaleIdentifier,
+    ) -> CFLocaleRef;
+
+    /// Returns the locale's identifier. The returned string follows the get rule.
+    pub fn CFLocaleGetIdentifier(locale: CFLocaleRef) -> CFLocaleIdentifier;
+}
@@ -0,0 +1,57 @@
+use crate::{Boolean, CFAllocatorRef, CFIndex, CFRunLoopSourceRef, CFStringRef, CFTypeID};
+use core::ffi::c_void;
+
+/// A Mach port name, as defined by `<mach/port.h>`.
+pub type mach_port_t = u32;
+
+declare_cf_type!(__CFMachPort, CFMachPortRef, type_id: CFMachPortGetTypeID);
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CFMachPortContext {
+    pub version: CFIndex,
+    pub info: *mut c_void,
+    pub retain: Option<extern "C" fn(info: *const c_void) -> *const c_void>,
+    pub release: Option<extern "C" fn(info: *const c_void)>,
+    pub copyDescription: Option<extern "C" fn(info: *const c_void) -> CFStringRef>,
+}
+
+pub type CFMachPortCallBack =
+    extern "C" fn(port: CFMachPortRef, msg: *mut c_void, size: CFIndex, info: *mut c_void);
+pub type CFMachPortInvalidationCallBack = extern "C" fn(port: CFMachPortRef, info: *mut c_void);
+
+extern "C" {
+    pub fn CFMachPortGetTypeID() -> CFTypeID;
+
+    pub fn CFMachPortCreate(
+        allocator: CFAllocatorRef,
+        callout: CFMachPortCallBack,
+        context: &CFMachPortContext,
+        shouldFreeInfo: *mut Boolean,
+    ) -> CFMachPortRef;
+    pub fn CFMachPortCreateWithPort(
+        allocator: CFAllocatorRef,
+        portNum: mach_port_t,
+        callout: CFMachPortCallBack,
+        context: &CFMachPortContext,
+        shouldFreeInfo: *mut Boolean,
+    ) -> CFMachPortRef;
+
+    pub fn CFMachPortGetPort(port: CFMachPortRef) -> mach_port_t;
+    pub fn CFMachPortGetContext(port: CFMachPortRef, context: *mut CFMachPortContext);
+    pub fn CFMachPortInvalidate(port: CFMachPortRef);
+    pub fn CFMachPortIsValid(port: CFMachPortRef) -> Boolean;
+    pub fn CFMachPortGetInvalidationCallBack(
+        port: CFMachPortRef,
+    ) -> Option<CFMachPortInvalidationCallBack>;
+    pub fn CFMachPortSetInvalidationCallBack(
+        port: CFMachPortRef,
+        callout: Option<CFMachPortInvalidationCallBack>,
+    );
+
+    pub fn CFMachPortCreateRunLoopSource(
+        allocator: CFAllocatorRef,
+        port: CFMachPortRef,
+        order: CFIndex,
+    ) -> CFRunLoopSourceRef;
+}
@@ -0,0 +1,86 @@
+use crate::{
+    Boolean, CFAllocatorRef, CFDataRef, CFIndex, CFRunLoopSourceRef, CFStringRef, CFTimeInterval,
+    CFTypeID,
+};
+use core::ffi::c_void;
+
+declare_cf_type!(__CFMessagePort, CFMessagePortRef, type_id: CFMessagePortGetTypeID);
+
+/// The message was successfully sent and, if a reply was expected, a reply was received.
+pub const kCFMessagePortSuccess: i32 = 0;
+/// The message could not be sent before the send timeout.
+pub const kCFMessagePortSendTimeout: i32 = -1;
+/// No reply was received before the receive timeout.
+pub const kCFMessagePortReceiveTimeout: i32 = -2;
+/// The message could not be sent because the message port is invalid.
+pub const kCFMessagePortIsInvalid: i32 = -3;
+/// An error occurred trying to send the message.
+pub const kCFMessagePortTransportError: i32 = -4;
+/// The message port was invalidated.
+pub const kCFMessagePortBecameInvalidError: i32 = -5;
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CFMessagePortContext {
+    pub version: CFIndex,
+    pub info: *mut c_void,
+    pub retain: Option<extern "C" fn(info: *const c_void) -> *const c_void>,
+    pub release: Option<extern "C" fn(info: *const c_void)>,
+    pub copyDescription: Option<extern "C" fn(info: *const c_void) -> CFStringRef>,
+}
+
+pub type CFMessagePortCallBack = extern "C" fn(
+    local: CFMessagePortRef,
+    msgid: i32,
+    data: CFDataRef,
+    info: *mut c_void,
+) -> CFDataRef;
+pub type CFMessagePortInvalidationCallBack = extern "C" fn(ms: CFMessagePortRef, info: *mut c_void);
+
+extern "C" {
+    pub fn CFMessagePortGetTypeID() -> CFTypeID;
+
+    pub fn CFMessagePortCreateLocal(
+        allocator: CFAllocatorRef,
+        name: CFStringRef,
+        callout: CFMessagePortCallBack,
+        context: &CFMessagePortContext,
+        shouldFreeInfo: *mut Boolean,
+    ) -> CFMessagePortRef;
+    pub fn CFMessagePortCreateRemote(
+        allocator: CFAllocatorRef,
+        name: CFStringRef,
+    ) -> CFMessagePortRef;
+
+    pub fn CFMessagePortIsRemote(ms: CFMessagePortRef) -> Boolean;
+    pub fn CFMessagePortGetName(ms: CFMessagePortRef) -> CFStringRef;
+    pub fn CFMessagePortSetName(ms: CFMessagePortRef, newName: CFStringRef) -> Boolean;
+    pub fn CFMessagePortGetContext(ms: CFMessagePortRef, context: *mut CFMessagePortContext);
+    pub fn CFMessagePortInvalidate(ms: CFMessagePortRef);
+    pub fn CFMessagePortIsValid(ms: CFMessagePortRef) -> Boolean;
+    pub fn CFMessagePortGetInvalidationCallBack(
+        ms: CFMessagePortRef,
+    ) -> Option<CFMessagePortInvalidationCallBack>;
+    pub fn CFMessagePortSetInvalidationCallBack(
+        ms: CFMessagePortRef,
+        callout: Option<CFMessagePortInvalidationCallBack>,
+    );
+
+    /// Sends a message to the `remote` port. If `replyMode` is non-`NULL`, the function waits for
+    /// a reply, running the current thread's run loop in `replyMode`.
+    pub fn CFMessagePortSendRequest(
+        remote: CFMessagePortRef,
+        msgid: i32,
+        data: CFDataRef,
+        sendTimeout: CFTimeInterval,
+        rcvTimeout: CFTimeInterval,
+        replyMode: CFStringRef,
+        returnData: *mut CFDataRef,
+    ) -> i32;
+
+    pub fn CFMessagePortCreateRunLoopSource(
+        allocator: CFAllocatorRef,
+        local: CFMessagePortRef,
+        order: CFIndex,
+    ) -> CFRunLoopSourceRef;
+}
@@ -0,0 +1,100 @@
+use crate::{c_void, Boolean, CFAllocatorRef, CFComparisonResult, CFIndex, CFTypeID};
+
+declare_cf_type!(__CFBoolean, CFBooleanRef, type_id: CFBooleanGetTypeID);
+
+/// Type of the `CFNumber` value storage types.
+pub type CFNumberType = CFIndex;
+
+/// Fixed-width type
+pub const kCFNumberSInt8Type: CFNumberType = 1;
+/// Fixed-width type
+pub const kCFNumberSInt16Type: CFNumberType = 2;
+/// Fixed-width type
+pub const kCFNumberSInt32Type: CFNumberType = 3;
+/// Fixed-width type
+pub const kCFNumberSInt64Type: CFNumberType = 4;
+/// Fixed-width type
+pub const kCFNumberFloat32Type: CFNumberType = 5;
+/// 64-bit IEEE 754
+pub const kCFNumberFloat64Type: CFNumberType = 6;
+/// Basic C type
+pub const kCFNumberCharType: CFNumberType = 7;
+/// Basic C type
+pub const kCFNumberShortType: CFNumberType = 8;
+/// Basic C type
+pub const kCFNumberIntType: CFNumberType = 9;
+/// Basic C type
+pub const kCFNumberLongType: CFNumberType = 10;
+/// Basic C type
+pub const kCFNumberLongLongType: CFNumberType = 11;
+/// Basic C type
+pub const kCFNumberFloatType: CFNumberType = 12;
+/// Basic C type
+pub const kCFNumberDoubleType: CFNumberType = 13;
+/// Other
+pub const kCFNumberCFIndexType: CFNumberType = 14;
+/// Other
+pub const kCFNumberNSIntegerType: CFNumberType = 15;
+/// Other
+pub const kCFNumberCGFloatType: CFNumberType = 16;
+pub const kCFNumberMaxType: CFNumberType = 16;
+
+declare_cf_type!(__CFNumber, CFNumberRef, type_id: CFNumberGetTypeID);
+
+extern "C" {
+    pub static kCFBooleanTrue: CFBooleanRef;
+    pub static kCFBooleanFalse: CFBooleanRef;
+
+    pub fn CFBooleanGetTypeID() -> CFTypeID;
+    pub fn CFBooleanGetValue(boolean: CFBooleanRef) -> Boolean;
+
+    pub static kCFNumberPositiveInfinity: CFNumberRef;
+    pub static kCFNumberNegativeInfinity: CFNumberRef;
+    pub static kCFNumberNaN: CFNumberRef;
+
+    pub fn CFNumberGetTypeID() -> CFTypeID;
+
+    /// Creates a `CFNumber` with the given value. The type of number pointed to by the `valuePtr`
+    /// is specified by type. If type is a floating point type and the value represents one of the
+    /// infinities or NaN, the well-defined `CFNumber` for that value is returned.
+    pub fn CFNumberCreate(
+        allocator: CFAllocatorRef,
+        theType: CFNumberType,
+        valuePtr: *const c_void,
+    ) -> CFNumberRef;
+
+    /// Returns the storage format of the `CFNumber`'s value. Note that this is not necessarily the
+    /// type provided in [`CFNumberCreate`].
+    pub fn CFNumberGetType(number: CFNumberRef) -> CFNumberType;
+
+    /// Returns the size in bytes of the type of the number.
+    pub fn CFNumberGetByteSize(number: CFNumberRef) -> CFIndex;
+
+    /// Returns true if the type of the `CFNumber`'s value is one of the defined floating point
+    /// types.
+    pub fn CFNumberIsFloatType(number: CFNumberRef) -> Boolean;
+
+    /// Copies the `CFNumber`'s value into the space pointed to by `valuePtr`, as the specified
+    /// type. If conversion needs to take place, the conversion rules follow human expectation and
+    /// not C's promotion and truncation rules. If the conversion is lossy, or the value is out of
+    /// range, false is returned. Best attempt at conversion will still be in `*valuePtr`.
+    pub fn CFNumberGetValue(
+        number: CFNumberRef,
+        theType: CFNumberType,
+        valuePtr: *mut c_void,
+    ) -> Boolean;
+
+    /// Compares the two `CFNumber` instances. If conversion of the types of the values is needed,
+    /// the conversion and comparison follow human expectations and not C's promotion and
+    /// comparison rules. Negative zero compares less than positive zero. Positive infinity
+    /// compares greater than everything except itself, to which it compares equal. Negative
+    /// infinity compares less than everything except itself, to which it compares equal. Unlike
+    /// standard practice, if both numbers are NaN, then they compare equal; if only one of the
+    /// numbers is NaN, then the NaN compares greater than the other number if it is negative, and
+    /// smaller than the other number if it is positive.
+    pub fn CFNumberCompare(
+        number: CFNumberRef,
+        otherNumber: CFNumberRef,
+        context: *mut c_void,
+    ) -> CFComparisonResult;
+}
@@ -0,0 +1,75 @@
+use crate::{Boolean, CFAllocatorRef, CFHashCode, CFIndex, CFStringRef, CFTimeInterval, CFTypeID};
+use core::ffi::c_void;
+
+declare_cf_type!(__CFRunLoop, CFRunLoopRef, type_id: CFRunLoopGetTypeID);
+declare_cf_type!(__CFRunLoopSource, CFRunLoopSourceRef, type_id: CFRunLoopSourceGetTypeID);
+
+pub type CFRunLoopMode = CFStringRef;
+
+/// Reasons for `CFRunLoopRunInMode()` to Return
+pub type CFRunLoopRunResult = i32;
+
+/// The running run loop mode has no sources or timers to process.
+pub const kCFRunLoopRunFinished: CFRunLoopRunResult = 1;
+/// `CFRunLoopStop()` was called on the run loop.
+pub const kCFRunLoopRunStopped: CFRunLoopRunResult = 2;
+/// The time interval `seconds` passed.
+pub const kCFRunLoopRunTimedOut: CFRunLoopRunResult = 3;
+/// A source was processed. This exit condition only applies when `returnAfterSourceHandled` is
+/// `true`.
+pub const kCFRunLoopRunHandledSource: CFRunLoopRunResult = 4;
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CFRunLoopSourceContext {
+    pub version: CFIndex,
+    pub info: *mut c_void,
+    pub retain: Option<extern "C" fn(info: *const c_void) -> *const c_void>,
+    pub release: Option<extern "C" fn(info: *const c_void)>,
+    pub copyDescription: Option<extern "C" fn(info: *const c_void) -> CFStringRef>,
+    pub equal: Option<extern "C" fn(info1: *const c_void, info2: *const c_void) -> Boolean>,
+    pub hash: Option<extern "C" fn(info: *const c_void) -> CFHashCode>,
+    pub schedule: Option<extern "C" fn(info: *mut c_void, rl: CFRunLoopRef, mode: CFRunLoopMode)>,
+    pub cancel: Option<extern "C" fn(info: *mut c_void, rl: CFRunLoopRef, mode: CFRunLoopMode)>,
+    pub perform: Option<extern "C" fn(info: *mut c_void)>,
+}
+
+extern "C" {
+    pub static kCFRunLoopDefaultMode: CFRunLoopMode;
+    pub static kCFRunLoopCommonModes: CFRunLoopMode;
+
+    pub fn CFRunLoopGetTypeID() -> CFTypeID;
+
+    pub fn CFRunLoopGetCurrent() -> CFRunLoopRef;
+    pub fn CFRunLoopGetMain() -> CFRunLoopRef;
+
+    pub fn CFRunLoopRun();
+    pub fn CFRunLoopRunInMode(
+        mode: CFRunLoopMode,
+        seconds: CFTimeInterval,
+        returnAfterSourceHandled: Boolean,
+    ) -> CFRunLoopRunResult;
+    pub fn CFRunLoopIsWaiting(rl: CFRunLoopRef) -> Boolean;
+    pub fn CFRunLoopWakeUp(rl: CFRunLoopRef);
+    pub fn CFRunLoopStop(rl: CFRunLoopRef);
+
+    pub fn CFRunLoopContainsSource(
+        rl: CFRunLoopRef,
+        source: CFRunLoopSourceRef,
+        mode: CFRunLoopMode,
+    ) -> Boolean;
+    pub fn CFRunLoopAddSource(rl: CFRunLoopRef, source: CFRunLoopSourceRef, mode: CFRunLoopMode);
+    pub fn CFRunLoopRemoveSource(rl: CFRunLoopRef, source: CFRunLoopSourceRef, mode: CFRunLoopMode);
+
+    pub fn CFRunLoopSourceGetTypeID() -> CFTypeID;
+
+    pub fn CFRunLoopSourceCreate(
+        allocator: CFAllocatorRef,
+        order: CFIndex,
+        context: *mut CFRunLoopSourceContext,
+    ) -> CFRunLoopSourceRef;
+    pub fn CFRunLoopSourceGetOrder(source: CFRunLoopSourceRef) -> CFIndex;
+    pub fn CFRunLoopSourceInvalidate(source: CFRunLoopSourceRef);
+    pub fn CFRunLoopSourceIsValid(source: CFRunLoopSourceRef) -> Boolean;
+    pub fn CFRunLoopSourceSignal(source: CFRunLoopSourceRef);
+}
@@ -0,0 +1,86 @@
+use crate::{Boolean, CFAllocatorRef, CFErrorRef, CFIndex, CFStringRef, CFTypeID, UInt8};
+
+declare_cf_type!(__CFReadStream, CFReadStreamRef, type_id: CFReadStreamGetTypeID);
+declare_cf_type!(__CFWriteStream, CFWriteStreamRef, type_id: CFWriteStreamGetTypeID);
+
+pub type CFStreamStatus = CFIndex;
+
+pub const kCFStreamStatusNotOpen: CFStreamStatus = 0;
+pub const kCFStreamStatusOpening: CFStreamStatus = 1;
+pub const kCFStreamStatusOpen: CFStreamStatus = 2;
+pub const kCFStreamStatusReading: CFStreamStatus = 3;
+pub const kCFStreamStatusWriting: CFStreamStatus = 4;
+pub const kCFStreamStatusAtEnd: CFStreamStatus = 5;
+pub const kCFStreamStatusClosed: CFStreamStatus = 6;
+pub const kCFStreamStatusError: CFStreamStatus = 7;
+
+pub type CFStreamErrorDomain = CFIndex;
+
+pub const kCFStreamErrorDomainCustom: CFStreamErrorDomain = -1;
+pub const kCFStreamErrorDomainPOSIX: CFStreamErrorDomain = 1;
+pub const kCFStreamErrorDomainMacOSStatus: CFStreamErrorDomain = 2;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(C)]
+pub struct CFStreamError {
+    pub domain: CFIndex,
+    pub error: i32,
+}
+
+extern "C" {
+    pub fn CFReadStreamGetTypeID() -> CFTypeID;
+    pub fn CFWriteStreamGetTypeID() -> CFTypeID;
+
+    /// Creates a pair of streams connected to each other through a buffer of `transferBufferSize`
+    /// bytes: bytes written to `writeStream` are read from `readStream`.
+    pub fn CFStreamCreateBoundPair(
+        alloc: CFAllocatorRef,
+        readStream: *mut CFReadStreamRef,
+        writeStream: *mut CFWriteStreamRef,
+        transferBufferSize: CFIndex,
+    );
+    /// Creates a pair of streams connected to a TCP socket on `port` of `host`. Either stream
+    /// pointer may be `NULL`.
+    pub fn CFStreamCreatePairWithSocketToHost(
+        alloc: CFAllocatorRef,
+        host: CFStringRef,
+        port: u32,
+        readStream: *mut CFReadStreamRef,
+        writeStream: *mut CFWriteStreamRef,
+    );
+
+    pub fn CFReadStreamGetStatus(stream: CFReadStreamRef) -> CFStreamStatus;
+    pub fn CFWriteStreamGetStatus(stream: CFWriteStreamRef) -> CFStreamStatus;
+
+    pub fn CFReadStreamCopyError(stream: CFReadStreamRef) -> CFErrorRef;
+    pub fn CFWriteStreamCopyError(stream: CFWriteStreamRef) -> CFErrorRef;
+    pub fn CFReadStreamGetError(stream: CFReadStreamRef) -> CFStreamError;
+    pub fn CFWriteStreamGetError(stream: CFWriteStreamRef) -> CFStreamError;
+
+    pub fn CFReadStreamOpen(stream: CFReadStreamRef) -> Boolean;
+    pub fn CFWriteStreamOpen(stream: CFWriteStreamRef) -> Boolean;
+    pub fn CFReadStreamClose(stream: CFReadStreamRef);
+    pub fn CFWriteStreamClose(stream: CFWriteStreamRef);
+
+    /// Returns `true` if a read can be performed without blocking.
+    pub fn CFReadStreamHasBytesAvailable(stream: CFReadStreamRef) -> Boolean;
+    /// Reads up to `bufferLength` bytes into `buffer`, blocking until at least one byte is
+    /// available. Returns the number of bytes read, `0` at the end of the stream, or `-1` if an
+    /// error occurs.
+    pub fn CFReadStreamRead(
+        stream: CFReadStreamRef,
+        buffer: *mut UInt8,
+        bufferLength: CFIndex,
+    ) -> CFIndex;
+
+    /// Returns `true` if a write can be performed without blocking.
+    pub fn CFWriteStreamCanAcceptBytes(stream: CFWriteStreamRef) -> Boolean;
+    /// Writes up to `bufferLength` bytes from `buffer`, blocking until at least one byte can be
+    /// written. Returns the number of bytes written, `0` if the stream is full and at its
+    /// capacity, or `-1` if an error occurs.
+    pub fn CFWriteStreamWrite(
+        stream: CFWriteStreamRef,
+        buffer: *const UInt8,
+        bufferLength: CFIndex,
+    ) -> CFIndex;
+}
@@ -1,5 +1,10 @@
-use crate::{Boolean, CFAllocatorRef, CFIndex, CFRange, CFStringRef, UInt8, UTF32Char, UniChar};
-use core::ffi::c_char;
+use crate::{
+    Boolean, CFAllocatorRef, CFArrayRef, CFCharacterSetRef, CFComparisonResult, CFDataRef,
+    CFDictionaryRef, CFIndex, CFLocaleRef, CFMutableStringRef, CFOptionFlags, CFRange, CFStringRef,
+    CFTypeID, ConstStr255Param, ConstStringPtr, StringPtr, UInt8, UTF32Char, UniChar,
+};
+use core::ffi::{c_char, c_ulong};
+use core::ptr;
 
 /// Identifier for character encoding; the values are the same as Text Encoding Converter
 /// `TextEncoding`.
@@ -50,7 +55,67 @@ pub const kCFStringEncodingUTF32BE: CFStringEncoding = 0x1800_0100;
 /// Platform-independent built-in encoding; always available on all platforms.
 pub const kCFStringEncodingUTF32LE: CFStringEncoding = 0x1c00_0100;
 
+/// Find and compare flags; these are OR'ed together and provided as `CFStringCompareFlags` in the
+/// various functions.
+pub type CFStringCompareFlags = CFOptionFlags;
+
+/// If specified, ignores the case of the characters being compared.
+pub const kCFCompareCaseInsensitive: CFStringCompareFlags = 1;
+/// Starting from the end of the string.
+pub const kCFCompareBackwards: CFStringCompareFlags = 4;
+/// Only at the specified starting point.
+pub const kCFCompareAnchored: CFStringCompareFlags = 8;
+/// If specified, loose equivalence is performed (o-umlaut == o, umlaut).
+pub const kCFCompareNonliteral: CFStringCompareFlags = 16;
+/// User's default locale is used for the comparisons.
+pub const kCFCompareLocalized: CFStringCompareFlags = 32;
+/// Numeric comparison is used; that is, Foo2.txt < Foo7.txt < Foo25.txt.
+pub const kCFCompareNumerically: CFStringCompareFlags = 64;
+/// If specified, ignores diacritic marks (o-umlaut == o).
+pub const kCFCompareDiacriticInsensitive: CFStringCompareFlags = 128;
+/// If specified, ignores width differences ('a' == UFF41).
+pub const kCFCompareWidthInsensitive: CFStringCompareFlags = 256;
+/// If specified, comparisons are forced to return either `kCFCompareLessThan` or
+/// `kCFCompareGreaterThan` if the strings are equivalent but not strictly equal.
+///
+/// This provides stability when sorting (e.g. "aaa" > "AAA" with `kCFCompareCaseInsensitive`
+/// specified).
+pub const kCFCompareForcedOrdering: CFStringCompareFlags = 512;
+
+/// The Unicode normalization forms, as described in Unicode Technical Report #15.
+pub type CFStringNormalizationForm = CFIndex;
+
+/// Canonical Decomposition
+pub const kCFStringNormalizationFormD: CFStringNormalizationForm = 0;
+/// Compatibility Decomposition
+pub const kCFStringNormalizationFormKD: CFStringNormalizationForm = 1;
+/// Canonical Decomposition followed by Canonical Composition
+pub const kCFStringNormalizationFormC: CFStringNormalizationForm = 2;
+/// Compatibility Decomposition followed by Canonical Composition
+pub const kCFStringNormalizationFormKC: CFStringNormalizationForm = 3;
+
+/// The number of characters [`CFStringGetCharacterFromInlineBuffer`] copies out of the string at
+/// a time.
+pub const __kCFStringInlineBufferLength: usize = 64;
+
+/// A buffer for fast sequential or random access to the characters of a string. Initialize it
+/// with [`CFStringInitInlineBuffer`] and read characters with
+/// [`CFStringGetCharacterFromInlineBuffer`].
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CFStringInlineBuffer {
+    pub buffer: [UniChar; __kCFStringInlineBufferLength],
+    pub theString: CFStringRef,
+    pub directUniCharBuffer: *const UniChar,
+    pub directCStringBuffer: *const c_char,
+    pub rangeToBuffer: CFRange,
+    pub bufferedRangeStart: CFIndex,
+    pub bufferedRangeEnd: CFIndex,
+}
+
 extern "C" {
+    pub fn CFStringGetTypeID() -> CFTypeID;
+
     /// Takes an explicit length, and allows you to specify whether the data is an external
     /// format—that is, whether to pay attention to the BOM character (if any) and do byte swapping
     /// if necessary.
@@ -64,6 +129,73 @@ extern "C" {
         isExternalRepresentation: Boolean,
     ) -> CFStringRef;
 
+    /// Like [`CFStringCreateWithBytes`], but the string may use the provided buffer as its backing
+    /// store instead of copying it. `contentsDeallocator` is used to free the buffer when the string
+    /// no longer needs it; pass [`kCFAllocatorNull`] to prevent the buffer from being freed.
+    ///
+    /// [`kCFAllocatorNull`]: crate::kCFAllocatorNull
+    pub fn CFStringCreateWithBytesNoCopy(
+        alloc: CFAllocatorRef,
+        bytes: *const UInt8,
+        numBytes: CFIndex,
+        encoding: CFStringEncoding,
+        isExternalRepresentation: Boolean,
+        contentsDeallocator: CFAllocatorRef,
+    ) -> CFStringRef;
+
+    pub fn CFStringCreateWithPascalString(
+        alloc: CFAllocatorRef,
+        pStr: ConstStr255Param,
+        encoding: CFStringEncoding,
+    ) -> CFStringRef;
+
+    pub fn CFStringCreateWithCString(
+        alloc: CFAllocatorRef,
+        cStr: *const c_char,
+        encoding: CFStringEncoding,
+    ) -> CFStringRef;
+
+    pub fn CFStringCreateWithCharacters(
+        alloc: CFAllocatorRef,
+        chars: *const UniChar,
+        numChars: CFIndex,
+    ) -> CFStringRef;
+
+    /// The `NoCopy` variants may use the provided buffer as the string's backing store.
+    /// `contentsDeallocator` is used to free the buffer when the string no longer needs it; pass
+    /// [`kCFAllocatorNull`] to prevent the buffer from being freed.
+    ///
+    /// [`kCFAllocatorNull`]: crate::kCFAllocatorNull
+    pub fn CFStringCreateWithPascalStringNoCopy(
+        alloc: CFAllocatorRef,
+        pStr: ConstStr255Param,
+        encoding: CFStringEncoding,
+        contentsDeallocator: CFAllocatorRef,
+    ) -> CFStringRef;
+
+    pub fn CFStringCreateWithCStringNoCopy(
+        alloc: CFAllocatorRef,
+        cStr: *const c_char,
+        encoding: CFStringEncoding,
+        contentsDeallocator: CFAllocatorRef,
+    ) -> CFStringRef;
+
+    pub fn CFStringCreateWithCharactersNoCopy(
+        alloc: CFAllocatorRef,
+        chars: *const UniChar,
+        numChars: CFIndex,
+        contentsDeallocator: CFAllocatorRef,
+    ) -> CFStringRef;
+
+    /// Creates a string from a `printf`-style format string, which also supports `%@` for Core
+    /// Foundation objects. `formatOptions` is reserved and should be `NULL`.
+    pub fn CFStringCreateWithFormat(
+        alloc: CFAllocatorRef,
+        formatOptions: CFDictionaryRef,
+        format: CFStringRef,
+        ...
+    ) -> CFStringRef;
+
     /// Number of 16-bit Unicode characters in the string.
     pub fn CFStringGetLength(theString: CFStringRef) -> CFIndex;
 
@@ -78,6 +210,30 @@ extern "C" {
 
     pub fn CFStringGetCharacters(theString: CFStringRef, range: CFRange, buffer: *mut UniChar);
 
+    /// Copies the string into `buffer` as a Pascal string. Returns false if the conversion fails or
+    /// the buffer is too small.
+    pub fn CFStringGetPascalString(
+        theString: CFStringRef,
+        buffer: StringPtr,
+        bufferSize: CFIndex,
+        encoding: CFStringEncoding,
+    ) -> Boolean;
+
+    /// Copies the string into `buffer` as a nul-terminated C string. Returns false if the
+    /// conversion fails or the buffer is too small.
+    pub fn CFStringGetCString(
+        theString: CFStringRef,
+        buffer: *mut c_char,
+        bufferSize: CFIndex,
+        encoding: CFStringEncoding,
+    ) -> Boolean;
+
+    /// May return `NULL` at any time; be prepared for `NULL`.
+    pub fn CFStringGetPascalStringPtr(
+        theString: CFStringRef,
+        encoding: CFStringEncoding,
+    ) -> ConstStringPtr;
+
     /// May return `NULL` at any time; be prepared for `NULL`, if not now, in some other time or
     /// place.
     pub fn CFStringGetCStringPtr(
@@ -85,6 +241,9 @@ extern "C" {
         encoding: CFStringEncoding,
     ) -> *const c_char;
 
+    /// May return `NULL` at any time; be prepared for `NULL`.
+    pub fn CFStringGetCharactersPtr(theString: CFStringRef) -> *const UniChar;
+
     /// The primitive conversion routine; allows you to convert a string piece at a time into a
     /// fixed size buffer. Returns number of characters converted.
     ///
@@ -117,6 +276,394 @@ extern "C" {
         maxBufLen: CFIndex,
         usedBufLen: *mut CFIndex,
     ) -> CFIndex;
+
+    /// Returns the smallest encoding that can represent the string losslessly.
+    pub fn CFStringGetSmallestEncoding(theString: CFStringRef) -> CFStringEncoding;
+
+    /// Returns the encoding that requires the least conversion from the string's internal
+    /// representation.
+    pub fn CFStringGetFastestEncoding(theString: CFStringRef) -> CFStringEncoding;
+
+    /// Returns the default encoding used by the operating system when it creates strings.
+    pub fn CFStringGetSystemEncoding() -> CFStringEncoding;
+
+    /// Returns the maximum number of bytes needed to store `length` UTF-16 code units in
+    /// `encoding`.
+    pub fn CFStringGetMaximumSizeForEncoding(
+        length: CFIndex,
+        encoding: CFStringEncoding,
+    ) -> CFIndex;
+
+    /// Extracts the file system representation (i.e., the decomposed UTF-8 bytes) of the string
+    /// into `buffer` as a nul-terminated C string. Returns false if the buffer is too small.
+    pub fn CFStringGetFileSystemRepresentation(
+        string: CFStringRef,
+        buffer: *mut c_char,
+        maxBufLen: CFIndex,
+    ) -> Boolean;
+
+    /// Returns the size of the buffer [`CFStringGetFileSystemRepresentation`] requires, including
+    /// the nul terminator.
+    pub fn CFStringGetMaximumSizeOfFileSystemRepresentation(string: CFStringRef) -> CFIndex;
+
+    pub fn CFStringCreateWithFileSystemRepresentation(
+        alloc: CFAllocatorRef,
+        buffer: *const c_char,
+    ) -> CFStringRef;
+
+    pub fn CFStringIsEncodingAvailable(encoding: CFStringEncoding) -> Boolean;
+
+    /// Returns a list of the available encodings, terminated by [`kCFStringEncodingInvalidId`].
+    pub fn CFStringGetListOfAvailableEncodings() -> *const CFStringEncoding;
+
+    pub fn CFStringGetNameOfEncoding(encoding: CFStringEncoding) -> CFStringRef;
+
+    pub fn CFStringConvertEncodingToNSStringEncoding(encoding: CFStringEncoding) -> c_ulong;
+
+    pub fn CFStringConvertNSStringEncodingToEncoding(encoding: c_ulong) -> CFStringEncoding;
+
+    pub fn CFStringConvertEncodingToWindowsCodepage(encoding: CFStringEncoding) -> u32;
+
+    pub fn CFStringConvertWindowsCodepageToEncoding(codepage: u32) -> CFStringEncoding;
+
+    pub fn CFStringConvertIANACharSetNameToEncoding(theString: CFStringRef) -> CFStringEncoding;
+
+    pub fn CFStringConvertEncodingToIANACharSetName(encoding: CFStringEncoding) -> CFStringRef;
+
+    /// Returns the most compatible Mac OS script value for `encoding`.
+    pub fn CFStringGetMostCompatibleMacStringEncoding(
+        encoding: CFStringEncoding,
+    ) -> CFStringEncoding;
+
+    /// Returns the integer value of the string, or `0` if it does not begin with a number. Values
+    /// that overflow return `INT_MAX` or `INT_MIN`.
+    pub fn CFStringGetIntValue(str: CFStringRef) -> i32;
+
+    /// Returns the floating point value of the string, or `0.0` if it does not begin with a
+    /// number.
+    pub fn CFStringGetDoubleValue(str: CFStringRef) -> f64;
+
+    /// Compares the specified range of `theString1` to `theString2` using `compareOptions`.
+    /// [`kCFCompareLocalized`] uses the user's default locale.
+    pub fn CFStringCompareWithOptions(
+        theString1: CFStringRef,
+        theString2: CFStringRef,
+        rangeToCompare: CFRange,
+        compareOptions: CFStringCompareFlags,
+    ) -> CFComparisonResult;
+
+    /// Compares the entirety of `theString1` to `theString2` using `compareOptions`.
+    pub fn CFStringCompare(
+        theString1: CFStringRef,
+        theString2: CFStringRef,
+        compareOptions: CFStringCompareFlags,
+    ) -> CFComparisonResult;
+
+    /// The main comparison routine; compares the specified range of `theString1` to `theString2`
+    /// using `compareOptions`. `locale` is used for localized comparisons if
+    /// [`kCFCompareLocalized`] is specified; if `NULL`, the canonical (system) locale is used.
+    pub fn CFStringCompareWithOptionsAndLocale(
+        theString1: CFStringRef,
+        theString2: CFStringRef,
+        rangeToCompare: CFRange,
+        compareOptions: CFStringCompareFlags,
+        locale: CFLocaleRef,
+    ) -> CFComparisonResult;
+
+    /// Find a character from the set in the specified range of the string. The `result` range is
+    /// the range of the first matched code point, which may be a surrogate pair.
+    ///
+    /// The only `searchOptions` supported are [`kCFCompareBackwards`] and [`kCFCompareAnchored`].
+    pub fn CFStringFindCharacterFromSet(
+        theString: CFStringRef,
+        theSet: CFCharacterSetRef,
+        rangeToSearch: CFRange,
+        searchOptions: CFStringCompareFlags,
+        result: *mut CFRange,
+    ) -> Boolean;
+
+    /// Find `stringToFind` in the specified range of the string. The `result` range is the range
+    /// of the first match.
+    pub fn CFStringFindWithOptions(
+        theString: CFStringRef,
+        stringToFind: CFStringRef,
+        rangeToSearch: CFRange,
+        searchOptions: CFStringCompareFlags,
+        result: *mut CFRange,
+    ) -> Boolean;
+
+    /// Like [`CFStringFindWithOptions`], but `locale` is used for localized searches if
+    /// [`kCFCompareLocalized`] is specified; if `NULL`, the canonical (system) locale is used.
+    pub fn CFStringFindWithOptionsAndLocale(
+        theString: CFStringRef,
+        stringToFind: CFStringRef,
+        rangeToSearch: CFRange,
+        searchOptions: CFStringCompareFlags,
+        locale: CFLocaleRef,
+        result: *mut CFRange,
+    ) -> Boolean;
+
+    /// Find `stringToFind` in the entire string. Returns the range of the first match, or
+    /// `{kCFNotFound, 0}` if there is no match.
+    pub fn CFStringFind(
+        theString: CFStringRef,
+        stringToFind: CFStringRef,
+        compareOptions: CFStringCompareFlags,
+    ) -> CFRange;
+
+    /// Returns an array of the ranges of every match of `stringToFind` in the specified range of
+    /// the string, or `NULL` if there are no matches. The array's values are `CFRange` pointers.
+    pub fn CFStringCreateArrayWithFindResults(
+        alloc: CFAllocatorRef,
+        theString: CFStringRef,
+        stringToFind: CFStringRef,
+        rangeToSearch: CFRange,
+        compareOptions: CFStringCompareFlags,
+    ) -> CFArrayRef;
+
+    pub fn CFStringHasPrefix(theString: CFStringRef, prefix: CFStringRef) -> Boolean;
+
+    pub fn CFStringHasSuffix(theString: CFStringRef, suffix: CFStringRef) -> Boolean;
+
+    /// Returns the range of the composed character sequence (e.g., a base character followed by
+    /// combining marks, or a surrogate pair) containing `theIndex`.
+    pub fn CFStringGetRangeOfComposedCharactersAtIndex(
+        theString: CFStringRef,
+        theIndex: CFIndex,
+    ) -> CFRange;
+
+    /// Get the bounds of the line(s) containing the specified range. A line is terminated by a
+    /// line feed, a carriage return, a carriage return followed by a line feed, or one of the
+    /// Unicode line (`U+0085`, `U+2028`) or paragraph (`U+2029`) separators.
+    ///
+    /// `lineBeginIndex` receives the index of the first character of the line, `lineEndIndex`
+    /// receives the index of the first character after the line terminator, and `contentsEndIndex`
+    /// receives the index of the first character of the line terminator. Any of them may be
+    /// `NULL`.
+    pub fn CFStringGetLineBounds(
+        theString: CFStringRef,
+        range: CFRange,
+        lineBeginIndex: *mut CFIndex,
+        lineEndIndex: *mut CFIndex,
+        contentsEndIndex: *mut CFIndex,
+    );
+
+    /// Like [`CFStringGetLineBounds`], but only paragraph separators (i.e., not `U+2028`) terminate
+    /// a paragraph.
+    pub fn CFStringGetParagraphBounds(
+        string: CFStringRef,
+        range: CFRange,
+        parBeginIndex: *mut CFIndex,
+        parEndIndex: *mut CFIndex,
+        contentsEndIndex: *mut CFIndex,
+    );
+
+    /// Returns the index of a potential hyphenation point before `location`, within `limitRange`,
+    /// or [`kCFNotFound`] if there is none. `options` is reserved and must be `0`. If `character`
+    /// is not `NULL`, it receives the hyphen character appropriate for `locale`.
+    ///
+    /// [`kCFNotFound`]: crate::kCFNotFound
+    pub fn CFStringGetHyphenationLocationBeforeIndex(
+        string: CFStringRef,
+        location: CFIndex,
+        limitRange: CFRange,
+        options: CFOptionFlags,
+        locale: CFLocaleRef,
+        character: *mut UTF32Char,
+    ) -> CFIndex;
+
+    pub fn CFStringIsHyphenationAvailableForLocale(locale: CFLocaleRef) -> Boolean;
+
+    /// Returns an array of the substrings of `theString` separated by `separatorString`.
+    pub fn CFStringCreateArrayBySeparatingStrings(
+        alloc: CFAllocatorRef,
+        theString: CFStringRef,
+        separatorString: CFStringRef,
+    ) -> CFArrayRef;
+
+    /// Returns the strings in `theArray` joined by `separatorString`.
+    pub fn CFStringCreateByCombiningStrings(
+        alloc: CFAllocatorRef,
+        theArray: CFArrayRef,
+        separatorString: CFStringRef,
+    ) -> CFStringRef;
+
+    /// Creates a string from the bytes of `data`, which is an external representation of a string
+    /// in `encoding` (e.g., as created by [`CFStringCreateExternalRepresentation`]). A byte order
+    /// mark (BOM) at the start of `data` is used to determine the byte order if `encoding` does not
+    /// specify one.
+    pub fn CFStringCreateFromExternalRepresentation(
+        alloc: CFAllocatorRef,
+        data: CFDataRef,
+        encoding: CFStringEncoding,
+    ) -> CFStringRef;
+
+    /// Creates a data object containing the bytes of `theString` converted into `encoding` in its
+    /// external representation format (i.e., with a BOM if the encoding allows it).
+    ///
+    /// Characters that cannot be converted are represented with `lossByte`; if `lossByte` is `0`,
+    /// lossy conversion is not allowed and `NULL` is returned if conversion fails.
+    pub fn CFStringCreateExternalRepresentation(
+        alloc: CFAllocatorRef,
+        theString: CFStringRef,
+        encoding: CFStringEncoding,
+        lossByte: UInt8,
+    ) -> CFDataRef;
+
+    /// Creates an immutable copy of `theString`. If `theString` is immutable, it may be returned
+    /// with an additional retain.
+    pub fn CFStringCreateCopy(alloc: CFAllocatorRef, theString: CFStringRef) -> CFStringRef;
+
+    /// Creates an immutable string from the UTF-16 code units in `range` of `str`. Returns `str`
+    /// retained if it is immutable and `range` covers the entire string.
+    pub fn CFStringCreateWithSubstring(
+        alloc: CFAllocatorRef,
+        str: CFStringRef,
+        range: CFRange,
+    ) -> CFStringRef;
+
+    /// Creates an empty mutable string. `maxLength` is a hard bound on the length of the string,
+    /// or `0` for no limit.
+    pub fn CFStringCreateMutable(alloc: CFAllocatorRef, maxLength: CFIndex) -> CFMutableStringRef;
+
+    /// Creates a mutable copy of `theString`. `maxLength` is a hard bound on the length of the
+    /// string, or `0` for no limit.
+    pub fn CFStringCreateMutableCopy(
+        alloc: CFAllocatorRef,
+        maxLength: CFIndex,
+        theString: CFStringRef,
+    ) -> CFMutableStringRef;
+
+    /// Creates a mutable string that uses `chars` as its backing store until it needs to grow
+    /// beyond `capacity`. `externalCharactersAllocator` frees the buffer when the string no longer
+    /// needs it; pass [`kCFAllocatorNull`] to prevent the buffer from being freed.
+    ///
+    /// [`kCFAllocatorNull`]: crate::kCFAllocatorNull
+    pub fn CFStringCreateMutableWithExternalCharactersNoCopy(
+        alloc: CFAllocatorRef,
+        chars: *mut UniChar,
+        numChars: CFIndex,
+        capacity: CFIndex,
+        externalCharactersAllocator: CFAllocatorRef,
+    ) -> CFMutableStringRef;
+
+    pub fn CFStringAppend(theString: CFMutableStringRef, appendedString: CFStringRef);
+
+    pub fn CFStringAppendCharacters(
+        theString: CFMutableStringRef,
+        chars: *const UniChar,
+        numChars: CFIndex,
+    );
+
+    pub fn CFStringAppendPascalString(
+        theString: CFMutableStringRef,
+        pStr: ConstStr255Param,
+        encoding: CFStringEncoding,
+    );
+
+    pub fn CFStringAppendCString(
+        theString: CFMutableStringRef,
+        cStr: *const c_char,
+        encoding: CFStringEncoding,
+    );
+
+    pub fn CFStringAppendFormat(
+        theString: CFMutableStringRef,
+        formatOptions: CFDictionaryRef,
+        format: CFStringRef,
+        ...
+    );
+
+    pub fn CFStringInsert(str: CFMutableStringRef, idx: CFIndex, insertedStr: CFStringRef);
+
+    pub fn CFStringDelete(theString: CFMutableStringRef, range: CFRange);
+
+    pub fn CFStringReplace(theString: CFMutableStringRef, range: CFRange, replacement: CFStringRef);
+
+    pub fn CFStringReplaceAll(theString: CFMutableStringRef, replacement: CFStringRef);
+
+    /// Replaces every occurrence of `stringToFind` in the specified range of the string with
+    /// `replacementString`. Returns the number of replacements.
+    pub fn CFStringFindAndReplace(
+        theString: CFMutableStringRef,
+        stringToFind: CFStringRef,
+        replacementString: CFStringRef,
+        rangeToSearch: CFRange,
+        compareOptions: CFStringCompareFlags,
+    ) -> CFIndex;
+
+    /// Replaces the backing store of a string created by
+    /// [`CFStringCreateMutableWithExternalCharactersNoCopy`].
+    pub fn CFStringSetExternalCharactersNoCopy(
+        theString: CFMutableStringRef,
+        chars: *mut UniChar,
+        length: CFIndex,
+        capacity: CFIndex,
+    );
+
+    /// Truncates the string to `length`, or extends it by repeating `padString` starting at
+    /// `indexIntoPad`. `padString` may be `NULL` when truncating.
+    pub fn CFStringPad(
+        theString: CFMutableStringRef,
+        padString: CFStringRef,
+        length: CFIndex,
+        indexIntoPad: CFIndex,
+    );
+
+    /// Removes every leading and trailing occurrence of `trimString`.
+    pub fn CFStringTrim(theString: CFMutableStringRef, trimString: CFStringRef);
+
+    pub fn CFStringTrimWhitespace(theString: CFMutableStringRef);
+
+    /// The case mapping functions use `locale` for language-specific rules; if `NULL`, the
+    /// canonical (system) locale is used.
+    pub fn CFStringLowercase(theString: CFMutableStringRef, locale: CFLocaleRef);
+
+    pub fn CFStringUppercase(theString: CFMutableStringRef, locale: CFLocaleRef);
+
+    pub fn CFStringCapitalize(theString: CFMutableStringRef, locale: CFLocaleRef);
+
+    /// Normalizes the string in place to the specified Unicode normalization form.
+    pub fn CFStringNormalize(theString: CFMutableStringRef, theForm: CFStringNormalizationForm);
+
+    /// Folds the string in place, removing the distinctions specified by `theFlags` for the
+    /// purposes of comparison. Only [`kCFCompareCaseInsensitive`],
+    /// [`kCFCompareDiacriticInsensitive`], and [`kCFCompareWidthInsensitive`] are supported.
+    /// `theLocale` affects case folding; if `NULL`, the canonical (system) locale is used.
+    pub fn CFStringFold(
+        theString: CFMutableStringRef,
+        theFlags: CFStringCompareFlags,
+        theLocale: CFLocaleRef,
+    );
+
+    /// Transliterates the characters of `string` in `range` with `transform`, which is one of the
+    /// predefined `kCFStringTransform*` identifiers or any valid ICU transform ID. If `reverse` is
+    /// true, the inverse transform is applied, if one exists. If `range` is `NULL`, the whole
+    /// string is transformed; otherwise, it is updated to the range of the transformed characters.
+    /// Returns false if the transform could not be performed.
+    pub fn CFStringTransform(
+        string: CFMutableStringRef,
+        range: *mut CFRange,
+        transform: CFStringRef,
+        reverse: Boolean,
+    ) -> Boolean;
+
+    pub static kCFStringTransformStripCombiningMarks: CFStringRef;
+    pub static kCFStringTransformToLatin: CFStringRef;
+    pub static kCFStringTransformFullwidthHalfwidth: CFStringRef;
+    pub static kCFStringTransformLatinKatakana: CFStringRef;
+    pub static kCFStringTransformLatinHiragana: CFStringRef;
+    pub static kCFStringTransformHiraganaKatakana: CFStringRef;
+    pub static kCFStringTransformMandarinLatin: CFStringRef;
+    pub static kCFStringTransformLatinHangul: CFStringRef;
+    pub static kCFStringTransformLatinArabic: CFStringRef;
+    pub static kCFStringTransformLatinHebrew: CFStringRef;
+    pub static kCFStringTransformLatinThai: CFStringRef;
+    pub static kCFStringTransformLatinCyrillic: CFStringRef;
+    pub static kCFStringTransformLatinGreek: CFStringRef;
+    pub static kCFStringTransformToXMLHex: CFStringRef;
+    pub static kCFStringTransformToUnicodeName: CFStringRef;
+    pub static kCFStringTransformStripDiacritics: CFStringRef;
 }
 
 #[inline]
@@ -174,6 +721,102 @@ pub const fn CFStringGetSurrogatePairForLongCharacter(mut character: UTF32Char)
     }
 }
 
+/// Initializes `buf` to access the characters of `str` in `range` with
+/// [`CFStringGetCharacterFromInlineBuffer`].
+///
+/// # Safety
+///
+/// `str` must be a valid string that outlives the use of `buf`, `buf` must be valid for writes,
+/// and `range` must lie within the bounds of `str`.
+#[inline]
+pub unsafe fn CFStringInitInlineBuffer(
+    str: CFStringRef,
+    buf: *mut CFStringInlineBuffer,
+    range: CFRange,
+) {
+    // SAFETY: Caller asserts `str` is a valid string.
+    let direct_uni_char_buffer = unsafe { CFStringGetCharactersPtr(str) };
+    let direct_c_string_buffer = if direct_uni_char_buffer.is_null() {
+        // SAFETY: Caller asserts `str` is a valid string.
+        unsafe { CFStringGetCStringPtr(str, kCFStringEncodingASCII) }
+    } else {
+        ptr::null()
+    };
+
+    // SAFETY: Caller asserts `buf` is valid for writes. The fields are written through raw
+    // pointers as `buf` may be uninitialized.
+    unsafe {
+        ptr::addr_of_mut!((*buf).theString).write(str);
+        ptr::addr_of_mut!((*buf).rangeToBuffer).write(range);
+        ptr::addr_of_mut!((*buf).directUniCharBuffer).write(direct_uni_char_buffer);
+        ptr::addr_of_mut!((*buf).directCStringBuffer).write(direct_c_string_buffer);
+        ptr::addr_of_mut!((*buf).bufferedRangeStart).write(0);
+        ptr::addr_of_mut!((*buf).bufferedRangeEnd).write(0);
+    }
+}
+
+/// Returns the character at `idx` in the range of the string `buf` was initialized with, or `0`
+/// if `idx` is out of bounds.
+///
+/// Characters are copied out of the string in chunks when it does not provide direct access to its
+/// storage.
+///
+/// # Safety
+///
+/// `buf` must have been initialized by [`CFStringInitInlineBuffer`] and its string must still be
+/// valid.
+// LINT: The arithmetic is performed on indices bounded by the buffered range, which the caller
+// asserts lies within the string.
+#[allow(clippy::arithmetic_side_effects)]
+#[inline]
+pub unsafe fn CFStringGetCharacterFromInlineBuffer(
+    buf: *mut CFStringInlineBuffer,
+    idx: CFIndex,
+) -> UniChar {
+    // SAFETY: Caller asserts `buf` was initialized.
+    let buf = unsafe { &mut *buf };
+    if idx < 0 || idx >= buf.rangeToBuffer.length {
+        return 0;
+    }
+
+    let location = buf.rangeToBuffer.location + idx;
+    if !buf.directUniCharBuffer.is_null() {
+        // SAFETY: Caller asserts the range lies within the string, so `location` is in bounds.
+        return unsafe { *buf.directUniCharBuffer.offset(location) };
+    }
+    if !buf.directCStringBuffer.is_null() {
+        // SAFETY: Caller asserts the range lies within the string, so `location` is in bounds.
+        let c = unsafe { *buf.directCStringBuffer.offset(location) };
+        // LINT: The buffer is only used for ASCII strings, so the reinterpretation is lossless.
+        #[allow(clippy::as_conversions, clippy::cast_sign_loss)]
+        return UniChar::from(c as u8);
+    }
+
+    if idx >= buf.bufferedRangeEnd || idx < buf.bufferedRangeStart {
+        // LINT: The buffer length is a small constant.
+        #[allow(clippy::as_conversions, clippy::cast_possible_wrap)]
+        const LEN: CFIndex = __kCFStringInlineBufferLength as CFIndex;
+
+        buf.bufferedRangeStart = (idx - 4).max(0);
+        buf.bufferedRangeEnd = (buf.bufferedRangeStart + LEN).min(buf.rangeToBuffer.length);
+        let range = CFRange {
+            location: buf.rangeToBuffer.location + buf.bufferedRangeStart,
+            length: buf.bufferedRangeEnd - buf.bufferedRangeStart,
+        };
+        // SAFETY: Caller asserts the string is valid and `range` is at most the buffer's length.
+        unsafe { CFStringGetCharacters(buf.theString, range, buf.buffer.as_mut_ptr()) };
+    }
+
+    // LINT: `idx` lies within the buffered range, which is non-negative and at most the buffer's
+    // length.
+    #[allow(
+        clippy::as_conversions,
+        clippy::cast_sign_loss,
+        clippy::indexing_slicing
+    )]
+    buf.buffer[(idx - buf.bufferedRangeStart) as usize]
+}
+
 /// The UTF-16 encoding of a single code point.
 #[derive(Clone, Copy, Debug)]
 pub enum Utf16CodePoint {
@@ -1,4 +1,5 @@
 use crate::CFStringEncoding;
+use availability::availability;
 
 pub const kCFStringEncodingMacJapanese: CFStringEncoding = 1;
 pub const kCFStringEncodingMacChineseTrad: CFStringEncoding = 2;
@@ -158,13 +159,7 @@ pub const kCFStringEncodingJIS_X0208_90: CFStringEncoding = 0x0622;
 pub const kCFStringEncodingJIS_X0212_90: CFStringEncoding = 0x0623;
 pub const kCFStringEncodingJIS_C6226_78: CFStringEncoding = 0x0624;
 /// Shift-JIS format encoding of JIS X0213 planes 1 and 2
-///
-/// # Availability
-///
-/// * iOS: 2.0
-/// * macOS: 10.5
-/// * tvOS: 9.0
-/// * watchOS: 2.0
+#[availability(macos = "10.5", ios = "2.0", tvos = "9.0", watchos = "2.0")]
 #[allow(clippy::doc_markdown)] // LINT: Casing is due to branding. It's not referring to an item.
 pub const kCFStringEncodingShiftJIS_X0213: CFStringEncoding = 0x0628;
 /// JIS X0213 in plane-row-column notation
@@ -232,22 +227,10 @@ pub const kCFStringEncodingEBCDIC_US: CFStringEncoding = 0x0c01;
 pub const kCFStringEncodingEBCDIC_CP037: CFStringEncoding = 0x0c02;
 
 /// `kTextEncodingUnicodeDefault + kUnicodeUTF7Format` RFC2152
-///
-/// # Availability
-///
-/// * iOS: 4.0
-/// * macOS: 10.6
-/// * tvOS: 9.0
-/// * watchOS: 2.0
+#[availability(macos = "10.6", ios = "4.0", tvos = "9.0", watchos = "2.0")]
 #[allow(clippy::doc_markdown)] // LINT: Casing is due to branding. It's not referring to an item.
 pub const kCFStringEncodingUTF7: CFStringEncoding = 0x0400_0100;
 /// UTF-7 (IMAP folder variant) RFC3501
-///
-/// # Availability
-///
-/// * iOS: 4.0
-/// * macOS: 10.6
-/// * tvOS: 9.0
-/// * watchOS: 2.0
+#[availability(macos = "10.6", ios = "4.0", tvos = "9.0", watchos = "2.0")]
 #[allow(clippy::doc_markdown)] // LINT: Casing is due to branding. It's not referring to an item.
 pub const kCFStringEncodingUTF7_IMAP: CFStringEncoding = 0x0a10;
@@ -0,0 +1,104 @@
+use crate::{
+    CFAllocatorRef, CFIndex, CFLocaleRef, CFMutableArrayRef, CFOptionFlags, CFRange, CFStringRef,
+    CFTypeID, CFTypeRef,
+};
+
+declare_cf_type!(
+    __CFStringTokenizer,
+    CFStringTokenizerRef,
+    type_id: CFStringTokenizerGetTypeID
+);
+
+/// Tokenization units, passed as the `options` of [`CFStringTokenizerCreate`]. The options are
+/// mutually exclusive, but may be OR'ed with the attribute options.
+pub const kCFStringTokenizerUnitWord: CFOptionFlags = 0;
+pub const kCFStringTokenizerUnitSentence: CFOptionFlags = 1;
+pub const kCFStringTokenizerUnitParagraph: CFOptionFlags = 2;
+pub const kCFStringTokenizerUnitLineBreak: CFOptionFlags = 3;
+/// Word boundaries, including the boundaries between words and the whitespace and punctuation that
+/// separates them.
+pub const kCFStringTokenizerUnitWordBoundary: CFOptionFlags = 4;
+
+/// Latin transcription of each token, as a [`CFStringRef`]. Only available for word tokens.
+pub const kCFStringTokenizerAttributeLatinTranscription: CFOptionFlags = 1 << 16;
+/// The language of each token, as a [`CFStringRef`] containing an RFC 3066bis language tag. Only
+/// available for sentence and paragraph tokens.
+pub const kCFStringTokenizerAttributeLanguage: CFOptionFlags = 1 << 17;
+
+/// Describes the token found by [`CFStringTokenizerGoToTokenAtIndex`] or
+/// [`CFStringTokenizerAdvanceToNextToken`].
+pub type CFStringTokenizerTokenType = CFOptionFlags;
+
+/// There is no token.
+pub const kCFStringTokenizerTokenNone: CFStringTokenizerTokenType = 0;
+/// The token is a normal token.
+pub const kCFStringTokenizerTokenNormal: CFStringTokenizerTokenType = 1 << 0;
+/// The token has sub-tokens (e.g., a compound word).
+pub const kCFStringTokenizerTokenHasSubTokensMask: CFStringTokenizerTokenType = 1 << 1;
+/// The token has derived sub-tokens (e.g., the words of a CJK compound word).
+pub const kCFStringTokenizerTokenHasDerivedSubTokensMask: CFStringTokenizerTokenType = 1 << 2;
+/// The token contains numbers.
+pub const kCFStringTokenizerTokenHasHasNumbersMask: CFStringTokenizerTokenType = 1 << 3;
+/// The token contains whitespace or punctuation.
+pub const kCFStringTokenizerTokenHasNonLettersMask: CFStringTokenizerTokenType = 1 << 4;
+/// The token is a Chinese or Japanese word.
+pub const kCFStringTokenizerTokenIsCJWordMask: CFStringTokenizerTokenType = 1 << 5;
+
+extern "C" {
+    pub fn CFStringTokenizerGetTypeID() -> CFTypeID;
+
+    /// Guesses the language of the specified range of `string`, returning a language tag or `NULL`
+    /// if the language cannot be determined.
+    pub fn CFStringTokenizerCopyBestStringLanguage(
+        string: CFStringRef,
+        range: CFRange,
+    ) -> CFStringRef;
+
+    /// Creates a tokenizer for the specified range of `string`. `options` is a tokenization unit,
+    /// optionally OR'ed with attribute options. `locale` affects the tokenization of words and line
+    /// breaks; if `NULL`, the user's default locale is used.
+    pub fn CFStringTokenizerCreate(
+        alloc: CFAllocatorRef,
+        string: CFStringRef,
+        range: CFRange,
+        options: CFOptionFlags,
+        locale: CFLocaleRef,
+    ) -> CFStringTokenizerRef;
+
+    /// Sets the string to tokenize, resetting the current token.
+    pub fn CFStringTokenizerSetString(
+        tokenizer: CFStringTokenizerRef,
+        string: CFStringRef,
+        range: CFRange,
+    );
+
+    /// Moves to the token containing the character at `index`.
+    pub fn CFStringTokenizerGoToTokenAtIndex(
+        tokenizer: CFStringTokenizerRef,
+        index: CFIndex,
+    ) -> CFStringTokenizerTokenType;
+
+    /// Moves to the next token, or the first token if this is the first call.
+    pub fn CFStringTokenizerAdvanceToNextToken(
+        tokenizer: CFStringTokenizerRef,
+    ) -> CFStringTokenizerTokenType;
+
+    /// Returns the range of the current token, or `{kCFNotFound, 0}` if there is no current token.
+    pub fn CFStringTokenizerGetCurrentTokenRange(tokenizer: CFStringTokenizerRef) -> CFRange;
+
+    /// Returns the requested attribute of the current token, or `NULL` if it is not available.
+    pub fn CFStringTokenizerCopyCurrentTokenAttribute(
+        tokenizer: CFStringTokenizerRef,
+        attribute: CFOptionFlags,
+    ) -> CFTypeRef;
+
+    /// Fills `ranges` with the ranges of up to `maxRangeLength` sub-tokens of the current token,
+    /// and appends derived sub-tokens to `derivedSubTokens` if it is not `NULL`. Returns the number
+    /// of ranges written.
+    pub fn CFStringTokenizerGetCurrentSubTokens(
+        tokenizer: CFStringTokenizerRef,
+        ranges: *mut CFRange,
+        maxRangeLength: CFIndex,
+        derivedSubTokens: CFMutableArrayRef,
+    ) -> CFIndex;
+}
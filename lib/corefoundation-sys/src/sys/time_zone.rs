@@ -0,0 +1,32 @@
+use crate::{Boolean, CFAbsoluteTime, CFAllocatorRef, CFStringRef, CFTimeInterval, CFTypeID};
+
+declare_cf_type!(__CFTimeZone, CFTimeZoneRef, type_id: CFTimeZoneGetTypeID);
+
+extern "C" {
+    pub fn CFTimeZoneGetTypeID() -> CFTypeID;
+
+    /// Returns a copy of the time zone for the system, which is cached until
+    /// [`CFTimeZoneResetSystem`] is called.
+    pub fn CFTimeZoneCopySystem() -> CFTimeZoneRef;
+    pub fn CFTimeZoneResetSystem();
+
+    /// Returns a copy of the default time zone for the process, which is the system time zone
+    /// unless it was replaced by `CFTimeZoneSetDefault`.
+    pub fn CFTimeZoneCopyDefault() -> CFTimeZoneRef;
+
+    pub fn CFTimeZoneCreateWithName(
+        allocator: CFAllocatorRef,
+        name: CFStringRef,
+        tryAbbrev: Boolean,
+    ) -> CFTimeZoneRef;
+    pub fn CFTimeZoneCreateWithTimeIntervalFromGMT(
+        allocator: CFAllocatorRef,
+        ti: CFTimeInterval,
+    ) -> CFTimeZoneRef;
+
+    /// Returns the time zone's geopolitical region name (e.g., `America/Los_Angeles`). The returned
+    /// string follows the get rule.
+    pub fn CFTimeZoneGetName(tz: CFTimeZoneRef) -> CFStringRef;
+    pub fn CFTimeZoneGetSecondsFromGMT(tz: CFTimeZoneRef, at: CFAbsoluteTime) -> CFTimeInterval;
+    pub fn CFTimeZoneIsDaylightSavingTime(tz: CFTimeZoneRef, at: CFAbsoluteTime) -> Boolean;
+}
@@ -0,0 +1,28 @@
+//! Bindings that are resolved at run time, for binaries deployed to OS versions that predate them.
+//!
+//! Each binding gated by a deployment target feature is also declared here as a
+//! [`WeakFn`](c_ffi::WeakFn) or [`WeakStatic`](c_ffi::WeakStatic), which is available regardless
+//! of the crate's features. Use `is_available()` to test whether the running OS provides the
+//! symbol.
+
+use crate::CFStringRef;
+
+/// A string constant exported by a framework, which has the same layout as a [`CFStringRef`].
+///
+/// Unlike a [`CFStringRef`], it may be shared between threads, as the constants are immutable and
+/// never deallocated.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct CFStringConstant(pub CFStringRef);
+
+// SAFETY: String constants are immutable and never deallocated.
+unsafe impl Send for CFStringConstant {}
+
+// SAFETY: See above.
+unsafe impl Sync for CFStringConstant {}
+
+c_ffi::weak_extern! {
+    /// Key to identify the end user-presentable failing operation ("what failed") description in
+    /// `userInfo`.
+    pub static kCFErrorLocalizedFailureKey: CFStringConstant;
+}
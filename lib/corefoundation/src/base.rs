@@ -1,6 +1,10 @@
 //! Common facilities for working with Core Foundation types.
 
+#[cfg(feature = "alloc")]
+pub(crate) mod allocator;
 pub mod ffi;
 mod index;
 pub(super) mod object;
 mod range;
+#[cfg(feature = "alloc")]
+pub(crate) mod unwind;
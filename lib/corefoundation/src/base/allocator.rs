@@ -0,0 +1,109 @@
+//! Core Foundation allocators that take ownership of Rust values.
+
+use alloc::boxed::Box;
+use core::ffi::c_void;
+use core::ptr;
+use corefoundation_sys::{
+    kCFAllocatorDefault, CFAllocatorContext, CFAllocatorCreate, CFAllocatorRef, CFIndex,
+    CFOptionFlags, CFRelease,
+};
+
+/// An allocator that owns a buffer of bytes and only deallocates. The bytes are dropped when Core
+/// Foundation deallocates the buffer, or when the allocator is released if the buffer was never
+/// used.
+///
+/// Pass the allocator as the deallocator of a `NoCopy` create function (e.g.,
+/// `CFDataCreateWithBytesNoCopy`) to transfer ownership of the bytes to the created object. The
+/// object retains the allocator for as long as it needs it.
+pub(crate) struct Deallocator {
+    allocator: CFAllocatorRef,
+    buf: *const u8,
+    len: usize,
+}
+
+impl Deallocator {
+    /// Creates an allocator that owns `bytes`, or returns [`None`] (dropping `bytes`) if the
+    /// allocator could not be created.
+    pub(crate) fn new<B>(bytes: B) -> Option<Self>
+    where
+        B: AsRef<[u8]> + Send + 'static,
+    {
+        // The owner is boxed before borrowing the bytes so their address remains stable (e.g., if
+        // `B` is an array).
+        let owner = Box::into_raw(Box::new(Some(bytes)));
+        let context = CFAllocatorContext {
+            version: 0,
+            info: owner.cast(),
+            retain: None,
+            release: Some(release::<B>),
+            copyDescription: None,
+            allocate: allocate_nothing,
+            reallocate: None,
+            deallocate: Some(deallocate::<B>),
+            preferredSize: None,
+        };
+
+        // SAFETY: `context` is a valid allocator context whose callbacks match the type of `info`.
+        let allocator = unsafe { CFAllocatorCreate(kCFAllocatorDefault, &context) };
+        if allocator.is_null() {
+            // SAFETY: Core Foundation did not take ownership of `owner`, which was created above by
+            // [`Box::into_raw`].
+            drop(unsafe { Box::from_raw(owner) });
+            return None;
+        }
+
+        // SAFETY: `owner` is valid until `allocator` is released, and it still holds `Some`
+        // because the allocator has not been used yet.
+        let (buf, len) = unsafe { &*owner }
+            .as_ref()
+            .map(AsRef::as_ref)
+            .map_or((ptr::null(), 0), |bytes| (bytes.as_ptr(), bytes.len()));
+
+        Some(Self {
+            allocator,
+            buf,
+            len,
+        })
+    }
+
+    /// Returns the allocator.
+    pub(crate) const fn as_ptr(&self) -> CFAllocatorRef {
+        self.allocator
+    }
+
+    /// Returns the address and length of the owned bytes, which remain valid until the allocator
+    /// deallocates them.
+    pub(crate) const fn bytes(&self) -> (*const u8, usize) {
+        (self.buf, self.len)
+    }
+}
+
+impl Drop for Deallocator {
+    fn drop(&mut self) {
+        // SAFETY: The allocator was created with a retain that must be released. Objects that use
+        // the allocator retain it for as long as they need it. If no object does, this drops the
+        // owned bytes.
+        unsafe { CFRelease(self.allocator.cast()) };
+    }
+}
+
+/// The allocation callback of a [`Deallocator`], which is only used to deallocate.
+const extern "C" fn allocate_nothing(
+    _size: CFIndex,
+    _hint: CFOptionFlags,
+    _info: *mut c_void,
+) -> *mut c_void {
+    ptr::null_mut()
+}
+
+extern "C" fn deallocate<B>(_ptr: *mut c_void, info: *mut c_void) {
+    // SAFETY: `info` was created from a `Box<Option<B>>` in [`Deallocator::new`] and is valid
+    // until Core Foundation calls [`release`].
+    drop(unsafe { &mut *info.cast::<Option<B>>() }.take());
+}
+
+extern "C" fn release<B>(info: *const c_void) {
+    // SAFETY: `info` was created by [`Box::into_raw`] in [`Deallocator::new`] and Core Foundation
+    // releases it exactly once.
+    drop(unsafe { Box::from_raw(info.cast::<Option<B>>().cast_mut()) });
+}
@@ -1,4 +1,4 @@
 //! Facilities to simplify safe crossing of the Rust/foreign interface boundary.
 
 pub mod convert;
-pub use retain_release::ffi::ForeignFunctionInterface;
+pub use retain_release::ffi::{ForeignFunctionInterface, TryFromForeign, TryFromForeignError};
@@ -16,9 +16,83 @@
 //! [`CFTypeRef`]: corefoundation_sys::CFTypeRef
 //! [`ForeignFunctionInterface`]: crate::ffi::ForeignFunctionInterface
 
+use crate::boxed::Box;
+use crate::ffi::ForeignFunctionInterface;
+use crate::sync::Arc;
+use core::ffi::c_void;
+
 /// The base trait of all Core Foundation objects.
 pub trait Object {}
 
+/// A Core Foundation object type whose instances can be copied into a new immutable instance.
+///
+/// Copying an immutable instance may return the instance itself with an additional retain, as no
+/// observer can tell the difference. Copying a mutable instance returns a snapshot of its current
+/// contents that is not affected by later mutations.
+pub trait Copying: Object {
+    /// The immutable type of a copy.
+    type Immutable: Object + ForeignFunctionInterface;
+
+    /// Returns an immutable copy of the instance.
+    #[must_use]
+    fn copy(&self) -> Arc<Self::Immutable>;
+}
+
+/// A Core Foundation object type whose instances can be copied into a new mutable instance.
+pub trait MutableCopying: Object {
+    /// The mutable type of a copy.
+    type Mutable: Object + ForeignFunctionInterface;
+
+    /// Returns a uniquely owned, mutable copy of the instance.
+    #[must_use]
+    fn mutable_copy(&self) -> Box<Self::Mutable>;
+}
+
+/// A Core Foundation object type that may be transferred and shared across threads.
+///
+/// Core Foundation guarantees immutable objects may be shared across threads, while mutable objects
+/// must not be mutated concurrently. A type is thread safe, and implements [`Send`] and [`Sync`], if
+/// all of the following are true:
+///
+/// * Instances are immutable, or every method that mutates an instance requires exclusive access
+///   (i.e., `&mut self`, which is only available through a uniquely owned [`Box`]), or the Core
+///   Foundation functions that mutate an instance are documented as thread safe.
+/// * Any Rust values retained by an instance (e.g., callbacks) are [`Send`] and [`Sync`].
+///
+/// Types are marked thread safe by passing `thread_safe` to [`define_and_impl_type`].
+///
+/// # Safety
+///
+/// The implementation **must** meet the criteria above.
+///
+/// [`Box`]: crate::boxed::Box
+pub unsafe trait ThreadSafe: Object + Send + Sync {}
+
+/// Records that a smart pointer took ownership of `cf` if the `debug-retain` feature is enabled.
+#[doc(hidden)]
+// LINT: The function can only be `const` when the `debug-retain` feature is disabled.
+#[allow(clippy::missing_const_for_fn)]
+#[inline]
+pub fn debug_retain_record_ownership(cf: *const c_void, type_name: &'static str) {
+    #[cfg(feature = "debug-retain")]
+    crate::debug::record_ownership(cf, type_name);
+    #[cfg(not(feature = "debug-retain"))]
+    let _ = (cf, type_name);
+}
+
+/// Records that a smart pointer released its ownership of `cf` if the `debug-retain` feature is
+/// enabled.
+#[doc(hidden)]
+// LINT: The function can only be `const` when the `debug-retain` feature is disabled.
+#[allow(clippy::missing_const_for_fn)]
+#[inline]
+pub fn debug_retain_record_release(cf: *const c_void) {
+    #[cfg(feature = "debug-retain")]
+    crate::debug::record_release(cf);
+    #[cfg(not(feature = "debug-retain"))]
+    let _ = cf;
+}
+
 /// Defines a new type on which to implement Rust bindings for a Core Foundation object type. This
 /// macro also implements the [`Object`], [`Debug`] [`Eq`], and [`PartialEq`] traits on the new
 /// type.
@@ -31,10 +105,48 @@ pub trait Object {}
 /// A new type is required to implement the many of the standard traits, as the type definition
 /// originates in a separate `-sys` crate.
 ///
+/// If `thread_safe` follows the raw type, the macro also implements [`ThreadSafe`], [`Send`], and
+/// [`Sync`] on the new type. The instantiator guarantees the safety of this by verifying the type
+/// meets the criteria documented by [`ThreadSafe`].
+///
+/// If `type_id` follows the raw type, the macro also implements [`TryFromForeign`] on the new type,
+/// using the given function (e.g., `CFStringGetTypeID`) to reject object instances of other types.
+///
 /// [`Debug`]: core::fmt::Debug
 /// [`ForeignFunctionInterface`]: crate::ffi::ForeignFunctionInterface
+/// [`TryFromForeign`]: crate::ffi::TryFromForeign
 #[macro_export]
 macro_rules! define_and_impl_type {
+    (
+        $(#[$doc:meta])* $ty:ident, raw: $raw_ty:ident, type_id: $type_id:path
+        $(, $thread_safe:ident)?
+    ) => {
+        $crate::define_and_impl_type!($(#[$doc])* $ty, raw: $raw_ty $(, $thread_safe)?);
+
+        #[allow(unused_qualifications)]
+        impl $crate::ffi::TryFromForeign for $ty {
+            #[inline]
+            unsafe fn is_instance(ptr: core::ptr::NonNull<Self::Raw>) -> bool {
+                let cf = ptr.as_ptr().cast_const().cast();
+                // SAFETY: Caller asserts `cf` is a non-null pointer to a [`CFTypeRef`].
+                let type_id = unsafe { corefoundation_sys::CFGetTypeID(cf) };
+                // SAFETY: The function is always safe to call.
+                type_id == unsafe { $type_id() }
+            }
+        }
+    };
+    ($(#[$doc:meta])* $ty:ident, raw: $raw_ty:ident, thread_safe) => {
+        $crate::define_and_impl_type!($(#[$doc])* $ty, raw: $raw_ty);
+
+        // SAFETY: The instantiator asserts `$ty` meets the criteria documented by [`ThreadSafe`].
+        unsafe impl $crate::ThreadSafe for $ty {}
+
+        // SAFETY: The instantiator asserts `$ty` meets the criteria documented by [`ThreadSafe`].
+        unsafe impl Send for $ty {}
+
+        // SAFETY: The instantiator asserts `$ty` meets the criteria documented by [`ThreadSafe`].
+        unsafe impl Sync for $ty {}
+    };
     ($(#[$doc:meta])* $ty:ident, raw: $raw_ty:ident) => {
         $crate::opaque_type!($(#[$doc])* $ty);
 
@@ -42,6 +154,34 @@ macro_rules! define_and_impl_type {
         impl $crate::ffi::ForeignFunctionInterface for $ty {
             type Raw = $raw_ty;
 
+            #[inline]
+            unsafe fn from_owned_ptr(ptr: core::ptr::NonNull<Self::Raw>) -> $crate::sync::Arc<Self>
+            where
+                Self: Sized,
+            {
+                $crate::debug_retain_record_ownership(
+                    ptr.as_ptr().cast_const().cast(),
+                    core::any::type_name::<Self>(),
+                );
+                // SAFETY: Caller asserts `ptr` meets all safety requirements.
+                unsafe { $crate::sync::Arc::from_owned_ptr(ptr) }
+            }
+
+            #[inline]
+            unsafe fn from_owned_mut_ptr(
+                ptr: core::ptr::NonNull<Self::Raw>
+            ) -> $crate::boxed::Box<Self>
+            where
+                Self: Sized,
+            {
+                $crate::debug_retain_record_ownership(
+                    ptr.as_ptr().cast_const().cast(),
+                    core::any::type_name::<Self>(),
+                );
+                // SAFETY: Caller asserts `ptr` meets all safety requirements.
+                unsafe { $crate::boxed::Box::from_owned_mut_ptr(ptr) }
+            }
+
             #[inline]
             unsafe fn from_borrowed_ptr(
                 ptr: core::ptr::NonNull<Self::Raw>
@@ -51,7 +191,9 @@ macro_rules! define_and_impl_type {
             {
                 let cf = ptr.as_ptr().cast();
                 // SAFETY: `cf` is a non-null pointer to a [`CFTypeRef`].
-                let cf = unsafe { corefoundation_sys::CFRetain(cf) }.cast_mut();
+                let cf = unsafe { corefoundation_sys::CFRetain(cf) };
+                $crate::debug_retain_record_ownership(cf, core::any::type_name::<Self>());
+                let cf = cf.cast_mut();
                 // SAFETY: [`CFRetain`] is guaranteed to return its argument.
                 let cf = unsafe { core::ptr::NonNull::new_unchecked(cf) }.cast();
                 // SAFETY: Caller asserts `cf` meets all safety requirements.
@@ -61,6 +203,7 @@ macro_rules! define_and_impl_type {
             #[inline]
             unsafe fn release(this: &mut Self) {
                 let cf = this.as_ptr().cast();
+                $crate::debug_retain_record_release(cf);
                 // SAFETY: The creator of the `Self` instance asserted `Self::Raw` is compatible
                 // with the polymorphic Core Foundation functions.
                 unsafe { corefoundation_sys::CFRelease(cf) };
@@ -123,3 +266,55 @@ macro_rules! define_and_impl_type {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use crate::data::Data;
+    use crate::date::Date;
+    use crate::ffi::{ForeignFunctionInterface, TryFromForeign, TryFromForeignError};
+    use crate::string::MutableString;
+    use corefoundation_sys::{
+        kCFAllocatorDefault, CFDataCreateMutable, CFGetRetainCount, CFRetain, CFStringCreateMutable,
+    };
+
+    #[test]
+    fn try_from_owned_mismatch_releases() {
+        let data = Data::from_bytes(b"owned");
+        let cf = data.as_ptr().cast();
+        // SAFETY: `cf` is a valid [`CFTypeRef`].
+        let retained = unsafe { CFRetain(cf) };
+        // SAFETY: `cf` is a valid [`CFTypeRef`].
+        let count = unsafe { CFGetRetainCount(cf) };
+
+        // SAFETY: `retained` is a valid [`CFTypeRef`] with a retain that must be released.
+        let error = unsafe { Date::try_from_owned(retained.cast()) }.unwrap_err();
+        assert_eq!(error, TryFromForeignError::TypeMismatch);
+        // SAFETY: `cf` is still retained by `data`.
+        assert_eq!(unsafe { CFGetRetainCount(cf) }, count - 1);
+    }
+
+    #[test]
+    fn try_from_owned_mut() {
+        // SAFETY: The function is always safe to call.
+        let cf = unsafe { CFStringCreateMutable(kCFAllocatorDefault, 0) };
+        // SAFETY: The [`CFMutableStringRef`] follows the create rule.
+        let mut string = unsafe { MutableString::try_from_owned_mut(cf) }.unwrap();
+        string.push_str("mutable");
+        assert_eq!(string.to_string(), "mutable");
+    }
+
+    #[test]
+    fn try_from_owned_mut_mismatch() {
+        // SAFETY: The function is always safe to call.
+        let cf = unsafe { CFDataCreateMutable(kCFAllocatorDefault, 0) };
+        // SAFETY: The [`CFMutableDataRef`] follows the create rule, and is released on mismatch.
+        let error = unsafe { MutableString::try_from_owned_mut(cf.cast()) }.unwrap_err();
+        assert_eq!(error, TryFromForeignError::TypeMismatch);
+
+        // SAFETY: A `NULL` pointer is rejected before it is used.
+        let error = unsafe { MutableString::try_from_owned_mut(core::ptr::null_mut()) };
+        assert_eq!(error.unwrap_err(), TryFromForeignError::Null);
+    }
+}
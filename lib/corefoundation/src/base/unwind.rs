@@ -0,0 +1,42 @@
+//! Support for calling Rust closures from Core Foundation callbacks.
+
+/// Calls `f`, aborting the process if it panics.
+///
+/// Unwinding out of an `extern "C"` function is undefined behavior, so callbacks invoked by Core
+/// Foundation run their closures through this function.
+#[cfg(feature = "std")]
+pub(crate) fn abort_on_unwind<F, T>(f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    std::panic::catch_unwind(core::panic::AssertUnwindSafe(f))
+        .unwrap_or_else(|_| std::process::abort())
+}
+
+/// Calls `f`, aborting the process if it panics.
+///
+/// Unwinding out of an `extern "C"` function is undefined behavior, so callbacks invoked by Core
+/// Foundation run their closures through this function.
+#[cfg(not(feature = "std"))]
+pub(crate) fn abort_on_unwind<F, T>(f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    struct AbortOnUnwind;
+
+    impl Drop for AbortOnUnwind {
+        fn drop(&mut self) {
+            extern "C" {
+                fn abort() -> !;
+            }
+
+            // SAFETY: The function has no preconditions.
+            unsafe { abort() }
+        }
+    }
+
+    let guard = AbortOnUnwind;
+    let result = f();
+    core::mem::forget(guard);
+    result
+}
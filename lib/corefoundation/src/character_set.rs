@@ -0,0 +1,235 @@
+//! A set of Unicode code points, used to search for characters in a [`String`].
+//!
+//! [`String`]: crate::string::String
+
+use crate::define_and_impl_type;
+use crate::ffi::{ForeignFunctionInterface, TryFromForeign};
+use crate::string::String;
+use crate::sync::Arc;
+use crate::Copying;
+use core::ops::RangeInclusive;
+use core::ptr::NonNull;
+use corefoundation_sys::{
+    kCFAllocatorDefault, kCFCharacterSetAlphaNumeric, kCFCharacterSetCapitalizedLetter,
+    kCFCharacterSetControl, kCFCharacterSetDecimalDigit, kCFCharacterSetDecomposable,
+    kCFCharacterSetIllegal, kCFCharacterSetLetter, kCFCharacterSetLowercaseLetter,
+    kCFCharacterSetNewline, kCFCharacterSetNonBase, kCFCharacterSetPunctuation,
+    kCFCharacterSetSymbol, kCFCharacterSetUppercaseLetter, kCFCharacterSetWhitespace,
+    kCFCharacterSetWhitespaceAndNewline, CFCharacterSetCreateCopy, CFCharacterSetCreateInvertedSet,
+    CFCharacterSetCreateWithCharactersInRange, CFCharacterSetCreateWithCharactersInString,
+    CFCharacterSetGetPredefined, CFCharacterSetIsLongCharacterMember, CFCharacterSetIsSupersetOfSet,
+    CFCharacterSetPredefinedSet, CFCharacterSetRef, CFIndex, CFRange, __CFCharacterSet,
+};
+
+// SAFETY: Character sets are immutable.
+define_and_impl_type!(
+    /// An immutable set of Unicode code points.
+    CharacterSet,
+    raw: __CFCharacterSet,
+    type_id: corefoundation_sys::CFCharacterSetGetTypeID,
+    thread_safe
+);
+
+/// Identifies one of the character sets predefined by Core Foundation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+#[repr(isize)]
+pub enum Predefined {
+    /// Unicode General Category L*, M*, and N*.
+    AlphaNumeric = kCFCharacterSetAlphaNumeric,
+
+    /// Titlecase letters (Unicode General Category Lt).
+    CapitalizedLetter = kCFCharacterSetCapitalizedLetter,
+
+    /// Control characters (Unicode General Category Cc and Cf).
+    Control = kCFCharacterSetControl,
+
+    /// Decimal digits (Unicode General Category Nd).
+    DecimalDigit = kCFCharacterSetDecimalDigit,
+
+    /// Canonically decomposable characters.
+    Decomposable = kCFCharacterSetDecomposable,
+
+    /// Values that are not valid Unicode code points or are not yet assigned.
+    Illegal = kCFCharacterSetIllegal,
+
+    /// Unicode General Category L* and M*.
+    Letter = kCFCharacterSetLetter,
+
+    /// Lowercase letters (Unicode General Category Ll).
+    LowercaseLetter = kCFCharacterSetLowercaseLetter,
+
+    /// Newline characters (`U+000A..=U+000D`, `U+0085`, `U+2028`, and `U+2029`).
+    Newline = kCFCharacterSetNewline,
+
+    /// Non-base characters (Unicode General Category M*).
+    NonBase = kCFCharacterSetNonBase,
+
+    /// Punctuation characters (Unicode General Category P*).
+    Punctuation = kCFCharacterSetPunctuation,
+
+    /// Symbol characters (Unicode General Category S*).
+    Symbol = kCFCharacterSetSymbol,
+
+    /// Uppercase and titlecase letters (Unicode General Category Lu and Lt).
+    UppercaseLetter = kCFCharacterSetUppercaseLetter,
+
+    /// Whitespace characters (Unicode General Category Zs and `U+0009`).
+    Whitespace = kCFCharacterSetWhitespace,
+
+    /// Whitespace and newline characters (Unicode General Category Z*, `U+000A..=U+000D`, and
+    /// `U+0085`).
+    WhitespaceAndNewline = kCFCharacterSetWhitespaceAndNewline,
+}
+
+impl CharacterSet {
+    /// Returns the character set predefined by Core Foundation identified by `set`.
+    #[inline]
+    #[must_use]
+    pub fn predefined(set: Predefined) -> Arc<Self> {
+        let set = set.into();
+        // SAFETY: `set` is a valid predefined set identifier.
+        let cf = unsafe { CFCharacterSetGetPredefined(set) };
+        // SAFETY: [`CFCharacterSetGetPredefined`] only returns `NULL` for invalid identifiers.
+        let cf = unsafe { NonNull::new_unchecked(cf.cast_mut()) };
+        // SAFETY: [`CFCharacterSetGetPredefined`] follows the get rule.
+        unsafe { Self::from_borrowed_ptr(cf) }
+    }
+
+    /// Returns a character set containing the code points in `range`.
+    #[inline]
+    #[must_use]
+    pub fn from_range(range: RangeInclusive<char>) -> Arc<Self> {
+        let (start, end) = range.into_inner();
+        let (start, end) = (u32::from(start), u32::from(end));
+        // UB: A Unicode scalar value is less than `0x110000`, so the increment cannot overflow. An
+        // empty range produces an empty set.
+        let length = end.wrapping_add(1).saturating_sub(start);
+        // A Unicode scalar value is less than `0x110000`, which is always representable by
+        // [`CFIndex`].
+        let range = CFRange {
+            location: start.try_into().unwrap_or(CFIndex::MAX),
+            length: length.try_into().unwrap_or(CFIndex::MAX),
+        };
+
+        // SAFETY: `range` is a valid [`CFRange`].
+        let cf = unsafe { CFCharacterSetCreateWithCharactersInRange(kCFAllocatorDefault, range) };
+        // SAFETY: The [`CFCharacterSetRef`] follows the create rule.
+        unsafe { Self::create(cf) }
+    }
+
+    /// Returns a character set containing the code points in `string`.
+    #[inline]
+    #[must_use]
+    pub fn from_string(string: &String) -> Arc<Self> {
+        // SAFETY: `string` is a valid [`CFStringRef`].
+        let cf = unsafe {
+            CFCharacterSetCreateWithCharactersInString(kCFAllocatorDefault, string.as_ptr())
+        };
+        // SAFETY: The [`CFCharacterSetRef`] follows the create rule.
+        unsafe { Self::create(cf) }
+    }
+
+    /// Creates an `Arc<CharacterSet>` from `cf`.
+    ///
+    /// # Safety
+    ///
+    /// `cf` must be a [`CFCharacterSetRef`] that follows the create rule.
+    unsafe fn create(cf: CFCharacterSetRef) -> Arc<Self> {
+        // SAFETY: Caller asserts `cf` follows the create rule.
+        let set = unsafe { Self::try_from_owned(cf) };
+        // Core Foundation only returns `NULL` if the allocator fails.
+        set.unwrap_or_else(|_| panic!("allocation failed"))
+    }
+
+    /// Returns `true` if the set contains the code point `c`.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, c: char) -> bool {
+        // SAFETY: `self` is a valid [`CFCharacterSetRef`].
+        let result = unsafe { CFCharacterSetIsLongCharacterMember(self.as_ptr(), c.into()) };
+        result != 0
+    }
+
+    /// Returns a character set containing only the code points *not* in `self`.
+    #[inline]
+    #[must_use]
+    pub fn inverted(&self) -> Arc<Self> {
+        // SAFETY: `self` is a valid [`CFCharacterSetRef`].
+        let cf = unsafe { CFCharacterSetCreateInvertedSet(kCFAllocatorDefault, self.as_ptr()) };
+        // SAFETY: The [`CFCharacterSetRef`] follows the create rule.
+        unsafe { Self::create(cf) }
+    }
+
+    /// Returns `true` if `self` contains every code point in `other`.
+    #[inline]
+    #[must_use]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        // SAFETY: `self` and `other` are valid [`CFCharacterSetRef`]s.
+        let result = unsafe { CFCharacterSetIsSupersetOfSet(self.as_ptr(), other.as_ptr()) };
+        result != 0
+    }
+}
+
+impl Copying for CharacterSet {
+    type Immutable = Self;
+
+    #[inline]
+    fn copy(&self) -> Arc<Self::Immutable> {
+        // SAFETY: `self` is a valid [`CFCharacterSetRef`].
+        let cf = unsafe { CFCharacterSetCreateCopy(kCFAllocatorDefault, self.as_ptr()) };
+        // SAFETY: The [`CFCharacterSetRef`] follows the create rule.
+        unsafe { Self::create(cf) }
+    }
+}
+
+impl From<Predefined> for CFCharacterSetPredefinedSet {
+    // LINT: This is a lossless conversion into the type required by the FFI.
+    #[allow(clippy::as_conversions)]
+    #[inline]
+    fn from(value: Predefined) -> Self {
+        value as Self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfstr;
+
+    #[test]
+    fn predefined() {
+        let whitespace = CharacterSet::predefined(Predefined::Whitespace);
+        assert!(whitespace.contains(' '));
+        assert!(whitespace.contains('\t'));
+        assert!(!whitespace.contains('\n'));
+
+        let whitespace_and_newline = CharacterSet::predefined(Predefined::WhitespaceAndNewline);
+        assert!(whitespace_and_newline.contains('\n'));
+        assert!(whitespace_and_newline.is_superset(&whitespace));
+        assert!(!whitespace.is_superset(&whitespace_and_newline));
+    }
+
+    #[test]
+    fn from_range() {
+        let set = CharacterSet::from_range('a'..='c');
+        assert!(set.contains('a'));
+        assert!(set.contains('c'));
+        assert!(!set.contains('d'));
+
+        let set = CharacterSet::from_range('🐻'..='🐻');
+        assert!(set.contains('🐻'));
+        assert!(!set.contains('🐼'));
+
+        let inverted = set.inverted();
+        assert!(!inverted.contains('🐻'));
+        assert!(inverted.contains('🐼'));
+    }
+
+    #[test]
+    fn from_string() {
+        let set = CharacterSet::from_string(cfstr!("xyz"));
+        assert!(set.contains('y'));
+        assert!(!set.contains('a'));
+    }
+}
@@ -0,0 +1,211 @@
+//! An immutable, contiguous sequence of bytes.
+
+use crate::define_and_impl_type;
+use crate::ffi::convert::FromUnchecked;
+use crate::ffi::{ForeignFunctionInterface, TryFromForeign};
+use crate::sync::Arc;
+use crate::Copying;
+#[cfg(feature = "darwin")]
+use core::ffi::CStr;
+use core::slice;
+use corefoundation_sys::{
+    kCFAllocatorDefault, CFDataCreate, CFDataCreateCopy, CFDataGetBytePtr, CFDataGetLength, CFIndex,
+    __CFData,
+};
+#[cfg(feature = "darwin")]
+use darwin::c::errno::Error;
+#[cfg(feature = "darwin")]
+use darwin::sys::mman::Mapping;
+
+// SAFETY: Data objects are immutable.
+define_and_impl_type!(
+    /// A static byte buffer, used to package bytes for Core Foundation APIs.
+    Data,
+    raw: __CFData,
+    type_id: corefoundation_sys::CFDataGetTypeID,
+    thread_safe
+);
+
+impl Data {
+    /// Returns a [`Data`] object initialized by copying the bytes from the byte slice.
+    #[inline]
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Arc<Self> {
+        fn inner(bytes: &[u8]) -> Arc<Data> {
+            let buf = bytes.as_ptr();
+            // UB: A slice's length cannot exceed [`isize::MAX`].
+            let len = CFIndex::from_unchecked(bytes.len());
+
+            // SAFETY: `len` is the correct size of `buf`, and `buf` is a valid pointer.
+            let cf = unsafe { CFDataCreate(kCFAllocatorDefault, buf, len) };
+
+            // SAFETY: The [`CFDataRef`] was just created so it's an exclusive pointer, it has a
+            // retain that must be released, and [`Data`] is a correct [`CFType`] implementation.
+            //
+            // [`CFDataCreate`] only returns `NULL` if the Core Foundation allocator fails.
+            unsafe { Data::try_from_owned(cf) }.unwrap_or_else(|_| alloc_error(bytes.len()))
+        }
+        inner(bytes.as_ref())
+    }
+
+    /// Returns a [`Data`] object that takes ownership of `bytes` and uses them as its backing store
+    /// instead of copying them. `bytes` is dropped once the object no longer needs it.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn from_bytes_no_copy<B>(bytes: B) -> Arc<Self>
+    where
+        B: AsRef<[u8]> + Send + 'static,
+    {
+        use crate::base::allocator::Deallocator;
+        use corefoundation_sys::CFDataCreateWithBytesNoCopy;
+
+        let len = bytes.as_ref().len();
+        let Some(deallocator) = Deallocator::new(bytes) else {
+            return alloc_error(len);
+        };
+        let (buf, len) = deallocator.bytes();
+        // UB: A slice's length cannot exceed [`isize::MAX`].
+        let cf_len = CFIndex::from_unchecked(len);
+
+        // SAFETY: `buf` and `len` describe the bytes owned by `deallocator`, which drops them only
+        // when Core Foundation no longer needs them. The object retains the allocator for as long
+        // as it needs it.
+        let cf = unsafe {
+            CFDataCreateWithBytesNoCopy(kCFAllocatorDefault, buf, cf_len, deallocator.as_ptr())
+        };
+
+        // SAFETY: The [`CFDataRef`] was just created so it's an exclusive pointer, it has a retain
+        // that must be released, and [`Data`] is a correct [`CFType`] implementation.
+        //
+        // [`CFDataCreateWithBytesNoCopy`] only returns `NULL` if the Core Foundation allocator
+        // fails.
+        unsafe { Self::try_from_owned(cf) }.unwrap_or_else(|_| alloc_error(len))
+    }
+
+    /// Returns a [`Data`] object backed by a read-only memory mapping of the file at `path`.
+    ///
+    /// The file's contents are paged in as they are accessed rather than copied, so the object can
+    /// represent a file that is much larger than the available memory. The file is unmapped once
+    /// the object no longer needs it.
+    ///
+    /// The file should not be modified while it is mapped: changes made by other processes may be
+    /// visible through the object, and accessing the object after the file is truncated raises
+    /// `SIGBUS`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the file could not be opened or mapped.
+    #[cfg(feature = "darwin")]
+    #[inline]
+    pub fn from_file_mapped(path: impl AsRef<CStr>) -> Result<Arc<Self>, Error> {
+        Mapping::from_path(path).map(Self::from_bytes_no_copy)
+    }
+
+    /// Returns a byte slice of the object's contents.
+    #[inline]
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        let len = self.len();
+        if len == 0 {
+            return &[];
+        }
+
+        let cf = self.as_ptr();
+        // SAFETY: `cf` is a valid [`CFDataRef`].
+        let data = unsafe { CFDataGetBytePtr(cf) };
+
+        // SAFETY: [`CFDataGetBytePtr`] returns a pointer to `len` contiguous bytes that remain
+        // valid for as long as `self` is not mutated or deallocated.
+        unsafe { slice::from_raw_parts(data, len) }
+    }
+
+    /// Returns `true` if the object does not contain any bytes.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of bytes contained by the object.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        let cf = self.as_ptr();
+        // SAFETY: `cf` is a valid [`CFDataRef`].
+        let length = unsafe { CFDataGetLength(cf) };
+        // UB: Core Foundation will never return a negative number.
+        usize::from_unchecked(length)
+    }
+}
+
+impl AsRef<[u8]> for Data {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Copying for Data {
+    type Immutable = Self;
+
+    #[inline]
+    fn copy(&self) -> Arc<Self::Immutable> {
+        // SAFETY: `self` is a valid [`CFDataRef`].
+        let cf = unsafe { CFDataCreateCopy(kCFAllocatorDefault, self.as_ptr()) };
+        // SAFETY: The [`CFDataRef`] follows the create rule. [`CFDataCreateCopy`] only returns
+        // `NULL` if the Core Foundation allocator fails.
+        unsafe { Self::try_from_owned(cf) }.unwrap_or_else(|_| alloc_error(self.len()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn alloc_error(len: usize) -> Arc<Data> {
+    use alloc::alloc::{handle_alloc_error, Layout};
+
+    // SAFETY: [`Layout`] is used only for error reporting purposes. 100% accuracy is not required.
+    let layout = unsafe { Layout::from_size_align_unchecked(len, 1) };
+
+    handle_alloc_error(layout);
+}
+
+#[cfg(not(feature = "alloc"))]
+fn alloc_error(_len: usize) -> Arc<Data> {
+    panic!("allocation failed")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn from_bytes() {
+        let data = Data::from_bytes(b"\x00\x01\xfe\xff");
+        assert!(!data.is_empty());
+        assert_eq!(data.len(), 4);
+        assert_eq!(data.as_bytes(), b"\x00\x01\xfe\xff");
+
+        let empty = Data::from_bytes([]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.as_bytes(), b"");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn from_bytes_no_copy() {
+        let data = Data::from_bytes_no_copy(alloc::vec![0, 1, 0xfe, 0xff]);
+        assert_eq!(data.as_bytes(), b"\x00\x01\xfe\xff");
+
+        assert!(Data::from_bytes_no_copy([]).is_empty());
+    }
+
+    #[cfg(feature = "darwin")]
+    #[test]
+    fn from_file_mapped() {
+        let path = CStr::from_bytes_with_nul(b"/etc/hosts\0").unwrap();
+        assert!(!Data::from_file_mapped(path).unwrap().is_empty());
+
+        let path = CStr::from_bytes_with_nul(b"/nonexistent\0").unwrap();
+        assert_eq!(Data::from_file_mapped(path).unwrap_err(), Error::NotFound);
+    }
+}
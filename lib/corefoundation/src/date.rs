@@ -0,0 +1,260 @@
+//! Points in time, independent of any calendar or time zone.
+
+use crate::define_and_impl_type;
+use crate::ffi::{ForeignFunctionInterface, TryFromForeign};
+use crate::sync::Arc;
+#[cfg(feature = "time")]
+use crate::time_zone::TimeZone;
+#[cfg(feature = "std")]
+use core::time::Duration;
+use corefoundation_sys::{
+    kCFAllocatorDefault, CFAbsoluteTimeGetCurrent, CFDateCreate, CFDateGetAbsoluteTime,
+    CFDateGetTimeIntervalSinceDate, __CFDate,
+};
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+#[cfg(feature = "time")]
+use time::{OffsetDateTime, UtcOffset};
+
+/// The number of nanoseconds in a second.
+#[cfg(feature = "time")]
+const NANOS_PER_SEC: f64 = 1_000_000_000.0;
+
+// SAFETY: Date objects are immutable.
+define_and_impl_type!(
+    /// An immutable point in time, measured in seconds relative to the absolute reference date of
+    /// 2001-01-01 00:00:00 UTC.
+    Date,
+    raw: __CFDate,
+    type_id: corefoundation_sys::CFDateGetTypeID,
+    thread_safe
+);
+
+impl Date {
+    /// Returns a date for `absolute_time`, the number of seconds since the absolute reference date.
+    /// Negative values precede the reference date.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date could not be created.
+    #[inline]
+    #[must_use]
+    pub fn from_absolute_time(absolute_time: f64) -> Arc<Self> {
+        // SAFETY: The function is always safe to call.
+        let cf = unsafe { CFDateCreate(kCFAllocatorDefault, absolute_time) };
+        // SAFETY: The [`CFDateRef`] follows the create rule.
+        unsafe { Self::try_from_owned(cf) }.expect("CFDateCreate")
+    }
+
+    /// Returns a date for the same point in time as `time`, regardless of its offset, with
+    /// sub-microsecond precision for dates within a few centuries of the reference date.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date could not be created.
+    #[cfg(feature = "time")]
+    #[inline]
+    #[must_use]
+    pub fn from_offset_date_time(time: OffsetDateTime) -> Arc<Self> {
+        // LINT: Precision loss is inherent in the conversion to an `f64` absolute time, and is
+        // only observable for dates millions of years from the reference date.
+        #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+        let seconds = time.unix_timestamp() as f64;
+        let since_1970 = seconds + f64::from(time.nanosecond()) / NANOS_PER_SEC;
+        Self::from_absolute_time(since_1970 - interval_since_1970())
+    }
+
+    /// Returns a date for the same point in time as `time`, with sub-microsecond precision for
+    /// dates within a few centuries of the reference date.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date could not be created.
+    #[cfg(feature = "std")]
+    #[inline]
+    #[must_use]
+    pub fn from_system_time(time: SystemTime) -> Arc<Self> {
+        let since_1970 = match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs_f64(),
+            Err(error) => -error.duration().as_secs_f64(),
+        };
+        Self::from_absolute_time(since_1970 - interval_since_1970())
+    }
+
+    /// Returns the current date.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date could not be created.
+    #[inline]
+    #[must_use]
+    pub fn now() -> Arc<Self> {
+        // SAFETY: The function is always safe to call.
+        Self::from_absolute_time(unsafe { CFAbsoluteTimeGetCurrent() })
+    }
+
+    /// Returns the number of seconds since the absolute reference date. Negative values precede
+    /// the reference date.
+    #[inline]
+    #[must_use]
+    pub fn absolute_time(&self) -> f64 {
+        // SAFETY: `self` is a valid [`CFDateRef`].
+        unsafe { CFDateGetAbsoluteTime(self.as_ptr()) }
+    }
+
+    /// Returns the number of seconds from `other` to this date, which is negative if this date
+    /// precedes `other`.
+    #[inline]
+    #[must_use]
+    pub fn time_interval_since(&self, other: &Self) -> f64 {
+        // SAFETY: `self` and `other` are valid [`CFDateRef`]s.
+        unsafe { CFDateGetTimeIntervalSinceDate(self.as_ptr(), other.as_ptr()) }
+    }
+
+    /// Returns the date as an [`OffsetDateTime`] whose offset is that of `time_zone` at the date
+    /// (e.g., accounting for daylight saving time), or [`None`] if the date cannot be represented by
+    /// an [`OffsetDateTime`].
+    ///
+    /// The time is rounded to the nearest nanosecond.
+    #[cfg(feature = "time")]
+    #[inline]
+    #[must_use]
+    pub fn to_offset_date_time(&self, time_zone: &TimeZone) -> Option<OffsetDateTime> {
+        let since_1970 = (self.absolute_time() + interval_since_1970()) * NANOS_PER_SEC;
+        if !since_1970.is_finite() {
+            return None;
+        }
+
+        // LINT: The float to integer conversion saturates, and any saturated value is out of the
+        // range supported by [`OffsetDateTime`], so it is rejected below.
+        #[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+        let since_1970 = since_1970.round() as i128;
+        let utc = OffsetDateTime::from_unix_timestamp_nanos(since_1970).ok()?;
+
+        // LINT: Core Foundation offsets are whole seconds within a day of GMT, and any saturated
+        // value is out of the range supported by [`UtcOffset`], so it is rejected below.
+        #[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+        let offset = time_zone.seconds_from_gmt(self).round() as i32;
+        utc.checked_to_offset(UtcOffset::from_whole_seconds(offset).ok()?)
+    }
+
+    /// Returns the date as a [`SystemTime`], or [`None`] if the date cannot be represented by a
+    /// [`SystemTime`].
+    #[cfg(feature = "std")]
+    #[inline]
+    #[must_use]
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        let since_1970 = self.absolute_time() + interval_since_1970();
+        if since_1970 >= 0.0 {
+            UNIX_EPOCH.checked_add(Duration::try_from_secs_f64(since_1970).ok()?)
+        } else {
+            UNIX_EPOCH.checked_sub(Duration::try_from_secs_f64(-since_1970).ok()?)
+        }
+    }
+}
+
+/// Returns the number of seconds between the Unix epoch and the absolute reference date.
+#[cfg(any(feature = "std", feature = "time"))]
+fn interval_since_1970() -> f64 {
+    // SAFETY: The constant is initialized when the framework is loaded and never modified.
+    unsafe { corefoundation_sys::kCFAbsoluteTimeIntervalSince1970 }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::data::Data;
+    use crate::ffi::{TryFromForeign, TryFromForeignError};
+    use core::ptr;
+    use core::time::Duration;
+    use std::time::UNIX_EPOCH;
+
+    #[test]
+    fn absolute_time() {
+        let date = Date::from_absolute_time(86_400.5);
+        assert_eq!(date.absolute_time(), 86_400.5_f64);
+
+        let reference = Date::from_absolute_time(0.0);
+        assert_eq!(date.time_interval_since(&reference), 86_400.5_f64);
+        assert_eq!(reference.time_interval_since(&date), -86_400.5_f64);
+    }
+
+    #[test]
+    fn try_from_foreign() {
+        let date = Date::now();
+        // SAFETY: `date` is a valid [`CFDateRef`].
+        let borrowed = unsafe { Date::try_from_borrowed(date.as_ptr()) }.unwrap();
+        assert_eq!(borrowed, date);
+
+        let bytes = Data::from_bytes(b"date");
+        let cf = bytes.as_ptr().cast();
+        // SAFETY: `cf` is a valid [`CFTypeRef`].
+        let error = unsafe { Date::try_from_borrowed(cf) }.unwrap_err();
+        assert_eq!(error, TryFromForeignError::TypeMismatch);
+
+        // SAFETY: A `NULL` pointer is rejected before it is used.
+        let error = unsafe { Date::try_from_owned(ptr::null()) }.unwrap_err();
+        assert_eq!(error, TryFromForeignError::Null);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn system_time() {
+        let reference = UNIX_EPOCH + Duration::from_secs(978_307_200);
+        assert_eq!(Date::from_system_time(reference).absolute_time(), 0.0_f64);
+
+        let time = reference + Duration::from_millis(1_500);
+        let date = Date::from_system_time(time);
+        assert_eq!(date.absolute_time(), 1.5_f64);
+        assert_eq!(date.to_system_time().unwrap(), time);
+
+        let before_1970 = UNIX_EPOCH - Duration::from_secs(60);
+        let date = Date::from_system_time(before_1970);
+        assert_eq!(date.to_system_time().unwrap(), before_1970);
+
+        assert!(Date::now().to_system_time().unwrap() > reference);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn offset_date_time() {
+        use crate::cfstr;
+
+        let at = |nanos| OffsetDateTime::from_unix_timestamp_nanos(nanos).unwrap();
+        let hours = |hours| UtcOffset::from_hms(hours, 0, 0).unwrap();
+
+        // 2001-01-01 00:00:01.5 UTC
+        let time = at(978_307_201_500_000_000);
+        let date = Date::from_offset_date_time(time);
+        assert_eq!(date.absolute_time(), 1.5_f64);
+
+        // The offset does not change the point in time.
+        let offset = time.to_offset(hours(-8));
+        assert_eq!(Date::from_offset_date_time(offset), date);
+
+        let utc = TimeZone::from_seconds_from_gmt(0.0).unwrap();
+        assert_eq!(date.to_offset_date_time(&utc).unwrap(), time);
+
+        let los_angeles = TimeZone::from_name(cfstr!("America/Los_Angeles")).unwrap();
+        let winter = date.to_offset_date_time(&los_angeles).unwrap();
+        assert_eq!(winter, time);
+        assert_eq!(winter.offset(), hours(-8));
+
+        // 2001-07-01 00:00:00 UTC is in daylight saving time.
+        let summer = Date::from_offset_date_time(at(993_945_600_000_000_000));
+        let summer = summer.to_offset_date_time(&los_angeles).unwrap();
+        assert_eq!(summer.offset(), hours(-7));
+        assert_eq!(summer.hour(), 17);
+
+        // 1969-12-31 23:59:59.25 UTC
+        let before_1970 = at(-750_000_000);
+        let date = Date::from_offset_date_time(before_1970);
+        assert_eq!(date.to_offset_date_time(&utc).unwrap(), before_1970);
+
+        assert!(Date::from_absolute_time(f64::MAX)
+            .to_offset_date_time(&utc)
+            .is_none());
+    }
+}
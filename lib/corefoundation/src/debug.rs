@@ -0,0 +1,168 @@
+//! Retain count debugging for crates implementing Rust bindings for Core Foundation types.
+//!
+//! When the `debug-retain` feature is enabled, every type defined by [`define_and_impl_type`]
+//! records each object it takes ownership of through the [`ForeignFunctionInterface`] constructors
+//! (the create and get rules) and each release of that ownership. Objects that remain in the
+//! registry are owned by a live [`Arc<T>`] or [`Box<T>`]. An object that outlives the code that
+//! created it typically indicates a leaked smart pointer, or that a constructor following the get
+//! rule was used for a pointer that follows the create rule.
+//!
+//! Releases of objects that are not in the registry indicate the opposite mistake: a constructor
+//! following the create rule was used for a pointer that follows the get rule, which over-releases
+//! the object.
+//!
+//! The registry only observes ownership transferred through the smart pointers. Retains and
+//! releases performed by Core Foundation, or by code using the raw bindings directly, are not
+//! recorded, but are reflected in the retain count reported by Core Foundation.
+//!
+//! [`Arc<T>`]: crate::sync::Arc
+//! [`Box<T>`]: crate::boxed::Box
+//! [`ForeignFunctionInterface`]: crate::ffi::ForeignFunctionInterface
+//! [`define_and_impl_type`]: crate::define_and_impl_type
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::ffi::c_void;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use corefoundation_sys::{CFGetRetainCount, CFIndex};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// An object owned by one or more smart pointers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LiveObject {
+    /// The address of the object.
+    pub address: *const c_void,
+
+    /// The name of the Rust type through which ownership of the object was most recently taken.
+    pub type_name: &'static str,
+
+    /// The number of smart pointers that own the object.
+    pub owners: usize,
+
+    /// The retain count reported by Core Foundation, which includes references not owned by a smart
+    /// pointer.
+    pub retain_count: CFIndex,
+}
+
+/// The address of an object, which is only used as a key and never dereferenced.
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+struct Address(*const c_void);
+
+// SAFETY: The address is never dereferenced through the registry.
+unsafe impl Send for Address {}
+
+struct Entry {
+    type_name: &'static str,
+    owners: usize,
+}
+
+static REGISTRY: Mutex<BTreeMap<Address, Entry>> = Mutex::new(BTreeMap::new());
+static UNBALANCED_RELEASES: AtomicUsize = AtomicUsize::new(0);
+
+fn registry() -> MutexGuard<'static, BTreeMap<Address, Entry>> {
+    // The registry is always left in a consistent state, so a poisoned lock can be recovered.
+    REGISTRY.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+pub(crate) fn record_ownership(cf: *const c_void, type_name: &'static str) {
+    let mut registry = registry();
+    let entry = registry.entry(Address(cf)).or_insert(Entry {
+        type_name,
+        owners: 0,
+    });
+    entry.type_name = type_name;
+    entry.owners = entry.owners.saturating_add(1);
+    drop(registry);
+}
+
+pub(crate) fn record_release(cf: *const c_void) {
+    let address = Address(cf);
+    let mut registry = registry();
+
+    if let Some(entry) = registry.get_mut(&address) {
+        entry.owners = entry.owners.saturating_sub(1);
+        if entry.owners == 0 {
+            let _ = registry.remove(&address);
+        }
+        drop(registry);
+    } else {
+        drop(registry);
+        let _ = UNBALANCED_RELEASES.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Returns the objects currently owned by one or more smart pointers, ordered by address.
+#[inline]
+#[must_use]
+pub fn live_objects() -> Vec<LiveObject> {
+    registry()
+        .iter()
+        .map(|(&address, entry)| {
+            // SAFETY: The object is owned by at least one smart pointer, so it's a valid
+            // [`CFTypeRef`].
+            let retain_count = unsafe { CFGetRetainCount(address.0) };
+            LiveObject {
+                address: address.0,
+                type_name: entry.type_name,
+                owners: entry.owners,
+                retain_count,
+            }
+        })
+        .collect()
+}
+
+/// Returns the number of times a smart pointer released an object it did not take ownership of
+/// through one of the [`ForeignFunctionInterface`] constructors.
+///
+/// [`ForeignFunctionInterface`]: crate::ffi::ForeignFunctionInterface
+#[inline]
+#[must_use]
+pub fn unbalanced_releases() -> usize {
+    UNBALANCED_RELEASES.load(Ordering::Relaxed)
+}
+
+/// Writes a report of the objects currently owned by smart pointers, and the number of unbalanced
+/// releases, to the standard error stream.
+#[inline]
+pub fn dump_live_objects() {
+    let objects = live_objects();
+
+    eprintln!("{} live Core Foundation object(s):", objects.len());
+    for object in &objects {
+        eprintln!(
+            "  {:p} {} (owners: {}, retain count: {})",
+            object.address, object.type_name, object.owners, object.retain_count
+        );
+    }
+    eprintln!("{} unbalanced release(s)", unbalanced_releases());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::ForeignFunctionInterface;
+    use crate::string::String;
+
+    fn owners(cf: *const c_void) -> usize {
+        live_objects()
+            .iter()
+            .find(|object| object.address == cf)
+            .map_or(0, |object| object.owners)
+    }
+
+    #[test]
+    fn ownership() {
+        let string = String::from_str("debug-retain");
+        let cf = string.as_ptr().cast();
+        assert_eq!(owners(cf), 1);
+
+        let clone = string.clone();
+        assert_eq!(owners(cf), 2);
+
+        drop(string);
+        assert_eq!(owners(cf), 1);
+
+        drop(clone);
+        assert_eq!(owners(cf), 0);
+    }
+}
@@ -0,0 +1,254 @@
+//! A minimal executor that drives Rust futures from a thread's run loop.
+//!
+//! [`LocalExecutor`] polls futures on the thread that created it, from a custom [`Source`] added to
+//! that thread's [`RunLoop`]. Waking a future signals the source and wakes the run loop, so async
+//! code interoperates with Apple's event loop (e.g., on the main thread of an application) without
+//! a separate runtime.
+
+use crate::base::unwind::abort_on_unwind;
+use crate::ffi::TryFromForeign;
+use crate::run_loop::{Mode, RunLoop, Source};
+use crate::sync::Arc;
+use alloc::boxed::Box;
+use alloc::task::Wake;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::ffi::c_void;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::mem;
+use core::pin::{pin, Pin};
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+use corefoundation_sys::{kCFAllocatorDefault, CFRunLoopSourceContext, CFRunLoopSourceCreate};
+
+/// The longest time [`LocalExecutor::block_on`] runs the run loop before checking its future.
+const RUN_INTERVAL: Duration = Duration::from_secs(60 * 60 * 24);
+
+/// Drives futures from the run loop of the thread that created it.
+///
+/// Futures spawned on the executor are polled while the thread's run loop runs in any of the
+/// common modes, whether it is run by [`LocalExecutor::block_on`], [`RunLoop::run`], or an
+/// application framework. Because the futures are always polled on that thread, they need not be
+/// [`Send`].
+///
+/// A panic in a future aborts the process, because it cannot unwind through the run loop.
+pub struct LocalExecutor {
+    tasks: Box<RefCell<Vec<Task>>>,
+    run_loop: Arc<RunLoop>,
+    source: Arc<Source>,
+}
+
+/// A spawned future and the waker that schedules it.
+struct Task {
+    future: Pin<Box<dyn Future<Output = ()>>>,
+    signal: alloc::sync::Arc<Signal>,
+}
+
+/// Wakes a future by marking it ready to be polled and signaling the executor's run loop source.
+struct Signal {
+    woken: AtomicBool,
+    run_loop: Arc<RunLoop>,
+    source: Arc<Source>,
+}
+
+impl LocalExecutor {
+    /// Constructs a new executor that polls futures from the current thread's run loop.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the run loop source could not be created.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        let tasks = Box::new(RefCell::new(Vec::new()));
+        let info: *const RefCell<Vec<Task>> = &*tasks;
+
+        let mut context = CFRunLoopSourceContext {
+            version: 0,
+            info: info.cast_mut().cast(),
+            retain: None,
+            release: None,
+            copyDescription: None,
+            equal: None,
+            hash: None,
+            schedule: None,
+            cancel: None,
+            perform: Some(perform),
+        };
+
+        // SAFETY: `context` is valid for the duration of the call, and `info` is valid until the
+        // source is invalidated when the executor is dropped.
+        let cf = unsafe { CFRunLoopSourceCreate(kCFAllocatorDefault, 0, &mut context) };
+        // SAFETY: The [`CFRunLoopSourceRef`] follows the create rule.
+        let source = unsafe { Source::try_from_owned(cf) }.expect("CFRunLoopSourceCreate");
+
+        let run_loop = RunLoop::current();
+        run_loop.add_source(&source, Mode::Common);
+
+        Self {
+            tasks,
+            run_loop,
+            source,
+        }
+    }
+
+    /// Runs the current thread's run loop until `future` completes, and returns its output.
+    ///
+    /// Futures spawned on the executor (and any other sources in the default mode) make progress
+    /// while this waits. The executor must have been created on the current thread.
+    #[inline]
+    pub fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future,
+    {
+        let signal = self.new_signal();
+        let waker = Waker::from(alloc::sync::Arc::clone(&signal));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+
+        loop {
+            if signal.woken.swap(false, Ordering::AcqRel) {
+                if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                    return output;
+                }
+            }
+
+            // The executor's source is always in the default mode, so the run loop returns after it
+            // processes the source (i.e., after any future is woken) or the interval elapses. Either
+            // way, the future's signal is checked again.
+            let _result = RunLoop::run_in_mode(Mode::Default, RUN_INTERVAL, true);
+        }
+    }
+
+    /// Spawns `future` to be polled to completion from the run loop.
+    ///
+    /// The future is first polled the next time the run loop runs.
+    #[inline]
+    pub fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        let signal = self.new_signal();
+        self.tasks.borrow_mut().push(Task {
+            future: Box::pin(future),
+            signal: alloc::sync::Arc::clone(&signal),
+        });
+        signal.wake();
+    }
+
+    fn new_signal(&self) -> alloc::sync::Arc<Signal> {
+        alloc::sync::Arc::new(Signal {
+            woken: AtomicBool::new(true),
+            run_loop: Arc::clone(&self.run_loop),
+            source: Arc::clone(&self.source),
+        })
+    }
+}
+
+impl Debug for LocalExecutor {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalExecutor")
+            .field("run_loop", &self.run_loop)
+            .field("source", &self.source)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for LocalExecutor {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for LocalExecutor {
+    #[inline]
+    fn drop(&mut self) {
+        // Invalidation removes the source from the run loop, so `perform` is never called again
+        // with the pointer to `tasks`. Wakers may outlive the executor; signaling an invalid source
+        // has no effect.
+        self.source.invalidate();
+    }
+}
+
+impl Task {
+    /// Polls the future if it was woken. Returns `true` if the future has not completed.
+    fn poll(&mut self) -> bool {
+        if !self.signal.woken.swap(false, Ordering::AcqRel) {
+            return true;
+        }
+
+        let waker = Waker::from(alloc::sync::Arc::clone(&self.signal));
+        let mut cx = Context::from_waker(&waker);
+        self.future.as_mut().poll(&mut cx).is_pending()
+    }
+}
+
+impl Wake for Signal {
+    #[inline]
+    fn wake(self: alloc::sync::Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    #[inline]
+    fn wake_by_ref(self: &alloc::sync::Arc<Self>) {
+        self.woken.store(true, Ordering::Release);
+        self.source.signal();
+        self.run_loop.wake_up();
+    }
+}
+
+extern "C" fn perform(info: *mut c_void) {
+    // SAFETY: `info` points to the executor's tasks, which outlive the source's registration with
+    // the run loop. The run loop only calls `perform` on the executor's thread.
+    let tasks = unsafe { &*info.cast::<RefCell<Vec<Task>>>().cast_const() };
+
+    // Take the tasks out of the cell so futures can spawn new tasks while they are polled.
+    let mut pending = mem::take(&mut *tasks.borrow_mut());
+    abort_on_unwind(|| pending.retain_mut(Task::poll));
+
+    let mut tasks = tasks.borrow_mut();
+    pending.append(&mut tasks);
+    *tasks = pending;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    #[test]
+    fn block_on() {
+        let executor = LocalExecutor::new();
+        assert_eq!(executor.block_on(async { 41_i32 + 1_i32 }), 42_i32);
+    }
+
+    #[test]
+    fn spawn() {
+        let executor = LocalExecutor::new();
+        let count = Rc::new(Cell::new(0_u32));
+
+        for _ in 0..3_u8 {
+            let count = Rc::clone(&count);
+            executor.spawn(async move { count.set(count.get() + 1) });
+        }
+
+        // Yield once so the run loop processes the spawned tasks before the main future completes.
+        let mut yielded = false;
+        executor.block_on(core::future::poll_fn(|cx| {
+            if yielded {
+                Poll::Ready(())
+            } else {
+                yielded = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }));
+
+        assert_eq!(count.get(), 3);
+    }
+}
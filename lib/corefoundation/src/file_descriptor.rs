@@ -0,0 +1,233 @@
+//! A wrapper for a native file descriptor that can be monitored for activity on a run loop.
+
+use crate::base::unwind::abort_on_unwind;
+use crate::define_and_impl_type;
+use crate::ffi::{ForeignFunctionInterface, TryFromForeign};
+use crate::run_loop::Source;
+use crate::sync::Arc;
+use alloc::boxed::Box;
+use core::ffi::{c_int, c_void};
+use core::ops::BitOr;
+use corefoundation_sys::{
+    kCFAllocatorDefault, kCFFileDescriptorReadCallBack, kCFFileDescriptorWriteCallBack,
+    CFFileDescriptorContext, CFFileDescriptorCreate, CFFileDescriptorCreateRunLoopSource,
+    CFFileDescriptorDisableCallBacks, CFFileDescriptorEnableCallBacks,
+    CFFileDescriptorGetNativeDescriptor, CFFileDescriptorInvalidate, CFFileDescriptorIsValid,
+    CFFileDescriptorRef, CFOptionFlags, __CFFileDescriptor,
+};
+
+// SAFETY: The file descriptor functions exposed through these bindings are thread safe. The
+// callback is required to be [`Send`] and [`Sync`] as the descriptor's run loop sources may be
+// added to run loops on multiple threads.
+define_and_impl_type!(
+    /// A wrapper for a native POSIX file descriptor.
+    ///
+    /// When the descriptor becomes readable or writable, the condition is delivered to the
+    /// descriptor's callback when a run loop [`Source`] created with
+    /// [`FileDescriptor::create_run_loop_source`] is added to a running [`RunLoop`].
+    ///
+    /// Callbacks are one-shot: once a condition is delivered, its callback type is disabled and
+    /// must be re-enabled with [`FileDescriptor::enable_callbacks`] to receive the next one.
+    ///
+    /// [`RunLoop`]: crate::run_loop::RunLoop
+    FileDescriptor,
+    raw: __CFFileDescriptor,
+    type_id: corefoundation_sys::CFFileDescriptorGetTypeID,
+    thread_safe
+);
+
+/// A set of conditions that trigger a [`FileDescriptor`]'s callback.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct CallBackTypes(CFOptionFlags);
+
+impl CallBackTypes {
+    /// The descriptor has data available to read.
+    pub const READ: Self = Self(kCFFileDescriptorReadCallBack);
+
+    /// The descriptor has space available to write.
+    pub const WRITE: Self = Self(kCFFileDescriptorWriteCallBack);
+
+    /// Tests whether all of the conditions in `other` are in this set.
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if the set has no conditions.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for CallBackTypes {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl FileDescriptor {
+    /// Creates a file descriptor object for the native descriptor `fd`. `callback` is invoked with
+    /// the conditions that triggered it each time an enabled condition is met.
+    ///
+    /// If `close_on_invalidate` is `true`, the object takes ownership of `fd` and closes it when
+    /// the object is invalidated or destroyed.
+    ///
+    /// Returns [`None`] if the object could not be created.
+    ///
+    /// # Safety
+    ///
+    /// `fd` must be an open file descriptor that remains open for the lifetime of the object. If
+    /// `close_on_invalidate` is `true`, `fd` must be owned by the caller, who transfers its
+    /// ownership to the object and must not use or close it afterwards.
+    #[inline]
+    pub unsafe fn new<F>(fd: c_int, close_on_invalidate: bool, callback: F) -> Option<Arc<Self>>
+    where
+        F: Fn(&Self, CallBackTypes) + Send + Sync + 'static,
+    {
+        let info = Box::into_raw(Box::new(callback));
+        let context = CFFileDescriptorContext {
+            version: 0,
+            info: info.cast(),
+            retain: None,
+            release: Some(release::<F>),
+            copyDescription: None,
+        };
+
+        // SAFETY: `context` is a valid pointer. Core Foundation copies the context and takes
+        // ownership of `info` if the object is created.
+        let cf = unsafe {
+            CFFileDescriptorCreate(
+                kCFAllocatorDefault,
+                fd,
+                close_on_invalidate.into(),
+                callout::<F>,
+                &context,
+            )
+        };
+
+        if cf.is_null() {
+            // SAFETY: Core Foundation did not take ownership of `info`, which was created above by
+            // [`Box::into_raw`].
+            drop(unsafe { Box::from_raw(info) });
+        }
+
+        // SAFETY: The [`CFFileDescriptorRef`] follows the create rule, and [`FileDescriptor`] is a
+        // correct [`CFType`] implementation.
+        unsafe { Self::try_from_owned(cf) }.ok()
+    }
+
+    /// Creates a run loop source that delivers the enabled conditions to the descriptor's
+    /// callback. `order` specifies the priority with which the run loop processes the source.
+    ///
+    /// Returns [`None`] if the source could not be created.
+    #[inline]
+    #[must_use]
+    pub fn create_run_loop_source(&self, order: isize) -> Option<Arc<Source>> {
+        // SAFETY: `self` is a valid [`CFFileDescriptorRef`].
+        let cf = unsafe {
+            CFFileDescriptorCreateRunLoopSource(kCFAllocatorDefault, self.as_ptr(), order)
+        };
+        // SAFETY: The [`CFRunLoopSourceRef`] follows the create rule.
+        unsafe { Source::try_from_owned(cf) }.ok()
+    }
+
+    /// Stops delivering the conditions in `types` to the descriptor's callback.
+    #[inline]
+    pub fn disable_callbacks(&self, types: CallBackTypes) {
+        // SAFETY: `self` is a valid [`CFFileDescriptorRef`].
+        unsafe { CFFileDescriptorDisableCallBacks(self.as_ptr(), types.0) };
+    }
+
+    /// Delivers the next occurrence of each condition in `types` to the descriptor's callback.
+    #[inline]
+    pub fn enable_callbacks(&self, types: CallBackTypes) {
+        // SAFETY: `self` is a valid [`CFFileDescriptorRef`].
+        unsafe { CFFileDescriptorEnableCallBacks(self.as_ptr(), types.0) };
+    }
+
+    /// Returns the native file descriptor represented by the object.
+    #[inline]
+    #[must_use]
+    pub fn fd(&self) -> c_int {
+        // SAFETY: `self` is a valid [`CFFileDescriptorRef`].
+        unsafe { CFFileDescriptorGetNativeDescriptor(self.as_ptr()) }
+    }
+
+    /// Invalidates the object, which stops monitoring the descriptor, removes its run loop sources
+    /// from all run loops, and closes the descriptor if the object owns it.
+    #[inline]
+    pub fn invalidate(&self) {
+        // SAFETY: `self` is a valid [`CFFileDescriptorRef`].
+        unsafe { CFFileDescriptorInvalidate(self.as_ptr()) };
+    }
+
+    /// Returns `true` if the object is valid and monitoring the descriptor.
+    #[inline]
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        // SAFETY: `self` is a valid [`CFFileDescriptorRef`].
+        let result = unsafe { CFFileDescriptorIsValid(self.as_ptr()) };
+        result != 0
+    }
+}
+
+extern "C" fn callout<F>(f: CFFileDescriptorRef, types: CFOptionFlags, info: *mut c_void)
+where
+    F: Fn(&FileDescriptor, CallBackTypes) + Send + Sync + 'static,
+{
+    // SAFETY: `info` was created from a `Box<F>` and is valid until Core Foundation calls
+    // [`release`].
+    let callback = unsafe { &*info.cast::<F>().cast_const() };
+    // SAFETY: Core Foundation passes the valid object whose conditions were met.
+    let f = unsafe { &*f.cast::<FileDescriptor>() };
+
+    abort_on_unwind(|| callback(f, CallBackTypes(types)));
+}
+
+extern "C" fn release<F>(info: *mut c_void) {
+    // SAFETY: `info` was created by [`Box::into_raw`] in [`FileDescriptor::new`] and Core
+    // Foundation releases it exactly once.
+    drop(unsafe { Box::from_raw(info.cast::<F>()) });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn new_and_invalidate() {
+        // SAFETY: Standard input remains open for the lifetime of the test process and is not owned
+        // by the object, so it must not be closed on invalidation.
+        let f = unsafe { FileDescriptor::new(0, false, |_, _| {}) }.unwrap();
+        assert!(f.is_valid());
+        assert_eq!(f.fd(), 0_i32);
+
+        f.enable_callbacks(CallBackTypes::READ | CallBackTypes::WRITE);
+        f.disable_callbacks(CallBackTypes::WRITE);
+
+        let source = f.create_run_loop_source(0).unwrap();
+        assert!(source.is_valid());
+
+        f.invalidate();
+        assert!(!f.is_valid());
+        assert!(!source.is_valid());
+    }
+
+    #[test]
+    fn callback_types() {
+        let both = CallBackTypes::READ | CallBackTypes::WRITE;
+        assert!(both.contains(CallBackTypes::READ));
+        assert!(both.contains(CallBackTypes::WRITE));
+        assert!(!CallBackTypes::READ.contains(CallBackTypes::WRITE));
+        assert!(CallBackTypes::default().is_empty());
+    }
+}
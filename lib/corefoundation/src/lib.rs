@@ -17,9 +17,30 @@
 extern crate alloc;
 
 mod base;
+pub mod character_set;
+pub mod data;
+pub mod date;
+#[cfg(feature = "debug-retain")]
+pub mod debug;
+#[cfg(feature = "executor")]
+pub mod executor;
+#[cfg(feature = "alloc")]
+pub mod file_descriptor;
+pub mod locale;
+#[cfg(feature = "alloc")]
+pub mod mach_port;
+pub mod main_thread;
+#[cfg(feature = "alloc")]
+pub mod message_port;
+pub mod range;
+pub mod run_loop;
+pub mod stream;
 pub mod string;
+pub mod time_zone;
 
 pub use base::ffi;
-pub use base::object::Object;
+#[doc(hidden)]
+pub use base::object::{debug_retain_record_ownership, debug_retain_record_release};
+pub use base::object::{Copying, MutableCopying, Object, ThreadSafe};
 pub use c_ffi::opaque_type;
 pub use retain_release::{boxed, sync};
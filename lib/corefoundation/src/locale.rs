@@ -0,0 +1,90 @@
+//! Information about linguistic, cultural, and technological conventions, used to compare and
+//! format data for presentation.
+
+use crate::define_and_impl_type;
+use crate::ffi::{ForeignFunctionInterface, TryFromForeign};
+use crate::string::String;
+use crate::sync::Arc;
+use core::ptr::NonNull;
+use corefoundation_sys::{
+    kCFAllocatorDefault, CFLocaleCopyCurrent, CFLocaleCreate, CFLocaleGetIdentifier,
+    CFLocaleGetSystem, __CFLocale,
+};
+
+// SAFETY: Locale objects are immutable.
+define_and_impl_type!(
+    /// An immutable set of conventions for a particular language and region.
+    Locale,
+    raw: __CFLocale,
+    type_id: corefoundation_sys::CFLocaleGetTypeID,
+    thread_safe
+);
+
+impl Locale {
+    /// Returns the logical locale for the current user.
+    ///
+    /// The locale is a snapshot of the user's settings; it does not change if the settings change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the locale could not be created.
+    #[inline]
+    #[must_use]
+    pub fn current() -> Arc<Self> {
+        // SAFETY: The function is always safe to call.
+        let cf = unsafe { CFLocaleCopyCurrent() };
+        // SAFETY: The [`CFLocaleRef`] follows the create rule.
+        unsafe { Self::try_from_owned(cf) }.expect("CFLocaleCopyCurrent")
+    }
+
+    /// Returns a locale for `identifier` (e.g., `en_US`), or [`None`] if the locale could not be
+    /// created.
+    ///
+    /// The identifier is canonicalized, so [`Locale::identifier`] may not return `identifier`.
+    #[inline]
+    #[must_use]
+    pub fn from_identifier(identifier: &String) -> Option<Arc<Self>> {
+        // SAFETY: `identifier` is a valid [`CFStringRef`].
+        let cf = unsafe { CFLocaleCreate(kCFAllocatorDefault, identifier.as_ptr()) };
+        // SAFETY: The [`CFLocaleRef`] follows the create rule.
+        unsafe { Self::try_from_owned(cf) }.ok()
+    }
+
+    /// Returns the generic root locale, which has no language or region.
+    #[inline]
+    #[must_use]
+    pub fn system() -> Arc<Self> {
+        // SAFETY: The function is always safe to call.
+        let cf = unsafe { CFLocaleGetSystem() };
+        // SAFETY: [`CFLocaleGetSystem`] never returns `NULL`.
+        let cf = unsafe { NonNull::new_unchecked(cf.cast_mut()) };
+        // SAFETY: [`CFLocaleGetSystem`] follows the get rule.
+        unsafe { Self::from_borrowed_ptr(cf) }
+    }
+
+    /// Returns the locale's canonical identifier.
+    #[inline]
+    #[must_use]
+    pub fn identifier(&self) -> Arc<String> {
+        // SAFETY: `self` is a valid [`CFLocaleRef`].
+        let cf = unsafe { CFLocaleGetIdentifier(self.as_ptr()) };
+        // SAFETY: A locale always has an identifier.
+        let cf = unsafe { NonNull::new_unchecked(cf.cast_mut()) };
+        // SAFETY: [`CFLocaleGetIdentifier`] follows the get rule.
+        unsafe { String::from_borrowed_ptr(cf) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::cfstr;
+
+    #[test]
+    fn identifier() {
+        let locale = Locale::from_identifier(cfstr!("en_US")).unwrap();
+        assert_eq!(locale.identifier().to_string(), "en_US");
+    }
+}
@@ -0,0 +1,195 @@
+//! A wrapper for a native Mach port that can receive messages on a run loop.
+
+use crate::base::unwind::abort_on_unwind;
+use crate::define_and_impl_type;
+use crate::ffi::convert::FromUnchecked;
+use crate::ffi::{ForeignFunctionInterface, TryFromForeign};
+use crate::run_loop::Source;
+use crate::sync::Arc;
+use alloc::boxed::Box;
+use core::ffi::c_void;
+use core::slice;
+use corefoundation_sys::{
+    kCFAllocatorDefault, Boolean, CFIndex, CFMachPortContext, CFMachPortCreate,
+    CFMachPortCreateRunLoopSource, CFMachPortCreateWithPort, CFMachPortGetPort,
+    CFMachPortInvalidate, CFMachPortIsValid, CFMachPortRef, __CFMachPort,
+};
+
+// SAFETY: The Mach port functions exposed through these bindings are thread safe. The callback is
+// required to be [`Send`] and [`Sync`] as the port's run loop sources may be added to run loops on
+// multiple threads.
+define_and_impl_type!(
+    /// A wrapper for a native Mach port.
+    ///
+    /// Messages received by the port are delivered to the port's callback when a run loop
+    /// [`Source`] created with [`MachPort::create_run_loop_source`] is added to a running
+    /// [`RunLoop`].
+    ///
+    /// [`RunLoop`]: crate::run_loop::RunLoop
+    MachPort,
+    raw: __CFMachPort,
+    type_id: corefoundation_sys::CFMachPortGetTypeID,
+    thread_safe
+);
+
+impl MachPort {
+    /// Creates a new Mach port with receive and send rights. `callback` is invoked with the raw
+    /// bytes of each message received by the port.
+    ///
+    /// Returns [`None`] if the port could not be created.
+    #[inline]
+    pub fn new<F>(callback: F) -> Option<Arc<Self>>
+    where
+        F: Fn(&Self, &[u8]) + Send + Sync + 'static,
+    {
+        Self::create(callback, |context, should_free_info| {
+            // SAFETY: `context` and `should_free_info` are valid pointers.
+            unsafe {
+                CFMachPortCreate(kCFAllocatorDefault, callout::<F>, context, should_free_info)
+            }
+        })
+    }
+
+    /// Creates a Mach port object for the existing native Mach `port`. `callback` is invoked with
+    /// the raw bytes of each message received by the port.
+    ///
+    /// If a [`MachPort`] object already exists for `port`, that object is returned and `callback`
+    /// is dropped. Returns [`None`] if the port could not be created.
+    #[inline]
+    pub fn with_port<F>(port: u32, callback: F) -> Option<Arc<Self>>
+    where
+        F: Fn(&Self, &[u8]) + Send + Sync + 'static,
+    {
+        Self::create(callback, |context, should_free_info| {
+            // SAFETY: `context` and `should_free_info` are valid pointers.
+            unsafe {
+                CFMachPortCreateWithPort(
+                    kCFAllocatorDefault,
+                    port,
+                    callout::<F>,
+                    context,
+                    should_free_info,
+                )
+            }
+        })
+    }
+
+    fn create<F>(
+        callback: F,
+        create: impl FnOnce(&CFMachPortContext, *mut Boolean) -> CFMachPortRef,
+    ) -> Option<Arc<Self>>
+    where
+        F: Fn(&Self, &[u8]) + Send + Sync + 'static,
+    {
+        let info = Box::into_raw(Box::new(callback));
+        let context = CFMachPortContext {
+            version: 0,
+            info: info.cast(),
+            retain: None,
+            release: Some(release::<F>),
+            copyDescription: None,
+        };
+
+        let mut should_free_info = 0;
+        let cf = create(&context, &mut should_free_info);
+
+        if cf.is_null() || should_free_info != 0 {
+            // SAFETY: Core Foundation did not take ownership of `info`, which was created above by
+            // [`Box::into_raw`].
+            drop(unsafe { Box::from_raw(info) });
+        }
+
+        // SAFETY: The [`CFMachPortRef`] follows the create rule, and [`MachPort`] is a correct
+        // [`CFType`] implementation.
+        unsafe { Self::try_from_owned(cf) }.ok()
+    }
+
+    /// Creates a run loop source that delivers messages received by the port to its callback.
+    /// `order` specifies the priority with which the run loop processes the source.
+    ///
+    /// Returns [`None`] if the source could not be created.
+    #[inline]
+    #[must_use]
+    pub fn create_run_loop_source(&self, order: isize) -> Option<Arc<Source>> {
+        // SAFETY: `self` is a valid [`CFMachPortRef`].
+        let cf =
+            unsafe { CFMachPortCreateRunLoopSource(kCFAllocatorDefault, self.as_ptr(), order) };
+        // SAFETY: The [`CFRunLoopSourceRef`] follows the create rule.
+        unsafe { Source::try_from_owned(cf) }.ok()
+    }
+
+    /// Invalidates the port, which prevents it from receiving any more messages and removes its
+    /// run loop sources from all run loops.
+    #[inline]
+    pub fn invalidate(&self) {
+        // SAFETY: `self` is a valid [`CFMachPortRef`].
+        unsafe { CFMachPortInvalidate(self.as_ptr()) };
+    }
+
+    /// Returns `true` if the port is valid and able to receive messages.
+    #[inline]
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        // SAFETY: `self` is a valid [`CFMachPortRef`].
+        let result = unsafe { CFMachPortIsValid(self.as_ptr()) };
+        result != 0
+    }
+
+    /// Returns the native Mach port name represented by the object.
+    #[inline]
+    #[must_use]
+    pub fn port(&self) -> u32 {
+        // SAFETY: `self` is a valid [`CFMachPortRef`].
+        unsafe { CFMachPortGetPort(self.as_ptr()) }
+    }
+}
+
+extern "C" fn callout<F>(port: CFMachPortRef, msg: *mut c_void, size: CFIndex, info: *mut c_void)
+where
+    F: Fn(&MachPort, &[u8]) + Send + Sync + 'static,
+{
+    // SAFETY: `info` was created from a `Box<F>` and is valid until Core Foundation calls
+    // [`release`].
+    let callback = unsafe { &*info.cast::<F>().cast_const() };
+    // SAFETY: Core Foundation passes the valid port object receiving the message.
+    let port = unsafe { &*port.cast::<MachPort>() };
+
+    let msg = if msg.is_null() {
+        &[][..]
+    } else {
+        // UB: Core Foundation will never pass a negative size.
+        let size = usize::from_unchecked(size);
+        // SAFETY: Core Foundation guarantees `msg` points to a message of `size` bytes that is
+        // valid for the duration of the call.
+        unsafe { slice::from_raw_parts(msg.cast::<u8>().cast_const(), size) }
+    };
+
+    abort_on_unwind(|| callback(port, msg));
+}
+
+extern "C" fn release<F>(info: *const c_void) {
+    // SAFETY: `info` was created by [`Box::into_raw`] in [`MachPort::create`] and Core Foundation
+    // releases it exactly once.
+    drop(unsafe { Box::from_raw(info.cast::<F>().cast_mut()) });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn new_and_invalidate() {
+        let port = MachPort::new(|_, _| {}).unwrap();
+        assert!(port.is_valid());
+        assert_ne!(port.port(), 0);
+
+        let source = port.create_run_loop_source(0).unwrap();
+        assert!(source.is_valid());
+
+        port.invalidate();
+        assert!(!port.is_valid());
+        assert!(!source.is_valid());
+    }
+}
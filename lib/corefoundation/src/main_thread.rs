@@ -0,0 +1,75 @@
+//! Utilities for APIs that must only be used on the main thread.
+//!
+//! APIs that are only safe to use on the main thread (e.g., those adjacent to user interface
+//! frameworks) take a [`MainThreadMarker`] parameter. Because the marker can only be obtained on
+//! the main thread and cannot be sent to another thread, the requirement is checked by the compiler
+//! rather than documented as a safety precondition.
+
+use core::marker::PhantomData;
+use corefoundation_sys::{CFRunLoopGetCurrent, CFRunLoopGetMain};
+
+/// A zero-sized token proving the current thread is the main thread.
+///
+/// The marker is neither [`Send`] nor [`Sync`], so it cannot leave the main thread.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MainThreadMarker {
+    _not_send_or_sync: PhantomData<*const ()>,
+}
+
+impl MainThreadMarker {
+    /// Returns a marker if the current thread is the main thread, or [`None`] otherwise.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Option<Self> {
+        // SAFETY: The marker is only constructed on the main thread.
+        is_main_thread().then(|| unsafe { Self::new_unchecked() })
+    }
+
+    /// Returns a marker without checking whether the current thread is the main thread.
+    ///
+    /// # Safety
+    ///
+    /// The current thread must be the main thread.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn new_unchecked() -> Self {
+        Self {
+            _not_send_or_sync: PhantomData,
+        }
+    }
+}
+
+/// Panics if the current thread is not the main thread.
+///
+/// # Panics
+///
+/// Panics if the current thread is not the main thread.
+#[inline]
+#[track_caller]
+pub fn assert_main_thread() {
+    assert!(is_main_thread(), "must be called on the main thread");
+}
+
+/// Returns `true` if the current thread is the main thread.
+///
+/// The current thread's run loop is compared to the main thread's run loop, so a run loop is
+/// created for the current thread if it does not already have one.
+#[inline]
+#[must_use]
+pub fn is_main_thread() -> bool {
+    // SAFETY: The functions are always safe to call and never return `NULL`. Each thread has
+    // exactly one run loop, so the main thread's run loop is only current on the main thread.
+    unsafe { CFRunLoopGetCurrent() == CFRunLoopGetMain() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thread_is_not_main_thread() {
+        // The test harness runs each test on a spawned thread.
+        assert!(!is_main_thread());
+        assert_eq!(MainThreadMarker::new(), None);
+    }
+}
@@ -0,0 +1,345 @@
+//! A communications channel for sending arbitrary data between threads or processes on the same
+//! machine.
+
+use crate::base::unwind::abort_on_unwind;
+use crate::data::Data;
+use crate::define_and_impl_type;
+use crate::ffi::{ForeignFunctionInterface, TryFromForeign};
+use crate::run_loop::{Mode, Source};
+use crate::string::String;
+use crate::sync::Arc;
+use alloc::boxed::Box;
+use core::ffi::c_void;
+use core::fmt::{self, Display, Formatter};
+use core::mem;
+use core::ptr::{self, NonNull};
+use core::time::Duration;
+use corefoundation_sys::{
+    kCFAllocatorDefault, kCFMessagePortBecameInvalidError, kCFMessagePortIsInvalid,
+    kCFMessagePortReceiveTimeout, kCFMessagePortSendTimeout, kCFMessagePortSuccess, Boolean,
+    CFDataRef, CFMessagePortContext, CFMessagePortCreateLocal, CFMessagePortCreateRemote,
+    CFMessagePortCreateRunLoopSource, CFMessagePortGetName, CFMessagePortInvalidate,
+    CFMessagePortIsRemote, CFMessagePortIsValid, CFMessagePortRef, CFMessagePortSendRequest,
+    __CFMessagePort,
+};
+
+// SAFETY: The message port functions exposed through these bindings are thread safe. The callback
+// is required to be [`Send`] and [`Sync`] as the port's run loop sources may be added to run loops
+// on multiple threads.
+define_and_impl_type!(
+    /// A named communications channel for sending [`Data`] between threads or processes.
+    ///
+    /// A local port receives messages and delivers them to the port's callback when a run loop
+    /// [`Source`] created with [`MessagePort::create_run_loop_source`] is added to a running
+    /// [`RunLoop`]. A remote port sends messages to the local port of the same name.
+    ///
+    /// [`RunLoop`]: crate::run_loop::RunLoop
+    MessagePort,
+    raw: __CFMessagePort,
+    type_id: corefoundation_sys::CFMessagePortGetTypeID,
+    thread_safe
+);
+
+/// Indicates an error when sending a message through [`MessagePort::send`] or
+/// [`MessagePort::send_request`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum SendRequestError {
+    /// The message port was invalidated while waiting for a reply.
+    BecameInvalid,
+
+    /// The message port is invalid.
+    IsInvalid,
+
+    /// No reply was received before the receive timeout.
+    ReceiveTimeout,
+
+    /// The message could not be sent before the send timeout.
+    SendTimeout,
+
+    /// An error occurred trying to send the message.
+    TransportError,
+}
+
+impl MessagePort {
+    /// Creates a local port that receives messages sent to `name`.
+    ///
+    /// `callback` is invoked with the message identifier and data of each message received by the
+    /// port. The data it returns, if any, is sent to the sender as the reply.
+    ///
+    /// Returns [`None`] if a port named `name` is already registered by another process or if the
+    /// port could not be created. If this process already created a local port named `name`, that
+    /// port is returned and `callback` is dropped.
+    #[inline]
+    pub fn new_local<F>(name: &String, callback: F) -> Option<Arc<Self>>
+    where
+        F: Fn(&Self, i32, Option<&Data>) -> Option<Arc<Data>> + Send + Sync + 'static,
+    {
+        let info = Box::into_raw(Box::new(callback));
+        let context = CFMessagePortContext {
+            version: 0,
+            info: info.cast(),
+            retain: None,
+            release: Some(release::<F>),
+            copyDescription: None,
+        };
+
+        let mut should_free_info: Boolean = 0;
+        // SAFETY: `name` is a valid [`CFStringRef`] and the other pointers are valid.
+        let cf = unsafe {
+            CFMessagePortCreateLocal(
+                kCFAllocatorDefault,
+                name.as_ptr(),
+                callout::<F>,
+                &context,
+                &mut should_free_info,
+            )
+        };
+
+        if cf.is_null() || should_free_info != 0 {
+            // SAFETY: Core Foundation did not take ownership of `info`, which was created above by
+            // [`Box::into_raw`].
+            drop(unsafe { Box::from_raw(info) });
+        }
+
+        // SAFETY: The [`CFMessagePortRef`] follows the create rule, and [`MessagePort`] is a
+        // correct [`CFType`] implementation.
+        unsafe { Self::try_from_owned(cf) }.ok()
+    }
+
+    /// Creates a remote port that sends messages to the local port registered as `name`.
+    ///
+    /// Returns [`None`] if no local port named `name` exists.
+    #[inline]
+    #[must_use]
+    pub fn new_remote(name: &String) -> Option<Arc<Self>> {
+        // SAFETY: `name` is a valid [`CFStringRef`].
+        let cf = unsafe { CFMessagePortCreateRemote(kCFAllocatorDefault, name.as_ptr()) };
+        // SAFETY: The [`CFMessagePortRef`] follows the create rule, and [`MessagePort`] is a
+        // correct [`CFType`] implementation.
+        unsafe { Self::try_from_owned(cf) }.ok()
+    }
+
+    /// Creates a run loop source that delivers messages received by a local port to its callback.
+    /// `order` specifies the priority with which the run loop processes the source.
+    ///
+    /// Returns [`None`] if the port is remote or if the source could not be created.
+    #[inline]
+    #[must_use]
+    pub fn create_run_loop_source(&self, order: isize) -> Option<Arc<Source>> {
+        // SAFETY: `self` is a valid [`CFMessagePortRef`].
+        let cf =
+            unsafe { CFMessagePortCreateRunLoopSource(kCFAllocatorDefault, self.as_ptr(), order) };
+        // SAFETY: The [`CFRunLoopSourceRef`] follows the create rule.
+        unsafe { Source::try_from_owned(cf) }.ok()
+    }
+
+    /// Invalidates the port, which prevents it from sending or receiving any more messages.
+    #[inline]
+    pub fn invalidate(&self) {
+        // SAFETY: `self` is a valid [`CFMessagePortRef`].
+        unsafe { CFMessagePortInvalidate(self.as_ptr()) };
+    }
+
+    /// Returns `true` if the port sends messages to a local port.
+    #[inline]
+    #[must_use]
+    pub fn is_remote(&self) -> bool {
+        // SAFETY: `self` is a valid [`CFMessagePortRef`].
+        let result = unsafe { CFMessagePortIsRemote(self.as_ptr()) };
+        result != 0
+    }
+
+    /// Returns `true` if the port is valid and able to send or receive messages.
+    #[inline]
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        // SAFETY: `self` is a valid [`CFMessagePortRef`].
+        let result = unsafe { CFMessagePortIsValid(self.as_ptr()) };
+        result != 0
+    }
+
+    /// Returns the name with which the port is registered, if any.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> Option<Arc<String>> {
+        // SAFETY: `self` is a valid [`CFMessagePortRef`].
+        let cf = unsafe { CFMessagePortGetName(self.as_ptr()) };
+        let cf = NonNull::new(cf.cast_mut())?;
+        // SAFETY: [`CFMessagePortGetName`] follows the get rule.
+        Some(unsafe { String::from_borrowed_ptr(cf) })
+    }
+
+    /// Sends a message with the identifier `msg_id` and the optional `data` payload to a remote
+    /// port without waiting for a reply.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SendRequestError`] if the message could not be sent within `send_timeout`.
+    #[inline]
+    pub fn send(
+        &self,
+        msg_id: i32,
+        data: Option<&Data>,
+        send_timeout: Duration,
+    ) -> Result<(), SendRequestError> {
+        self.send_request_inner(msg_id, data, send_timeout, None)
+            .map(|_| ())
+    }
+
+    /// Sends a message with the identifier `msg_id` and the optional `data` payload to a remote
+    /// port and waits for the reply.
+    ///
+    /// While waiting for the reply, the current thread's run loop runs in the default mode, so
+    /// sources added to that mode (including those of a local port in this process) continue to
+    /// be serviced.
+    ///
+    /// Returns the reply data, or [`None`] if the receiver did not reply with any data.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SendRequestError`] if the message could not be sent within `send_timeout` or if
+    /// a reply was not received within `receive_timeout`.
+    #[inline]
+    pub fn send_request(
+        &self,
+        msg_id: i32,
+        data: Option<&Data>,
+        send_timeout: Duration,
+        receive_timeout: Duration,
+    ) -> Result<Option<Arc<Data>>, SendRequestError> {
+        self.send_request_inner(msg_id, data, send_timeout, Some(receive_timeout))
+    }
+
+    fn send_request_inner(
+        &self,
+        msg_id: i32,
+        data: Option<&Data>,
+        send_timeout: Duration,
+        receive_timeout: Option<Duration>,
+    ) -> Result<Option<Arc<Data>>, SendRequestError> {
+        let data = data.map_or(ptr::null(), ForeignFunctionInterface::as_ptr);
+        let (receive_timeout, reply_mode) = receive_timeout.map_or_else(
+            || (0.0_f64, ptr::null()),
+            |timeout| (timeout.as_secs_f64(), Mode::Default.as_ptr()),
+        );
+
+        let mut reply: CFDataRef = ptr::null();
+        // SAFETY: `self` is a valid [`CFMessagePortRef`], `data` is null or a valid [`CFDataRef`],
+        // and `reply_mode` is null or a valid [`CFStringRef`].
+        let result = unsafe {
+            CFMessagePortSendRequest(
+                self.as_ptr(),
+                msg_id,
+                data,
+                send_timeout.as_secs_f64(),
+                receive_timeout,
+                reply_mode,
+                &mut reply,
+            )
+        };
+
+        // LINT: The constants use Core Foundation's naming convention.
+        #[allow(non_upper_case_globals)]
+        match result {
+            // SAFETY: The reply [`CFDataRef`] follows the create rule.
+            kCFMessagePortSuccess => Ok(unsafe { Data::try_from_owned(reply) }.ok()),
+            kCFMessagePortBecameInvalidError => Err(SendRequestError::BecameInvalid),
+            kCFMessagePortIsInvalid => Err(SendRequestError::IsInvalid),
+            kCFMessagePortReceiveTimeout => Err(SendRequestError::ReceiveTimeout),
+            kCFMessagePortSendTimeout => Err(SendRequestError::SendTimeout),
+            _ => Err(SendRequestError::TransportError),
+        }
+    }
+}
+
+impl Display for SendRequestError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let description = match *self {
+            Self::BecameInvalid => "message port became invalid",
+            Self::IsInvalid => "message port is invalid",
+            Self::ReceiveTimeout => "timed out receiving reply",
+            Self::SendTimeout => "timed out sending message",
+            Self::TransportError => "message transport failed",
+        };
+        f.write_str(description)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SendRequestError {}
+
+extern "C" fn callout<F>(
+    local: CFMessagePortRef,
+    msg_id: i32,
+    data: CFDataRef,
+    info: *mut c_void,
+) -> CFDataRef
+where
+    F: Fn(&MessagePort, i32, Option<&Data>) -> Option<Arc<Data>> + Send + Sync + 'static,
+{
+    // SAFETY: `info` was created from a `Box<F>` and is valid until Core Foundation calls
+    // [`release`].
+    let callback = unsafe { &*info.cast::<F>().cast_const() };
+    // SAFETY: Core Foundation passes the valid port object receiving the message.
+    let local = unsafe { &*local.cast::<MessagePort>() };
+    // SAFETY: `data` is null or a valid [`CFDataRef`] for the duration of the call.
+    let data = unsafe { data.cast::<Data>().as_ref() };
+
+    abort_on_unwind(|| callback(local, msg_id, data)).map_or(ptr::null(), |reply| {
+        let cf = reply.as_ptr();
+        // Core Foundation releases the reply after it is sent, so transfer ownership of the retain.
+        mem::forget(reply);
+        cf
+    })
+}
+
+extern "C" fn release<F>(info: *const c_void) {
+    // SAFETY: `info` was created by [`Box::into_raw`] in [`MessagePort::new_local`] and Core
+    // Foundation releases it exactly once.
+    drop(unsafe { Box::from_raw(info.cast::<F>().cast_mut()) });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::cfstr;
+    use crate::run_loop::RunLoop;
+
+    const TIMEOUT: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn send_request() {
+        let name = cfstr!("com.github.briantkelley.apple-rs.corefoundation.message-port-test");
+
+        let local = MessagePort::new_local(name, |port, msg_id, data| {
+            assert!(!port.is_remote());
+            assert_eq!(msg_id, 7_i32);
+            let mut bytes = data.unwrap().as_bytes().to_vec();
+            bytes.reverse();
+            Some(Data::from_bytes(bytes))
+        })
+        .unwrap();
+        assert!(!local.is_remote());
+        assert_eq!(*local.name().unwrap(), *name);
+
+        let source = local.create_run_loop_source(0).unwrap();
+        RunLoop::current().add_source(&source, Mode::Default);
+
+        let remote = MessagePort::new_remote(name).unwrap();
+        assert!(remote.is_remote());
+
+        let request = Data::from_bytes(b"ping");
+        let reply = remote
+            .send_request(7, Some(&request), TIMEOUT, TIMEOUT)
+            .unwrap()
+            .unwrap();
+        assert_eq!(reply.as_bytes(), b"gnip");
+
+        local.invalidate();
+        assert!(!local.is_valid());
+    }
+}
@@ -0,0 +1,166 @@
+//! Conversion and bounds-checking of ranges of items in Core Foundation objects.
+//!
+//! Core Foundation describes a range of items with a [`CFRange`]: a signed `location` and
+//! `length`. Rust describes one with a [`RangeBounds<usize>`]. The conversions between them are
+//! implemented on [`CFRange`] so binding crates can share a single implementation of the bounds
+//! checks:
+//!
+//! * [`CFRange::try_from_range_bounds`] and [`CFRange::expect_from_range_bounds`] convert and
+//!   bounds-check a [`RangeBounds<usize>`] against the length of a container.
+//! * [`CFRange::saturating_from_range_bounds`] clamps a [`RangeBounds<usize>`] to the bounds of a
+//!   container instead of failing.
+//! * [`TryFrom<Range<usize>>`] and [`TryFrom<CFRange>`] convert between [`CFRange`] and
+//!   [`Range<usize>`] (or <code>[Option]<[Range]<[usize]>></code>, which maps `kCFNotFound` to
+//!   [`None`]).
+//! * [`CFRange::checked_end`], [`CFRange::checked_offset`], and [`CFRange::intersection`] perform
+//!   overflow-checked range arithmetic.
+//!
+//! [`StringRange`] is a range of UTF-16 code unit indices that has been validated against the length
+//! of a [`String`].
+//!
+//! [`String`]: crate::string::String
+
+use crate::ffi::convert::FromUnchecked;
+use crate::string::String;
+use core::ops::{Bound, Range, RangeBounds};
+pub use corefoundation_sys::{CFRange, TryFromCFRangeError, TryFromRangeError};
+
+/// A range of UTF-16 code unit indices into a [`String`].
+///
+/// The range is bounds-checked against the string's length when it's created, and every index can
+/// be represented by a `CFIndex`, so converting it into a [`CFRange`] is infallible.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StringRange(Range<usize>);
+
+impl StringRange {
+    /// Bounds-checks `range` against the UTF-16 code units of `string`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TryFromRangeError`] if `range`'s end is greater than the length of `string`, if
+    /// a range bound cannot be represented by a `CFIndex`, or if an exclusive bound overflows
+    /// [`usize`].
+    #[inline]
+    pub fn new(string: &String, range: impl RangeBounds<usize>) -> Result<Self, TryFromRangeError> {
+        let range = CFRange::try_from_range_bounds(range, string.len())?;
+        Ok(Self(Range::from_unchecked(range)))
+    }
+
+    /// Clamps `range` to the bounds of the UTF-16 code units of `string`.
+    #[inline]
+    #[must_use]
+    pub fn saturating_new(string: &String, range: impl RangeBounds<usize>) -> Self {
+        let range = CFRange::saturating_from_range_bounds(range, string.len());
+        Self(Range::from_unchecked(range))
+    }
+
+    /// Returns the index of the first UTF-16 code unit in the range.
+    #[inline]
+    #[must_use]
+    pub const fn start(&self) -> usize {
+        self.0.start
+    }
+
+    /// Returns the index one past the last UTF-16 code unit in the range.
+    #[inline]
+    #[must_use]
+    pub const fn end(&self) -> usize {
+        self.0.end
+    }
+
+    /// Returns the range of UTF-16 code units contained by both `self` and `other`, or [`None`] if
+    /// the ranges do not overlap.
+    #[inline]
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let start = self.0.start.max(other.0.start);
+        let end = self.0.end.min(other.0.end);
+        (start <= end).then_some(Self(start..end))
+    }
+
+    /// Returns `true` if the range does not contain any UTF-16 code units.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.start == self.0.end
+    }
+
+    /// Returns the number of UTF-16 code units in the range.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        // UB: The range was validated to have `start <= end`.
+        self.0.end.wrapping_sub(self.0.start)
+    }
+}
+
+impl From<StringRange> for CFRange {
+    #[inline]
+    fn from(value: StringRange) -> Self {
+        // UB: Both bounds were validated to be representable by `CFIndex`.
+        Self::from_unchecked(value.0)
+    }
+}
+
+impl From<StringRange> for Range<usize> {
+    #[inline]
+    fn from(value: StringRange) -> Self {
+        value.0
+    }
+}
+
+impl RangeBounds<usize> for StringRange {
+    #[inline]
+    fn start_bound(&self) -> Bound<&usize> {
+        Bound::Included(&self.0.start)
+    }
+
+    #[inline]
+    fn end_bound(&self) -> Bound<&usize> {
+        Bound::Excluded(&self.0.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::cfstr;
+
+    #[test]
+    fn new() {
+        let s = cfstr!("🐻‍❄️");
+
+        let range = StringRange::new(s, 2..).unwrap();
+        assert_eq!(range.start(), 2);
+        assert_eq!(range.end(), 5);
+        assert_eq!(range.len(), 3);
+        assert_eq!(
+            CFRange::from(range),
+            CFRange {
+                location: 2,
+                length: 3
+            }
+        );
+
+        let _ = StringRange::new(s, ..6).unwrap_err();
+        assert_eq!(StringRange::saturating_new(s, 3..100).len(), 2);
+    }
+
+    #[test]
+    fn intersection() {
+        let s = cfstr!("🐻‍❄️");
+        let head = StringRange::new(s, ..3).unwrap();
+        let tail = StringRange::new(s, 2..).unwrap();
+
+        assert_eq!(
+            Range::from(head.intersection(&tail).unwrap()),
+            Range { start: 2, end: 3 }
+        );
+        assert!(StringRange::new(s, ..1)
+            .unwrap()
+            .intersection(&StringRange::new(s, 2..).unwrap())
+            .is_none());
+    }
+}
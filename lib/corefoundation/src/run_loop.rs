@@ -0,0 +1,329 @@
+//! An event processing loop that monitors sources of input to a thread.
+
+#[cfg(feature = "alloc")]
+use crate::base::unwind::abort_on_unwind;
+use crate::define_and_impl_type;
+use crate::ffi::{ForeignFunctionInterface, TryFromForeign};
+use crate::string::String;
+use crate::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use core::ffi::c_void;
+use core::ptr::NonNull;
+use core::time::Duration;
+use corefoundation_sys::{
+    kCFRunLoopCommonModes, kCFRunLoopDefaultMode, kCFRunLoopRunFinished, kCFRunLoopRunHandledSource,
+    kCFRunLoopRunStopped, CFRunLoopAddSource, CFRunLoopContainsSource, CFRunLoopGetCurrent,
+    CFRunLoopGetMain, CFRunLoopMode, CFRunLoopRemoveSource, CFRunLoopRun, CFRunLoopRunInMode,
+    CFRunLoopSourceGetOrder, CFRunLoopSourceInvalidate, CFRunLoopSourceIsValid,
+    CFRunLoopSourceSignal, CFRunLoopStop, CFRunLoopWakeUp, __CFRunLoop, __CFRunLoopSource,
+};
+#[cfg(feature = "alloc")]
+use corefoundation_sys::{kCFAllocatorDefault, CFRunLoopSourceContext, CFRunLoopSourceCreate};
+
+// SAFETY: Core Foundation allows run loop references to be used from any thread, and the run loop
+// functions exposed through these bindings are thread safe. The run loop itself always runs on the
+// thread that owns it.
+define_and_impl_type!(
+    /// A loop that monitors sources of input to a thread and dispatches control when they become
+    /// ready for processing.
+    ///
+    /// Each thread has exactly one run loop, which is created on demand.
+    RunLoop,
+    raw: __CFRunLoop,
+    type_id: corefoundation_sys::CFRunLoopGetTypeID,
+    thread_safe
+);
+
+// SAFETY: The run loop source functions exposed through these bindings are thread safe.
+define_and_impl_type!(
+    /// An input source that can be added to a [`RunLoop`].
+    Source,
+    raw: __CFRunLoopSource,
+    type_id: corefoundation_sys::CFRunLoopSourceGetTypeID,
+    thread_safe
+);
+
+/// The collection of input sources to be monitored while a [`RunLoop`] is running.
+#[derive(Clone, Copy, Debug)]
+pub enum Mode<'name> {
+    /// The set of modes registered as "common" with the run loop. Sources added to this
+    /// pseudo-mode are monitored by every mode in the set.
+    Common,
+
+    /// The mode to deal with input sources other than connection objects.
+    Default,
+
+    /// A mode identified by a custom name.
+    Named(&'name String),
+}
+
+/// The reason [`RunLoop::run_in_mode`] returned.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RunResult {
+    /// The run loop mode has no sources or timers to process.
+    Finished,
+
+    /// A source was processed. This is only returned when `return_after_source_handled` is `true`.
+    HandledSource,
+
+    /// [`RunLoop::stop`] was called on the run loop.
+    Stopped,
+
+    /// The time interval `duration` passed.
+    TimedOut,
+}
+
+impl Mode<'_> {
+    pub(crate) fn as_ptr(self) -> CFRunLoopMode {
+        match self {
+            // SAFETY: The constant is initialized by Core Foundation and never mutated.
+            Self::Common => unsafe { kCFRunLoopCommonModes },
+            // SAFETY: The constant is initialized by Core Foundation and never mutated.
+            Self::Default => unsafe { kCFRunLoopDefaultMode },
+            Self::Named(name) => name.as_ptr(),
+        }
+    }
+}
+
+impl RunLoop {
+    /// Returns the run loop for the current thread, creating one if necessary.
+    #[inline]
+    #[must_use]
+    pub fn current() -> Arc<Self> {
+        // SAFETY: The function is always safe to call.
+        let cf = unsafe { CFRunLoopGetCurrent() };
+        // SAFETY: [`CFRunLoopGetCurrent`] never returns `NULL`.
+        let cf = unsafe { NonNull::new_unchecked(cf.cast_mut()) };
+        // SAFETY: [`CFRunLoopGetCurrent`] follows the get rule.
+        unsafe { Self::from_borrowed_ptr(cf) }
+    }
+
+    /// Returns the run loop of the main thread.
+    #[inline]
+    #[must_use]
+    pub fn main() -> Arc<Self> {
+        // SAFETY: The function is always safe to call.
+        let cf = unsafe { CFRunLoopGetMain() };
+        // SAFETY: [`CFRunLoopGetMain`] never returns `NULL`.
+        let cf = unsafe { NonNull::new_unchecked(cf.cast_mut()) };
+        // SAFETY: [`CFRunLoopGetMain`] follows the get rule.
+        unsafe { Self::from_borrowed_ptr(cf) }
+    }
+
+    /// Runs the current thread's run loop in the default mode indefinitely.
+    ///
+    /// The run loop returns when it is stopped with [`RunLoop::stop`] or when all sources and
+    /// timers are removed from the default mode.
+    #[inline]
+    pub fn run() {
+        // SAFETY: The function is always safe to call.
+        unsafe { CFRunLoopRun() };
+    }
+
+    /// Runs the current thread's run loop in `mode` for at most `duration`.
+    ///
+    /// If `return_after_source_handled` is `true`, the run loop returns after processing one
+    /// source.
+    #[inline]
+    #[must_use]
+    pub fn run_in_mode(
+        mode: Mode<'_>,
+        duration: Duration,
+        return_after_source_handled: bool,
+    ) -> RunResult {
+        let mode = mode.as_ptr();
+        let seconds = duration.as_secs_f64();
+        let return_after_source_handled = return_after_source_handled.into();
+
+        // SAFETY: `mode` is a valid [`CFStringRef`].
+        let result = unsafe { CFRunLoopRunInMode(mode, seconds, return_after_source_handled) };
+
+        // LINT: The constants use Core Foundation's naming convention.
+        #[allow(non_upper_case_globals)]
+        match result {
+            kCFRunLoopRunFinished => RunResult::Finished,
+            kCFRunLoopRunHandledSource => RunResult::HandledSource,
+            kCFRunLoopRunStopped => RunResult::Stopped,
+            // Core Foundation does not return any other value.
+            _ => RunResult::TimedOut,
+        }
+    }
+
+    /// Adds `source` to `mode` of the run loop.
+    #[inline]
+    pub fn add_source(&self, source: &Source, mode: Mode<'_>) {
+        // SAFETY: All arguments are valid Core Foundation objects.
+        unsafe { CFRunLoopAddSource(self.as_ptr(), source.as_ptr(), mode.as_ptr()) };
+    }
+
+    /// Returns `true` if `source` was added to `mode` of the run loop.
+    #[inline]
+    #[must_use]
+    pub fn contains_source(&self, source: &Source, mode: Mode<'_>) -> bool {
+        // SAFETY: All arguments are valid Core Foundation objects.
+        let result =
+            unsafe { CFRunLoopContainsSource(self.as_ptr(), source.as_ptr(), mode.as_ptr()) };
+        result != 0
+    }
+
+    /// Removes `source` from `mode` of the run loop.
+    #[inline]
+    pub fn remove_source(&self, source: &Source, mode: Mode<'_>) {
+        // SAFETY: All arguments are valid Core Foundation objects.
+        unsafe { CFRunLoopRemoveSource(self.as_ptr(), source.as_ptr(), mode.as_ptr()) };
+    }
+
+    /// Forces the run loop to stop running. Control returns to the function that started the
+    /// current run loop activation.
+    #[inline]
+    pub fn stop(&self) {
+        // SAFETY: `self` is a valid [`CFRunLoopRef`].
+        unsafe { CFRunLoopStop(self.as_ptr()) };
+    }
+
+    /// Wakes the run loop if it is waiting for a source to become ready.
+    #[inline]
+    pub fn wake_up(&self) {
+        // SAFETY: `self` is a valid [`CFRunLoopRef`].
+        unsafe { CFRunLoopWakeUp(self.as_ptr()) };
+    }
+}
+
+impl Source {
+    /// Creates a custom source that calls `perform` on the run loop's thread each time the source
+    /// is processed after it is [signaled](Self::signal). `order` specifies the priority with which
+    /// run loops process the source.
+    ///
+    /// Any thread may signal the source, which makes it the building block for scheduling work onto
+    /// a thread driven by its run loop (e.g., the main thread). `perform` must be [`Send`] and
+    /// [`Sync`] because the source may be added to the run loops of multiple threads.
+    ///
+    /// Returns [`None`] if the source could not be created.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn new<F>(order: isize, perform: F) -> Option<Arc<Self>>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let info = Box::into_raw(Box::new(perform));
+        let mut context = CFRunLoopSourceContext {
+            version: 0,
+            info: info.cast(),
+            retain: None,
+            release: Some(release::<F>),
+            copyDescription: None,
+            equal: None,
+            hash: None,
+            schedule: None,
+            cancel: None,
+            perform: Some(callout::<F>),
+        };
+
+        // SAFETY: `context` is valid for the duration of the call, and Core Foundation copies it.
+        let cf = unsafe { CFRunLoopSourceCreate(kCFAllocatorDefault, order, &mut context) };
+        if cf.is_null() {
+            // SAFETY: Core Foundation did not take ownership of `info`, which was created above by
+            // [`Box::into_raw`].
+            drop(unsafe { Box::from_raw(info) });
+        }
+
+        // SAFETY: The [`CFRunLoopSourceRef`] follows the create rule, and [`Source`] is a correct
+        // [`CFType`] implementation.
+        unsafe { Self::try_from_owned(cf) }.ok()
+    }
+
+    /// Invalidates the source, removing it from all run loop modes to which it was added.
+    #[inline]
+    pub fn invalidate(&self) {
+        // SAFETY: `self` is a valid [`CFRunLoopSourceRef`].
+        unsafe { CFRunLoopSourceInvalidate(self.as_ptr()) };
+    }
+
+    /// Returns `true` if the source is valid, i.e. it has not been invalidated.
+    #[inline]
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        // SAFETY: `self` is a valid [`CFRunLoopSourceRef`].
+        let result = unsafe { CFRunLoopSourceIsValid(self.as_ptr()) };
+        result != 0
+    }
+
+    /// Returns the order in which the source is processed relative to other sources. Sources with
+    /// a lower order are processed first.
+    #[inline]
+    #[must_use]
+    pub fn order(&self) -> isize {
+        // SAFETY: `self` is a valid [`CFRunLoopSourceRef`].
+        unsafe { CFRunLoopSourceGetOrder(self.as_ptr()) }
+    }
+
+    /// Marks the source as ready to fire. The source is processed the next time a run loop to which
+    /// it was added runs, so [`RunLoop::wake_up`] may also need to be called.
+    #[inline]
+    pub fn signal(&self) {
+        // SAFETY: `self` is a valid [`CFRunLoopSourceRef`].
+        unsafe { CFRunLoopSourceSignal(self.as_ptr()) };
+    }
+}
+
+#[cfg(feature = "alloc")]
+extern "C" fn callout<F>(info: *mut c_void)
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    // SAFETY: `info` was created from a `Box<F>` and is valid until Core Foundation calls
+    // [`release`].
+    let perform = unsafe { &*info.cast::<F>().cast_const() };
+    abort_on_unwind(perform);
+}
+
+#[cfg(feature = "alloc")]
+extern "C" fn release<F>(info: *const c_void) {
+    // SAFETY: `info` was created by [`Box::into_raw`] in [`Source::new`] and Core Foundation
+    // releases it exactly once.
+    drop(unsafe { Box::from_raw(info.cast::<F>().cast_mut()) });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_in_mode_without_sources() {
+        let result = RunLoop::run_in_mode(Mode::Default, Duration::from_millis(1), false);
+        assert!(matches!(result, RunResult::Finished | RunResult::TimedOut));
+    }
+
+    #[test]
+    fn current() {
+        assert_eq!(RunLoop::current(), RunLoop::current());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn custom_source() {
+        use core::sync::atomic::{AtomicU32, Ordering};
+
+        static PERFORMED: AtomicU32 = AtomicU32::new(0);
+
+        let source = Source::new(0, || {
+            // LINT: The previous count is not needed; the test reads the total after running.
+            let _ = PERFORMED.fetch_add(1, Ordering::Relaxed);
+        })
+        .expect("failed to create the run loop source");
+        assert_eq!(source.order(), 0);
+
+        let run_loop = RunLoop::current();
+        run_loop.add_source(&source, Mode::Default);
+
+        source.signal();
+        let result = RunLoop::run_in_mode(Mode::Default, Duration::from_secs(1), true);
+        assert!(matches!(result, RunResult::HandledSource));
+        assert_eq!(PERFORMED.load(Ordering::Relaxed), 1);
+
+        source.invalidate();
+        assert!(!run_loop.contains_source(&source, Mode::Default));
+    }
+}
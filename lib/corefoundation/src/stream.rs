@@ -0,0 +1,338 @@
+//! Streams that read or write bytes sequentially, such as the two directions of a TCP connection.
+//!
+//! The streams block the calling thread until a read or write can make progress. Use
+//! [`ReadStream::has_bytes_available`] and [`WriteStream::can_accept_bytes`] to avoid blocking.
+
+use crate::define_and_impl_type;
+use crate::ffi::{ForeignFunctionInterface, TryFromForeign};
+use crate::string::String;
+use crate::sync::Arc;
+use core::fmt::{self, Display, Formatter};
+use core::ptr;
+use corefoundation_sys::{
+    kCFAllocatorDefault, kCFStreamErrorDomainMacOSStatus, kCFStreamErrorDomainPOSIX,
+    kCFStreamStatusAtEnd, kCFStreamStatusClosed, kCFStreamStatusNotOpen, kCFStreamStatusOpen,
+    kCFStreamStatusOpening, kCFStreamStatusReading, kCFStreamStatusWriting, CFIndex,
+    CFReadStreamClose, CFReadStreamGetError, CFReadStreamGetStatus, CFReadStreamHasBytesAvailable,
+    CFReadStreamOpen, CFReadStreamRead, CFReadStreamRef, CFStreamCreateBoundPair,
+    CFStreamCreatePairWithSocketToHost, CFStreamError, CFStreamStatus, CFWriteStreamCanAcceptBytes,
+    CFWriteStreamClose, CFWriteStreamGetError, CFWriteStreamGetStatus, CFWriteStreamOpen,
+    CFWriteStreamRef, CFWriteStreamWrite, __CFReadStream, __CFWriteStream,
+};
+
+define_and_impl_type!(
+    /// A stream that reads bytes sequentially from a source.
+    ReadStream,
+    raw: __CFReadStream,
+    type_id: corefoundation_sys::CFReadStreamGetTypeID
+);
+
+define_and_impl_type!(
+    /// A stream that writes bytes sequentially to a destination.
+    WriteStream,
+    raw: __CFWriteStream,
+    type_id: corefoundation_sys::CFWriteStreamGetTypeID
+);
+
+/// The state of a [`ReadStream`] or [`WriteStream`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Status {
+    /// The stream has not been opened.
+    NotOpen,
+    /// The stream is being opened (e.g., a socket is connecting).
+    Opening,
+    /// The stream is open.
+    Open,
+    /// A read is in progress.
+    Reading,
+    /// A write is in progress.
+    Writing,
+    /// There are no more bytes to read, or no more bytes can be written.
+    AtEnd,
+    /// The stream was closed.
+    Closed,
+    /// An error occurred.
+    Error,
+}
+
+/// Indicates an error opening, reading from, or writing to a stream.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum StreamError {
+    /// A POSIX error number (e.g., `ECONNREFUSED`).
+    Posix(i32),
+
+    /// An `OSStatus` error code.
+    OsStatus(i32),
+
+    /// An error code in a domain defined by the stream's implementation.
+    Other {
+        /// The domain of the error code.
+        domain: isize,
+        /// The error code.
+        code: i32,
+    },
+}
+
+/// Creates a pair of streams connected through a buffer of `buffer_size` bytes: the bytes written
+/// to the write stream are read from the read stream.
+///
+/// Returns [`None`] if the streams could not be created.
+#[inline]
+#[must_use]
+pub fn bound_pair(buffer_size: usize) -> Option<(Arc<ReadStream>, Arc<WriteStream>)> {
+    let buffer_size = CFIndex::try_from(buffer_size).ok()?;
+    create_pair(|read, write| {
+        // SAFETY: `read` and `write` are valid pointers to write the streams to.
+        unsafe { CFStreamCreateBoundPair(kCFAllocatorDefault, read, write, buffer_size) };
+    })
+}
+
+/// Creates a pair of streams that read from and write to a TCP connection to `port` on `host`,
+/// which is either a host name or an IPv4 or IPv6 address.
+///
+/// The connection is not established (and `host` is not resolved) until one of the streams is
+/// opened, so errors are reported by [`ReadStream::open`] or [`WriteStream::open`], or by the
+/// first read or write.
+///
+/// Returns [`None`] if the streams could not be created.
+#[inline]
+#[must_use]
+pub fn socket_pair_to_host(
+    host: &String,
+    port: u16,
+) -> Option<(Arc<ReadStream>, Arc<WriteStream>)> {
+    create_pair(|read, write| {
+        // SAFETY: `host` is a valid [`CFStringRef`], and `read` and `write` are valid pointers to
+        // write the streams to.
+        unsafe {
+            CFStreamCreatePairWithSocketToHost(
+                kCFAllocatorDefault,
+                host.as_ptr(),
+                port.into(),
+                read,
+                write,
+            );
+        };
+    })
+}
+
+fn create_pair(
+    f: impl FnOnce(*mut CFReadStreamRef, *mut CFWriteStreamRef),
+) -> Option<(Arc<ReadStream>, Arc<WriteStream>)> {
+    let mut read: CFReadStreamRef = ptr::null();
+    let mut write: CFWriteStreamRef = ptr::null();
+    f(&mut read, &mut write);
+
+    // SAFETY: The [`CFReadStreamRef`] follows the create rule.
+    let read = unsafe { ReadStream::try_from_owned(read) }.ok();
+    // SAFETY: The [`CFWriteStreamRef`] follows the create rule.
+    let write = unsafe { WriteStream::try_from_owned(write) }.ok();
+    read.zip(write)
+}
+
+impl ReadStream {
+    /// Closes the stream, which releases the resources (e.g., the socket) it uses. The stream
+    /// cannot be reopened.
+    #[inline]
+    pub fn close(&self) {
+        // SAFETY: `self` is a valid [`CFReadStreamRef`].
+        unsafe { CFReadStreamClose(self.as_ptr()) };
+    }
+
+    /// Returns `true` if the stream can be read from without blocking.
+    #[inline]
+    #[must_use]
+    pub fn has_bytes_available(&self) -> bool {
+        // SAFETY: `self` is a valid [`CFReadStreamRef`].
+        let result = unsafe { CFReadStreamHasBytesAvailable(self.as_ptr()) };
+        result != 0
+    }
+
+    /// Opens the stream. Opening may complete asynchronously (e.g., while a socket connects), in
+    /// which case the first read blocks until the stream is open.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StreamError`] if the stream could not be opened.
+    #[inline]
+    pub fn open(&self) -> Result<(), StreamError> {
+        // SAFETY: `self` is a valid [`CFReadStreamRef`].
+        let result = unsafe { CFReadStreamOpen(self.as_ptr()) };
+        if result == 0 {
+            Err(self.error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Reads bytes into `buf`, blocking until at least one byte is available. Returns the number
+    /// of bytes read, which is `0` at the end of the stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StreamError`] if the stream is not open or if the read fails.
+    #[inline]
+    pub fn read(&self, buf: &mut [u8]) -> Result<usize, StreamError> {
+        let len = CFIndex::try_from(buf.len()).unwrap_or(CFIndex::MAX);
+        // SAFETY: `self` is a valid [`CFReadStreamRef`] and `buf` is valid for writes of `len`
+        // bytes.
+        let result = unsafe { CFReadStreamRead(self.as_ptr(), buf.as_mut_ptr(), len) };
+        usize::try_from(result).map_err(|_| self.error())
+    }
+
+    /// Returns the current state of the stream.
+    #[inline]
+    #[must_use]
+    pub fn status(&self) -> Status {
+        // SAFETY: `self` is a valid [`CFReadStreamRef`].
+        Status::from(unsafe { CFReadStreamGetStatus(self.as_ptr()) })
+    }
+
+    fn error(&self) -> StreamError {
+        // SAFETY: `self` is a valid [`CFReadStreamRef`].
+        StreamError::from(unsafe { CFReadStreamGetError(self.as_ptr()) })
+    }
+}
+
+impl WriteStream {
+    /// Returns `true` if the stream can be written to without blocking.
+    #[inline]
+    #[must_use]
+    pub fn can_accept_bytes(&self) -> bool {
+        // SAFETY: `self` is a valid [`CFWriteStreamRef`].
+        let result = unsafe { CFWriteStreamCanAcceptBytes(self.as_ptr()) };
+        result != 0
+    }
+
+    /// Closes the stream, which releases the resources (e.g., the socket) it uses. The stream
+    /// cannot be reopened.
+    #[inline]
+    pub fn close(&self) {
+        // SAFETY: `self` is a valid [`CFWriteStreamRef`].
+        unsafe { CFWriteStreamClose(self.as_ptr()) };
+    }
+
+    /// Opens the stream. Opening may complete asynchronously (e.g., while a socket connects), in
+    /// which case the first write blocks until the stream is open.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StreamError`] if the stream could not be opened.
+    #[inline]
+    pub fn open(&self) -> Result<(), StreamError> {
+        // SAFETY: `self` is a valid [`CFWriteStreamRef`].
+        let result = unsafe { CFWriteStreamOpen(self.as_ptr()) };
+        if result == 0 {
+            Err(self.error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the current state of the stream.
+    #[inline]
+    #[must_use]
+    pub fn status(&self) -> Status {
+        // SAFETY: `self` is a valid [`CFWriteStreamRef`].
+        Status::from(unsafe { CFWriteStreamGetStatus(self.as_ptr()) })
+    }
+
+    /// Writes bytes from `buf`, blocking until at least one byte can be written. Returns the
+    /// number of bytes written, which is `0` if the stream cannot accept any more bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StreamError`] if the stream is not open or if the write fails.
+    #[inline]
+    pub fn write(&self, buf: &[u8]) -> Result<usize, StreamError> {
+        let len = CFIndex::try_from(buf.len()).unwrap_or(CFIndex::MAX);
+        // SAFETY: `self` is a valid [`CFWriteStreamRef`] and `buf` is valid for reads of `len`
+        // bytes.
+        let result = unsafe { CFWriteStreamWrite(self.as_ptr(), buf.as_ptr(), len) };
+        usize::try_from(result).map_err(|_| self.error())
+    }
+
+    fn error(&self) -> StreamError {
+        // SAFETY: `self` is a valid [`CFWriteStreamRef`].
+        StreamError::from(unsafe { CFWriteStreamGetError(self.as_ptr()) })
+    }
+}
+
+impl From<CFStreamStatus> for Status {
+    #[inline]
+    fn from(status: CFStreamStatus) -> Self {
+        // LINT: The constants use Core Foundation's naming convention.
+        #[allow(non_upper_case_globals)]
+        match status {
+            kCFStreamStatusNotOpen => Self::NotOpen,
+            kCFStreamStatusOpening => Self::Opening,
+            kCFStreamStatusOpen => Self::Open,
+            kCFStreamStatusReading => Self::Reading,
+            kCFStreamStatusWriting => Self::Writing,
+            kCFStreamStatusAtEnd => Self::AtEnd,
+            kCFStreamStatusClosed => Self::Closed,
+            _ => Self::Error,
+        }
+    }
+}
+
+impl From<CFStreamError> for StreamError {
+    #[inline]
+    fn from(error: CFStreamError) -> Self {
+        // LINT: The constants use Core Foundation's naming convention.
+        #[allow(non_upper_case_globals)]
+        match error.domain {
+            kCFStreamErrorDomainPOSIX => Self::Posix(error.error),
+            kCFStreamErrorDomainMacOSStatus => Self::OsStatus(error.error),
+            domain => Self::Other {
+                domain,
+                code: error.error,
+            },
+        }
+    }
+}
+
+impl Display for StreamError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Posix(code) => write!(f, "stream failed with POSIX error {code}"),
+            Self::OsStatus(code) => write!(f, "stream failed with OSStatus {code}"),
+            Self::Other { domain, code } => {
+                write!(f, "stream failed with error {code} in domain {domain}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StreamError {}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn bound_pair() {
+        let (read, write) = super::bound_pair(16).unwrap();
+        assert_eq!(read.status(), Status::NotOpen);
+
+        read.open().unwrap();
+        write.open().unwrap();
+        assert!(!read.has_bytes_available());
+        assert!(write.can_accept_bytes());
+
+        assert_eq!(write.write(b"hello").unwrap(), 5);
+        write.close();
+
+        let mut buf = [0; 16];
+        assert_eq!(read.read(&mut buf).unwrap(), 5);
+        assert_eq!(&buf[..5], b"hello");
+        assert_eq!(read.read(&mut buf).unwrap(), 0);
+        assert_eq!(read.status(), Status::AtEnd);
+    }
+}
@@ -1,48 +1,135 @@
 //! A UTF-16–encoded string, instances of which may be read-only or mutable.
 
+use crate::boxed::Box;
+// The string encoding `CharacterSet` is defined in this module.
+use crate::character_set::CharacterSet as UnicodeCharacterSet;
+use crate::data::Data;
 use crate::define_and_impl_type;
 use crate::ffi::convert::{ExpectFrom, FromUnchecked};
-use crate::ffi::ForeignFunctionInterface;
+use crate::ffi::{ForeignFunctionInterface, TryFromForeign};
+use crate::locale::Locale;
+use crate::range::{StringRange, TryFromRangeError};
 use crate::sync::Arc;
+use crate::{Copying, MutableCopying};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::ffi::CStr;
 use core::fmt::{self, Display, Formatter};
 use core::mem::size_of;
 use core::num::NonZeroU8;
-use core::ops::{Range, RangeBounds};
-use core::ptr;
+use core::ops::{BitOr, Range, RangeBounds};
+use core::ptr::{self, NonNull};
 use core::slice;
 use core::str;
 use corefoundation_sys::{
-    kCFAllocatorDefault, kCFStringEncodingNonLossyASCII, kCFStringEncodingUTF16,
-    kCFStringEncodingUTF16BE, kCFStringEncodingUTF16LE, kCFStringEncodingUTF32,
-    kCFStringEncodingUTF32BE, kCFStringEncodingUTF32LE, kCFStringEncodingUTF8, CFIndex, CFRange,
-    CFStringCreateWithBytes, CFStringEncoding, CFStringGetBytes, CFStringGetCStringPtr,
-    CFStringGetCharacterAtIndex, CFStringGetLength, CFStringGetLongCharacterForSurrogatePair,
-    CFStringIsSurrogateHighCharacter, CFStringIsSurrogateLowCharacter, __CFString,
+    kCFAllocatorDefault, kCFAllocatorNull, kCFCompareBackwards, kCFCompareCaseInsensitive,
+    kCFCompareDiacriticInsensitive, kCFCompareEqualTo, kCFCompareForcedOrdering,
+    kCFCompareLocalized, kCFCompareNonliteral, kCFCompareNumerically, kCFCompareWidthInsensitive,
+    kCFNotFound, kCFStringEncodingNonLossyASCII, kCFStringEncodingUTF16, kCFStringEncodingUTF16BE,
+    kCFStringEncodingUTF16LE, kCFStringEncodingUTF32, kCFStringEncodingUTF32BE,
+    kCFStringEncodingUTF32LE, kCFStringEncodingUTF8, CFAllocatorRef, CFIndex, CFRange,
+    CFStringCompareFlags, CFStringCompareWithOptionsAndLocale, CFStringCreateCopy,
+    CFStringCreateExternalRepresentation, CFStringCreateFromExternalRepresentation,
+    CFStringCreateWithBytes, CFStringCreateWithBytesNoCopy, CFStringCreateWithSubstring,
+    CFStringEncoding, CFStringFindCharacterFromSet, CFStringGetBytes, CFStringGetCStringPtr,
+    CFStringGetCharacterAtIndex, CFStringGetCharacters, CFStringGetLength,
+    CFStringGetLongCharacterForSurrogatePair, CFStringIsSurrogateHighCharacter,
+    CFStringIsSurrogateLowCharacter, __CFString,
 };
 
 mod character_set;
 #[doc(hidden)]
 pub mod constant;
+mod encoder;
+mod mutable;
 #[allow(clippy::module_name_repetitions)]
 mod reader;
+mod split;
 #[cfg(test)]
 mod tests;
+mod transform;
 
 pub use character_set::CharacterSet;
+#[cfg(feature = "std")]
+pub use encoder::IoByteSink;
+pub use encoder::{ByteSink, FmtByteSink};
+pub use mutable::MutableString;
 pub use reader::{
     GetBytesLossyReader, GetBytesReader, GetBytesReaderResult, GetBytesReaderSummary,
     GetBytesStrReader, GetBytesStrReplacement,
 };
+pub use split::{Lines, Split};
+pub use transform::Transform;
 
+// SAFETY: Every method that mutates a string requires exclusive access.
 define_and_impl_type!(
     /// An abstract interface for working with a logically contiguous sequence of UTF-16 code units.
     ///
     /// The internal encoding may not be UTF-16, and the internal storage may not be contiguous.
     String,
-    raw: __CFString
+    raw: __CFString,
+    type_id: corefoundation_sys::CFStringGetTypeID,
+    thread_safe
 );
 
+/// A set of options that modify how strings are compared by [`String::compare`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct CompareOptions(CFStringCompareFlags);
+
+impl CompareOptions {
+    /// Ignores the case of the characters being compared (e.g., "a" is equal to "A").
+    pub const CASE_INSENSITIVE: Self = Self(kCFCompareCaseInsensitive);
+
+    /// Ignores diacritic marks (e.g., "o" is equal to "ö").
+    pub const DIACRITIC_INSENSITIVE: Self = Self(kCFCompareDiacriticInsensitive);
+
+    /// Orders strings that are equal under the other options by their literal contents, so the
+    /// comparison never returns [`Ordering::Equal`] for strings that are not literally equal.
+    ///
+    /// This provides a stable order when sorting (e.g., "aaa" > "AAA" with
+    /// [`CompareOptions::CASE_INSENSITIVE`]).
+    pub const FORCED_ORDERING: Self = Self(kCFCompareForcedOrdering);
+
+    /// Uses the collation rules of the comparison's locale rather than comparing code units.
+    pub const LOCALIZED: Self = Self(kCFCompareLocalized);
+
+    /// Treats canonically equivalent sequences of code points as equal (e.g., "ö" precomposed as
+    /// `U+00F6` is equal to "o" followed by `U+0308` COMBINING DIAERESIS).
+    pub const NONLITERAL: Self = Self(kCFCompareNonliteral);
+
+    /// Compares sequences of decimal digits by their numeric value (e.g., "version 9" <
+    /// "version 10").
+    pub const NUMERICALLY: Self = Self(kCFCompareNumerically);
+
+    /// Ignores the width differences of East Asian characters (e.g., "Ａ" is equal to "A").
+    pub const WIDTH_INSENSITIVE: Self = Self(kCFCompareWidthInsensitive);
+
+    /// Tests whether all of the options in `other` are in this set.
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if the set has no options, i.e. the comparison is literal.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for CompareOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
 /// Specifies the byte order used to encode UTF-16 code units or UTF-32 code points.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum FromUtfByteOrder {
@@ -93,6 +180,34 @@ pub struct FromUtf8Error(());
 #[derive(Debug)]
 pub struct FromUtf32Error(());
 
+/// Indicates an error when converting a [`String`] into a nul-terminated C string through
+/// [`String::to_cstr_in`] or [`String::to_cstring`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ToCStrError {
+    /// The buffer cannot hold the converted string and its nul terminator.
+    BufferTooSmall {
+        /// The number of bytes required to hold the converted string and its nul terminator.
+        required: usize,
+    },
+
+    /// The converted string contains a nul byte before its end.
+    InteriorNul,
+
+    /// A code unit or code point cannot be converted into the requested encoding.
+    Unconvertible,
+}
+
+/// Returned by [`String::try_get_bytes`] if `range` is invalid or a code unit in `range` could not
+/// be converted into `encoding`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TryGetBytesError {
+    /// A code unit could not be converted into `encoding`.
+    Convert(GetBytesError),
+
+    /// The range exceeds the bounds of the string or cannot be represented by a `CFIndex`.
+    Range(TryFromRangeError),
+}
+
 // Note: The [`CFStringCreateWithBytes`] `lossByte` and `isExternalRepresentation` arguments are not
 // directly exposed through these bindings.
 //
@@ -257,13 +372,6 @@ pub enum SurrogateHalf {
     Low,
 }
 
-// SAFETY: Core Foundation allows transferring ownership of strings across threads.
-unsafe impl Send for String {}
-
-// SAFETY: Core Foundation allows sharing strings across threads as long as any mutations are
-// performed with exclusive access (which is guaranteed by the Rust type system).
-unsafe impl Sync for String {}
-
 impl String {
     /// Returns a [`String`] object initialized by copying the code points encoded using
     /// `character_set` from the byte slice.
@@ -304,7 +412,169 @@ impl String {
 
         // SAFETY: The [`CFStringRef`] was just created so it's an exclusive pointer, it has a
         // retain that must be released, and [`String`] is a correct [`CFType`] implementation.
-        unsafe { Self::try_from_owned_ptr(cf) }.ok_or(FromBytesError(()))
+        unsafe { Self::try_from_owned(cf) }.map_err(|_| FromBytesError(()))
+    }
+
+    /// Returns a [`String`] object that takes ownership of `bytes`, encoded using `character_set`,
+    /// and uses them as its backing store instead of copying them. `bytes` is dropped once the
+    /// string no longer needs it.
+    ///
+    /// Core Foundation may still copy the bytes (e.g., if `character_set` is not one of its
+    /// internal storage encodings), in which case `bytes` is dropped before this method returns.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FromBytesError`] if `bytes` contains an invalid sequence for `character_set`.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn from_bytes_no_copy<B>(
+        bytes: B,
+        character_set: CharacterSet,
+    ) -> Result<Arc<Self>, FromBytesError>
+    where
+        B: AsRef<[u8]> + Send + 'static,
+    {
+        use crate::base::allocator::Deallocator;
+
+        let deallocator = Deallocator::new(bytes).ok_or(FromBytesError(()))?;
+        let (buf, len) = deallocator.bytes();
+
+        // SAFETY: `buf` and `len` describe the bytes owned by `deallocator`, which drops them only
+        // when Core Foundation no longer needs them. The string retains the allocator for as long
+        // as it needs it.
+        let string = unsafe {
+            Self::from_bytes_no_copy_inner(buf, len, character_set.into(), deallocator.as_ptr())
+        };
+
+        string.ok_or(FromBytesError(()))
+    }
+
+    /// Returns a [`String`] object initialized by copying the code points encoded using
+    /// `character_set` from the nul-terminated C string.
+    ///
+    /// Use [`Self::from_utf8_cstr`] if the C string is UTF-8 encoded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FromBytesError`] if `s` contains an invalid sequence for `character_set`.
+    #[inline]
+    pub fn from_cstr(
+        s: impl AsRef<CStr>,
+        character_set: CharacterSet,
+    ) -> Result<Arc<Self>, FromBytesError> {
+        Self::from_bytes(s.as_ref().to_bytes(), character_set)
+    }
+
+    /// Returns a [`String`] object initialized from `data`, the external representation of a string
+    /// in `encoding` (e.g., as returned by [`String::external_representation`]).
+    ///
+    /// For UTF-16 or UTF-32 in the host's native byte order, a byte order mark (BOM) at the start
+    /// of `data` determines the byte order and is not included in the string. Any loss byte
+    /// specified by `encoding` is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FromBytesError`] if `data` contains an invalid sequence for `encoding`.
+    #[inline]
+    pub fn from_external_representation(
+        data: &Data,
+        encoding: GetBytesEncoding,
+    ) -> Result<Arc<Self>, FromBytesError> {
+        // SAFETY: `data` is a valid [`CFDataRef`].
+        let cf = unsafe {
+            CFStringCreateFromExternalRepresentation(
+                kCFAllocatorDefault,
+                data.as_ptr(),
+                encoding.into(),
+            )
+        };
+
+        // SAFETY: The [`CFStringRef`] was just created so it's an exclusive pointer, it has a
+        // retain that must be released, and [`String`] is a correct [`CFType`] implementation.
+        unsafe { Self::try_from_owned(cf) }.map_err(|_| FromBytesError(()))
+    }
+
+    /// Returns a [`String`] object that uses `bytes`, encoded using `character_set`, as its backing
+    /// store instead of copying them.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FromBytesError`] if `bytes` contains an invalid sequence for `character_set`.
+    #[inline]
+    pub fn from_static_bytes(
+        bytes: &'static [u8],
+        character_set: CharacterSet,
+    ) -> Result<Arc<Self>, FromBytesError> {
+        // SAFETY: The constant is initialized by Core Foundation and never mutated.
+        let deallocator = unsafe { kCFAllocatorNull };
+
+        // SAFETY: `bytes` is valid for the remainder of the program, and the null allocator never
+        // frees it.
+        let string = unsafe {
+            Self::from_bytes_no_copy_inner(
+                bytes.as_ptr(),
+                bytes.len(),
+                character_set.into(),
+                deallocator,
+            )
+        };
+        string.ok_or(FromBytesError(()))
+    }
+
+    /// Returns a [`String`] object that uses the UTF-8 code units of the string slice as its
+    /// backing store instead of copying them.
+    #[inline]
+    #[must_use]
+    pub fn from_static_str(s: &'static str) -> Arc<Self> {
+        let bytes = s.as_bytes();
+        // SAFETY: The constant is initialized by Core Foundation and never mutated.
+        let deallocator = unsafe { kCFAllocatorNull };
+
+        // SAFETY: `s` is valid for the remainder of the program, and the null allocator never
+        // frees it.
+        let string = unsafe {
+            Self::from_bytes_no_copy_inner(
+                bytes.as_ptr(),
+                bytes.len(),
+                kCFStringEncodingUTF8,
+                deallocator,
+            )
+        };
+
+        // [`str`] is guaranteed to be valid UTF-8, so Core Foundation only returns `NULL` if the
+        // allocator fails.
+        string.unwrap_or_else(|| alloc_error(s.encode_utf16().count()))
+    }
+
+    /// Creates a [`String`] with [`CFStringCreateWithBytesNoCopy`].
+    ///
+    /// # Safety
+    ///
+    /// `buf` must point to `len` bytes that remain valid until `deallocator` frees them.
+    unsafe fn from_bytes_no_copy_inner(
+        buf: *const u8,
+        len: usize,
+        encoding: CFStringEncoding,
+        deallocator: CFAllocatorRef,
+    ) -> Option<Arc<Self>> {
+        // UB: A slice's length cannot exceed [`isize::MAX`].
+        let len = CFIndex::from_unchecked(len);
+
+        // SAFETY: Caller asserts `buf` is valid for `len` bytes until `deallocator` frees it.
+        let cf = unsafe {
+            CFStringCreateWithBytesNoCopy(
+                kCFAllocatorDefault,
+                buf,
+                len,
+                encoding,
+                false.into(),
+                deallocator,
+            )
+        };
+
+        // SAFETY: The [`CFStringRef`] was just created so it's an exclusive pointer, it has a
+        // retain that must be released, and [`String`] is a correct [`CFType`] implementation.
+        unsafe { Self::try_from_owned(cf) }.ok()
     }
 
     /// Returns a [`String`] object initialized by copying the UTF-8 code units from the string
@@ -342,6 +612,17 @@ impl String {
         inner(code_units.as_ref())
     }
 
+    /// Returns a [`String`] object initialized by copying the UTF-8 code units from the
+    /// nul-terminated C string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FromUtf8Error`] if `s` contains an invalid encoding of a code point.
+    #[inline]
+    pub fn from_utf8_cstr(s: impl AsRef<CStr>) -> Result<Arc<Self>, FromUtf8Error> {
+        Self::from_utf8(s.as_ref().to_bytes())
+    }
+
     /// Returns a [`String`] object initialized by copying the UTF-16 code units encoded in
     /// `byte_order` from the [`u16`] slice.
     ///
@@ -407,6 +688,32 @@ impl String {
         inner(code_points.as_ref(), byte_order)
     }
 
+    /// Returns a copy of the string transliterated with `transform`, which is a [`Transform`] or
+    /// any ICU transform ID (e.g., `Any-Latin; Latin-ASCII`). If `reverse` is `true`, the inverse
+    /// of `transform` is applied.
+    ///
+    /// Returns [`None`] if the transform is not valid or cannot be reversed.
+    #[inline]
+    #[must_use]
+    pub fn applying_transform(
+        &self,
+        transform: impl AsRef<Self>,
+        reverse: bool,
+    ) -> Option<Arc<Self>> {
+        fn inner(string: &String, transform: &String, reverse: bool) -> Option<Arc<String>> {
+            let mut string = MutableString::from_string(string);
+            if !string.transform(transform, reverse) {
+                return None;
+            }
+
+            let cf = NonNull::from(&**string).cast();
+            // SAFETY: `cf` is a valid [`CFStringRef`]. The [`MutableString`] is dropped when this
+            // function returns, so the string cannot be mutated again.
+            Some(unsafe { String::from_borrowed_ptr(cf) })
+        }
+        inner(self, transform.as_ref(), reverse)
+    }
+
     /// Returns the entire `String` as a Rust [`String`] slice.
     ///
     /// **Important:** This may allocate a temporary [`String`]. Consider using
@@ -423,6 +730,106 @@ impl String {
             .map_or_else(|| Cow::Owned(self.into()), Cow::Borrowed)
     }
 
+    /// Compares the string to `other` using `options`, and returns how the string is ordered
+    /// relative to `other`.
+    ///
+    /// If `locale` is [`Some`], [`CompareOptions::LOCALIZED`] comparisons use the locale's
+    /// collation rules and case-insensitive comparisons use its case mappings; otherwise, the
+    /// system locale is used.
+    #[inline]
+    #[must_use]
+    pub fn compare(
+        &self,
+        other: &Self,
+        options: CompareOptions,
+        locale: Option<&Locale>,
+    ) -> Ordering {
+        let range = self.range(..);
+        let locale = locale.map_or(ptr::null(), Locale::as_ptr);
+
+        // SAFETY: `self` and `other` are valid [`CFStringRef`]s, `range` is within the bounds of
+        // the string, and `locale` is a valid [`CFLocaleRef`] or `NULL`.
+        let result = unsafe {
+            CFStringCompareWithOptionsAndLocale(
+                self.as_ptr(),
+                other.as_ptr(),
+                range,
+                options.0,
+                locale,
+            )
+        };
+
+        result.cmp(&kCFCompareEqualTo)
+    }
+
+    /// Returns the string's contents converted into `encoding` in the external representation
+    /// format, which is suitable for writing to a file or the pasteboard. Returns [`None`] if a code
+    /// unit cannot be converted into `encoding` and `encoding` does not specify a loss byte.
+    ///
+    /// The external representation of UTF-16 and UTF-32 in the host's native byte order is always
+    /// prefixed with a byte order mark (BOM), regardless of `include_bom`, so the bytes can be read
+    /// on a host with either byte order. No other encoding, including UTF-8, includes a BOM.
+    #[inline]
+    #[must_use]
+    pub fn external_representation(&self, encoding: GetBytesEncoding) -> Option<Arc<Data>> {
+        let loss_byte = encoding.loss_byte().map_or(0, NonZeroU8::get);
+
+        // SAFETY: `self` is a valid [`CFStringRef`].
+        let cf = unsafe {
+            CFStringCreateExternalRepresentation(
+                kCFAllocatorDefault,
+                self.as_ptr(),
+                encoding.into(),
+                loss_byte,
+            )
+        };
+
+        // SAFETY: The [`CFDataRef`] was just created so it's an exclusive pointer, it has a retain
+        // that must be released, and [`Data`] is a correct [`CFType`] implementation.
+        unsafe { Data::try_from_owned(cf) }.ok()
+    }
+
+    /// Returns the range of UTF-16 code units of the first code point in the string that is a
+    /// member of `set`, or [`None`] if the string does not contain any code point in `set`.
+    ///
+    /// The range has a length of two if the code point is encoded as a surrogate pair.
+    #[inline]
+    #[must_use]
+    pub fn find_character_from_set(&self, set: &UnicodeCharacterSet) -> Option<Range<usize>> {
+        self.find_character_from_set_inner(set, 0)
+    }
+
+    /// Returns the range of UTF-16 code units of the last code point in the string that is a
+    /// member of `set`, or [`None`] if the string does not contain any code point in `set`.
+    ///
+    /// The range has a length of two if the code point is encoded as a surrogate pair.
+    #[inline]
+    #[must_use]
+    pub fn rfind_character_from_set(&self, set: &UnicodeCharacterSet) -> Option<Range<usize>> {
+        self.find_character_from_set_inner(set, kCFCompareBackwards)
+    }
+
+    fn find_character_from_set_inner(
+        &self,
+        set: &UnicodeCharacterSet,
+        options: CFStringCompareFlags,
+    ) -> Option<Range<usize>> {
+        let range = self.range(..);
+        let mut result = CFRange {
+            location: kCFNotFound,
+            length: 0,
+        };
+
+        // SAFETY: `self` and `set` are valid objects, `range` is within the bounds of the string,
+        // and `result` is a valid pointer.
+        let found = unsafe {
+            CFStringFindCharacterFromSet(self.as_ptr(), set.as_ptr(), range, options, &mut result)
+        };
+
+        // UB: Core Foundation returns a valid range within the bounds of the string.
+        (found != 0).then(|| Range::from_unchecked(result))
+    }
+
     /// Fetches a range of the code points from the string, converts the code points to `encoding`,
     /// and writes the result into the byte `buf`fer.
     ///
@@ -462,6 +869,25 @@ impl String {
         self.get_bytes_checked(self.range(range), encoding, buf)
     }
 
+    /// Like [`String::get_bytes`], but returns an error instead of panicking if `range` is
+    /// invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryGetBytesError::Range`] if `range` exceeds the bounds of the string or cannot be
+    /// represented by a `CFIndex`, or [`TryGetBytesError::Convert`] if [`String::get_bytes`] would
+    /// return an error.
+    #[inline]
+    pub fn try_get_bytes(
+        &self,
+        range: impl RangeBounds<usize>,
+        encoding: GetBytesEncoding,
+        buf: Option<&mut [u8]>,
+    ) -> Result<GetBytesResult, TryGetBytesError> {
+        let range = self.try_range(range)?;
+        Ok(self.get_bytes_checked(range.into(), encoding, buf)?)
+    }
+
     fn get_bytes_checked(
         &self,
         range: CFRange,
@@ -807,6 +1233,60 @@ impl String {
         usize::from_unchecked(length)
     }
 
+    /// Returns an iterator over the lines of the string, as ranges of UTF-16 code units.
+    ///
+    /// Lines are terminated by a line feed (`\n`), a carriage return (`\r`), a carriage return
+    /// followed by a line feed (`\r\n`), or one of the Unicode line and paragraph separators
+    /// (`U+0085`, `U+2028`, and `U+2029`). The ranges do not include the line terminators, and a
+    /// final line terminator does not produce an empty line.
+    #[inline]
+    #[must_use]
+    pub fn lines(&self) -> Lines<'_> {
+        Lines::new(self)
+    }
+
+    /// Returns a key for sorting a large set of strings that are compared repeatedly.
+    ///
+    /// The key is the string folded with `options` and `locale` (see [`MutableString::fold`]),
+    /// encoded as big endian UTF-16 code units. Comparing two keys bytewise orders the strings the
+    /// same as [`String::compare`] with the same options, but is much faster because the folding is
+    /// done once per string rather than once per comparison.
+    ///
+    /// # Panics
+    ///
+    /// Core Foundation does not expose collation keys, so `options` may only contain the folding
+    /// options [`CompareOptions::CASE_INSENSITIVE`], [`CompareOptions::DIACRITIC_INSENSITIVE`], and
+    /// [`CompareOptions::WIDTH_INSENSITIVE`]. Panics if `options` contains any other option.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[must_use]
+    pub fn sort_key(&self, options: CompareOptions, locale: Option<&Locale>) -> Vec<u8> {
+        let folding = CompareOptions::CASE_INSENSITIVE
+            | CompareOptions::DIACRITIC_INSENSITIVE
+            | CompareOptions::WIDTH_INSENSITIVE;
+        assert!(
+            folding.contains(options),
+            "sort keys only support the folding compare options"
+        );
+
+        let mut folded = MutableString::from_string(self);
+        folded.fold(options, locale);
+
+        let len = folded.len();
+        let mut code_units = alloc::vec![0; len];
+        if len != 0 {
+            let range = folded.range(..);
+            // SAFETY: `folded` is a valid [`CFStringRef`], `range` is within the bounds of the
+            // string, and `code_units` can hold every code unit in `range`.
+            unsafe { CFStringGetCharacters(folded.as_ptr(), range, code_units.as_mut_ptr()) };
+        }
+
+        code_units
+            .iter()
+            .flat_map(|code_unit| code_unit.to_be_bytes())
+            .collect()
+    }
+
     /// Converts a [`RangeBounds<T>`] to a [`Range<usize>`].
     ///
     /// # Panics
@@ -817,6 +1297,139 @@ impl String {
         CFRange::expect_from_range_bounds(range, self.len())
     }
 
+    /// Returns an iterator over the substrings of the string separated by `separator`, as ranges
+    /// of UTF-16 code units.
+    ///
+    /// `separator` is matched literally (i.e., code unit by code unit). Like [`str::split`], the
+    /// iterator returns an empty range for adjacent separators and for a separator at the start or
+    /// end of the string. If `separator` is empty, the iterator returns the whole string.
+    #[inline]
+    #[must_use]
+    pub fn split<'string>(&'string self, separator: &'string Self) -> Split<'string> {
+        Split::new(self, separator)
+    }
+
+    /// Returns a [`String`] object containing the UTF-16 code units in `range`.
+    ///
+    /// If the string is immutable and `range` covers the entire string, the string itself is
+    /// returned (retained). Otherwise, the code units are copied. To refer to part of a string
+    /// without copying it, borrow the string along with a [`StringRange`] from
+    /// [`String::try_range`], which the methods that accept a range of code units take as is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` cannot be represented in [`Range<usize>`] or if the `range` exceeds the
+    /// bounds the string.
+    #[inline]
+    #[must_use]
+    pub fn substring(&self, range: impl RangeBounds<usize>) -> Arc<Self> {
+        let range = self.range(range);
+
+        // SAFETY: `self` is a valid [`CFStringRef`] and `range` is in bounds.
+        let cf = unsafe { CFStringCreateWithSubstring(kCFAllocatorDefault, self.as_ptr(), range) };
+
+        // SAFETY: The [`CFStringRef`] follows the create rule. [`CFStringCreateWithSubstring`]
+        // only returns `NULL` if the Core Foundation allocator fails.
+        unsafe { Self::try_from_owned(cf) }
+            .unwrap_or_else(|_| alloc_error(usize::from_unchecked(range.length)))
+    }
+
+    /// Gets the code unit at `index`, or an error if `index` exceeds the bounds of the string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TryFromRangeError`] if `index` is not less than the length of the string.
+    #[inline]
+    pub fn try_index(&self, index: usize) -> Result<u16, TryFromRangeError> {
+        let range = CFRange::from(self.try_range(index..=index)?);
+
+        // SAFETY: `self` is a valid [`CFStringRef`] and `range.location` is in bounds.
+        Ok(unsafe { CFStringGetCharacterAtIndex(self.as_ptr(), range.location) })
+    }
+
+    /// Bounds-checks `range` against the UTF-16 code units of the string.
+    ///
+    /// The returned [`StringRange`] can be passed to the methods that accept a range of code units
+    /// without risk of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TryFromRangeError`] if `range`'s end is greater than the length of the string,
+    /// if a range bound cannot be represented by a `CFIndex`, or if an exclusive bound overflows
+    /// [`usize`].
+    #[inline]
+    pub fn try_range(
+        &self,
+        range: impl RangeBounds<usize>,
+    ) -> Result<StringRange, TryFromRangeError> {
+        StringRange::new(self, range)
+    }
+
+    /// Converts the string into `encoding` and writes the result, followed by a nul terminator,
+    /// into the caller's `buf`fer. Returns a [`CStr`] borrowing the written bytes.
+    ///
+    /// `encoding` should be one in which no code point is represented with a zero byte (i.e., not
+    /// UTF-16 or UTF-32), otherwise the conversion fails with [`ToCStrError::InteriorNul`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ToCStrError`] if `buf` cannot hold the converted string and the nul
+    /// terminator, if a code point cannot be converted into `encoding`, or if the converted string
+    /// contains a nul byte.
+    #[inline]
+    pub fn to_cstr_in<'buf>(
+        &self,
+        encoding: GetBytesEncoding,
+        buf: &'buf mut [u8],
+    ) -> Result<&'buf CStr, ToCStrError> {
+        let required = self.cstr_len(encoding)?;
+        let buf = buf
+            .get_mut(..required)
+            .ok_or(ToCStrError::BufferTooSmall { required })?;
+
+        if let Some((nul, dest)) = buf.split_last_mut() {
+            let result = self.get_bytes_unchecked(.., encoding, Some(dest));
+            if result.remaining.is_some() || result.buf_len != dest.len() {
+                return Err(ToCStrError::Unconvertible);
+            }
+            *nul = 0;
+        }
+
+        CStr::from_bytes_with_nul(buf).map_err(|_| ToCStrError::InteriorNul)
+    }
+
+    /// Converts the string into `encoding` and returns the result as an owned, nul-terminated C
+    /// string.
+    ///
+    /// `encoding` should be one in which no code point is represented with a zero byte (i.e., not
+    /// UTF-16 or UTF-32), otherwise the conversion fails with [`ToCStrError::InteriorNul`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ToCStrError`] if a code point cannot be converted into `encoding` or if the
+    /// converted string contains a nul byte.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_cstring(
+        &self,
+        encoding: GetBytesEncoding,
+    ) -> Result<alloc::ffi::CString, ToCStrError> {
+        let mut buf = alloc::vec![0; self.cstr_len(encoding)?];
+        let _ = self.to_cstr_in(encoding, &mut buf)?;
+        alloc::ffi::CString::from_vec_with_nul(buf).map_err(|_| ToCStrError::InteriorNul)
+    }
+
+    /// Returns the number of bytes required to convert the string into `encoding`, including a
+    /// nul terminator.
+    fn cstr_len(&self, encoding: GetBytesEncoding) -> Result<usize, ToCStrError> {
+        let result = self.get_bytes_unchecked(.., encoding, None);
+        if result.remaining.is_some() {
+            return Err(ToCStrError::Unconvertible);
+        }
+        // UB: This will not wrap because the upper bound is [`CFIndex::max`].
+        Ok(result.buf_len.wrapping_add(1))
+    }
+
     /// Yields a <code>&[str]</code> slice if the `String` is UTF-8 encoded and has contiguous
     /// storage. If the `String` is not UTF-8 encoded or does not have contiguous storage, returns
     /// [`None`].
@@ -848,6 +1461,31 @@ impl String {
     }
 }
 
+impl Copying for String {
+    type Immutable = Self;
+
+    #[inline]
+    fn copy(&self) -> Arc<Self::Immutable> {
+        copy(self)
+    }
+}
+
+impl MutableCopying for String {
+    type Mutable = MutableString;
+
+    #[inline]
+    fn mutable_copy(&self) -> Box<Self::Mutable> {
+        MutableString::from_string(self)
+    }
+}
+
+impl AsRef<Self> for String {
+    #[inline]
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
 impl Display for String {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -858,13 +1496,8 @@ impl Display for String {
             // overhead of each additional call to [`CFStringGetBytes`]. It's also the number of
             // bytes reserved for [`CFStringInlineBuffer`]'s code units.
             let mut buf = [0_u8; 128];
-            let mut iter = GetBytesStrReader::new(self, GetBytesStrReplacement::default(), ..);
-
-            while let Some(s) = iter.read(&mut buf) {
-                f.write_str(s)?;
-            }
-
-            Ok(())
+            GetBytesStrReader::new(self, GetBytesStrReplacement::default(), ..)
+                .encode_into(&mut buf, FmtByteSink(f))
         }
     }
 }
@@ -907,6 +1540,49 @@ impl Display for FromUtf32Error {
 #[cfg(feature = "std")]
 impl std::error::Error for FromUtf32Error {}
 
+impl Display for ToCStrError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::BufferTooSmall { required } => {
+                write!(f, "buffer too small: {required} bytes required")
+            }
+            Self::InteriorNul => f.write_str("nul byte found in converted string"),
+            Self::Unconvertible => f.write_str("code point cannot be converted to encoding"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ToCStrError {}
+
+impl Display for TryGetBytesError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Convert(_) => f.write_str("code unit cannot be converted to encoding"),
+            Self::Range(ref err) => write!(f, "invalid range: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryGetBytesError {}
+
+impl From<GetBytesError> for TryGetBytesError {
+    #[inline]
+    fn from(value: GetBytesError) -> Self {
+        Self::Convert(value)
+    }
+}
+
+impl From<TryFromRangeError> for TryGetBytesError {
+    #[inline]
+    fn from(value: TryFromRangeError) -> Self {
+        Self::Range(value)
+    }
+}
+
 impl GetBytesByteOrder {
     const fn is_external_representation(self) -> bool {
         match self {
@@ -1037,6 +1713,15 @@ impl SurrogateHalf {
     }
 }
 
+/// Returns an immutable copy of `string`, which may be a [`MutableString`].
+fn copy(string: &String) -> Arc<String> {
+    // SAFETY: `string` is a valid [`CFStringRef`].
+    let cf = unsafe { CFStringCreateCopy(kCFAllocatorDefault, string.as_ptr()) };
+    // SAFETY: The [`CFStringRef`] follows the create rule. [`CFStringCreateCopy`] only returns
+    // `NULL` if the Core Foundation allocator fails.
+    unsafe { String::try_from_owned(cf) }.unwrap_or_else(|_| alloc_error(string.len()))
+}
+
 #[cfg(feature = "alloc")]
 fn alloc_error(utf16_len: usize) -> Arc<String> {
     use alloc::alloc::{handle_alloc_error, Layout};
@@ -0,0 +1,117 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::str;
+
+/// A destination for the bytes converted by [`GetBytesLossyReader::encode_into`] and
+/// [`GetBytesStrReader::encode_into`].
+///
+/// The encoders own the conversion loop and push each converted chunk into the sink as it becomes
+/// available, so the converted output can be streamed without the caller managing the chunks.
+///
+/// [`GetBytesLossyReader::encode_into`]: crate::string::GetBytesLossyReader::encode_into
+/// [`GetBytesStrReader::encode_into`]: crate::string::GetBytesStrReader::encode_into
+pub trait ByteSink {
+    /// The type of error returned when the sink fails to accept bytes.
+    type Error;
+
+    /// Appends all of `bytes` to the sink.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink cannot accept `bytes`. The encoder stops and forwards the error
+    /// to its caller.
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error>;
+
+    /// Appends all of the UTF-8 bytes of `s` to the sink.
+    ///
+    /// This is called instead of [`Self::write_bytes`] when the converted bytes are known to be
+    /// UTF-8. The default implementation forwards to [`Self::write_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink cannot accept `s`. The encoder stops and forwards the error to
+    /// its caller.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.write_bytes(s.as_bytes())
+    }
+}
+
+/// Adapts a [`fmt::Write`] implementation into a [`ByteSink`].
+///
+/// The sink only accepts UTF-8. Bytes that are not valid UTF-8 are rejected with [`fmt::Error`], so
+/// the sink should be used with [`GetBytesStrReader::encode_into`] or with a
+/// [`GetBytesLossyReader`] that converts to [`GetBytesEncoding::Utf8`].
+///
+/// [`GetBytesEncoding::Utf8`]: crate::string::GetBytesEncoding::Utf8
+/// [`GetBytesLossyReader`]: crate::string::GetBytesLossyReader
+/// [`GetBytesStrReader::encode_into`]: crate::string::GetBytesStrReader::encode_into
+#[derive(Debug)]
+pub struct FmtByteSink<W>(pub W);
+
+/// Adapts a [`Write`] implementation into a [`ByteSink`].
+///
+/// [`Write`]: std::io::Write
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct IoByteSink<W>(pub W);
+
+impl<S> ByteSink for &mut S
+where
+    S: ByteSink + ?Sized,
+{
+    type Error = S::Error;
+
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        (**self).write_bytes(bytes)
+    }
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        (**self).write_str(s)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ByteSink for Vec<u8> {
+    type Error = core::convert::Infallible;
+
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+impl<W> ByteSink for FmtByteSink<W>
+where
+    W: fmt::Write,
+{
+    type Error = fmt::Error;
+
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        let s = str::from_utf8(bytes).map_err(|_| fmt::Error)?;
+        self.0.write_str(s)
+    }
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.0.write_str(s)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W> ByteSink for IoByteSink<W>
+where
+    W: std::io::Write,
+{
+    type Error = std::io::Error;
+
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.0.write_all(bytes)
+    }
+}
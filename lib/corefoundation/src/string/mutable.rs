@@ -0,0 +1,329 @@
+use crate::boxed::Box;
+use crate::define_and_impl_type;
+use crate::ffi::convert::ExpectFrom;
+use crate::ffi::{ForeignFunctionInterface, TryFromForeign};
+use crate::locale::Locale;
+use crate::string::{self, CompareOptions, String};
+use crate::sync::Arc;
+use crate::{Copying, MutableCopying};
+use core::fmt::{self, Display, Formatter, Write};
+use core::ops::{Deref, RangeBounds};
+use core::ptr;
+use corefoundation_sys::{
+    kCFAllocatorDefault, CFIndex, CFMutableStringRef, CFStringAppend, CFStringAppendCharacters,
+    CFStringCreateMutable, CFStringCreateMutableCopy, CFStringDelete, CFStringFold, CFStringInsert,
+    CFStringReplace, CFStringTransform, __CFString,
+};
+
+// SAFETY: Every method that mutates a string requires exclusive access.
+define_and_impl_type!(
+    /// A [`String`] whose contents can be modified in place.
+    ///
+    /// Implements [`Write`], so the [`write!`] macro can format text directly into the string.
+    MutableString,
+    raw: __CFString,
+    type_id: corefoundation_sys::CFStringGetTypeID,
+    thread_safe
+);
+
+/// The number of UTF-16 code units [`MutableString::push_str`] converts on the stack before
+/// appending them to the string.
+const PUSH_STR_CHUNK_LEN: usize = 64;
+
+impl MutableString {
+    /// Creates a new, empty mutable string.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Box<Self> {
+        // SAFETY: A `maxLength` of `0` means the string's length is not limited.
+        let cf = unsafe { CFStringCreateMutable(kCFAllocatorDefault, 0) };
+        // SAFETY: The [`CFMutableStringRef`] follows the create rule.
+        unsafe { Self::create(cf) }
+    }
+
+    /// Creates a mutable string with a copy of the contents of `string`.
+    #[inline]
+    #[must_use]
+    pub fn from_string(string: &String) -> Box<Self> {
+        // SAFETY: `string` is a valid [`CFStringRef`]. A `maxLength` of `0` means the string's
+        // length is not limited.
+        let cf = unsafe { CFStringCreateMutableCopy(kCFAllocatorDefault, 0, string.as_ptr()) };
+        // SAFETY: The [`CFMutableStringRef`] follows the create rule.
+        unsafe { Self::create(cf) }
+    }
+
+    /// Creates a `Box<MutableString>` from `cf`.
+    ///
+    /// # Safety
+    ///
+    /// `cf` must be a newly created [`CFMutableStringRef`] that follows the create rule.
+    unsafe fn create(cf: CFMutableStringRef) -> Box<Self> {
+        // SAFETY: Caller asserts `cf` is a newly created, and therefore exclusive, pointer that
+        // follows the create rule.
+        let string = unsafe { Self::try_from_owned_mut(cf) };
+        // Core Foundation only returns `NULL` if the allocator fails.
+        string.unwrap_or_else(|_| panic!("allocation failed"))
+    }
+
+    /// Appends the contents of `string` to the end of the string.
+    #[inline]
+    pub fn append(&mut self, string: &String) {
+        // SAFETY: `self` is a valid [`CFMutableStringRef`] and `string` is a valid [`CFStringRef`].
+        unsafe { CFStringAppend(self.as_mut_ptr(), string.as_ptr()) };
+    }
+
+    /// Removes all code units from the string.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.remove(..);
+    }
+
+    /// Folds the string in place, removing the distinctions specified by `options` so that folded
+    /// strings can be compared literally.
+    ///
+    /// Only [`CompareOptions::CASE_INSENSITIVE`], [`CompareOptions::DIACRITIC_INSENSITIVE`], and
+    /// [`CompareOptions::WIDTH_INSENSITIVE`] are supported; other options are ignored. `locale`
+    /// affects case folding (e.g., the dotless i in Turkish). If `locale` is [`None`], the system
+    /// locale is used.
+    #[inline]
+    pub fn fold(&mut self, options: CompareOptions, locale: Option<&Locale>) {
+        let locale = locale.map_or(ptr::null(), Locale::as_ptr);
+        // SAFETY: `self` is a valid [`CFMutableStringRef`] and `locale` is a valid [`CFLocaleRef`]
+        // or `NULL`.
+        unsafe { CFStringFold(self.as_mut_ptr(), options.0, locale) };
+    }
+
+    /// Inserts the contents of `string` at the UTF-16 code unit `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` exceeds the bounds of the string.
+    #[inline]
+    pub fn insert(&mut self, index: usize, string: &String) {
+        assert!(index <= self.len(), "index out of bounds");
+        let index = CFIndex::expect_from(index);
+
+        // SAFETY: `self` is a valid [`CFMutableStringRef`], `string` is a valid [`CFStringRef`],
+        // and `index` is in bounds.
+        unsafe { CFStringInsert(self.as_mut_ptr(), index, string.as_ptr()) };
+    }
+
+    /// Appends `c` to the end of the string.
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        let mut buf = [0; 2];
+        self.push_utf16(c.encode_utf16(&mut buf));
+    }
+
+    /// Appends `s` to the end of the string.
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        let mut buf = [0; PUSH_STR_CHUNK_LEN];
+        let mut len = 0;
+
+        for code_unit in s.encode_utf16() {
+            if let Some(dest) = buf.get_mut(len) {
+                *dest = code_unit;
+                // UB: `len` cannot exceed `PUSH_STR_CHUNK_LEN`.
+                len = len.wrapping_add(1);
+            } else {
+                // A surrogate pair may be split across two appends, which Core Foundation handles
+                // as it stores UTF-16 code units.
+                self.push_utf16(&buf);
+                if let Some(first) = buf.first_mut() {
+                    *first = code_unit;
+                    len = 1;
+                }
+            }
+        }
+
+        if let Some(code_units) = buf.get(..len) {
+            self.push_utf16(code_units);
+        }
+    }
+
+    /// Appends the UTF-16 `code_units` to the end of the string.
+    fn push_utf16(&mut self, code_units: &[u16]) {
+        if code_units.is_empty() {
+            return;
+        }
+
+        // UB: A slice's length cannot exceed [`isize::MAX`].
+        let len = CFIndex::expect_from(code_units.len());
+
+        // SAFETY: `self` is a valid [`CFMutableStringRef`] and `len` is the correct size of
+        // `code_units`.
+        unsafe { CFStringAppendCharacters(self.as_mut_ptr(), code_units.as_ptr(), len) };
+    }
+
+    /// Removes the UTF-16 code units in `range` from the string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` cannot be represented in [`Range<usize>`] or if the `range` exceeds the
+    /// bounds the string.
+    ///
+    /// [`Range<usize>`]: core::ops::Range
+    #[inline]
+    pub fn remove(&mut self, range: impl RangeBounds<usize>) {
+        let range = self.range(range);
+        // SAFETY: `self` is a valid [`CFMutableStringRef`] and `range` is in bounds.
+        unsafe { CFStringDelete(self.as_mut_ptr(), range) };
+    }
+
+    /// Replaces the UTF-16 code units in `range` with the contents of `replacement`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` cannot be represented in [`Range<usize>`] or if the `range` exceeds the
+    /// bounds the string.
+    ///
+    /// [`Range<usize>`]: core::ops::Range
+    #[inline]
+    pub fn replace(&mut self, range: impl RangeBounds<usize>, replacement: &String) {
+        let range = self.range(range);
+        // SAFETY: `self` is a valid [`CFMutableStringRef`], `replacement` is a valid
+        // [`CFStringRef`], and `range` is in bounds.
+        unsafe { CFStringReplace(self.as_mut_ptr(), range, replacement.as_ptr()) };
+    }
+
+    /// Transliterates the string in place with `transform`, which is a [`Transform`] or any ICU
+    /// transform ID. If `reverse` is `true`, the inverse of `transform` is applied.
+    ///
+    /// Returns `false`, leaving the string unchanged, if the transform is not valid or cannot be
+    /// reversed.
+    ///
+    /// [`Transform`]: crate::string::Transform
+    #[inline]
+    pub fn transform(&mut self, transform: impl AsRef<String>, reverse: bool) -> bool {
+        let transform = transform.as_ref().as_ptr();
+        // SAFETY: `self` is a valid [`CFMutableStringRef`], a `NULL` range transforms the whole
+        // string, and `transform` is a valid [`CFStringRef`].
+        let result = unsafe {
+            CFStringTransform(
+                self.as_mut_ptr(),
+                ptr::null_mut(),
+                transform,
+                reverse.into(),
+            )
+        };
+        result != 0
+    }
+}
+
+impl Copying for MutableString {
+    type Immutable = String;
+
+    #[inline]
+    fn copy(&self) -> Arc<Self::Immutable> {
+        string::copy(self)
+    }
+}
+
+impl MutableCopying for MutableString {
+    type Mutable = Self;
+
+    #[inline]
+    fn mutable_copy(&self) -> Box<Self::Mutable> {
+        Self::from_string(self)
+    }
+}
+
+impl AsRef<String> for MutableString {
+    #[inline]
+    fn as_ref(&self) -> &String {
+        self
+    }
+}
+
+impl Deref for MutableString {
+    type Target = String;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        let string: *const Self = self;
+        // SAFETY: Both types wrap a `__CFString`, and every mutable string is a valid immutable
+        // string.
+        unsafe { &*string.cast::<String>() }
+    }
+}
+
+impl Display for MutableString {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl Write for MutableString {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::cfstr;
+
+    #[test]
+    fn push() {
+        let mut s = MutableString::new();
+        assert!(s.is_empty());
+
+        s.push('🐻');
+        s.push_str("‍❄️");
+        assert_eq!(&**s, cfstr!("🐻‍❄️"));
+
+        let long = "0123456789".repeat(20);
+        s.clear();
+        s.push_str(&long);
+        assert_eq!(s.len(), 200);
+        assert_eq!(s.to_string(), long);
+    }
+
+    #[test]
+    fn write() {
+        let mut s = MutableString::from_string(cfstr!("x"));
+        write!(s, " = {}, y = {:?}", 42_i32, "🐻").unwrap();
+        assert_eq!(&**s, cfstr!("x = 42, y = \"🐻\""));
+    }
+
+    #[test]
+    fn edit() {
+        let mut s = MutableString::from_string(cfstr!("Hello"));
+        s.append(cfstr!(" World"));
+        s.insert(5, cfstr!(","));
+        assert_eq!(&**s, cfstr!("Hello, World"));
+
+        s.replace(7.., cfstr!("Rust"));
+        assert_eq!(&**s, cfstr!("Hello, Rust"));
+
+        s.remove(..7);
+        assert_eq!(&**s, cfstr!("Rust"));
+    }
+
+    #[test]
+    fn copy() {
+        let mut s = MutableString::from_string(cfstr!("Hello"));
+        let snapshot = s.copy();
+        let mut copy = snapshot.mutable_copy();
+        s.push_str(", World");
+        copy.push('!');
+
+        assert_eq!(&*snapshot, cfstr!("Hello"));
+        assert_eq!(&**s, cfstr!("Hello, World"));
+        assert_eq!(&**copy, cfstr!("Hello!"));
+        assert_eq!(&*cfstr!("constant").copy(), cfstr!("constant"));
+    }
+}
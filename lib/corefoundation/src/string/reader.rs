@@ -1,6 +1,7 @@
 use crate::ffi::convert::FromUnchecked;
 use crate::string::{
-    GetBytesByteOrder, GetBytesEncoding, GetBytesError, GetBytesErrorKind, GetBytesResult, String,
+    ByteSink, GetBytesByteOrder, GetBytesEncoding, GetBytesError, GetBytesErrorKind,
+    GetBytesResult, String,
 };
 use core::ops::{Range, RangeBounds};
 use core::str;
@@ -69,12 +70,20 @@ pub struct GetBytesReaderSummary {
 /// * Transparent tracking of the UTF-16 code unit range that has yet to be processed.
 /// * A panic if conversion does not make progress to prevent the caller from looping infinitely.
 ///
+/// With the `std` feature, the type also implements [`Read`], which accepts buffers of any size.
+///
 /// [`Read`]: std::io::Read
 #[derive(Debug)]
 pub struct GetBytesLossyReader<'caller> {
     inner: GetBytesReader<'caller>,
     replacement_bytes: Option<&'caller [u8]>,
     replacement_bytes_to_copy: Option<&'caller [u8]>,
+
+    /// Converted bytes that did not fit in the buffer given to [`Read::read`].
+    ///
+    /// [`Read::read`]: std::io::Read::read
+    #[cfg(feature = "std")]
+    pending: Vec<u8>,
 }
 
 /// An [`Read`]-like type to simplify calling [`String::get_bytes`]. It provides:
@@ -85,6 +94,8 @@ pub struct GetBytesLossyReader<'caller> {
 /// * Transparent tracking of the UTF-16 code unit range that has yet to be processed.
 /// * A panic if conversion does not make progress to prevent the caller from looping infinitely.
 ///
+/// With the `std` feature, the type also implements [`Read`], which accepts buffers of any size.
+///
 /// [`Read`]: std::io::Read
 /// [str]: prim@str
 #[derive(Debug)]
@@ -109,6 +120,11 @@ pub enum GetBytesStrReplacement<'caller> {
 /// A character to substitute for code units that cannot be converted to UTF-8.
 const REPLACEMENT_CHARACTER_UTF8: [u8; 3] = [0xef, 0xbf, 0xbd];
 
+/// The largest number of bytes [`String::get_bytes`] writes for a single code point, which is a
+/// UTF-32 code point preceded by a byte order mark.
+#[cfg(feature = "std")]
+const MAX_CODE_POINT_LEN: usize = 8;
+
 impl<'caller> GetBytesReader<'caller> {
     /// Creates [`Read`]-like type that calls [`String::get_bytes`] with `encoding` over the given
     /// `range`.
@@ -260,6 +276,8 @@ impl<'caller> GetBytesLossyReader<'caller> {
                 (!replacement_bytes.is_empty()).then_some(replacement_bytes)
             }),
             replacement_bytes_to_copy: None,
+            #[cfg(feature = "std")]
+            pending: Vec::new(),
         }
     }
 }
@@ -291,6 +309,29 @@ impl GetBytesLossyReader<'_> {
         buf
     }
 
+    /// Converts all of the remaining `string`'s `range` into `encoding`, using `buf` as scratch
+    /// space, and appends each converted chunk to `sink`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by `sink`. The conversion stops and the remainder of the
+    /// `range` is not converted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller provided `buf`fer is too small to hold one code point or the
+    /// replacement bytes.
+    #[inline]
+    pub fn encode_into<S>(mut self, buf: &mut [u8], mut sink: S) -> Result<(), S::Error>
+    where
+        S: ByteSink,
+    {
+        while let Some(bytes) = self.read(buf) {
+            sink.write_bytes(bytes)?;
+        }
+        Ok(())
+    }
+
     fn get_bytes(&mut self, buf: &mut [u8]) -> usize {
         let mut next_write_index: usize = 0;
 
@@ -413,6 +454,29 @@ impl GetBytesStrReader<'_> {
         unsafe { alloc::string::String::from_utf8_unchecked(bytes) }
     }
 
+    /// Converts all of the remaining `string`'s `range` into UTF-8, using `buf` as scratch space,
+    /// and appends each converted chunk to `sink` with [`ByteSink::write_str`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by `sink`. The conversion stops and the remainder of the
+    /// `range` is not converted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller provided `buf`fer is too small to hold one code point or the
+    /// replacement bytes.
+    #[inline]
+    pub fn encode_into<S>(mut self, buf: &mut [u8], mut sink: S) -> Result<(), S::Error>
+    where
+        S: ByteSink,
+    {
+        while let Some(s) = self.read(buf) {
+            sink.write_str(s)?;
+        }
+        Ok(())
+    }
+
     /// Calls [`String::get_bytes`] and returns the portion of `buf` that was written into as a
     /// <code>&[str]</code> for idiomatic access to the UTF-8 encoding of the `string`. Returns
     /// [`None`] after returning bytes for the previously given string range.
@@ -437,6 +501,56 @@ impl GetBytesStrReader<'_> {
     }
 }
 
+#[cfg(feature = "std")]
+impl std::io::Read for GetBytesLossyReader<'_> {
+    /// Converts the next part of the `string`'s `range` into `buf`.
+    ///
+    /// Unlike [`GetBytesLossyReader::read`], `buf` may be too small to hold a code point or the
+    /// replacement bytes. Converted bytes that do not fit are returned by subsequent reads.
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if self.pending.is_empty() {
+            let min_len = self
+                .replacement_bytes
+                .map_or(0, <[u8]>::len)
+                .max(MAX_CODE_POINT_LEN);
+
+            // Convert directly into the caller's buffer if it's guaranteed to be large enough.
+            if buf.len() >= min_len {
+                return Ok(Self::read(self, buf).map_or(0, <[u8]>::len));
+            }
+
+            let mut pending = core::mem::take(&mut self.pending);
+            pending.resize(min_len, 0);
+            let len = Self::read(self, &mut pending).map_or(0, <[u8]>::len);
+            pending.truncate(len);
+            self.pending = pending;
+        }
+
+        let len = buf.len().min(self.pending.len());
+        for (dest, src) in buf.iter_mut().zip(self.pending.drain(..len)) {
+            *dest = src;
+        }
+        Ok(len)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Read for GetBytesStrReader<'_> {
+    /// Converts the next part of the `string`'s `range` into `buf` as UTF-8.
+    ///
+    /// Unlike [`GetBytesStrReader::read`], `buf` may be too small to hold a code point, in which
+    /// case a UTF-8 sequence may be split across reads.
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        std::io::Read::read(&mut self.0, buf)
+    }
+}
+
 impl<'caller> GetBytesStrReplacement<'caller> {
     /// Returns the lossy character replacement as a slice of UTF-8 bytes.
     #[inline]
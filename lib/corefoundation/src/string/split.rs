@@ -0,0 +1,135 @@
+use crate::ffi::convert::FromUnchecked;
+use crate::ffi::ForeignFunctionInterface;
+use crate::string::String;
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::ptr;
+use corefoundation_sys::{
+    kCFNotFound, CFIndex, CFRange, CFStringFindWithOptions, CFStringGetLineBounds,
+};
+
+/// An iterator over the lines of a [`String`], as ranges of UTF-16 code units.
+///
+/// Created by [`String::lines`]. See its documentation for details.
+#[derive(Clone, Debug)]
+pub struct Lines<'string> {
+    string: &'string String,
+
+    /// The bounds of `string`'s UTF-16 code units that have yet to be split into lines.
+    range: Range<usize>,
+}
+
+/// An iterator over the substrings of a [`String`] separated by another string, as ranges of
+/// UTF-16 code units.
+///
+/// Created by [`String::split`]. See its documentation for details.
+#[derive(Clone, Debug)]
+pub struct Split<'string> {
+    string: &'string String,
+    separator: &'string String,
+
+    /// The bounds of `string`'s UTF-16 code units that have yet to be split, or [`None`] if the
+    /// final substring has been returned.
+    range: Option<Range<usize>>,
+}
+
+impl<'string> Lines<'string> {
+    pub(super) fn new(string: &'string String) -> Self {
+        Self {
+            string,
+            range: 0..string.len(),
+        }
+    }
+}
+
+impl Iterator for Lines<'_> {
+    type Item = Range<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.range.is_empty() {
+            return None;
+        }
+
+        let range = CFRange {
+            // UB: The index is less than the length of the string, which is a `CFIndex`.
+            location: CFIndex::from_unchecked(self.range.start),
+            length: 0,
+        };
+        let mut line_end: CFIndex = 0;
+        let mut contents_end: CFIndex = 0;
+
+        // SAFETY: `self.string` is a valid [`CFStringRef`], `range` is within its bounds, and the
+        // index pointers are valid or `NULL`.
+        unsafe {
+            CFStringGetLineBounds(
+                self.string.as_ptr(),
+                range,
+                ptr::null_mut(),
+                &mut line_end,
+                &mut contents_end,
+            );
+        };
+
+        // UB: Core Foundation returns indices within the bounds of the string.
+        let line = self.range.start..usize::from_unchecked(contents_end);
+        self.range.start = usize::from_unchecked(line_end);
+        Some(line)
+    }
+}
+
+impl FusedIterator for Lines<'_> {}
+
+impl<'string> Split<'string> {
+    pub(super) fn new(string: &'string String, separator: &'string String) -> Self {
+        Self {
+            string,
+            separator,
+            range: Some(0..string.len()),
+        }
+    }
+}
+
+impl Iterator for Split<'_> {
+    type Item = Range<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let range = self.range.as_mut()?;
+
+        // UB: The range is within the bounds of the string, whose length is a `CFIndex`.
+        let search = CFRange {
+            location: CFIndex::from_unchecked(range.start),
+            length: CFIndex::from_unchecked(range.len()),
+        };
+        let mut result = CFRange {
+            location: kCFNotFound,
+            length: 0,
+        };
+
+        // SAFETY: `self.string` and `self.separator` are valid [`CFStringRef`]s, `search` is
+        // within the bounds of the string, and `result` is a valid pointer.
+        let found = unsafe {
+            CFStringFindWithOptions(
+                self.string.as_ptr(),
+                self.separator.as_ptr(),
+                search,
+                0,
+                &mut result,
+            )
+        };
+
+        // An empty separator never matches, so the string is returned whole.
+        if found == 0 || result.length == 0 {
+            return self.range.take();
+        }
+
+        // UB: Core Foundation returns a valid range within the bounds of the search range.
+        let separator = Range::from_unchecked(result);
+        let substring = range.start..separator.start;
+        range.start = separator.end;
+        Some(substring)
+    }
+}
+
+impl FusedIterator for Split<'_> {}
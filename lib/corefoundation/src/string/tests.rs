@@ -4,9 +4,14 @@ use crate::cfstr;
 use crate::string::String;
 use core::mem::align_of;
 
+mod compare;
 mod create;
+mod external_representation;
+mod find;
 mod get_bytes;
 mod reader;
+mod split;
+mod transform;
 
 #[derive(Clone, Copy)]
 #[repr(align(2))]
@@ -105,6 +110,7 @@ macro_rules! impl_align {
         }
 
         impl<const N: usize> PartialEq<$struct<N>> for [u8] {
+            #[inline]
             fn eq(&self, other: &$struct<N>) -> bool {
                 <[u8] as PartialEq>::eq(self, other)
             }
@@ -171,6 +177,21 @@ fn to_string() {
     assert_eq!(cfstr!("Hello, World!").to_string(), "Hello, World!");
 }
 
+#[test]
+fn try_index() {
+    assert_eq!(POLAR_BEAR.try_index(0).unwrap(), 0xd83d);
+    assert_eq!(POLAR_BEAR.try_index(4).unwrap(), 0xfe0f);
+    let _ = POLAR_BEAR.try_index(5).unwrap_err();
+    let _ = EMPTY_STRING.try_index(0).unwrap_err();
+    let _ = POLAR_BEAR.try_index(usize::MAX).unwrap_err();
+}
+
+#[test]
+fn try_range() {
+    assert_eq!(POLAR_BEAR.try_range(2..).unwrap().len(), 3);
+    let _ = POLAR_BEAR.try_range(..6).unwrap_err();
+}
+
 #[test]
 fn try_as_str() {
     assert_eq!(EMPTY_STRING.try_as_str(), Some(""));
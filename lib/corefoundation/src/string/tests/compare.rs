@@ -0,0 +1,87 @@
+use crate::cfstr;
+use crate::locale::Locale;
+use crate::string::{CompareOptions, MutableString};
+use alloc::string::ToString;
+use core::cmp::Ordering;
+
+#[test]
+fn compare() {
+    let lower = cfstr!("hello");
+    let upper = cfstr!("HELLO");
+
+    assert_eq!(
+        lower.compare(lower, CompareOptions::default(), None),
+        Ordering::Equal
+    );
+    assert_eq!(
+        lower.compare(upper, CompareOptions::default(), None),
+        Ordering::Greater
+    );
+    assert_eq!(
+        lower.compare(upper, CompareOptions::CASE_INSENSITIVE, None),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn compare_numerically() {
+    let nine = cfstr!("version 9");
+    let ten = cfstr!("version 10");
+
+    assert_eq!(
+        nine.compare(ten, CompareOptions::default(), None),
+        Ordering::Greater
+    );
+    assert_eq!(
+        nine.compare(ten, CompareOptions::NUMERICALLY, None),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn compare_localized() {
+    // Swedish sorts "ö" after "z", whereas German sorts it with "o".
+    let german = Locale::from_identifier(cfstr!("de_DE")).unwrap();
+    let swedish = Locale::from_identifier(cfstr!("sv_SE")).unwrap();
+    let o = cfstr!("ö");
+    let z = cfstr!("z");
+
+    assert_eq!(
+        o.compare(z, CompareOptions::LOCALIZED, Some(&german)),
+        Ordering::Less
+    );
+    assert_eq!(
+        o.compare(z, CompareOptions::LOCALIZED, Some(&swedish)),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn fold() {
+    let mut s = MutableString::from_string(cfstr!("Résumé"));
+    s.fold(
+        CompareOptions::CASE_INSENSITIVE | CompareOptions::DIACRITIC_INSENSITIVE,
+        None,
+    );
+    assert_eq!(s.to_string(), "resume");
+}
+
+#[test]
+fn sort_key() {
+    let options = CompareOptions::CASE_INSENSITIVE;
+    let mut strings = [cfstr!("b"), cfstr!("C"), cfstr!("a")];
+    strings.sort_by_cached_key(|s| s.sort_key(options, None));
+
+    let sorted = strings.map(ToString::to_string);
+    assert_eq!(sorted, ["a", "b", "C"]);
+    assert_eq!(
+        cfstr!("AbC").sort_key(options, None),
+        [0, b'a', 0, b'b', 0, b'c']
+    );
+}
+
+#[should_panic(expected = "sort keys only support the folding compare options")]
+#[test]
+fn sort_key_numerically() {
+    drop(cfstr!("version 10").sort_key(CompareOptions::NUMERICALLY, None));
+}
@@ -6,6 +6,7 @@ use super::{
 };
 use crate::cfstr;
 use crate::string::{CharacterSet, FromUtfByteOrder, String};
+use core::ffi::CStr;
 use core::mem::size_of;
 use core::slice;
 
@@ -19,11 +20,64 @@ fn from_bytes() {
     );
 }
 
+#[test]
+fn from_cstr() {
+    let s = CStr::from_bytes_with_nul(b"\xc0\xd2\xa6\xd3\xb7\xee\xf4\0").unwrap();
+    assert_eq!(
+        String::from_cstr(s, CharacterSet::MacRoman).unwrap(),
+        cfstr!("¿“¶”∑ÓÙ")
+    );
+
+    let s = CStr::from_bytes_with_nul("🐻‍❄️\0".as_bytes()).unwrap();
+    assert_eq!(String::from_utf8_cstr(s).unwrap(), POLAR_BEAR);
+}
+
 #[test]
 fn from_invalid_bytes() {
     let _ = String::from_bytes([0x81, 0x81], CharacterSet::TraditionalChinese).unwrap_err();
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn from_bytes_no_copy() {
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    static DROPPED: AtomicBool = AtomicBool::new(false);
+
+    struct Bytes(Vec<u8>);
+
+    impl AsRef<[u8]> for Bytes {
+        fn as_ref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    impl Drop for Bytes {
+        fn drop(&mut self) {
+            DROPPED.store(true, Ordering::Relaxed);
+        }
+    }
+
+    let bytes = Bytes(vec![0xc0, 0xd2, 0xa6, 0xd3, 0xb7, 0xee, 0xf4]);
+    let s = String::from_bytes_no_copy(bytes, CharacterSet::MacRoman).unwrap();
+    assert_eq!(s, cfstr!("¿“¶”∑ÓÙ"));
+    drop(s);
+    assert!(DROPPED.load(Ordering::Relaxed));
+
+    let _ = String::from_bytes_no_copy([0x81, 0x81], CharacterSet::TraditionalChinese).unwrap_err();
+}
+
+#[test]
+fn from_static() {
+    static BYTES: [u8; 7] = [0xc0, 0xd2, 0xa6, 0xd3, 0xb7, 0xee, 0xf4];
+
+    assert_eq!(
+        String::from_static_bytes(&BYTES, CharacterSet::MacRoman).unwrap(),
+        cfstr!("¿“¶”∑ÓÙ")
+    );
+    assert_eq!(String::from_static_str("🐻‍❄️"), POLAR_BEAR);
+}
+
 #[test]
 fn from_str() {
     assert_eq!(String::from_str("🐻‍❄️"), POLAR_BEAR);
@@ -0,0 +1,76 @@
+use super::{
+    POLAR_BEAR, POLAR_BEAR_UTF16_BE, POLAR_BEAR_UTF16_NE_BOM, POLAR_BEAR_UTF32_NE_BOM,
+    POLAR_BEAR_UTF8,
+};
+use crate::cfstr;
+use crate::string::{CharacterSet, GetBytesByteOrder, GetBytesEncoding, String};
+use core::num::NonZeroU8;
+
+#[test]
+fn external_representation() {
+    let utf8 = POLAR_BEAR
+        .external_representation(GetBytesEncoding::Utf8)
+        .unwrap();
+    assert_eq!(utf8.as_bytes(), POLAR_BEAR_UTF8);
+
+    let utf16 = POLAR_BEAR
+        .external_representation(GetBytesEncoding::Utf16 {
+            byte_order: GetBytesByteOrder::BigEndian,
+        })
+        .unwrap();
+    assert_eq!(utf16.as_bytes(), &*POLAR_BEAR_UTF16_BE);
+
+    // A BOM is always included for the host's native byte order.
+    let utf16 = POLAR_BEAR
+        .external_representation(GetBytesEncoding::Utf16 {
+            byte_order: GetBytesByteOrder::HostNative { include_bom: false },
+        })
+        .unwrap();
+    assert_eq!(utf16.as_bytes(), &*POLAR_BEAR_UTF16_NE_BOM);
+
+    let utf32 = POLAR_BEAR
+        .external_representation(GetBytesEncoding::Utf32 {
+            byte_order: GetBytesByteOrder::HostNative { include_bom: true },
+            loss_byte: None,
+        })
+        .unwrap();
+    assert_eq!(utf32.as_bytes(), &*POLAR_BEAR_UTF32_NE_BOM);
+}
+
+#[test]
+fn external_representation_lossy() {
+    let encoding = |loss_byte| GetBytesEncoding::CharacterSet {
+        character_set: CharacterSet::MacRoman,
+        loss_byte,
+    };
+
+    assert!(POLAR_BEAR.external_representation(encoding(None)).is_none());
+
+    let lossy = cfstr!("a🐻b")
+        .external_representation(encoding(NonZeroU8::new(b'?')))
+        .unwrap();
+    assert_eq!(lossy.as_bytes(), b"a??b");
+}
+
+#[test]
+fn from_external_representation() {
+    let encodings = [
+        GetBytesEncoding::Utf8,
+        GetBytesEncoding::Utf16 {
+            byte_order: GetBytesByteOrder::LittleEndian,
+        },
+        GetBytesEncoding::Utf16 {
+            byte_order: GetBytesByteOrder::HostNative { include_bom: true },
+        },
+        GetBytesEncoding::Utf32 {
+            byte_order: GetBytesByteOrder::HostNative { include_bom: true },
+            loss_byte: None,
+        },
+    ];
+
+    for encoding in encodings {
+        let data = POLAR_BEAR.external_representation(encoding).unwrap();
+        let string = String::from_external_representation(&data, encoding).unwrap();
+        assert_eq!(&*string, POLAR_BEAR);
+    }
+}
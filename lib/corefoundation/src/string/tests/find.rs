@@ -0,0 +1,22 @@
+use super::{EMPTY_STRING, POLAR_BEAR};
+use crate::cfstr;
+use crate::character_set::{CharacterSet, Predefined};
+
+#[test]
+fn find_character_from_set() {
+    let whitespace = CharacterSet::predefined(Predefined::Whitespace);
+    let s = cfstr!("a b\tc");
+
+    assert_eq!(s.find_character_from_set(&whitespace), Some(1..2));
+    assert_eq!(s.rfind_character_from_set(&whitespace), Some(3..4));
+    assert_eq!(EMPTY_STRING.find_character_from_set(&whitespace), None);
+    assert_eq!(POLAR_BEAR.rfind_character_from_set(&whitespace), None);
+}
+
+#[test]
+fn find_character_from_set_surrogate_pair() {
+    let bear = CharacterSet::from_range('🐻'..='🐻');
+
+    assert_eq!(POLAR_BEAR.find_character_from_set(&bear), Some(0..2));
+    assert_eq!(POLAR_BEAR.rfind_character_from_set(&bear), Some(0..2));
+}
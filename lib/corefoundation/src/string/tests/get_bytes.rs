@@ -6,7 +6,8 @@ use super::{
 use crate::cfstr;
 use crate::string::{
     CharacterSet, FromUtfByteOrder, GetBytesByteOrder, GetBytesEncoding, GetBytesError,
-    GetBytesErrorKind, GetBytesResult, GetBytesSurrogateError, String,
+    GetBytesErrorKind, GetBytesResult, GetBytesSurrogateError, String, ToCStrError,
+    TryGetBytesError,
 };
 use core::num::NonZeroU8;
 
@@ -1090,3 +1091,65 @@ fn first_unaligned_offset<T>(v: &[u8]) -> usize {
     assert_ne!(i, v.len(), "unable to find an unaligned offset in v for T");
     i
 }
+
+#[test]
+fn to_cstr() {
+    let mut buf = [0xff_u8; 16];
+
+    let s = POLAR_BEAR
+        .to_cstr_in(GetBytesEncoding::Utf8, &mut buf)
+        .unwrap();
+    assert_eq!(s.to_bytes(), POLAR_BEAR_UTF8);
+
+    assert_eq!(
+        POLAR_BEAR.to_cstr_in(GetBytesEncoding::Utf8, &mut buf[..13]),
+        Err(ToCStrError::BufferTooSmall { required: 14 })
+    );
+
+    let encoding = GetBytesEncoding::CharacterSet {
+        character_set: CharacterSet::MacRoman,
+        loss_byte: None,
+    };
+    assert_eq!(
+        POLAR_BEAR.to_cstr_in(encoding, &mut buf),
+        Err(ToCStrError::Unconvertible)
+    );
+
+    let encoding = GetBytesEncoding::Utf16 {
+        byte_order: GetBytesByteOrder::BigEndian,
+    };
+    assert_eq!(
+        cfstr!("A").to_cstr_in(encoding, &mut buf),
+        Err(ToCStrError::InteriorNul)
+    );
+
+    let s = EMPTY_STRING
+        .to_cstr_in(GetBytesEncoding::Utf8, &mut buf[..1])
+        .unwrap();
+    assert!(s.is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_cstring() {
+    let s = POLAR_BEAR.to_cstring(GetBytesEncoding::Utf8).unwrap();
+    assert_eq!(s.as_bytes(), POLAR_BEAR_UTF8);
+}
+
+#[test]
+fn try_get_bytes() {
+    let mut buf = [0_u8; 16];
+    let result = POLAR_BEAR
+        .try_get_bytes(.., GetBytesEncoding::Utf8, Some(&mut buf))
+        .unwrap();
+    assert_eq!(buf[..result.buf_len], POLAR_BEAR_UTF8);
+
+    assert!(matches!(
+        POLAR_BEAR.try_get_bytes(..6, GetBytesEncoding::Utf8, None),
+        Err(TryGetBytesError::Range(_))
+    ));
+    assert!(matches!(
+        POLAR_BEAR.try_get_bytes(1..2, GetBytesEncoding::Utf8, None),
+        Err(TryGetBytesError::Convert(_))
+    ));
+}
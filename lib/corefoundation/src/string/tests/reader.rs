@@ -1,7 +1,7 @@
 use super::{POLAR_BEAR, POLAR_BEAR_UTF16_NE_BOM, POLAR_BEAR_UTF32_NE_BOM};
 use crate::string::{
-    FromUtfByteOrder, GetBytesByteOrder, GetBytesEncoding, GetBytesLossyReader, GetBytesReader,
-    GetBytesReaderSummary, GetBytesStrReader, GetBytesStrReplacement, String,
+    FmtByteSink, FromUtfByteOrder, GetBytesByteOrder, GetBytesEncoding, GetBytesLossyReader,
+    GetBytesReader, GetBytesReaderSummary, GetBytesStrReader, GetBytesStrReplacement, String,
 };
 use core::mem::size_of;
 
@@ -247,6 +247,32 @@ fn str_reader_replacement_custom() {
     assert!(reader.read(&mut buf).is_none());
 }
 
+#[test]
+fn encode_into() {
+    let mut buf = [0_u8; 4];
+
+    let mut s = alloc::string::String::new();
+    GetBytesStrReader::new(POLAR_BEAR, GetBytesStrReplacement::None, ..)
+        .encode_into(&mut buf, FmtByteSink(&mut s))
+        .unwrap();
+    assert_eq!(s, "🐻‍❄️");
+
+    let mut v = Vec::new();
+    GetBytesLossyReader::new(POLAR_BEAR, GetBytesEncoding::Utf8, None, ..)
+        .encode_into(&mut buf, &mut v)
+        .unwrap();
+    assert_eq!(v, "🐻‍❄️".as_bytes());
+
+    // Bytes that are not UTF-8 cannot be written to a `fmt::Write`.
+    let encoding = GetBytesEncoding::Utf16 {
+        byte_order: GetBytesByteOrder::HostNative { include_bom: false },
+    };
+    let mut s = alloc::string::String::new();
+    assert!(GetBytesLossyReader::new(POLAR_BEAR, encoding, None, ..)
+        .encode_into(&mut buf, FmtByteSink(&mut s))
+        .is_err());
+}
+
 #[test]
 fn utf16_bom() {
     let mut buf = [0_u8; 16];
@@ -341,3 +367,35 @@ fn buf_too_small() {
 
     let _ = reader.read(&mut buf);
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn io_read() {
+    use std::io::Read;
+
+    let mut reader = GetBytesStrReader::new(POLAR_BEAR, GetBytesStrReplacement::None, ..);
+    let mut s = alloc::string::String::new();
+    let _ = reader.read_to_string(&mut s).unwrap();
+    assert_eq!(s, "🐻‍❄️");
+
+    let mut reader = GetBytesLossyReader::new(POLAR_BEAR, GetBytesEncoding::Utf8, None, ..);
+    let mut buf = [0_u8; 1];
+    let mut bytes = Vec::new();
+    while Read::read(&mut reader, &mut buf).unwrap() != 0 {
+        bytes.extend_from_slice(&buf);
+    }
+    assert_eq!(bytes, "🐻‍❄️".as_bytes());
+
+    let s = String::from_utf16([0xd83d], FromUtfByteOrder::HostNative);
+    let mut reader = GetBytesStrReader::new(&s, GetBytesStrReplacement::Custom("Too Long"), ..);
+    let mut bytes = Vec::new();
+    let mut buf = [0_u8; 3];
+    loop {
+        let len = Read::read(&mut reader, &mut buf).unwrap();
+        if len == 0 {
+            break;
+        }
+        bytes.extend_from_slice(&buf[..len]);
+    }
+    assert_eq!(bytes, b"Too Long");
+}
@@ -0,0 +1,44 @@
+// LINT: The expected values are arrays of ranges, some of which contain a single range.
+#![allow(clippy::single_range_in_vec_init)]
+
+use super::{EMPTY_STRING, POLAR_BEAR};
+use crate::cfstr;
+
+#[test]
+fn lines() {
+    let s = cfstr!("a\nbc\r\n\rd\u{2028}e\n");
+    let lines: Vec<_> = s.lines().collect();
+    assert_eq!(lines, [0..1, 2..4, 6..6, 7..8, 9..10]);
+
+    assert_eq!(cfstr!("no terminator").lines().collect::<Vec<_>>(), [0..13]);
+    assert_eq!(EMPTY_STRING.lines().next(), None);
+}
+
+#[test]
+fn split() {
+    let s = cfstr!(", a,, b, ");
+    let separator = cfstr!(", ");
+    assert_eq!(
+        s.split(separator).collect::<Vec<_>>(),
+        [0..0, 2..4, 6..7, 9..9]
+    );
+
+    assert_eq!(EMPTY_STRING.split(separator).collect::<Vec<_>>(), [0..0]);
+    assert_eq!(POLAR_BEAR.split(EMPTY_STRING).collect::<Vec<_>>(), [0..5]);
+    assert_eq!(
+        POLAR_BEAR.split(cfstr!("\u{200d}")).collect::<Vec<_>>(),
+        [0..2, 3..5]
+    );
+}
+
+#[test]
+fn substring() {
+    let s = cfstr!("hello world");
+    assert_eq!(&*s.substring(6..), cfstr!("world"));
+    assert_eq!(&*s.substring(..5), cfstr!("hello"));
+    assert_eq!(&*s.substring(5..5), EMPTY_STRING);
+    assert_eq!(&*POLAR_BEAR.substring(..2), cfstr!("\u{1f43b}"));
+
+    let range = s.try_range(..).unwrap();
+    assert_eq!(&*s.substring(range), s);
+}
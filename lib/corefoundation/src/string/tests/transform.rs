@@ -0,0 +1,36 @@
+use crate::cfstr;
+use crate::string::{MutableString, Transform};
+
+#[test]
+fn applying_transform() {
+    let s = cfstr!("Crème Brûlée");
+    let stripped = s
+        .applying_transform(Transform::StripDiacritics, false)
+        .unwrap();
+    assert_eq!(&*stripped, cfstr!("Creme Brulee"));
+
+    let greek = cfstr!("alpha")
+        .applying_transform(Transform::LatinGreek, false)
+        .unwrap();
+    let latin = greek
+        .applying_transform(Transform::LatinGreek, true)
+        .unwrap();
+    assert_eq!(&*latin, cfstr!("alpha"));
+
+    let ascii = cfstr!("Ελληνικά")
+        .applying_transform(cfstr!("Any-Latin; Latin-ASCII"), false)
+        .unwrap();
+    assert!(ascii.to_string().is_ascii());
+}
+
+#[test]
+fn invalid_transform() {
+    let s = cfstr!("text");
+    assert!(s
+        .applying_transform(cfstr!("Not-A-Transform"), false)
+        .is_none());
+
+    let mut s = MutableString::from_string(s);
+    assert!(!s.transform(cfstr!("Not-A-Transform"), false));
+    assert_eq!(&**s, cfstr!("text"));
+}
@@ -0,0 +1,83 @@
+use crate::string::String;
+use corefoundation_sys::{
+    kCFStringTransformFullwidthHalfwidth, kCFStringTransformHiraganaKatakana,
+    kCFStringTransformLatinArabic, kCFStringTransformLatinCyrillic, kCFStringTransformLatinGreek,
+    kCFStringTransformLatinHangul, kCFStringTransformLatinHebrew, kCFStringTransformLatinHiragana,
+    kCFStringTransformLatinKatakana, kCFStringTransformLatinThai, kCFStringTransformMandarinLatin,
+    kCFStringTransformStripCombiningMarks, kCFStringTransformStripDiacritics,
+    kCFStringTransformToLatin, kCFStringTransformToUnicodeName, kCFStringTransformToXMLHex,
+};
+
+/// A predefined transform for [`String::applying_transform`] and [`MutableString::transform`].
+///
+/// Transforms that convert between two scripts (e.g., [`Transform::LatinGreek`]) are applied in the
+/// named direction; apply the transform in reverse to convert in the opposite direction. Any ICU
+/// transform ID (e.g., `Any-Latin; Latin-ASCII`) may be used in place of a predefined transform.
+///
+/// [`MutableString::transform`]: crate::string::MutableString::transform
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Transform {
+    /// Converts full-width characters to their half-width equivalents.
+    FullwidthHalfwidth,
+    /// Converts Hiragana to Katakana.
+    HiraganaKatakana,
+    /// Transliterates Latin to Arabic.
+    LatinArabic,
+    /// Transliterates Latin to Cyrillic.
+    LatinCyrillic,
+    /// Transliterates Latin to Greek.
+    LatinGreek,
+    /// Transliterates Latin to Hangul.
+    LatinHangul,
+    /// Transliterates Latin to Hebrew.
+    LatinHebrew,
+    /// Transliterates Latin to Hiragana.
+    LatinHiragana,
+    /// Transliterates Latin to Katakana.
+    LatinKatakana,
+    /// Transliterates Latin to Thai.
+    LatinThai,
+    /// Transliterates Mandarin to Latin (Pinyin). This transform cannot be reversed.
+    MandarinLatin,
+    /// Removes combining marks (e.g., accents) after decomposition.
+    StripCombiningMarks,
+    /// Removes diacritics (e.g., "é" becomes "e").
+    StripDiacritics,
+    /// Transliterates any script to Latin. This transform cannot be reversed.
+    ToLatin,
+    /// Replaces each code point with its Unicode name (e.g., `\N{LATIN SMALL LETTER A}`).
+    ToUnicodeName,
+    /// Replaces characters that are not printable ASCII with XML hexadecimal escapes (e.g.,
+    /// `&#x1F43B;`).
+    ToXmlHex,
+}
+
+impl AsRef<String> for Transform {
+    #[inline]
+    fn as_ref(&self) -> &String {
+        // SAFETY: The constants are initialized when the framework is loaded and never modified.
+        let cf = unsafe {
+            match *self {
+                Self::FullwidthHalfwidth => kCFStringTransformFullwidthHalfwidth,
+                Self::HiraganaKatakana => kCFStringTransformHiraganaKatakana,
+                Self::LatinArabic => kCFStringTransformLatinArabic,
+                Self::LatinCyrillic => kCFStringTransformLatinCyrillic,
+                Self::LatinGreek => kCFStringTransformLatinGreek,
+                Self::LatinHangul => kCFStringTransformLatinHangul,
+                Self::LatinHebrew => kCFStringTransformLatinHebrew,
+                Self::LatinHiragana => kCFStringTransformLatinHiragana,
+                Self::LatinKatakana => kCFStringTransformLatinKatakana,
+                Self::LatinThai => kCFStringTransformLatinThai,
+                Self::MandarinLatin => kCFStringTransformMandarinLatin,
+                Self::StripCombiningMarks => kCFStringTransformStripCombiningMarks,
+                Self::StripDiacritics => kCFStringTransformStripDiacritics,
+                Self::ToLatin => kCFStringTransformToLatin,
+                Self::ToUnicodeName => kCFStringTransformToUnicodeName,
+                Self::ToXmlHex => kCFStringTransformToXMLHex,
+            }
+        };
+        // SAFETY: The constants are valid, immortal [`CFStringRef`]s.
+        unsafe { &*cf.cast::<String>() }
+    }
+}
@@ -0,0 +1,146 @@
+//! Geopolitical regions that share a standard time, used to convert between points in time and
+//! local (wall clock) time.
+
+use crate::date::Date;
+use crate::define_and_impl_type;
+use crate::ffi::{ForeignFunctionInterface, TryFromForeign};
+use crate::string::String;
+use crate::sync::Arc;
+use core::ptr::NonNull;
+use corefoundation_sys::{
+    kCFAllocatorDefault, CFTimeZoneCopyDefault, CFTimeZoneCopySystem, CFTimeZoneCreateWithName,
+    CFTimeZoneCreateWithTimeIntervalFromGMT, CFTimeZoneGetName, CFTimeZoneGetSecondsFromGMT,
+    CFTimeZoneIsDaylightSavingTime, __CFTimeZone,
+};
+
+// SAFETY: Time zone objects are immutable.
+define_and_impl_type!(
+    /// An immutable set of rules for the offset of local time from GMT, which may change at points
+    /// in time (e.g., to observe daylight saving time).
+    TimeZone,
+    raw: __CFTimeZone,
+    type_id: corefoundation_sys::CFTimeZoneGetTypeID,
+    thread_safe
+);
+
+impl TimeZone {
+    /// Returns the default time zone for the process, which is the system time zone unless the
+    /// process replaced it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time zone could not be created.
+    #[inline]
+    #[must_use]
+    pub fn current() -> Arc<Self> {
+        // SAFETY: The function is always safe to call.
+        let cf = unsafe { CFTimeZoneCopyDefault() };
+        // SAFETY: The [`CFTimeZoneRef`] follows the create rule.
+        unsafe { Self::try_from_owned(cf) }.expect("CFTimeZoneCopyDefault")
+    }
+
+    /// Returns a time zone for `name` (e.g., `America/Los_Angeles`) or, failing that, for the
+    /// abbreviation `name` (e.g., `PST`). Returns [`None`] if `name` is not a known time zone.
+    #[inline]
+    #[must_use]
+    pub fn from_name(name: &String) -> Option<Arc<Self>> {
+        // SAFETY: `name` is a valid [`CFStringRef`].
+        let cf = unsafe { CFTimeZoneCreateWithName(kCFAllocatorDefault, name.as_ptr(), 1) };
+        // SAFETY: The [`CFTimeZoneRef`] follows the create rule.
+        unsafe { Self::try_from_owned(cf) }.ok()
+    }
+
+    /// Returns a time zone whose local time is always `seconds_from_gmt` seconds ahead of GMT
+    /// (behind, if negative). Returns [`None`] if the offset is out of range.
+    #[inline]
+    #[must_use]
+    pub fn from_seconds_from_gmt(seconds_from_gmt: f64) -> Option<Arc<Self>> {
+        // SAFETY: The function is always safe to call.
+        let cf = unsafe {
+            CFTimeZoneCreateWithTimeIntervalFromGMT(kCFAllocatorDefault, seconds_from_gmt)
+        };
+        // SAFETY: The [`CFTimeZoneRef`] follows the create rule.
+        unsafe { Self::try_from_owned(cf) }.ok()
+    }
+
+    /// Returns the time zone for the system.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time zone could not be created.
+    #[inline]
+    #[must_use]
+    pub fn system() -> Arc<Self> {
+        // SAFETY: The function is always safe to call.
+        let cf = unsafe { CFTimeZoneCopySystem() };
+        // SAFETY: The [`CFTimeZoneRef`] follows the create rule.
+        unsafe { Self::try_from_owned(cf) }.expect("CFTimeZoneCopySystem")
+    }
+
+    /// Returns `true` if the time zone observes daylight saving time at `date`.
+    #[inline]
+    #[must_use]
+    pub fn is_daylight_saving_time(&self, date: &Date) -> bool {
+        // SAFETY: `self` is a valid [`CFTimeZoneRef`].
+        let result = unsafe { CFTimeZoneIsDaylightSavingTime(self.as_ptr(), date.absolute_time()) };
+        result != 0
+    }
+
+    /// Returns the time zone's name (e.g., `America/Los_Angeles`).
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> Arc<String> {
+        // SAFETY: `self` is a valid [`CFTimeZoneRef`].
+        let cf = unsafe { CFTimeZoneGetName(self.as_ptr()) };
+        // SAFETY: A time zone always has a name.
+        let cf = unsafe { NonNull::new_unchecked(cf.cast_mut()) };
+        // SAFETY: [`CFTimeZoneGetName`] follows the get rule.
+        unsafe { String::from_borrowed_ptr(cf) }
+    }
+
+    /// Returns the number of seconds local time is ahead of GMT (behind, if negative) at `date`.
+    #[inline]
+    #[must_use]
+    pub fn seconds_from_gmt(&self, date: &Date) -> f64 {
+        // SAFETY: `self` is a valid [`CFTimeZoneRef`].
+        unsafe { CFTimeZoneGetSecondsFromGMT(self.as_ptr(), date.absolute_time()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::cfstr;
+
+    #[test]
+    fn fixed_offset() {
+        let time_zone = TimeZone::from_seconds_from_gmt(-3_600.0).unwrap();
+        let date = Date::from_absolute_time(0.0);
+        assert_eq!(time_zone.seconds_from_gmt(&date), -3_600.0_f64);
+        assert!(!time_zone.is_daylight_saving_time(&date));
+    }
+
+    #[test]
+    fn from_name() {
+        let time_zone = TimeZone::from_name(cfstr!("America/Los_Angeles")).unwrap();
+        assert_eq!(time_zone.name().to_string(), "America/Los_Angeles");
+
+        // 2001-01-01 is in standard time and 2001-07-01 is in daylight saving time.
+        let winter = Date::from_absolute_time(0.0);
+        let summer = Date::from_absolute_time(15_638_400.0);
+        assert_eq!(time_zone.seconds_from_gmt(&winter), -28_800.0_f64);
+        assert_eq!(time_zone.seconds_from_gmt(&summer), -25_200.0_f64);
+        assert!(time_zone.is_daylight_saving_time(&summer));
+
+        assert!(TimeZone::from_name(cfstr!("Not/A_Time_Zone")).is_none());
+    }
+
+    #[test]
+    fn system() {
+        let system = TimeZone::system();
+        assert!(!system.name().is_empty());
+        assert!(!TimeZone::current().name().is_empty());
+    }
+}
@@ -2,33 +2,116 @@ use core::ffi::c_int;
 
 pub(crate) const EPERM: c_int = 1;
 pub(crate) const ENOENT: c_int = 2;
+pub(crate) const ESRCH: c_int = 3;
 pub(crate) const EINTR: c_int = 4;
 pub(crate) const EIO: c_int = 5;
 pub(crate) const ENXIO: c_int = 6;
+pub(crate) const E2BIG: c_int = 7;
+pub(crate) const ENOEXEC: c_int = 8;
 pub(crate) const EBADF: c_int = 9;
+pub(crate) const ECHILD: c_int = 10;
 pub(crate) const EDEADLK: c_int = 11;
 pub(crate) const ENOMEM: c_int = 12;
 pub(crate) const EACCES: c_int = 13;
 pub(crate) const EFAULT: c_int = 14;
+pub(crate) const ENOTBLK: c_int = 15;
 pub(crate) const EBUSY: c_int = 16;
 pub(crate) const EEXIST: c_int = 17;
 pub(crate) const EXDEV: c_int = 18;
+pub(crate) const ENODEV: c_int = 19;
 pub(crate) const ENOTDIR: c_int = 20;
 pub(crate) const EISDIR: c_int = 21;
 pub(crate) const EINVAL: c_int = 22;
 pub(crate) const ENFILE: c_int = 23;
 pub(crate) const EMFILE: c_int = 24;
+pub(crate) const ENOTTY: c_int = 25;
 pub(crate) const ETXTBSY: c_int = 26;
+pub(crate) const EFBIG: c_int = 27;
 pub(crate) const ENOSPC: c_int = 28;
+pub(crate) const ESPIPE: c_int = 29;
 pub(crate) const EROFS: c_int = 30;
+pub(crate) const EMLINK: c_int = 31;
+pub(crate) const EPIPE: c_int = 32;
+pub(crate) const EDOM: c_int = 33;
+pub(crate) const ERANGE: c_int = 34;
 pub(crate) const EAGAIN: c_int = 35;
+// LINT: The alias mirrors the header; the idiomatic interface uses the canonical name.
+#[allow(dead_code)]
+pub(crate) const EWOULDBLOCK: c_int = EAGAIN;
+pub(crate) const EINPROGRESS: c_int = 36;
+pub(crate) const EALREADY: c_int = 37;
+pub(crate) const ENOTSOCK: c_int = 38;
+pub(crate) const EDESTADDRREQ: c_int = 39;
+pub(crate) const EMSGSIZE: c_int = 40;
+pub(crate) const EPROTOTYPE: c_int = 41;
+pub(crate) const ENOPROTOOPT: c_int = 42;
+pub(crate) const EPROTONOSUPPORT: c_int = 43;
+pub(crate) const ESOCKTNOSUPPORT: c_int = 44;
+pub(crate) const ENOTSUP: c_int = 45;
+pub(crate) const EPFNOSUPPORT: c_int = 46;
+pub(crate) const EAFNOSUPPORT: c_int = 47;
+pub(crate) const EADDRINUSE: c_int = 48;
+pub(crate) const EADDRNOTAVAIL: c_int = 49;
+pub(crate) const ENETDOWN: c_int = 50;
+pub(crate) const ENETUNREACH: c_int = 51;
+pub(crate) const ENETRESET: c_int = 52;
+pub(crate) const ECONNABORTED: c_int = 53;
+pub(crate) const ECONNRESET: c_int = 54;
+pub(crate) const ENOBUFS: c_int = 55;
+pub(crate) const EISCONN: c_int = 56;
+pub(crate) const ENOTCONN: c_int = 57;
+pub(crate) const ESHUTDOWN: c_int = 58;
+pub(crate) const ETOOMANYREFS: c_int = 59;
+pub(crate) const ETIMEDOUT: c_int = 60;
+pub(crate) const ECONNREFUSED: c_int = 61;
 pub(crate) const ELOOP: c_int = 62;
 pub(crate) const ENAMETOOLONG: c_int = 63;
+pub(crate) const EHOSTDOWN: c_int = 64;
+pub(crate) const EHOSTUNREACH: c_int = 65;
 pub(crate) const ENOTEMPTY: c_int = 66;
+pub(crate) const EPROCLIM: c_int = 67;
+pub(crate) const EUSERS: c_int = 68;
 pub(crate) const EDQUOT: c_int = 69;
+pub(crate) const ESTALE: c_int = 70;
+pub(crate) const EREMOTE: c_int = 71;
+pub(crate) const EBADRPC: c_int = 72;
+pub(crate) const ERPCMISMATCH: c_int = 73;
+pub(crate) const EPROGUNAVAIL: c_int = 74;
+pub(crate) const EPROGMISMATCH: c_int = 75;
+pub(crate) const EPROCUNAVAIL: c_int = 76;
+pub(crate) const ENOLCK: c_int = 77;
+pub(crate) const ENOSYS: c_int = 78;
+pub(crate) const EFTYPE: c_int = 79;
+pub(crate) const EAUTH: c_int = 80;
+pub(crate) const ENEEDAUTH: c_int = 81;
+pub(crate) const EPWROFF: c_int = 82;
+pub(crate) const EDEVERR: c_int = 83;
 pub(crate) const EOVERFLOW: c_int = 84;
+pub(crate) const EBADEXEC: c_int = 85;
+pub(crate) const EBADARCH: c_int = 86;
+pub(crate) const ESHLIBVERS: c_int = 87;
+pub(crate) const EBADMACHO: c_int = 88;
+pub(crate) const ECANCELED: c_int = 89;
+pub(crate) const EIDRM: c_int = 90;
+pub(crate) const ENOMSG: c_int = 91;
 pub(crate) const EILSEQ: c_int = 92;
+pub(crate) const ENOATTR: c_int = 93;
+pub(crate) const EBADMSG: c_int = 94;
+pub(crate) const EMULTIHOP: c_int = 95;
+pub(crate) const ENODATA: c_int = 96;
+pub(crate) const ENOLINK: c_int = 97;
+pub(crate) const ENOSR: c_int = 98;
+pub(crate) const ENOSTR: c_int = 99;
+pub(crate) const EPROTO: c_int = 100;
+pub(crate) const ETIME: c_int = 101;
 pub(crate) const EOPNOTSUPP: c_int = 102;
+pub(crate) const ENOPOLICY: c_int = 103;
+pub(crate) const ENOTRECOVERABLE: c_int = 104;
+pub(crate) const EOWNERDEAD: c_int = 105;
+pub(crate) const EQFULL: c_int = 106;
+// LINT: The bound mirrors the header; the idiomatic interface matches each value explicitly.
+#[allow(dead_code)]
+pub(crate) const ELAST: c_int = 106;
 
 extern "C" {
     pub(crate) fn __error() -> &'static mut c_int;
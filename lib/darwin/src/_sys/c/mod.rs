@@ -1 +1,4 @@
 pub(crate) mod errno;
+pub(crate) mod stdlib;
+pub(crate) mod string;
+pub(crate) mod time;
@@ -0,0 +1,8 @@
+use core::ffi::{c_char, c_int};
+
+extern "C" {
+    pub(crate) fn abort() -> !;
+    pub(crate) fn getenv(name: *const c_char) -> *mut c_char;
+    pub(crate) fn setenv(name: *const c_char, value: *const c_char, overwrite: c_int) -> c_int;
+    pub(crate) fn unsetenv(name: *const c_char) -> c_int;
+}
@@ -0,0 +1,5 @@
+use core::ffi::{c_char, c_int};
+
+extern "C" {
+    pub(crate) fn strerror_r(errnum: c_int, strerrbuf: *mut c_char, buflen: usize) -> c_int;
+}
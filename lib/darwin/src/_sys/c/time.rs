@@ -0,0 +1,23 @@
+#![allow(non_camel_case_types)]
+
+use crate::_sys::sys::types::timespec;
+use core::ffi::c_int;
+
+pub(crate) type clockid_t = u32;
+
+pub(crate) const _CLOCK_REALTIME: clockid_t = 0;
+pub(crate) const _CLOCK_MONOTONIC: clockid_t = 6;
+pub(crate) const _CLOCK_MONOTONIC_RAW: clockid_t = 4;
+pub(crate) const _CLOCK_MONOTONIC_RAW_APPROX: clockid_t = 5;
+pub(crate) const _CLOCK_UPTIME_RAW: clockid_t = 8;
+pub(crate) const _CLOCK_UPTIME_RAW_APPROX: clockid_t = 9;
+pub(crate) const _CLOCK_PROCESS_CPUTIME_ID: clockid_t = 12;
+pub(crate) const _CLOCK_THREAD_CPUTIME_ID: clockid_t = 16;
+
+extern "C" {
+    pub(crate) fn clock_getres(clock_id: clockid_t, res: *mut timespec) -> c_int;
+    pub(crate) fn clock_gettime(clock_id: clockid_t, tp: *mut timespec) -> c_int;
+    // LINT: Bound alongside `clock_gettime`, which the idiomatic interface uses instead.
+    #[allow(dead_code)]
+    pub(crate) fn clock_gettime_nsec_np(clock_id: clockid_t) -> u64;
+}
@@ -0,0 +1,104 @@
+#![allow(non_camel_case_types)]
+
+use crate::io::BorrowedFd;
+use core::ffi::{c_char, c_int, c_void};
+
+#[repr(C)]
+pub(crate) struct _copyfile_state {
+    _data: [u8; 0],
+}
+
+pub(crate) type copyfile_state_t = *mut _copyfile_state;
+pub(crate) type copyfile_flags_t = u32;
+
+pub(crate) type copyfile_callback_t = extern "C" fn(
+    what: c_int,
+    stage: c_int,
+    state: copyfile_state_t,
+    src: *const c_char,
+    dst: *const c_char,
+    ctx: *mut c_void,
+) -> c_int;
+
+pub(crate) const COPYFILE_STATE_SRC_FD: u32 = 1;
+pub(crate) const COPYFILE_STATE_SRC_FILENAME: u32 = 2;
+pub(crate) const COPYFILE_STATE_DST_FD: u32 = 3;
+pub(crate) const COPYFILE_STATE_DST_FILENAME: u32 = 4;
+pub(crate) const COPYFILE_STATE_QUARANTINE: u32 = 5;
+pub(crate) const COPYFILE_STATE_STATUS_CB: u32 = 6;
+pub(crate) const COPYFILE_STATE_STATUS_CTX: u32 = 7;
+pub(crate) const COPYFILE_STATE_COPIED: u32 = 8;
+pub(crate) const COPYFILE_STATE_XATTRNAME: u32 = 9;
+pub(crate) const COPYFILE_STATE_WAS_CLONED: u32 = 10;
+
+pub(crate) const COPYFILE_ACL: copyfile_flags_t = 1 << 0;
+pub(crate) const COPYFILE_STAT: copyfile_flags_t = 1 << 1;
+pub(crate) const COPYFILE_XATTR: copyfile_flags_t = 1 << 2;
+pub(crate) const COPYFILE_DATA: copyfile_flags_t = 1 << 3;
+
+pub(crate) const COPYFILE_SECURITY: copyfile_flags_t = COPYFILE_STAT | COPYFILE_ACL;
+pub(crate) const COPYFILE_METADATA: copyfile_flags_t = COPYFILE_SECURITY | COPYFILE_XATTR;
+pub(crate) const COPYFILE_ALL: copyfile_flags_t = COPYFILE_METADATA | COPYFILE_DATA;
+
+pub(crate) const COPYFILE_RECURSIVE: copyfile_flags_t = 1 << 15;
+pub(crate) const COPYFILE_CHECK: copyfile_flags_t = 1 << 16;
+pub(crate) const COPYFILE_EXCL: copyfile_flags_t = 1 << 17;
+pub(crate) const COPYFILE_NOFOLLOW_SRC: copyfile_flags_t = 1 << 18;
+pub(crate) const COPYFILE_NOFOLLOW_DST: copyfile_flags_t = 1 << 19;
+pub(crate) const COPYFILE_MOVE: copyfile_flags_t = 1 << 20;
+pub(crate) const COPYFILE_UNLINK: copyfile_flags_t = 1 << 21;
+pub(crate) const COPYFILE_NOFOLLOW: copyfile_flags_t =
+    COPYFILE_NOFOLLOW_SRC | COPYFILE_NOFOLLOW_DST;
+pub(crate) const COPYFILE_PACK: copyfile_flags_t = 1 << 22;
+pub(crate) const COPYFILE_UNPACK: copyfile_flags_t = 1 << 23;
+pub(crate) const COPYFILE_CLONE: copyfile_flags_t = 1 << 24;
+pub(crate) const COPYFILE_CLONE_FORCE: copyfile_flags_t = 1 << 25;
+pub(crate) const COPYFILE_RUN_IN_PLACE: copyfile_flags_t = 1 << 26;
+pub(crate) const COPYFILE_DATA_SPARSE: copyfile_flags_t = 1 << 27;
+pub(crate) const COPYFILE_PRESERVE_DST_TRACKED: copyfile_flags_t = 1 << 28;
+pub(crate) const COPYFILE_VERBOSE: copyfile_flags_t = 1 << 30;
+
+pub(crate) const COPYFILE_RECURSE_ERROR: c_int = 0;
+pub(crate) const COPYFILE_RECURSE_FILE: c_int = 1;
+pub(crate) const COPYFILE_RECURSE_DIR: c_int = 2;
+pub(crate) const COPYFILE_RECURSE_DIR_CLEANUP: c_int = 3;
+pub(crate) const COPYFILE_COPY_DATA: c_int = 4;
+pub(crate) const COPYFILE_COPY_XATTR: c_int = 5;
+
+pub(crate) const COPYFILE_START: c_int = 1;
+pub(crate) const COPYFILE_FINISH: c_int = 2;
+pub(crate) const COPYFILE_ERR: c_int = 3;
+pub(crate) const COPYFILE_PROGRESS: c_int = 4;
+
+pub(crate) const COPYFILE_CONTINUE: c_int = 0;
+pub(crate) const COPYFILE_SKIP: c_int = 1;
+pub(crate) const COPYFILE_QUIT: c_int = 2;
+
+extern "C" {
+    pub(crate) fn copyfile(
+        from: *const c_char,
+        to: *const c_char,
+        state: copyfile_state_t,
+        flags: copyfile_flags_t,
+    ) -> c_int;
+
+    pub(crate) fn fcopyfile(
+        from_fd: BorrowedFd<'_>,
+        to_fd: BorrowedFd<'_>,
+        state: copyfile_state_t,
+        flags: copyfile_flags_t,
+    ) -> c_int;
+
+    pub(crate) fn copyfile_state_alloc() -> copyfile_state_t;
+
+    pub(crate) fn copyfile_state_free(state: copyfile_state_t) -> c_int;
+
+    pub(crate) fn copyfile_state_get(state: copyfile_state_t, flag: u32, dst: *mut c_void)
+        -> c_int;
+
+    pub(crate) fn copyfile_state_set(
+        state: copyfile_state_t,
+        flag: u32,
+        src: *const c_void,
+    ) -> c_int;
+}
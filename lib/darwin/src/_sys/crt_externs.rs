@@ -0,0 +1,5 @@
+use core::ffi::c_char;
+
+extern "C" {
+    pub(crate) fn _NSGetEnviron() -> *mut *mut *mut c_char;
+}
@@ -7,5 +7,9 @@
 //! convention. So, for this crate, the system interface is available in this `_sys` module.
 
 pub(crate) mod c;
+// LINT: The bindings mirror the header, not only what the idiomatic interface uses so far.
+#[allow(dead_code)]
+pub(crate) mod copyfile;
+pub(crate) mod crt_externs;
 pub(crate) mod posix;
 pub(crate) mod sys;
@@ -0,0 +1,73 @@
+use core::ffi::{c_char, c_int, c_long};
+use core::marker::{PhantomData, PhantomPinned};
+
+pub(crate) const DT_UNKNOWN: u8 = 0;
+pub(crate) const DT_FIFO: u8 = 1;
+pub(crate) const DT_CHR: u8 = 2;
+pub(crate) const DT_DIR: u8 = 4;
+pub(crate) const DT_BLK: u8 = 6;
+pub(crate) const DT_REG: u8 = 8;
+pub(crate) const DT_LNK: u8 = 10;
+pub(crate) const DT_SOCK: u8 = 12;
+pub(crate) const DT_WHT: u8 = 14;
+
+/// The maximum length of a path in bytes, including the nul terminator.
+pub(crate) const MAXPATHLEN: usize = 1024;
+
+/// A directory entry, as laid out by the 64-bit inode ABI (`__DARWIN_64_BIT_INO_T`).
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct dirent {
+    pub(crate) ino: u64,
+    pub(crate) seekoff: u64,
+    pub(crate) reclen: u16,
+    pub(crate) namlen: u16,
+    pub(crate) r#type: u8,
+    pub(crate) name: [c_char; MAXPATHLEN],
+}
+
+/// An open directory stream.
+// LINT: The name matches the C type.
+#[allow(clippy::upper_case_acronyms)]
+#[repr(C)]
+pub(crate) struct DIR {
+    _data: [u8; 0],
+    _marker: PhantomData<(*const u8, PhantomPinned)>,
+}
+
+// The x86_64 macOS ABI predates 64-bit inodes, so the 64-bit inode variants of these functions are
+// exported under a `$INODE64` suffix.
+extern "C" {
+    pub(crate) fn closedir(dirp: *mut DIR) -> c_int;
+    pub(crate) fn dirfd(dirp: *mut DIR) -> c_int;
+    #[cfg_attr(
+        all(target_os = "macos", target_arch = "x86_64"),
+        link_name = "fdopendir$INODE64"
+    )]
+    pub(crate) fn fdopendir(fd: c_int) -> *mut DIR;
+    #[cfg_attr(
+        all(target_os = "macos", target_arch = "x86_64"),
+        link_name = "opendir$INODE64"
+    )]
+    pub(crate) fn opendir(dirname: *const c_char) -> *mut DIR;
+    #[cfg_attr(
+        all(target_os = "macos", target_arch = "x86_64"),
+        link_name = "readdir$INODE64"
+    )]
+    pub(crate) fn readdir(dirp: *mut DIR) -> *mut dirent;
+    #[cfg_attr(
+        all(target_os = "macos", target_arch = "x86_64"),
+        link_name = "rewinddir$INODE64"
+    )]
+    pub(crate) fn rewinddir(dirp: *mut DIR);
+    #[cfg_attr(
+        all(target_os = "macos", target_arch = "x86_64"),
+        link_name = "seekdir$INODE64"
+    )]
+    pub(crate) fn seekdir(dirp: *mut DIR, loc: c_long);
+    #[cfg_attr(
+        all(target_os = "macos", target_arch = "x86_64"),
+        link_name = "telldir$INODE64"
+    )]
+    pub(crate) fn telldir(dirp: *mut DIR) -> c_long;
+}
@@ -5,8 +5,43 @@ pub(crate) const O_WRONLY: c_int = 0x0001;
 pub(crate) const O_RDWR: c_int = 0x0002;
 pub(crate) const O_ACCMODE: c_int = 0x0003;
 
+pub(crate) const O_NONBLOCK: c_int = 0x0000_0004;
+pub(crate) const O_APPEND: c_int = 0x0000_0008;
+pub(crate) const O_SHLOCK: c_int = 0x0000_0010;
+pub(crate) const O_EXLOCK: c_int = 0x0000_0020;
+pub(crate) const O_NOFOLLOW: c_int = 0x0000_0100;
+pub(crate) const O_CREAT: c_int = 0x0000_0200;
+pub(crate) const O_TRUNC: c_int = 0x0000_0400;
+pub(crate) const O_EXCL: c_int = 0x0000_0800;
+pub(crate) const O_EVTONLY: c_int = 0x0000_8000;
+pub(crate) const O_NOCTTY: c_int = 0x0002_0000;
+pub(crate) const O_DIRECTORY: c_int = 0x0010_0000;
+pub(crate) const O_SYMLINK: c_int = 0x0020_0000;
 pub(crate) const O_CLOEXEC: c_int = 0x0100_0000;
+pub(crate) const O_NOFOLLOW_ANY: c_int = 0x2000_0000;
+
+pub(crate) const AT_FDCWD: c_int = -2;
+pub(crate) const AT_EACCESS: c_int = 0x0010;
+pub(crate) const AT_SYMLINK_NOFOLLOW: c_int = 0x0020;
+pub(crate) const AT_SYMLINK_FOLLOW: c_int = 0x0040;
+pub(crate) const AT_REMOVEDIR: c_int = 0x0080;
+
+pub(crate) const F_DUPFD: c_int = 0;
+pub(crate) const F_GETFD: c_int = 1;
+pub(crate) const F_SETFD: c_int = 2;
+pub(crate) const F_GETFL: c_int = 3;
+pub(crate) const F_SETFL: c_int = 4;
+pub(crate) const F_GETLK: c_int = 7;
+pub(crate) const F_SETLK: c_int = 8;
+pub(crate) const F_SETLKW: c_int = 9;
+pub(crate) const F_FULLFSYNC: c_int = 51;
+pub(crate) const F_DUPFD_CLOEXEC: c_int = 67;
+pub(crate) const F_GETPATH: c_int = 50;
+
+pub(crate) const FD_CLOEXEC: c_int = 1;
 
 extern "C" {
+    pub(crate) fn fcntl(fildes: c_int, cmd: c_int, ...) -> c_int;
     pub(crate) fn open(path: *const c_char, oflag: c_int, ...) -> c_int;
+    pub(crate) fn openat(fd: c_int, path: *const c_char, oflag: c_int, ...) -> c_int;
 }
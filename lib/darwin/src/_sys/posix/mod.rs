@@ -1,2 +1,13 @@
+// LINT: The bindings mirror the header, not only what the idiomatic interface uses so far.
+#[allow(dead_code)]
+pub(crate) mod dirent;
+// LINT: The bindings mirror the header, not only what the idiomatic interface uses so far.
+#[allow(dead_code)]
 pub(crate) mod fcntl;
+// LINT: The bindings mirror the header, not only what the idiomatic interface uses so far.
+#[allow(dead_code)]
+pub(crate) mod pthread;
+// LINT: The bindings mirror the header, not only what the idiomatic interface uses so far.
+#[allow(dead_code)]
+pub(crate) mod signal;
 pub(crate) mod unistd;
@@ -0,0 +1,140 @@
+#![allow(non_camel_case_types)]
+
+use crate::_sys::sys::types::timespec;
+use core::ffi::{c_char, c_int, c_long, c_uint, c_void};
+
+pub(crate) const PTHREAD_CREATE_JOINABLE: c_int = 1;
+pub(crate) const PTHREAD_CREATE_DETACHED: c_int = 2;
+
+pub(crate) const PTHREAD_PRIO_NONE: c_int = 0;
+pub(crate) const PTHREAD_PRIO_INHERIT: c_int = 1;
+pub(crate) const PTHREAD_PRIO_PROTECT: c_int = 2;
+
+pub(crate) const __PTHREAD_ATTR_SIZE__: usize = 56;
+pub(crate) const __PTHREAD_COND_SIZE__: usize = 40;
+pub(crate) const __PTHREAD_CONDATTR_SIZE__: usize = 8;
+pub(crate) const __PTHREAD_MUTEX_SIZE__: usize = 56;
+pub(crate) const __PTHREAD_MUTEXATTR_SIZE__: usize = 8;
+pub(crate) const __PTHREAD_RWLOCK_SIZE__: usize = 192;
+pub(crate) const __PTHREAD_RWLOCKATTR_SIZE__: usize = 16;
+
+pub(crate) type pthread_t = *mut c_void;
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct pthread_attr_t {
+    pub(crate) __sig: c_long,
+    pub(crate) __opaque: [c_char; __PTHREAD_ATTR_SIZE__],
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct pthread_cond_t {
+    pub(crate) __sig: c_long,
+    pub(crate) __opaque: [c_char; __PTHREAD_COND_SIZE__],
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct pthread_condattr_t {
+    pub(crate) __sig: c_long,
+    pub(crate) __opaque: [c_char; __PTHREAD_CONDATTR_SIZE__],
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct pthread_mutex_t {
+    pub(crate) __sig: c_long,
+    pub(crate) __opaque: [c_char; __PTHREAD_MUTEX_SIZE__],
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct pthread_mutexattr_t {
+    pub(crate) __sig: c_long,
+    pub(crate) __opaque: [c_char; __PTHREAD_MUTEXATTR_SIZE__],
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct pthread_rwlock_t {
+    pub(crate) __sig: c_long,
+    pub(crate) __opaque: [c_char; __PTHREAD_RWLOCK_SIZE__],
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct pthread_rwlockattr_t {
+    pub(crate) __sig: c_long,
+    pub(crate) __opaque: [c_char; __PTHREAD_RWLOCKATTR_SIZE__],
+}
+
+extern "C" {
+    pub(crate) fn pthread_attr_destroy(attr: *mut pthread_attr_t) -> c_int;
+    pub(crate) fn pthread_attr_init(attr: *mut pthread_attr_t) -> c_int;
+    pub(crate) fn pthread_attr_set_qos_class_np(
+        attr: *mut pthread_attr_t,
+        qos_class: c_uint,
+        relative_priority: c_int,
+    ) -> c_int;
+    pub(crate) fn pthread_attr_setdetachstate(
+        attr: *mut pthread_attr_t,
+        detachstate: c_int,
+    ) -> c_int;
+    pub(crate) fn pthread_attr_setstacksize(attr: *mut pthread_attr_t, stacksize: usize) -> c_int;
+    pub(crate) fn pthread_cond_broadcast(cond: *mut pthread_cond_t) -> c_int;
+    pub(crate) fn pthread_cond_destroy(cond: *mut pthread_cond_t) -> c_int;
+    pub(crate) fn pthread_cond_init(
+        cond: *mut pthread_cond_t,
+        attr: *const pthread_condattr_t,
+    ) -> c_int;
+    pub(crate) fn pthread_cond_signal(cond: *mut pthread_cond_t) -> c_int;
+    pub(crate) fn pthread_cond_timedwait_relative_np(
+        cond: *mut pthread_cond_t,
+        mutex: *mut pthread_mutex_t,
+        reltime: *const timespec,
+    ) -> c_int;
+    pub(crate) fn pthread_cond_wait(
+        cond: *mut pthread_cond_t,
+        mutex: *mut pthread_mutex_t,
+    ) -> c_int;
+    pub(crate) fn pthread_create(
+        thread: *mut pthread_t,
+        attr: *const pthread_attr_t,
+        start_routine: extern "C" fn(*mut c_void) -> *mut c_void,
+        arg: *mut c_void,
+    ) -> c_int;
+    pub(crate) fn pthread_detach(thread: pthread_t) -> c_int;
+    pub(crate) fn pthread_getname_np(thread: pthread_t, name: *mut c_char, len: usize) -> c_int;
+    pub(crate) fn pthread_join(thread: pthread_t, value_ptr: *mut *mut c_void) -> c_int;
+    pub(crate) fn pthread_mutex_destroy(mutex: *mut pthread_mutex_t) -> c_int;
+    pub(crate) fn pthread_mutex_init(
+        mutex: *mut pthread_mutex_t,
+        attr: *const pthread_mutexattr_t,
+    ) -> c_int;
+    pub(crate) fn pthread_mutex_lock(mutex: *mut pthread_mutex_t) -> c_int;
+    pub(crate) fn pthread_mutex_trylock(mutex: *mut pthread_mutex_t) -> c_int;
+    pub(crate) fn pthread_mutex_unlock(mutex: *mut pthread_mutex_t) -> c_int;
+    pub(crate) fn pthread_mutexattr_destroy(attr: *mut pthread_mutexattr_t) -> c_int;
+    pub(crate) fn pthread_mutexattr_init(attr: *mut pthread_mutexattr_t) -> c_int;
+    pub(crate) fn pthread_mutexattr_setprotocol(
+        attr: *mut pthread_mutexattr_t,
+        protocol: c_int,
+    ) -> c_int;
+    pub(crate) fn pthread_rwlock_destroy(rwlock: *mut pthread_rwlock_t) -> c_int;
+    pub(crate) fn pthread_rwlock_init(
+        rwlock: *mut pthread_rwlock_t,
+        attr: *const pthread_rwlockattr_t,
+    ) -> c_int;
+    pub(crate) fn pthread_rwlock_rdlock(rwlock: *mut pthread_rwlock_t) -> c_int;
+    pub(crate) fn pthread_rwlock_tryrdlock(rwlock: *mut pthread_rwlock_t) -> c_int;
+    pub(crate) fn pthread_rwlock_trywrlock(rwlock: *mut pthread_rwlock_t) -> c_int;
+    pub(crate) fn pthread_rwlock_unlock(rwlock: *mut pthread_rwlock_t) -> c_int;
+    pub(crate) fn pthread_rwlock_wrlock(rwlock: *mut pthread_rwlock_t) -> c_int;
+    pub(crate) fn pthread_self() -> pthread_t;
+    pub(crate) fn pthread_set_qos_class_self_np(
+        qos_class: c_uint,
+        relative_priority: c_int,
+    ) -> c_int;
+    pub(crate) fn pthread_setname_np(name: *const c_char) -> c_int;
+}
@@ -0,0 +1,98 @@
+#![allow(non_camel_case_types)]
+
+use crate::_sys::sys::types::{pid_t, uid_t};
+use core::ffi::{c_int, c_long, c_ulong, c_void};
+
+pub(crate) const SIGHUP: c_int = 1;
+pub(crate) const SIGINT: c_int = 2;
+pub(crate) const SIGQUIT: c_int = 3;
+pub(crate) const SIGILL: c_int = 4;
+pub(crate) const SIGTRAP: c_int = 5;
+pub(crate) const SIGABRT: c_int = 6;
+pub(crate) const SIGEMT: c_int = 7;
+pub(crate) const SIGFPE: c_int = 8;
+pub(crate) const SIGKILL: c_int = 9;
+pub(crate) const SIGBUS: c_int = 10;
+pub(crate) const SIGSEGV: c_int = 11;
+pub(crate) const SIGSYS: c_int = 12;
+pub(crate) const SIGPIPE: c_int = 13;
+pub(crate) const SIGALRM: c_int = 14;
+pub(crate) const SIGTERM: c_int = 15;
+pub(crate) const SIGURG: c_int = 16;
+pub(crate) const SIGSTOP: c_int = 17;
+pub(crate) const SIGTSTP: c_int = 18;
+pub(crate) const SIGCONT: c_int = 19;
+pub(crate) const SIGCHLD: c_int = 20;
+pub(crate) const SIGTTIN: c_int = 21;
+pub(crate) const SIGTTOU: c_int = 22;
+pub(crate) const SIGIO: c_int = 23;
+pub(crate) const SIGXCPU: c_int = 24;
+pub(crate) const SIGXFSZ: c_int = 25;
+pub(crate) const SIGVTALRM: c_int = 26;
+pub(crate) const SIGPROF: c_int = 27;
+pub(crate) const SIGWINCH: c_int = 28;
+pub(crate) const SIGINFO: c_int = 29;
+pub(crate) const SIGUSR1: c_int = 30;
+pub(crate) const SIGUSR2: c_int = 31;
+
+pub(crate) const SA_ONSTACK: c_int = 0x0001;
+pub(crate) const SA_RESTART: c_int = 0x0002;
+pub(crate) const SA_RESETHAND: c_int = 0x0004;
+pub(crate) const SA_NOCLDSTOP: c_int = 0x0008;
+pub(crate) const SA_NODEFER: c_int = 0x0010;
+pub(crate) const SA_NOCLDWAIT: c_int = 0x0020;
+pub(crate) const SA_SIGINFO: c_int = 0x0040;
+
+pub(crate) const SIG_BLOCK: c_int = 1;
+pub(crate) const SIG_UNBLOCK: c_int = 2;
+pub(crate) const SIG_SETMASK: c_int = 3;
+
+/// The address of a signal handler, or one of [`SIG_DFL`], [`SIG_IGN`], or [`SIG_ERR`].
+///
+/// `struct sigaction` stores the handler in a union of `void (*)(int)` and
+/// `void (*)(int, siginfo_t *, void *)`; which member is valid depends on [`SA_SIGINFO`].
+pub(crate) type sighandler_t = usize;
+
+pub(crate) const SIG_DFL: sighandler_t = 0;
+pub(crate) const SIG_IGN: sighandler_t = 1;
+pub(crate) const SIG_ERR: sighandler_t = usize::MAX;
+
+pub(crate) type sigset_t = u32;
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct sigaction {
+    pub(crate) handler: sighandler_t,
+    pub(crate) mask: sigset_t,
+    pub(crate) flags: c_int,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct siginfo_t {
+    pub(crate) signo: c_int,
+    pub(crate) errno: c_int,
+    pub(crate) code: c_int,
+    pub(crate) pid: pid_t,
+    pub(crate) uid: uid_t,
+    pub(crate) status: c_int,
+    pub(crate) addr: *mut c_void,
+    /// `union sigval`, which is the size of a pointer.
+    pub(crate) value: *mut c_void,
+    pub(crate) band: c_long,
+    pub(crate) pad: [c_ulong; 7],
+}
+
+extern "C" {
+    pub(crate) fn kill(pid: pid_t, sig: c_int) -> c_int;
+    pub(crate) fn pthread_sigmask(how: c_int, set: *const sigset_t, oset: *mut sigset_t) -> c_int;
+    pub(crate) fn raise(sig: c_int) -> c_int;
+    pub(crate) fn sigaction(sig: c_int, act: *const sigaction, oact: *mut sigaction) -> c_int;
+    pub(crate) fn sigaddset(set: *mut sigset_t, signo: c_int) -> c_int;
+    pub(crate) fn sigdelset(set: *mut sigset_t, signo: c_int) -> c_int;
+    pub(crate) fn sigemptyset(set: *mut sigset_t) -> c_int;
+    pub(crate) fn sigfillset(set: *mut sigset_t) -> c_int;
+    pub(crate) fn sigismember(set: *const sigset_t, signo: c_int) -> c_int;
+    pub(crate) fn sigpending(set: *mut sigset_t) -> c_int;
+    pub(crate) fn sigprocmask(how: c_int, set: *const sigset_t, oset: *mut sigset_t) -> c_int;
+}
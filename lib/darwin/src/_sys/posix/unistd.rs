@@ -6,6 +6,7 @@ extern "C" {
     pub(crate) fn close(fildes: c_int) -> c_int;
     pub(crate) fn rmdir(path: *const c_char) -> c_int;
     pub(crate) fn unlink(path: *const c_char) -> c_int;
+    pub(crate) fn gethostname(name: *mut c_char, namelen: usize) -> c_int;
     pub(crate) fn confstr(name: c_int, buf: *mut c_char, len: usize) -> usize;
     pub(crate) fn mkdtemp(template: *mut c_char) -> *mut c_char;
     pub(crate) fn mkstemp(template: *mut c_char) -> c_int;
@@ -6,6 +6,18 @@ pub(crate) const CLONE_NOOWNERCOPY: u32 = 0x0002;
 pub(crate) const CLONE_ACL: u32 = 0x0004;
 
 extern "C" {
+    pub(crate) fn clonefile(src: *const c_char, dst: *const c_char, flags: u32) -> c_int;
+
+    // LINT: Bound alongside `clonefile` and `fclonefileat`, which cover the idiomatic interface.
+    #[allow(dead_code)]
+    pub(crate) fn clonefileat(
+        src_dirfd: BorrowedFd<'_>,
+        src: *const c_char,
+        dst_dirfd: BorrowedFd<'_>,
+        dst: *const c_char,
+        flags: u32,
+    ) -> c_int;
+
     pub(crate) fn fclonefileat(
         srcfd: BorrowedFd<'_>,
         dst_dirfd: BorrowedFd<'_>,
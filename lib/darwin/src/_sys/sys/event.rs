@@ -0,0 +1,54 @@
+#![allow(non_camel_case_types)]
+
+use crate::_sys::sys::types::timespec;
+use crate::io::BorrowedFd;
+use core::ffi::{c_int, c_void};
+
+pub(crate) const EVFILT_READ: i16 = -1;
+pub(crate) const EVFILT_WRITE: i16 = -2;
+pub(crate) const EVFILT_VNODE: i16 = -4;
+pub(crate) const EVFILT_PROC: i16 = -5;
+pub(crate) const EVFILT_SIGNAL: i16 = -6;
+pub(crate) const EVFILT_TIMER: i16 = -7;
+
+pub(crate) const EV_ADD: u16 = 0x0001;
+pub(crate) const EV_DELETE: u16 = 0x0002;
+pub(crate) const EV_ENABLE: u16 = 0x0004;
+pub(crate) const EV_DISABLE: u16 = 0x0008;
+pub(crate) const EV_ONESHOT: u16 = 0x0010;
+pub(crate) const EV_CLEAR: u16 = 0x0020;
+pub(crate) const EV_RECEIPT: u16 = 0x0040;
+pub(crate) const EV_EOF: u16 = 0x8000;
+pub(crate) const EV_ERROR: u16 = 0x4000;
+
+pub(crate) const NOTE_DELETE: u32 = 0x0000_0001;
+pub(crate) const NOTE_WRITE: u32 = 0x0000_0002;
+pub(crate) const NOTE_EXTEND: u32 = 0x0000_0004;
+pub(crate) const NOTE_ATTRIB: u32 = 0x0000_0008;
+pub(crate) const NOTE_LINK: u32 = 0x0000_0010;
+pub(crate) const NOTE_RENAME: u32 = 0x0000_0020;
+pub(crate) const NOTE_REVOKE: u32 = 0x0000_0040;
+pub(crate) const NOTE_FUNLOCK: u32 = 0x0000_0100;
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct kevent {
+    pub(crate) ident: usize,
+    pub(crate) filter: i16,
+    pub(crate) flags: u16,
+    pub(crate) fflags: u32,
+    pub(crate) data: isize,
+    pub(crate) udata: *mut c_void,
+}
+
+extern "C" {
+    pub(crate) fn kqueue() -> c_int;
+    pub(crate) fn kevent(
+        kq: BorrowedFd<'_>,
+        changelist: *const kevent,
+        nchanges: c_int,
+        eventlist: *mut kevent,
+        nevents: c_int,
+        timeout: *const timespec,
+    ) -> c_int;
+}
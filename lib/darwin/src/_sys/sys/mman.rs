@@ -0,0 +1,48 @@
+use crate::_sys::sys::types::off_t;
+use core::ffi::{c_int, c_void};
+
+pub(crate) const PROT_NONE: c_int = 0x00;
+pub(crate) const PROT_READ: c_int = 0x01;
+pub(crate) const PROT_WRITE: c_int = 0x02;
+pub(crate) const PROT_EXEC: c_int = 0x04;
+
+pub(crate) const MAP_SHARED: c_int = 0x0001;
+pub(crate) const MAP_PRIVATE: c_int = 0x0002;
+pub(crate) const MAP_FIXED: c_int = 0x0010;
+pub(crate) const MAP_NORESERVE: c_int = 0x0040;
+pub(crate) const MAP_NOCACHE: c_int = 0x0400;
+pub(crate) const MAP_JIT: c_int = 0x0800;
+pub(crate) const MAP_FILE: c_int = 0x0000;
+pub(crate) const MAP_ANON: c_int = 0x1000;
+
+// LINT: `MAP_FAILED` is defined as `((void *)-1)`.
+#[allow(clippy::as_conversions)]
+pub(crate) const MAP_FAILED: *mut c_void = usize::MAX as *mut c_void;
+
+pub(crate) const MS_ASYNC: c_int = 0x0001;
+pub(crate) const MS_INVALIDATE: c_int = 0x0002;
+pub(crate) const MS_SYNC: c_int = 0x0010;
+
+pub(crate) const MADV_NORMAL: c_int = 0;
+pub(crate) const MADV_RANDOM: c_int = 1;
+pub(crate) const MADV_SEQUENTIAL: c_int = 2;
+pub(crate) const MADV_WILLNEED: c_int = 3;
+pub(crate) const MADV_DONTNEED: c_int = 4;
+pub(crate) const MADV_FREE: c_int = 5;
+
+extern "C" {
+    pub(crate) fn madvise(addr: *mut c_void, len: usize, advice: c_int) -> c_int;
+    pub(crate) fn mlock(addr: *const c_void, len: usize) -> c_int;
+    pub(crate) fn mmap(
+        addr: *mut c_void,
+        len: usize,
+        prot: c_int,
+        flags: c_int,
+        fd: c_int,
+        offset: off_t,
+    ) -> *mut c_void;
+    pub(crate) fn mprotect(addr: *mut c_void, len: usize, prot: c_int) -> c_int;
+    pub(crate) fn msync(addr: *mut c_void, len: usize, flags: c_int) -> c_int;
+    pub(crate) fn munlock(addr: *const c_void, len: usize) -> c_int;
+    pub(crate) fn munmap(addr: *mut c_void, len: usize) -> c_int;
+}
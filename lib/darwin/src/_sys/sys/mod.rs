@@ -1,4 +1,18 @@
 pub(crate) mod clonefile;
+// LINT: The bindings mirror the header, not only what the idiomatic interface uses so far.
+#[allow(dead_code)]
+pub(crate) mod event;
+// LINT: The bindings mirror the header, not only what the idiomatic interface uses so far.
+#[allow(dead_code)]
+pub(crate) mod mman;
 pub(crate) mod qos;
+pub(crate) mod resource;
+// LINT: The bindings mirror the header, not only what the idiomatic interface uses so far.
+#[allow(dead_code)]
+pub(crate) mod socket;
+// LINT: The bindings mirror the header, not only what the idiomatic interface uses so far.
+#[allow(dead_code)]
 pub(crate) mod stat;
+pub(crate) mod time;
 pub(crate) mod types;
+pub(crate) mod utsname;
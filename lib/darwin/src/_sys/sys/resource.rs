@@ -0,0 +1,66 @@
+#![allow(non_camel_case_types)]
+
+use core::ffi::{c_int, c_long};
+
+pub(crate) type rlim_t = u64;
+pub(crate) type suseconds_t = i32;
+pub(crate) type time_t = c_long;
+
+pub(crate) const RLIM_INFINITY: rlim_t = (1 << 63) - 1;
+
+pub(crate) const RLIMIT_CPU: c_int = 0;
+pub(crate) const RLIMIT_FSIZE: c_int = 1;
+pub(crate) const RLIMIT_DATA: c_int = 2;
+pub(crate) const RLIMIT_STACK: c_int = 3;
+pub(crate) const RLIMIT_CORE: c_int = 4;
+pub(crate) const RLIMIT_AS: c_int = 5;
+// LINT: The alias mirrors the header; the idiomatic interface uses the canonical name.
+#[allow(dead_code)]
+pub(crate) const RLIMIT_RSS: c_int = RLIMIT_AS;
+pub(crate) const RLIMIT_MEMLOCK: c_int = 6;
+pub(crate) const RLIMIT_NPROC: c_int = 7;
+pub(crate) const RLIMIT_NOFILE: c_int = 8;
+
+pub(crate) const RUSAGE_SELF: c_int = 0;
+pub(crate) const RUSAGE_CHILDREN: c_int = -1;
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct rlimit {
+    pub(crate) rlim_cur: rlim_t,
+    pub(crate) rlim_max: rlim_t,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct timeval {
+    pub(crate) tv_sec: time_t,
+    pub(crate) tv_usec: suseconds_t,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct rusage {
+    pub(crate) ru_utime: timeval,
+    pub(crate) ru_stime: timeval,
+    pub(crate) ru_maxrss: c_long,
+    pub(crate) ru_ixrss: c_long,
+    pub(crate) ru_idrss: c_long,
+    pub(crate) ru_isrss: c_long,
+    pub(crate) ru_minflt: c_long,
+    pub(crate) ru_majflt: c_long,
+    pub(crate) ru_nswap: c_long,
+    pub(crate) ru_inblock: c_long,
+    pub(crate) ru_oublock: c_long,
+    pub(crate) ru_msgsnd: c_long,
+    pub(crate) ru_msgrcv: c_long,
+    pub(crate) ru_nsignals: c_long,
+    pub(crate) ru_nvcsw: c_long,
+    pub(crate) ru_nivcsw: c_long,
+}
+
+extern "C" {
+    pub(crate) fn getrlimit(resource: c_int, rlp: &mut rlimit) -> c_int;
+    pub(crate) fn getrusage(who: c_int, r_usage: &mut rusage) -> c_int;
+    pub(crate) fn setrlimit(resource: c_int, rlp: &rlimit) -> c_int;
+}
@@ -0,0 +1,158 @@
+#![allow(non_camel_case_types)]
+
+use core::ffi::{c_char, c_int, c_void};
+
+pub(crate) type sa_family_t = u8;
+pub(crate) type socklen_t = u32;
+
+pub(crate) const AF_UNSPEC: c_int = 0;
+pub(crate) const AF_UNIX: c_int = 1;
+pub(crate) const AF_INET: c_int = 2;
+pub(crate) const AF_INET6: c_int = 30;
+
+pub(crate) const SOCK_STREAM: c_int = 1;
+pub(crate) const SOCK_DGRAM: c_int = 2;
+pub(crate) const SOCK_RAW: c_int = 3;
+pub(crate) const SOCK_SEQPACKET: c_int = 5;
+
+pub(crate) const SOL_SOCKET: c_int = 0xffff;
+
+pub(crate) const SO_DEBUG: c_int = 0x0001;
+pub(crate) const SO_ACCEPTCONN: c_int = 0x0002;
+pub(crate) const SO_REUSEADDR: c_int = 0x0004;
+pub(crate) const SO_KEEPALIVE: c_int = 0x0008;
+pub(crate) const SO_DONTROUTE: c_int = 0x0010;
+pub(crate) const SO_BROADCAST: c_int = 0x0020;
+pub(crate) const SO_LINGER: c_int = 0x0080;
+pub(crate) const SO_OOBINLINE: c_int = 0x0100;
+pub(crate) const SO_REUSEPORT: c_int = 0x0200;
+pub(crate) const SO_SNDBUF: c_int = 0x1001;
+pub(crate) const SO_RCVBUF: c_int = 0x1002;
+pub(crate) const SO_SNDLOWAT: c_int = 0x1003;
+pub(crate) const SO_RCVLOWAT: c_int = 0x1004;
+pub(crate) const SO_SNDTIMEO: c_int = 0x1005;
+pub(crate) const SO_RCVTIMEO: c_int = 0x1006;
+pub(crate) const SO_ERROR: c_int = 0x1007;
+pub(crate) const SO_TYPE: c_int = 0x1008;
+pub(crate) const SO_NOSIGPIPE: c_int = 0x1022;
+
+pub(crate) const MSG_OOB: c_int = 0x0001;
+pub(crate) const MSG_PEEK: c_int = 0x0002;
+pub(crate) const MSG_DONTROUTE: c_int = 0x0004;
+pub(crate) const MSG_EOR: c_int = 0x0008;
+pub(crate) const MSG_TRUNC: c_int = 0x0010;
+pub(crate) const MSG_CTRUNC: c_int = 0x0020;
+pub(crate) const MSG_WAITALL: c_int = 0x0040;
+pub(crate) const MSG_DONTWAIT: c_int = 0x0080;
+
+pub(crate) const SHUT_RD: c_int = 0;
+pub(crate) const SHUT_WR: c_int = 1;
+pub(crate) const SHUT_RDWR: c_int = 2;
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct sockaddr {
+    pub(crate) len: u8,
+    pub(crate) family: sa_family_t,
+    pub(crate) data: [c_char; 14],
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct sockaddr_storage {
+    pub(crate) len: u8,
+    pub(crate) family: sa_family_t,
+    pub(crate) pad1: [c_char; 6],
+    pub(crate) align: i64,
+    pub(crate) pad2: [c_char; 112],
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct linger {
+    pub(crate) onoff: c_int,
+    pub(crate) linger: c_int,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct iovec {
+    pub(crate) base: *mut c_void,
+    pub(crate) len: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct msghdr {
+    pub(crate) name: *mut c_void,
+    pub(crate) namelen: socklen_t,
+    pub(crate) iov: *mut iovec,
+    pub(crate) iovlen: c_int,
+    pub(crate) control: *mut c_void,
+    pub(crate) controllen: socklen_t,
+    pub(crate) flags: c_int,
+}
+
+extern "C" {
+    pub(crate) fn accept(
+        socket: c_int,
+        address: *mut sockaddr,
+        address_len: *mut socklen_t,
+    ) -> c_int;
+    pub(crate) fn bind(socket: c_int, address: *const sockaddr, address_len: socklen_t) -> c_int;
+    pub(crate) fn connect(socket: c_int, address: *const sockaddr, address_len: socklen_t)
+        -> c_int;
+    pub(crate) fn getpeername(
+        socket: c_int,
+        address: *mut sockaddr,
+        address_len: *mut socklen_t,
+    ) -> c_int;
+    pub(crate) fn getsockname(
+        socket: c_int,
+        address: *mut sockaddr,
+        address_len: *mut socklen_t,
+    ) -> c_int;
+    pub(crate) fn getsockopt(
+        socket: c_int,
+        level: c_int,
+        option_name: c_int,
+        option_value: *mut c_void,
+        option_len: *mut socklen_t,
+    ) -> c_int;
+    pub(crate) fn listen(socket: c_int, backlog: c_int) -> c_int;
+    pub(crate) fn recv(socket: c_int, buffer: *mut c_void, length: usize, flags: c_int) -> isize;
+    pub(crate) fn recvfrom(
+        socket: c_int,
+        buffer: *mut c_void,
+        length: usize,
+        flags: c_int,
+        address: *mut sockaddr,
+        address_len: *mut socklen_t,
+    ) -> isize;
+    pub(crate) fn recvmsg(socket: c_int, message: *mut msghdr, flags: c_int) -> isize;
+    pub(crate) fn send(socket: c_int, buffer: *const c_void, length: usize, flags: c_int) -> isize;
+    pub(crate) fn sendmsg(socket: c_int, message: *const msghdr, flags: c_int) -> isize;
+    pub(crate) fn sendto(
+        socket: c_int,
+        buffer: *const c_void,
+        length: usize,
+        flags: c_int,
+        dest_addr: *const sockaddr,
+        dest_len: socklen_t,
+    ) -> isize;
+    pub(crate) fn setsockopt(
+        socket: c_int,
+        level: c_int,
+        option_name: c_int,
+        option_value: *const c_void,
+        option_len: socklen_t,
+    ) -> c_int;
+    pub(crate) fn shutdown(socket: c_int, how: c_int) -> c_int;
+    pub(crate) fn socket(domain: c_int, r#type: c_int, protocol: c_int) -> c_int;
+    pub(crate) fn socketpair(
+        domain: c_int,
+        r#type: c_int,
+        protocol: c_int,
+        socket_vector: *mut c_int,
+    ) -> c_int;
+}
@@ -3,7 +3,7 @@ use crate::_sys::sys::types::{
     S_IRUSR, S_IRWXG, S_IRWXO, S_IRWXU, S_ISGID, S_ISUID, S_ISVTX, S_IWGRP, S_IWOTH, S_IWUSR,
 };
 use crate::io::BorrowedFd;
-use core::ffi::c_int;
+use core::ffi::{c_char, c_int};
 
 #[derive(Clone, Copy, Debug)]
 #[repr(C)]
@@ -31,6 +31,62 @@ pub(crate) struct stat {
 pub(crate) const ALLPERMS: mode_t = S_ISUID | S_ISGID | S_ISVTX | S_IRWXU | S_IRWXG | S_IRWXO;
 pub(crate) const DEFFILEMODE: mode_t = S_IRUSR | S_IWUSR | S_IRGRP | S_IWGRP | S_IROTH | S_IWOTH;
 
+pub(crate) const UF_SETTABLE: u32 = 0x0000_ffff;
+pub(crate) const UF_NODUMP: u32 = 0x0000_0001;
+pub(crate) const UF_IMMUTABLE: u32 = 0x0000_0002;
+pub(crate) const UF_APPEND: u32 = 0x0000_0004;
+pub(crate) const UF_OPAQUE: u32 = 0x0000_0008;
+pub(crate) const UF_COMPRESSED: u32 = 0x0000_0020;
+pub(crate) const UF_TRACKED: u32 = 0x0000_0040;
+pub(crate) const UF_DATAVAULT: u32 = 0x0000_0080;
+pub(crate) const UF_HIDDEN: u32 = 0x0000_8000;
+
+pub(crate) const SF_SUPPORTED: u32 = 0x009f_0000;
+pub(crate) const SF_SETTABLE: u32 = 0x3fff_0000;
+pub(crate) const SF_SYNTHETIC: u32 = 0xc000_0000;
+pub(crate) const SF_ARCHIVED: u32 = 0x0001_0000;
+pub(crate) const SF_IMMUTABLE: u32 = 0x0002_0000;
+pub(crate) const SF_APPEND: u32 = 0x0004_0000;
+pub(crate) const SF_RESTRICTED: u32 = 0x0008_0000;
+pub(crate) const SF_NOUNLINK: u32 = 0x0010_0000;
+pub(crate) const SF_FIRMLINK: u32 = 0x0080_0000;
+pub(crate) const SF_DATALESS: u32 = 0x4000_0000;
+
+// The x86_64 macOS ABI predates 64-bit inodes, so the 64-bit inode variants of the functions that
+// fill a `stat` are exported under a `$INODE64` suffix.
 extern "C" {
+    pub(crate) fn chflags(path: *const c_char, flags: u32) -> c_int;
+    pub(crate) fn chmod(path: *const c_char, mode: mode_t) -> c_int;
+    pub(crate) fn fchflags(fd: BorrowedFd<'_>, flags: u32) -> c_int;
+    pub(crate) fn fchmod(fildes: BorrowedFd<'_>, mode: mode_t) -> c_int;
+    pub(crate) fn fchmodat(fd: c_int, path: *const c_char, mode: mode_t, flag: c_int) -> c_int;
+    #[cfg_attr(
+        all(target_os = "macos", target_arch = "x86_64"),
+        link_name = "fstat$INODE64"
+    )]
     pub(crate) fn fstat(fildes: BorrowedFd<'_>, buf: &mut stat) -> c_int;
+    #[cfg_attr(
+        all(target_os = "macos", target_arch = "x86_64"),
+        link_name = "fstatat$INODE64"
+    )]
+    pub(crate) fn fstatat(
+        fd: BorrowedFd<'_>,
+        path: *const c_char,
+        buf: &mut stat,
+        flag: c_int,
+    ) -> c_int;
+    #[cfg_attr(
+        all(target_os = "macos", target_arch = "x86_64"),
+        link_name = "lstat$INODE64"
+    )]
+    pub(crate) fn lstat(path: *const c_char, buf: &mut stat) -> c_int;
+    pub(crate) fn mkdir(path: *const c_char, mode: mode_t) -> c_int;
+    pub(crate) fn mkdirat(fd: c_int, path: *const c_char, mode: mode_t) -> c_int;
+    pub(crate) fn mkfifo(path: *const c_char, mode: mode_t) -> c_int;
+    #[cfg_attr(
+        all(target_os = "macos", target_arch = "x86_64"),
+        link_name = "stat$INODE64"
+    )]
+    pub(crate) fn stat(path: *const c_char, buf: &mut stat) -> c_int;
+    pub(crate) fn umask(cmask: mode_t) -> mode_t;
 }
@@ -0,0 +1,6 @@
+use crate::_sys::sys::resource::timeval;
+use core::ffi::{c_int, c_void};
+
+extern "C" {
+    pub(crate) fn gettimeofday(tp: *mut timeval, tzp: *mut c_void) -> c_int;
+}
@@ -7,6 +7,9 @@ pub(crate) type gid_t = u32;
 pub(crate) type mode_t = u16;
 pub(crate) type nlink_t = u16;
 pub(crate) type off_t = i64;
+// LINT: The idiomatic interface does not yet take or return process IDs.
+#[allow(dead_code)]
+pub(crate) type pid_t = i32;
 
 pub(crate) const S_IFMT: mode_t = 0o170_000;
 pub(crate) const S_IFIFO: mode_t = 0o010_000;
@@ -0,0 +1,17 @@
+use core::ffi::{c_char, c_int};
+
+pub(crate) const _SYS_NAMELEN: usize = 256;
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub(crate) struct utsname {
+    pub(crate) sysname: [c_char; _SYS_NAMELEN],
+    pub(crate) nodename: [c_char; _SYS_NAMELEN],
+    pub(crate) release: [c_char; _SYS_NAMELEN],
+    pub(crate) version: [c_char; _SYS_NAMELEN],
+    pub(crate) machine: [c_char; _SYS_NAMELEN],
+}
+
+extern "C" {
+    pub(crate) fn uname(name: *mut utsname) -> c_int;
+}
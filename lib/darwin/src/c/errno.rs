@@ -1,80 +1,526 @@
 use crate::_sys::c::errno::{self, __error};
+use crate::_sys::c::string::strerror_r;
+use core::ffi::CStr;
+use core::fmt::{self, Display, Formatter};
 use core::num::NonZeroI32;
 
-#[derive(Clone, Copy, Debug)]
-#[repr(i32)]
+/// An error number reported by a failed system call or library function.
+///
+/// Every error number defined by Darwin has a corresponding variant. An error number that is not
+/// known to this crate is preserved by [`Error::Other`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
-    NotPermitted = errno::EPERM,
-    NotFound = errno::ENOENT,
-    Interrupted = errno::EINTR,
-    IO = errno::EIO,
-    NoDevice = errno::ENXIO,
-    BadFileDescriptor = errno::EBADF,
-    Deadlock = errno::EDEADLK,
-    OutOfMemory = errno::ENOMEM,
-    NoAccess = errno::EACCES,
-    BadAddress = errno::EFAULT,
-    ResourceBusy = errno::EBUSY,
-    AlreadyExists = errno::EEXIST,
-    CrossesDevices = errno::EXDEV,
-    NotADirectory = errno::ENOTDIR,
-    IsADirectory = errno::EISDIR,
-    InvalidArgument = errno::EINVAL,
-    SystemFileLimit = errno::ENFILE,
-    ProcessFileLimit = errno::EMFILE,
-    ExecutableFileBusy = errno::ETXTBSY,
-    StorageFull = errno::ENOSPC,
-    ReadOnlyFilesystem = errno::EROFS,
-    WouldBlock = errno::EAGAIN,
-    FilesystemLoop = errno::ELOOP,
-    InvalidFilename = errno::ENAMETOOLONG,
-    DirectoryNotEmpty = errno::ENOTEMPTY,
-    FilesystemQuotaExceeded = errno::EDQUOT,
-    Overflow = errno::EOVERFLOW,
-    IllegalByteSequence = errno::EILSEQ,
-    NotSupported = errno::EOPNOTSUPP,
+    /// Operation not permitted (`EPERM`).
+    NotPermitted,
+    /// No such file or directory (`ENOENT`).
+    NotFound,
+    /// No such process (`ESRCH`).
+    NoSuchProcess,
+    /// Interrupted system call (`EINTR`).
+    Interrupted,
+    /// Input/output error (`EIO`).
+    IO,
+    /// Device not configured (`ENXIO`).
+    NoDevice,
+    /// Argument list too long (`E2BIG`).
+    ArgumentListTooLong,
+    /// Exec format error (`ENOEXEC`).
+    ExecutableFormat,
+    /// Bad file descriptor (`EBADF`).
+    BadFileDescriptor,
+    /// No child processes (`ECHILD`).
+    NoChildProcesses,
+    /// Resource deadlock avoided (`EDEADLK`).
+    Deadlock,
+    /// Cannot allocate memory (`ENOMEM`).
+    OutOfMemory,
+    /// Permission denied (`EACCES`).
+    NoAccess,
+    /// Bad address (`EFAULT`).
+    BadAddress,
+    /// Block device required (`ENOTBLK`).
+    NotABlockDevice,
+    /// Device / Resource busy (`EBUSY`).
+    ResourceBusy,
+    /// File exists (`EEXIST`).
+    AlreadyExists,
+    /// Cross-device link (`EXDEV`).
+    CrossesDevices,
+    /// Operation not supported by device (`ENODEV`).
+    OperationNotSupportedByDevice,
+    /// Not a directory (`ENOTDIR`).
+    NotADirectory,
+    /// Is a directory (`EISDIR`).
+    IsADirectory,
+    /// Invalid argument (`EINVAL`).
+    InvalidArgument,
+    /// Too many open files in system (`ENFILE`).
+    SystemFileLimit,
+    /// Too many open files (`EMFILE`).
+    ProcessFileLimit,
+    /// Inappropriate ioctl for device (`ENOTTY`).
+    InappropriateIoctl,
+    /// Text file busy (`ETXTBSY`).
+    ExecutableFileBusy,
+    /// File too large (`EFBIG`).
+    FileTooLarge,
+    /// No space left on device (`ENOSPC`).
+    StorageFull,
+    /// Illegal seek (`ESPIPE`).
+    IllegalSeek,
+    /// Read-only file system (`EROFS`).
+    ReadOnlyFilesystem,
+    /// Too many links (`EMLINK`).
+    TooManyLinks,
+    /// Broken pipe (`EPIPE`).
+    BrokenPipe,
+    /// Numerical argument out of domain (`EDOM`).
+    DomainError,
+    /// Result too large (`ERANGE`).
+    ResultOutOfRange,
+    /// Resource temporarily unavailable (`EAGAIN`).
+    WouldBlock,
+    /// Operation now in progress (`EINPROGRESS`).
+    InProgress,
+    /// Operation already in progress (`EALREADY`).
+    AlreadyInProgress,
+    /// Socket operation on non-socket (`ENOTSOCK`).
+    NotASocket,
+    /// Destination address required (`EDESTADDRREQ`).
+    DestinationAddressRequired,
+    /// Message too long (`EMSGSIZE`).
+    MessageTooLong,
+    /// Protocol wrong type for socket (`EPROTOTYPE`).
+    WrongProtocolType,
+    /// Protocol not available (`ENOPROTOOPT`).
+    ProtocolOptionNotAvailable,
+    /// Protocol not supported (`EPROTONOSUPPORT`).
+    ProtocolNotSupported,
+    /// Socket type not supported (`ESOCKTNOSUPPORT`).
+    SocketTypeNotSupported,
+    /// Operation not supported (`ENOTSUP`).
+    Unsupported,
+    /// Protocol family not supported (`EPFNOSUPPORT`).
+    ProtocolFamilyNotSupported,
+    /// Address family not supported by protocol family (`EAFNOSUPPORT`).
+    AddressFamilyNotSupported,
+    /// Address already in use (`EADDRINUSE`).
+    AddressInUse,
+    /// Can't assign requested address (`EADDRNOTAVAIL`).
+    AddressNotAvailable,
+    /// Network is down (`ENETDOWN`).
+    NetworkDown,
+    /// Network is unreachable (`ENETUNREACH`).
+    NetworkUnreachable,
+    /// Network dropped connection on reset (`ENETRESET`).
+    NetworkReset,
+    /// Software caused connection abort (`ECONNABORTED`).
+    ConnectionAborted,
+    /// Connection reset by peer (`ECONNRESET`).
+    ConnectionReset,
+    /// No buffer space available (`ENOBUFS`).
+    NoBufferSpace,
+    /// Socket is already connected (`EISCONN`).
+    AlreadyConnected,
+    /// Socket is not connected (`ENOTCONN`).
+    NotConnected,
+    /// Can't send after socket shutdown (`ESHUTDOWN`).
+    SocketShutdown,
+    /// Too many references: can't splice (`ETOOMANYREFS`).
+    TooManyReferences,
+    /// Operation timed out (`ETIMEDOUT`).
+    TimedOut,
+    /// Connection refused (`ECONNREFUSED`).
+    ConnectionRefused,
+    /// Too many levels of symbolic links (`ELOOP`).
+    FilesystemLoop,
+    /// File name too long (`ENAMETOOLONG`).
+    InvalidFilename,
+    /// Host is down (`EHOSTDOWN`).
+    HostDown,
+    /// No route to host (`EHOSTUNREACH`).
+    HostUnreachable,
+    /// Directory not empty (`ENOTEMPTY`).
+    DirectoryNotEmpty,
+    /// Too many processes (`EPROCLIM`).
+    ProcessLimit,
+    /// Too many users (`EUSERS`).
+    UserLimit,
+    /// Disc quota exceeded (`EDQUOT`).
+    FilesystemQuotaExceeded,
+    /// Stale NFS file handle (`ESTALE`).
+    StaleNetworkFileHandle,
+    /// Too many levels of remote in path (`EREMOTE`).
+    RemotePath,
+    /// RPC struct is bad (`EBADRPC`).
+    BadRpcStruct,
+    /// RPC version wrong (`ERPCMISMATCH`).
+    RpcVersionMismatch,
+    /// RPC prog. not avail (`EPROGUNAVAIL`).
+    RpcProgramUnavailable,
+    /// Program version wrong (`EPROGMISMATCH`).
+    RpcProgramVersionMismatch,
+    /// Bad procedure for program (`EPROCUNAVAIL`).
+    RpcProcedureUnavailable,
+    /// No locks available (`ENOLCK`).
+    NoLocks,
+    /// Function not implemented (`ENOSYS`).
+    FunctionNotImplemented,
+    /// Inappropriate file type or format (`EFTYPE`).
+    InappropriateFileType,
+    /// Authentication error (`EAUTH`).
+    AuthenticationError,
+    /// Need authenticator (`ENEEDAUTH`).
+    NeedAuthenticator,
+    /// Device power is off (`EPWROFF`).
+    DevicePowerOff,
+    /// Device error (`EDEVERR`).
+    DeviceError,
+    /// Value too large to be stored in data type (`EOVERFLOW`).
+    Overflow,
+    /// Bad executable (or shared library) (`EBADEXEC`).
+    BadExecutable,
+    /// Bad CPU type in executable (`EBADARCH`).
+    BadArchitecture,
+    /// Shared library version mismatch (`ESHLIBVERS`).
+    SharedLibraryVersionMismatch,
+    /// Malformed Mach-o file (`EBADMACHO`).
+    BadMachO,
+    /// Operation canceled (`ECANCELED`).
+    Canceled,
+    /// Identifier removed (`EIDRM`).
+    IdentifierRemoved,
+    /// No message of desired type (`ENOMSG`).
+    NoMessage,
+    /// Illegal byte sequence (`EILSEQ`).
+    IllegalByteSequence,
+    /// Attribute not found (`ENOATTR`).
+    NoAttribute,
+    /// Bad message (`EBADMSG`).
+    BadMessage,
+    /// Reserved (`EMULTIHOP`).
+    Multihop,
+    /// No message available on STREAM (`ENODATA`).
+    NoData,
+    /// Reserved (`ENOLINK`).
+    NoLink,
+    /// No STREAM resources (`ENOSR`).
+    NoStreamResources,
+    /// Not a STREAM (`ENOSTR`).
+    NotAStream,
+    /// Protocol error (`EPROTO`).
+    ProtocolError,
+    /// STREAM ioctl timeout (`ETIME`).
+    StreamTimeout,
+    /// Operation not supported on socket (`EOPNOTSUPP`).
+    NotSupported,
+    /// Policy not found (`ENOPOLICY`).
+    NoPolicy,
+    /// State not recoverable (`ENOTRECOVERABLE`).
+    NotRecoverable,
+    /// Previous owner died (`EOWNERDEAD`).
+    OwnerDead,
+    /// Interface output queue is full (`EQFULL`).
+    QueueFull,
+    /// An error number not known to this crate.
+    Other(NonZeroI32),
 }
 
-impl TryFrom<NonZeroI32> for Error {
-    type Error = NonZeroI32;
+impl Error {
+    /// Returns the error number of the last failed call on the current thread, or [`None`] if the
+    /// error number is not set.
+    #[inline]
+    #[must_use]
+    pub fn last() -> Option<Self> {
+        get().map(Self::from)
+    }
+
+    /// Returns the error number.
+    // LINT: The function maps every variant, one per line.
+    #[allow(clippy::too_many_lines)]
+    #[inline]
+    #[must_use]
+    pub const fn into_raw(self) -> NonZeroI32 {
+        let errno = match self {
+            Self::NotPermitted => errno::EPERM,
+            Self::NotFound => errno::ENOENT,
+            Self::NoSuchProcess => errno::ESRCH,
+            Self::Interrupted => errno::EINTR,
+            Self::IO => errno::EIO,
+            Self::NoDevice => errno::ENXIO,
+            Self::ArgumentListTooLong => errno::E2BIG,
+            Self::ExecutableFormat => errno::ENOEXEC,
+            Self::BadFileDescriptor => errno::EBADF,
+            Self::NoChildProcesses => errno::ECHILD,
+            Self::Deadlock => errno::EDEADLK,
+            Self::OutOfMemory => errno::ENOMEM,
+            Self::NoAccess => errno::EACCES,
+            Self::BadAddress => errno::EFAULT,
+            Self::NotABlockDevice => errno::ENOTBLK,
+            Self::ResourceBusy => errno::EBUSY,
+            Self::AlreadyExists => errno::EEXIST,
+            Self::CrossesDevices => errno::EXDEV,
+            Self::OperationNotSupportedByDevice => errno::ENODEV,
+            Self::NotADirectory => errno::ENOTDIR,
+            Self::IsADirectory => errno::EISDIR,
+            Self::InvalidArgument => errno::EINVAL,
+            Self::SystemFileLimit => errno::ENFILE,
+            Self::ProcessFileLimit => errno::EMFILE,
+            Self::InappropriateIoctl => errno::ENOTTY,
+            Self::ExecutableFileBusy => errno::ETXTBSY,
+            Self::FileTooLarge => errno::EFBIG,
+            Self::StorageFull => errno::ENOSPC,
+            Self::IllegalSeek => errno::ESPIPE,
+            Self::ReadOnlyFilesystem => errno::EROFS,
+            Self::TooManyLinks => errno::EMLINK,
+            Self::BrokenPipe => errno::EPIPE,
+            Self::DomainError => errno::EDOM,
+            Self::ResultOutOfRange => errno::ERANGE,
+            Self::WouldBlock => errno::EAGAIN,
+            Self::InProgress => errno::EINPROGRESS,
+            Self::AlreadyInProgress => errno::EALREADY,
+            Self::NotASocket => errno::ENOTSOCK,
+            Self::DestinationAddressRequired => errno::EDESTADDRREQ,
+            Self::MessageTooLong => errno::EMSGSIZE,
+            Self::WrongProtocolType => errno::EPROTOTYPE,
+            Self::ProtocolOptionNotAvailable => errno::ENOPROTOOPT,
+            Self::ProtocolNotSupported => errno::EPROTONOSUPPORT,
+            Self::SocketTypeNotSupported => errno::ESOCKTNOSUPPORT,
+            Self::Unsupported => errno::ENOTSUP,
+            Self::ProtocolFamilyNotSupported => errno::EPFNOSUPPORT,
+            Self::AddressFamilyNotSupported => errno::EAFNOSUPPORT,
+            Self::AddressInUse => errno::EADDRINUSE,
+            Self::AddressNotAvailable => errno::EADDRNOTAVAIL,
+            Self::NetworkDown => errno::ENETDOWN,
+            Self::NetworkUnreachable => errno::ENETUNREACH,
+            Self::NetworkReset => errno::ENETRESET,
+            Self::ConnectionAborted => errno::ECONNABORTED,
+            Self::ConnectionReset => errno::ECONNRESET,
+            Self::NoBufferSpace => errno::ENOBUFS,
+            Self::AlreadyConnected => errno::EISCONN,
+            Self::NotConnected => errno::ENOTCONN,
+            Self::SocketShutdown => errno::ESHUTDOWN,
+            Self::TooManyReferences => errno::ETOOMANYREFS,
+            Self::TimedOut => errno::ETIMEDOUT,
+            Self::ConnectionRefused => errno::ECONNREFUSED,
+            Self::FilesystemLoop => errno::ELOOP,
+            Self::InvalidFilename => errno::ENAMETOOLONG,
+            Self::HostDown => errno::EHOSTDOWN,
+            Self::HostUnreachable => errno::EHOSTUNREACH,
+            Self::DirectoryNotEmpty => errno::ENOTEMPTY,
+            Self::ProcessLimit => errno::EPROCLIM,
+            Self::UserLimit => errno::EUSERS,
+            Self::FilesystemQuotaExceeded => errno::EDQUOT,
+            Self::StaleNetworkFileHandle => errno::ESTALE,
+            Self::RemotePath => errno::EREMOTE,
+            Self::BadRpcStruct => errno::EBADRPC,
+            Self::RpcVersionMismatch => errno::ERPCMISMATCH,
+            Self::RpcProgramUnavailable => errno::EPROGUNAVAIL,
+            Self::RpcProgramVersionMismatch => errno::EPROGMISMATCH,
+            Self::RpcProcedureUnavailable => errno::EPROCUNAVAIL,
+            Self::NoLocks => errno::ENOLCK,
+            Self::FunctionNotImplemented => errno::ENOSYS,
+            Self::InappropriateFileType => errno::EFTYPE,
+            Self::AuthenticationError => errno::EAUTH,
+            Self::NeedAuthenticator => errno::ENEEDAUTH,
+            Self::DevicePowerOff => errno::EPWROFF,
+            Self::DeviceError => errno::EDEVERR,
+            Self::Overflow => errno::EOVERFLOW,
+            Self::BadExecutable => errno::EBADEXEC,
+            Self::BadArchitecture => errno::EBADARCH,
+            Self::SharedLibraryVersionMismatch => errno::ESHLIBVERS,
+            Self::BadMachO => errno::EBADMACHO,
+            Self::Canceled => errno::ECANCELED,
+            Self::IdentifierRemoved => errno::EIDRM,
+            Self::NoMessage => errno::ENOMSG,
+            Self::IllegalByteSequence => errno::EILSEQ,
+            Self::NoAttribute => errno::ENOATTR,
+            Self::BadMessage => errno::EBADMSG,
+            Self::Multihop => errno::EMULTIHOP,
+            Self::NoData => errno::ENODATA,
+            Self::NoLink => errno::ENOLINK,
+            Self::NoStreamResources => errno::ENOSR,
+            Self::NotAStream => errno::ENOSTR,
+            Self::ProtocolError => errno::EPROTO,
+            Self::StreamTimeout => errno::ETIME,
+            Self::NotSupported => errno::EOPNOTSUPP,
+            Self::NoPolicy => errno::ENOPOLICY,
+            Self::NotRecoverable => errno::ENOTRECOVERABLE,
+            Self::OwnerDead => errno::EOWNERDEAD,
+            Self::QueueFull => errno::EQFULL,
+            Self::Other(errno) => return errno,
+        };
+        // SAFETY: Darwin does not define an error number of `0`.
+        unsafe { NonZeroI32::new_unchecked(errno) }
+    }
+
+    /// Returns `true` if the call was interrupted by a signal before it completed (`EINTR`).
+    ///
+    /// The call may be retried.
+    #[inline]
+    #[must_use]
+    pub const fn is_interrupted(self) -> bool {
+        matches!(self, Self::Interrupted)
+    }
+
+    /// Returns `true` if the call would have blocked on a non-blocking object
+    /// (`EAGAIN`/`EWOULDBLOCK`).
+    ///
+    /// The call may be retried when the object is ready.
+    #[inline]
+    #[must_use]
+    pub const fn is_would_block(self) -> bool {
+        matches!(self, Self::WouldBlock)
+    }
+}
+
+impl Display for Error {
+    /// Writes the system's message for the error number, as returned by `strerror_r(3)`.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // The longest message defined by Darwin is 47 bytes.
+        let mut buf = [0_u8; 128];
+        let errnum = self.into_raw().get();
 
-    fn try_from(err: NonZeroI32) -> Result<Self, Self::Error> {
-        let variant = match err.get() {
+        // SAFETY: `buf` is a valid write destination of `buf.len()` bytes. The system function
+        // never overruns the buffer and always nul terminates the output, even if the message is
+        // truncated or the error number is unknown.
+        let _ = unsafe { strerror_r(errnum, buf.as_mut_ptr().cast(), buf.len()) };
+
+        match CStr::from_bytes_until_nul(&buf).map(CStr::to_str) {
+            Ok(Ok(message)) if !message.is_empty() => f.write_str(message),
+            _ => write!(f, "Unknown error: {errnum}"),
+        }
+    }
+}
+
+impl From<NonZeroI32> for Error {
+    // LINT: The function maps every error number, one per line.
+    #[allow(clippy::too_many_lines)]
+    #[inline]
+    fn from(err: NonZeroI32) -> Self {
+        match err.get() {
             errno::EPERM => Self::NotPermitted,
             errno::ENOENT => Self::NotFound,
+            errno::ESRCH => Self::NoSuchProcess,
             errno::EINTR => Self::Interrupted,
             errno::EIO => Self::IO,
             errno::ENXIO => Self::NoDevice,
+            errno::E2BIG => Self::ArgumentListTooLong,
+            errno::ENOEXEC => Self::ExecutableFormat,
             errno::EBADF => Self::BadFileDescriptor,
+            errno::ECHILD => Self::NoChildProcesses,
             errno::EDEADLK => Self::Deadlock,
             errno::ENOMEM => Self::OutOfMemory,
             errno::EACCES => Self::NoAccess,
             errno::EFAULT => Self::BadAddress,
+            errno::ENOTBLK => Self::NotABlockDevice,
             errno::EBUSY => Self::ResourceBusy,
             errno::EEXIST => Self::AlreadyExists,
             errno::EXDEV => Self::CrossesDevices,
+            errno::ENODEV => Self::OperationNotSupportedByDevice,
             errno::ENOTDIR => Self::NotADirectory,
             errno::EISDIR => Self::IsADirectory,
             errno::EINVAL => Self::InvalidArgument,
             errno::ENFILE => Self::SystemFileLimit,
             errno::EMFILE => Self::ProcessFileLimit,
+            errno::ENOTTY => Self::InappropriateIoctl,
             errno::ETXTBSY => Self::ExecutableFileBusy,
+            errno::EFBIG => Self::FileTooLarge,
             errno::ENOSPC => Self::StorageFull,
+            errno::ESPIPE => Self::IllegalSeek,
             errno::EROFS => Self::ReadOnlyFilesystem,
+            errno::EMLINK => Self::TooManyLinks,
+            errno::EPIPE => Self::BrokenPipe,
+            errno::EDOM => Self::DomainError,
+            errno::ERANGE => Self::ResultOutOfRange,
             errno::EAGAIN => Self::WouldBlock,
+            errno::EINPROGRESS => Self::InProgress,
+            errno::EALREADY => Self::AlreadyInProgress,
+            errno::ENOTSOCK => Self::NotASocket,
+            errno::EDESTADDRREQ => Self::DestinationAddressRequired,
+            errno::EMSGSIZE => Self::MessageTooLong,
+            errno::EPROTOTYPE => Self::WrongProtocolType,
+            errno::ENOPROTOOPT => Self::ProtocolOptionNotAvailable,
+            errno::EPROTONOSUPPORT => Self::ProtocolNotSupported,
+            errno::ESOCKTNOSUPPORT => Self::SocketTypeNotSupported,
+            errno::ENOTSUP => Self::Unsupported,
+            errno::EPFNOSUPPORT => Self::ProtocolFamilyNotSupported,
+            errno::EAFNOSUPPORT => Self::AddressFamilyNotSupported,
+            errno::EADDRINUSE => Self::AddressInUse,
+            errno::EADDRNOTAVAIL => Self::AddressNotAvailable,
+            errno::ENETDOWN => Self::NetworkDown,
+            errno::ENETUNREACH => Self::NetworkUnreachable,
+            errno::ENETRESET => Self::NetworkReset,
+            errno::ECONNABORTED => Self::ConnectionAborted,
+            errno::ECONNRESET => Self::ConnectionReset,
+            errno::ENOBUFS => Self::NoBufferSpace,
+            errno::EISCONN => Self::AlreadyConnected,
+            errno::ENOTCONN => Self::NotConnected,
+            errno::ESHUTDOWN => Self::SocketShutdown,
+            errno::ETOOMANYREFS => Self::TooManyReferences,
+            errno::ETIMEDOUT => Self::TimedOut,
+            errno::ECONNREFUSED => Self::ConnectionRefused,
             errno::ELOOP => Self::FilesystemLoop,
             errno::ENAMETOOLONG => Self::InvalidFilename,
+            errno::EHOSTDOWN => Self::HostDown,
+            errno::EHOSTUNREACH => Self::HostUnreachable,
             errno::ENOTEMPTY => Self::DirectoryNotEmpty,
+            errno::EPROCLIM => Self::ProcessLimit,
+            errno::EUSERS => Self::UserLimit,
             errno::EDQUOT => Self::FilesystemQuotaExceeded,
+            errno::ESTALE => Self::StaleNetworkFileHandle,
+            errno::EREMOTE => Self::RemotePath,
+            errno::EBADRPC => Self::BadRpcStruct,
+            errno::ERPCMISMATCH => Self::RpcVersionMismatch,
+            errno::EPROGUNAVAIL => Self::RpcProgramUnavailable,
+            errno::EPROGMISMATCH => Self::RpcProgramVersionMismatch,
+            errno::EPROCUNAVAIL => Self::RpcProcedureUnavailable,
+            errno::ENOLCK => Self::NoLocks,
+            errno::ENOSYS => Self::FunctionNotImplemented,
+            errno::EFTYPE => Self::InappropriateFileType,
+            errno::EAUTH => Self::AuthenticationError,
+            errno::ENEEDAUTH => Self::NeedAuthenticator,
+            errno::EPWROFF => Self::DevicePowerOff,
+            errno::EDEVERR => Self::DeviceError,
             errno::EOVERFLOW => Self::Overflow,
+            errno::EBADEXEC => Self::BadExecutable,
+            errno::EBADARCH => Self::BadArchitecture,
+            errno::ESHLIBVERS => Self::SharedLibraryVersionMismatch,
+            errno::EBADMACHO => Self::BadMachO,
+            errno::ECANCELED => Self::Canceled,
+            errno::EIDRM => Self::IdentifierRemoved,
+            errno::ENOMSG => Self::NoMessage,
             errno::EILSEQ => Self::IllegalByteSequence,
+            errno::ENOATTR => Self::NoAttribute,
+            errno::EBADMSG => Self::BadMessage,
+            errno::EMULTIHOP => Self::Multihop,
+            errno::ENODATA => Self::NoData,
+            errno::ENOLINK => Self::NoLink,
+            errno::ENOSR => Self::NoStreamResources,
+            errno::ENOSTR => Self::NotAStream,
+            errno::EPROTO => Self::ProtocolError,
+            errno::ETIME => Self::StreamTimeout,
             errno::EOPNOTSUPP => Self::NotSupported,
-            _ => Err(err)?,
-        };
-        Ok(variant)
+            errno::ENOPOLICY => Self::NoPolicy,
+            errno::ENOTRECOVERABLE => Self::NotRecoverable,
+            errno::EOWNERDEAD => Self::OwnerDead,
+            errno::EQFULL => Self::QueueFull,
+            _ => Self::Other(err),
+        }
+    }
+}
+
+impl From<Error> for NonZeroI32 {
+    #[inline]
+    fn from(err: Error) -> Self {
+        err.into_raw()
     }
 }
 
+/// Returns the last error number set on the current thread.
+#[inline]
 #[must_use]
 pub fn get() -> Option<NonZeroI32> {
     // SAFETY: __error() is guaranteed to return a thread-local, non-null pointer.
@@ -82,29 +528,91 @@ pub fn get() -> Option<NonZeroI32> {
 }
 
 /// Set the last error number visible to the current thread.
+#[inline]
 pub fn set(errno: Option<NonZeroI32>) {
     let errno = errno.map_or(0, NonZeroI32::get);
     // SAFETY: __error() is guaranteed to return a thread-specific non-null pointer.
     unsafe { *__error() = errno };
 }
 
-/// Returns the value of [`get()`] as an [`Err`] if `result == -1`, otherwise returns the value of
-/// `result` as [`Ok`].
-pub(crate) fn check(result: i32) -> Result<i32, NonZeroI32> {
+/// Returns the value of [`Error::last()`] as an [`Err`] if `result == -1`, otherwise returns the
+/// value of `result` as [`Ok`].
+pub(crate) fn check(result: i32) -> Result<i32, Error> {
     if result == -1 {
-        Err(get().unwrap())
+        Err(Error::last().unwrap())
     } else {
         Ok(result)
     }
 }
 
+/// Returns `result` as an [`Err`] if it is a non-zero error number, otherwise returns [`Ok`]. This
+/// validates the result of functions that return an error number rather than setting `errno` (e.g.,
+/// the `pthread` family of functions).
+pub(crate) fn check_error_number(result: i32) -> Result<(), Error> {
+    NonZeroI32::new(result).map_or(Ok(()), |err| Err(Error::from(err)))
+}
+
 /// Calls `f` and validates the result with [`check()`]. Continues to call `f` while the result is
 /// the [`Err`] variant with a value of [`Error::Interrupted`]. Otherwise returns the result.
-pub(crate) fn check_retry(mut f: impl FnMut() -> i32) -> Result<i32, NonZeroI32> {
+pub(crate) fn check_retry(mut f: impl FnMut() -> i32) -> Result<i32, Error> {
     loop {
         match check(f()) {
-            Err(e) if e.get() == Error::Interrupted as _ => {}
+            Err(e) if e.is_interrupted() => {}
             result => return result,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use core::fmt::Write;
+    use core::num::NonZeroI32;
+
+    struct Buffer {
+        buf: [u8; 128],
+        len: usize,
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            self.buf[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    fn message(err: Error) -> Buffer {
+        let mut buf = Buffer {
+            buf: [0; 128],
+            len: 0,
+        };
+        write!(buf, "{err}").unwrap();
+        buf
+    }
+
+    #[test]
+    fn display() {
+        let buf = message(Error::NotFound);
+        assert_eq!(&buf.buf[..buf.len], b"No such file or directory");
+
+        let buf = message(Error::Other(NonZeroI32::new(1000).unwrap()));
+        assert_eq!(&buf.buf[..buf.len], b"Unknown error: 1000");
+    }
+
+    #[test]
+    fn round_trip() {
+        for errno in 1..=crate::_sys::c::errno::ELAST {
+            let raw = NonZeroI32::new(errno).unwrap();
+            let err = Error::from(raw);
+
+            assert!(!matches!(err, Error::Other(_)));
+            assert_eq!(err.into_raw(), raw);
+        }
+
+        let raw = NonZeroI32::new(1000).unwrap();
+        assert_eq!(Error::from(raw), Error::Other(raw));
+        assert_eq!(Error::Other(raw).into_raw(), raw);
+    }
+}
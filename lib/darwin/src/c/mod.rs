@@ -1 +1,3 @@
 pub mod errno;
+pub mod stdlib;
+pub mod time;
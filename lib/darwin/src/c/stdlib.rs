@@ -0,0 +1,196 @@
+//! Access to the process environment.
+//!
+//! The environment is process-global state that the C library does not synchronize. Reading the
+//! environment is safe with respect to other reads, but any modification (by [`set_env`],
+//! [`unset_env`], or by C code calling `setenv(3)`, `unsetenv(3)`, or `putenv(3)`) may reallocate
+//! the environment array or overwrite a value in place, freeing the memory that previously
+//! returned references point to. Safe Rust code can modify the environment too (e.g., with
+//! `std::env::set_var`), so every function in this module is `unsafe`: the functions that modify
+//! the environment require exclusive access to it, and the references returned by [`get_env`] and
+//! [`environ`] must not be used after the next modification.
+
+use crate::_sys::c::stdlib::{getenv, setenv, unsetenv};
+use crate::_sys::crt_externs::_NSGetEnviron;
+use crate::c::errno::{check, Error};
+use core::ffi::{c_char, CStr};
+use core::iter::FusedIterator;
+use core::ptr;
+
+/// An iterator over the variables in the process environment, returned by [`environ`].
+///
+/// Each item is a pair of the variable's name (without the `=` separator) and its value.
+#[derive(Debug)]
+pub struct Environ {
+    next: *const *const c_char,
+}
+
+/// Returns an iterator over the variables in the process environment.
+///
+/// The iterator walks the environment array in place.
+///
+/// # Safety
+///
+/// The environment must not be modified, by this thread or any other, until the iterator and every
+/// item it yields are no longer used. The `'static` lifetime of the items does not reflect this.
+#[inline]
+#[must_use]
+pub unsafe fn environ() -> Environ {
+    // SAFETY: The function has no preconditions and always returns a valid pointer to the
+    // process's `environ` variable.
+    let next = unsafe { *_NSGetEnviron() };
+    Environ { next: next.cast() }
+}
+
+/// Returns the value of the environment variable `name`, or [`None`] if it is not set.
+///
+/// # Safety
+///
+/// The environment must not be modified, by this thread or any other, for the duration of the call
+/// or while the returned string is used. The `'static` lifetime of the string does not reflect
+/// this.
+#[inline]
+#[must_use]
+pub unsafe fn get_env(name: impl AsRef<CStr>) -> Option<&'static CStr> {
+    // SAFETY: name is guaranteed to be a valid, nul-terminated C string.
+    let value = unsafe { getenv(name.as_ref().as_ptr()) };
+
+    // SAFETY: A non-null result is guaranteed to be a nul-terminated C string owned by the
+    // environment. The caller asserts it is not used after the environment is modified.
+    (!value.is_null()).then(|| unsafe { CStr::from_ptr(value) })
+}
+
+/// Sets the environment variable `name` to `value`. If the variable is already set, its value is
+/// replaced only if `overwrite` is `true`.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidArgument`] if `name` is empty or contains `=`, or
+/// [`Error::OutOfMemory`] if the environment could not be grown.
+///
+/// # Safety
+///
+/// No other thread may read or modify the environment for the duration of the call, and no string
+/// previously returned by [`get_env`] or [`Environ`] (nor an [`Environ`] itself) may be used
+/// afterward.
+#[inline]
+pub unsafe fn set_env(
+    name: impl AsRef<CStr>,
+    value: impl AsRef<CStr>,
+    overwrite: bool,
+) -> Result<(), Error> {
+    let name = name.as_ref().as_ptr();
+    let value = value.as_ref().as_ptr();
+
+    // SAFETY: name and value are guaranteed to be valid, nul-terminated C strings. The caller
+    // guarantees exclusive access to the environment.
+    let _ = check(unsafe { setenv(name, value, overwrite.into()) })?;
+    Ok(())
+}
+
+/// Removes the environment variable `name`. It is not an error if the variable is not set.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidArgument`] if `name` is empty or contains `=`.
+///
+/// # Safety
+///
+/// No other thread may read or modify the environment for the duration of the call, and no string
+/// previously returned by [`get_env`] or [`Environ`] (nor an [`Environ`] itself) may be used
+/// afterward.
+#[inline]
+pub unsafe fn unset_env(name: impl AsRef<CStr>) -> Result<(), Error> {
+    // SAFETY: name is guaranteed to be a valid, nul-terminated C string. The caller guarantees
+    // exclusive access to the environment.
+    let _ = check(unsafe { unsetenv(name.as_ref().as_ptr()) })?;
+    Ok(())
+}
+
+impl Iterator for Environ {
+    type Item = (&'static [u8], &'static CStr);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next.is_null() {
+            return None;
+        }
+
+        // SAFETY: The environment array is terminated by a null pointer, and self.next is set to
+        // null once it is reached, so self.next points to a valid element.
+        let entry = unsafe { *self.next };
+        if entry.is_null() {
+            self.next = ptr::null();
+            return None;
+        }
+
+        // SAFETY: Advancing past a non-null element stays within the environment array, which is
+        // terminated by a null pointer.
+        self.next = unsafe { self.next.add(1) };
+
+        // SAFETY: Each element of the environment array is a nul-terminated C string.
+        let entry = unsafe { CStr::from_ptr(entry) };
+
+        // Entries without a separator (which can only be added by modifying `environ` directly)
+        // are treated as a name with an empty value.
+        let Some(index) = entry.to_bytes().iter().position(|&b| b == b'=') else {
+            return Some((entry.to_bytes(), <&CStr>::default()));
+        };
+        let (name, value) = entry.to_bytes_with_nul().split_at(index);
+        let value = value.get(1..).unwrap_or_default();
+
+        // SAFETY: value is the nul-terminated tail of entry following the separator, which has no
+        // interior nul bytes.
+        Some((name, unsafe { CStr::from_bytes_with_nul_unchecked(value) }))
+    }
+}
+
+impl FusedIterator for Environ {}
+
+#[cfg(test)]
+mod tests {
+    use super::{environ, get_env, set_env, unset_env};
+    use crate::c::errno::Error;
+    use core::ffi::CStr;
+
+    // All environment access occurs in a single test because modifications race with reads.
+    #[test]
+    fn get_set_unset() {
+        let name = CStr::from_bytes_with_nul(b"DARWIN_RS_STDLIB_TEST\0").unwrap();
+        let first = CStr::from_bytes_with_nul(b"first\0").unwrap();
+        let second = CStr::from_bytes_with_nul(b"second\0").unwrap();
+
+        let path = CStr::from_bytes_with_nul(b"PATH\0").unwrap();
+        // SAFETY: No other test accesses the environment.
+        assert!(unsafe { get_env(path) }.is_some());
+        // SAFETY: See above.
+        assert!(unsafe { environ() }.any(|(name, _)| name == b"PATH"));
+        // SAFETY: See above.
+        assert_eq!(unsafe { get_env(name) }, None);
+
+        // SAFETY: See above.
+        unsafe { set_env(name, first, true) }.unwrap();
+        // SAFETY: See above.
+        assert_eq!(unsafe { get_env(name) }, Some(first));
+
+        // SAFETY: See above.
+        unsafe { set_env(name, second, false) }.unwrap();
+        // SAFETY: See above.
+        assert_eq!(unsafe { get_env(name) }, Some(first));
+
+        // SAFETY: See above.
+        let value = unsafe { environ() }
+            .find(|(entry, _)| *entry == name.to_bytes())
+            .map(|(_, value)| value);
+        assert_eq!(value, Some(first));
+
+        // SAFETY: See above.
+        unsafe { unset_env(name) }.unwrap();
+        // SAFETY: See above.
+        assert_eq!(unsafe { get_env(name) }, None);
+
+        let invalid = CStr::from_bytes_with_nul(b"A=B\0").unwrap();
+        // SAFETY: See above.
+        let result = unsafe { set_env(invalid, first, true) };
+        assert_eq!(result, Err(Error::InvalidArgument));
+    }
+}
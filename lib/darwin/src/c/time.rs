@@ -0,0 +1,90 @@
+//! The system clocks (`clock_gettime(3)`).
+
+use crate::_sys::c::time::{
+    clock_getres, clock_gettime, _CLOCK_MONOTONIC, _CLOCK_MONOTONIC_RAW,
+    _CLOCK_MONOTONIC_RAW_APPROX, _CLOCK_PROCESS_CPUTIME_ID, _CLOCK_REALTIME,
+    _CLOCK_THREAD_CPUTIME_ID, _CLOCK_UPTIME_RAW, _CLOCK_UPTIME_RAW_APPROX,
+};
+use crate::_sys::sys::types::timespec;
+use crate::c::errno::{check, Error};
+use core::time::Duration;
+
+/// A clock that can be read with [`Clock::now`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum Clock {
+    /// The time since the Unix epoch, which jumps if the system time is changed
+    /// (`CLOCK_REALTIME`).
+    Realtime = _CLOCK_REALTIME,
+    /// The time since boot, including time the system was asleep, which is adjusted to match the
+    /// network time (`CLOCK_MONOTONIC`).
+    Monotonic = _CLOCK_MONOTONIC,
+    /// Like [`Clock::Monotonic`], but the time is not adjusted (`CLOCK_MONOTONIC_RAW`).
+    MonotonicRaw = _CLOCK_MONOTONIC_RAW,
+    /// Like [`Clock::MonotonicRaw`], but the time is only updated on a context switch, so reading
+    /// it is faster (`CLOCK_MONOTONIC_RAW_APPROX`).
+    MonotonicRawApprox = _CLOCK_MONOTONIC_RAW_APPROX,
+    /// The time since boot, excluding time the system was asleep, which is not adjusted
+    /// (`CLOCK_UPTIME_RAW`).
+    UptimeRaw = _CLOCK_UPTIME_RAW,
+    /// Like [`Clock::UptimeRaw`], but the time is only updated on a context switch, so reading it
+    /// is faster (`CLOCK_UPTIME_RAW_APPROX`).
+    UptimeRawApprox = _CLOCK_UPTIME_RAW_APPROX,
+    /// The CPU time consumed by the calling process (`CLOCK_PROCESS_CPUTIME_ID`).
+    ProcessCpuTime = _CLOCK_PROCESS_CPUTIME_ID,
+    /// The CPU time consumed by the calling thread (`CLOCK_THREAD_CPUTIME_ID`).
+    ThreadCpuTime = _CLOCK_THREAD_CPUTIME_ID,
+}
+
+impl Clock {
+    /// Reads the current value of the clock.
+    pub fn now(self) -> Result<Duration, Error> {
+        Self::query(|buf| {
+            // SAFETY: The buffer is guaranteed to be valid. The operating system will not write
+            // outside the bounds of the buffer.
+            unsafe { clock_gettime(self as _, buf) }
+        })
+    }
+
+    /// Returns the smallest interval by which the value of the clock can change.
+    pub fn resolution(self) -> Result<Duration, Error> {
+        Self::query(|buf| {
+            // SAFETY: The buffer is guaranteed to be valid. The operating system will not write
+            // outside the bounds of the buffer.
+            unsafe { clock_getres(self as _, buf) }
+        })
+    }
+
+    fn query(f: impl FnOnce(&mut timespec) -> i32) -> Result<Duration, Error> {
+        let mut timespec = timespec { sec: 0, nsec: 0 };
+        let _ = check(f(&mut timespec))?;
+
+        let secs = timespec.sec.try_into().unwrap_or_default();
+        let nanos = timespec.nsec.try_into().unwrap_or_default();
+        Ok(Duration::new(secs, nanos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Clock;
+
+    #[test]
+    fn now() {
+        for clock in [
+            Clock::Realtime,
+            Clock::Monotonic,
+            Clock::MonotonicRaw,
+            Clock::MonotonicRawApprox,
+            Clock::UptimeRaw,
+            Clock::UptimeRawApprox,
+            Clock::ProcessCpuTime,
+            Clock::ThreadCpuTime,
+        ] {
+            let earlier = clock.now().unwrap();
+            assert!(clock.now().unwrap() >= earlier);
+            assert!(!clock.resolution().unwrap().is_zero());
+        }
+    }
+}
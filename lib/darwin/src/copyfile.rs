@@ -0,0 +1,388 @@
+//! Copies files and directories, including their metadata, with `copyfile(3)`.
+//!
+//! A panic in a progress callback cannot unwind through `copyfile(3)`, so it aborts the process.
+
+use crate::_sys::copyfile::{
+    copyfile, copyfile_callback_t, copyfile_flags_t, copyfile_state_alloc, copyfile_state_free,
+    copyfile_state_get, copyfile_state_set, copyfile_state_t, fcopyfile, COPYFILE_ACL,
+    COPYFILE_ALL, COPYFILE_CLONE, COPYFILE_CONTINUE, COPYFILE_COPY_DATA, COPYFILE_COPY_XATTR,
+    COPYFILE_DATA, COPYFILE_ERR, COPYFILE_EXCL, COPYFILE_FINISH, COPYFILE_NOFOLLOW,
+    COPYFILE_PROGRESS, COPYFILE_QUIT, COPYFILE_RECURSE_DIR, COPYFILE_RECURSE_DIR_CLEANUP,
+    COPYFILE_RECURSE_ERROR, COPYFILE_RECURSE_FILE, COPYFILE_RECURSIVE, COPYFILE_SKIP,
+    COPYFILE_START, COPYFILE_STAT, COPYFILE_STATE_COPIED, COPYFILE_STATE_STATUS_CB,
+    COPYFILE_STATE_STATUS_CTX, COPYFILE_XATTR,
+};
+use crate::_sys::sys::types::off_t;
+use crate::c::errno::{check, Error};
+use crate::io::AsFd;
+use crate::unwind::abort_on_unwind;
+use core::ffi::{c_char, c_int, c_void, CStr};
+use core::ptr;
+
+/// Settings for copying a file or directory.
+///
+/// By default, the data and all metadata (the access control list, the POSIX mode, owner, and
+/// timestamps, and the extended attributes) are copied.
+#[allow(missing_copy_implementations)]
+#[derive(Debug)]
+pub struct CopyFile {
+    flags: copyfile_flags_t,
+}
+
+/// The part of a copy that a progress callback is being notified about.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Operation {
+    /// A file is copied as part of a recursive copy.
+    File,
+    /// A directory is copied as part of a recursive copy.
+    Directory,
+    /// A directory's metadata is finalized after its contents were copied in a recursive copy.
+    DirectoryCleanup,
+    /// A file's data is copied.
+    Data,
+    /// An extended attribute is copied.
+    ExtendedAttribute,
+    /// An item could not be traversed in a recursive copy.
+    Error,
+}
+
+/// A notification passed to the progress callback of [`CopyFile::path_with_progress`].
+#[derive(Clone, Copy, Debug)]
+pub struct Progress<'path> {
+    operation: Operation,
+    stage: Stage,
+    source: Option<&'path CStr>,
+    destination: Option<&'path CStr>,
+    bytes_copied: Option<u64>,
+}
+
+/// How a copy proceeds after a progress callback returns.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Reply {
+    /// Continues the copy.
+    Continue,
+    /// Skips the current item, e.g. a file in a recursive copy.
+    Skip,
+    /// Stops the copy, which fails with [`Error::Canceled`].
+    Quit,
+}
+
+/// The stage of an [`Operation`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stage {
+    /// The operation is starting.
+    Start,
+    /// The operation finished.
+    Finish,
+    /// The operation failed. The error number is available from [`Error::last`].
+    Error,
+    /// Data was copied. Sent periodically while copying a large file's data.
+    Progress,
+}
+
+/// Owns a `copyfile_state_t`.
+struct State(copyfile_state_t);
+
+impl CopyFile {
+    /// Creates settings that copy the data and all metadata.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            flags: COPYFILE_ALL,
+        }
+    }
+
+    /// Copies the access control list.
+    #[must_use]
+    pub const fn acl(self, acl: bool) -> Self {
+        self.set_flag_enabled(COPYFILE_ACL, acl)
+    }
+
+    /// Clones the item with copy-on-write if the file system supports it, falling back to a copy
+    /// otherwise. A clone always includes the data and all metadata and requires that the
+    /// destination does not exist.
+    #[must_use]
+    pub const fn copy_on_write(self, copy_on_write: bool) -> Self {
+        self.set_flag_enabled(COPYFILE_CLONE, copy_on_write)
+    }
+
+    /// Copies the data.
+    #[must_use]
+    pub const fn data(self, data: bool) -> Self {
+        self.set_flag_enabled(COPYFILE_DATA, data)
+    }
+
+    /// Fails with [`Error::AlreadyExists`] if the destination exists.
+    #[must_use]
+    pub const fn exclusive(self, exclusive: bool) -> Self {
+        self.set_flag_enabled(COPYFILE_EXCL, exclusive)
+    }
+
+    /// Copies the extended attributes.
+    #[must_use]
+    pub const fn extended_attributes(self, extended_attributes: bool) -> Self {
+        self.set_flag_enabled(COPYFILE_XATTR, extended_attributes)
+    }
+
+    /// Does not follow a symbolic link at the source or destination.
+    #[must_use]
+    pub const fn no_follow(self, no_follow: bool) -> Self {
+        self.set_flag_enabled(COPYFILE_NOFOLLOW, no_follow)
+    }
+
+    /// Copies a directory's contents, recursively.
+    #[must_use]
+    pub const fn recursive(self, recursive: bool) -> Self {
+        self.set_flag_enabled(COPYFILE_RECURSIVE, recursive)
+    }
+
+    /// Copies the POSIX information (mode, owner, and timestamps).
+    #[must_use]
+    pub const fn stat(self, stat: bool) -> Self {
+        self.set_flag_enabled(COPYFILE_STAT, stat)
+    }
+
+    /// Copies the open file `source` to the open file `destination`. Recursive copies are not
+    /// supported.
+    pub fn fd(self, source: &impl AsFd, destination: &impl AsFd) -> Result<(), Error> {
+        let from_fd = source.as_fd();
+        let to_fd = destination.as_fd();
+        let flags = self.flags;
+
+        // SAFETY: from_fd and to_fd are guaranteed to be valid file descriptors, a `NULL` state is
+        // permitted, and flags is guaranteed to be a valid combination.
+        let _ = check(unsafe { fcopyfile(from_fd, to_fd, ptr::null_mut(), flags) })?;
+        Ok(())
+    }
+
+    /// Copies the item at `source` to `destination`.
+    pub fn path(
+        self,
+        source: impl AsRef<CStr>,
+        destination: impl AsRef<CStr>,
+    ) -> Result<(), Error> {
+        let from = source.as_ref().as_ptr();
+        let to = destination.as_ref().as_ptr();
+        let flags = self.flags;
+
+        // SAFETY: from and to are guaranteed to be valid, nul-terminated C-style strings the
+        // function will not write to, a `NULL` state is permitted, and flags is guaranteed to be a
+        // valid combination.
+        let _ = check(unsafe { copyfile(from, to, ptr::null_mut(), flags) })?;
+        Ok(())
+    }
+
+    /// Copies the item at `source` to `destination`, calling `progress` as the copy proceeds.
+    ///
+    /// The [`Reply`] returned by `progress` controls whether the copy continues.
+    pub fn path_with_progress<F>(
+        self,
+        source: impl AsRef<CStr>,
+        destination: impl AsRef<CStr>,
+        mut progress: F,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(&Progress<'_>) -> Reply,
+    {
+        let state = State::new()?;
+
+        let callback: copyfile_callback_t = callback::<F>;
+        // LINT: The state stores the callback as an untyped pointer.
+        #[allow(clippy::as_conversions)]
+        let callback = callback as *const c_void;
+        // SAFETY: state is valid and callback is a `copyfile_callback_t`.
+        let _ = check(unsafe { copyfile_state_set(state.0, COPYFILE_STATE_STATUS_CB, callback) })?;
+
+        let ctx: *mut F = &mut progress;
+        // SAFETY: state is valid. ctx points to `progress`, which outlives the copy below.
+        let _ = check(unsafe {
+            copyfile_state_set(state.0, COPYFILE_STATE_STATUS_CTX, ctx.cast_const().cast())
+        })?;
+
+        let from = source.as_ref().as_ptr();
+        let to = destination.as_ref().as_ptr();
+        let flags = self.flags;
+
+        // SAFETY: from and to are guaranteed to be valid, nul-terminated C-style strings the
+        // function will not write to, state is valid, and flags is guaranteed to be a valid
+        // combination.
+        let _ = check(unsafe { copyfile(from, to, state.0, flags) })?;
+        Ok(())
+    }
+
+    const fn set_flag_enabled(mut self, flag: copyfile_flags_t, enable: bool) -> Self {
+        if enable {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+        self
+    }
+}
+
+impl Default for CopyFile {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Progress<'_> {
+    /// Returns the number of bytes of the file's data copied so far, if the operation is
+    /// [`Operation::Data`] and the stage is [`Stage::Progress`].
+    #[must_use]
+    pub const fn bytes_copied(&self) -> Option<u64> {
+        self.bytes_copied
+    }
+
+    /// Returns the path of the destination item, if known.
+    #[must_use]
+    pub const fn destination(&self) -> Option<&CStr> {
+        self.destination
+    }
+
+    /// Returns the part of the copy the notification is about.
+    #[must_use]
+    pub const fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    /// Returns the path of the source item, if known.
+    #[must_use]
+    pub const fn source(&self) -> Option<&CStr> {
+        self.source
+    }
+
+    /// Returns the stage of the operation.
+    #[must_use]
+    pub const fn stage(&self) -> Stage {
+        self.stage
+    }
+}
+
+impl State {
+    fn new() -> Result<Self, Error> {
+        // SAFETY: The function is always safe to call.
+        let state = unsafe { copyfile_state_alloc() };
+        if state.is_null() {
+            Err(Error::OutOfMemory)
+        } else {
+            Ok(Self(state))
+        }
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        // SAFETY: self.0 was allocated by `copyfile_state_alloc` and is not used again.
+        let _ = unsafe { copyfile_state_free(self.0) };
+    }
+}
+
+extern "C" fn callback<F>(
+    what: c_int,
+    stage: c_int,
+    state: copyfile_state_t,
+    src: *const c_char,
+    dst: *const c_char,
+    ctx: *mut c_void,
+) -> c_int
+where
+    F: FnMut(&Progress<'_>) -> Reply,
+{
+    let operation = match what {
+        COPYFILE_RECURSE_FILE => Operation::File,
+        COPYFILE_RECURSE_DIR => Operation::Directory,
+        COPYFILE_RECURSE_DIR_CLEANUP => Operation::DirectoryCleanup,
+        COPYFILE_COPY_DATA => Operation::Data,
+        COPYFILE_COPY_XATTR => Operation::ExtendedAttribute,
+        COPYFILE_RECURSE_ERROR => Operation::Error,
+        _ => return COPYFILE_CONTINUE,
+    };
+    let stage = match stage {
+        COPYFILE_START => Stage::Start,
+        COPYFILE_FINISH => Stage::Finish,
+        COPYFILE_ERR => Stage::Error,
+        COPYFILE_PROGRESS => Stage::Progress,
+        _ => return COPYFILE_CONTINUE,
+    };
+
+    let bytes_copied = if operation == Operation::Data && stage == Stage::Progress {
+        let mut copied: off_t = 0;
+        let copied_ptr: *mut off_t = &mut copied;
+        // SAFETY: state is valid for the duration of the callback and `COPYFILE_STATE_COPIED` is
+        // written to an `off_t`.
+        check(unsafe { copyfile_state_get(state, COPYFILE_STATE_COPIED, copied_ptr.cast()) })
+            .ok()
+            .and_then(|_| u64::try_from(copied).ok())
+    } else {
+        None
+    };
+
+    let progress = Progress {
+        operation,
+        stage,
+        // SAFETY: The paths are valid, nul-terminated C-style strings or `NULL`.
+        source: (!src.is_null()).then(|| unsafe { CStr::from_ptr(src) }),
+        // SAFETY: The paths are valid, nul-terminated C-style strings or `NULL`.
+        destination: (!dst.is_null()).then(|| unsafe { CStr::from_ptr(dst) }),
+        bytes_copied,
+    };
+
+    // SAFETY: CopyFile::path_with_progress set ctx to a pointer to `F`, which outlives the copy.
+    let f = unsafe { &mut *ctx.cast::<F>() };
+    match abort_on_unwind(|| f(&progress)) {
+        Reply::Continue => COPYFILE_CONTINUE,
+        Reply::Skip => COPYFILE_SKIP,
+        Reply::Quit => COPYFILE_QUIT,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CopyFile, Operation, Reply, Stage};
+    use crate::c::errno::Error;
+    use crate::posix::fcntl::{AccessMode, Open};
+    use crate::posix::unistd::unlink;
+    use crate::sys::stat::Metadata;
+    use crate::tests::TemporaryDirectory;
+    use core::ffi::CStr;
+
+    const SOURCE: &[u8] = b"/System/Volumes/Data/Applications/Safari.app/Contents/Info.plist\0";
+
+    #[test]
+    fn copy() {
+        let directory = TemporaryDirectory::new();
+        let destination = directory.join(b"Info.plist");
+        let destination = destination.as_c_str();
+        let source = CStr::from_bytes_with_nul(SOURCE).unwrap();
+
+        let mut finished = false;
+        CopyFile::new()
+            .path_with_progress(source, destination, |progress| {
+                if progress.operation() == Operation::Data && progress.stage() == Stage::Finish {
+                    finished = true;
+                }
+                Reply::Continue
+            })
+            .unwrap();
+        assert!(finished);
+
+        let error = CopyFile::new()
+            .exclusive(true)
+            .path(source, destination)
+            .unwrap_err();
+        assert_eq!(error, Error::AlreadyExists);
+
+        let source = Open::new(AccessMode::ReadOnly).path(source).unwrap();
+        let copy = Open::new(AccessMode::ReadWrite).path(destination).unwrap();
+        CopyFile::new().data(true).fd(&source, &copy).unwrap();
+        assert_eq!(
+            Metadata::from_fd(&source).unwrap().len(),
+            Metadata::from_fd(&copy).unwrap().len()
+        );
+
+        unlink(destination).unwrap();
+        directory.remove();
+    }
+}
@@ -1,6 +1,20 @@
 use crate::_sys::posix::unistd::close;
 use core::ffi::c_int;
 use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use core::mem::forget;
+#[cfg(feature = "std")]
+use std::fs::File;
+#[cfg(feature = "std")]
+use std::net::TcpStream;
+#[cfg(feature = "std")]
+use std::os::fd::{self, IntoRawFd as _};
+
+#[cfg(feature = "alloc")]
+mod watcher;
+
+#[cfg(feature = "alloc")]
+pub use watcher::{Change, Changes, Event, WatchId, Watcher};
 
 /// An interface to borrow the file descriptor from the underlying object.
 pub trait AsFd {
@@ -23,7 +37,7 @@ pub trait FromRawFd {
 #[repr(transparent)]
 #[derive(Debug)]
 pub struct BorrowedFd<'fd> {
-    _fd: c_int,
+    pub(crate) fd: c_int,
     _phantom: PhantomData<&'fd OwnedFd>,
 }
 
@@ -39,7 +53,7 @@ pub struct OwnedFd {
 impl AsFd for OwnedFd {
     fn as_fd(&self) -> BorrowedFd<'_> {
         BorrowedFd {
-            _fd: self.fd,
+            fd: self.fd,
             _phantom: PhantomData,
         }
     }
@@ -62,3 +76,155 @@ impl FromRawFd for OwnedFd {
         Self { fd }
     }
 }
+
+#[cfg(feature = "std")]
+impl AsFd for File {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        fd::AsFd::as_fd(self).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl AsFd for TcpStream {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        fd::AsFd::as_fd(self).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl AsFd for fd::OwnedFd {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        fd::AsFd::as_fd(self).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl fd::AsFd for BorrowedFd<'_> {
+    fn as_fd(&self) -> fd::BorrowedFd<'_> {
+        // SAFETY: `self` borrows an open file descriptor for at least the returned lifetime.
+        unsafe { fd::BorrowedFd::borrow_raw(self.fd) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl fd::AsRawFd for BorrowedFd<'_> {
+    fn as_raw_fd(&self) -> fd::RawFd {
+        self.fd
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'fd> From<fd::BorrowedFd<'fd>> for BorrowedFd<'fd> {
+    fn from(fd: fd::BorrowedFd<'fd>) -> Self {
+        Self {
+            fd: fd::AsRawFd::as_raw_fd(&fd),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl fd::AsFd for OwnedFd {
+    fn as_fd(&self) -> fd::BorrowedFd<'_> {
+        // SAFETY: `self` owns the open file descriptor, which outlives the borrow.
+        unsafe { fd::BorrowedFd::borrow_raw(self.fd) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl fd::AsRawFd for OwnedFd {
+    fn as_raw_fd(&self) -> fd::RawFd {
+        self.fd
+    }
+}
+
+#[cfg(feature = "std")]
+impl fd::FromRawFd for OwnedFd {
+    unsafe fn from_raw_fd(fd: fd::RawFd) -> Self {
+        Self { fd }
+    }
+}
+
+#[cfg(feature = "std")]
+impl fd::IntoRawFd for OwnedFd {
+    fn into_raw_fd(self) -> fd::RawFd {
+        let fd = self.fd;
+        // Ownership of `fd` is transferred to the caller, so it must not be closed.
+        forget(self);
+        fd
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<File> for OwnedFd {
+    fn from(file: File) -> Self {
+        Self {
+            fd: file.into_raw_fd(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<TcpStream> for OwnedFd {
+    fn from(stream: TcpStream) -> Self {
+        Self {
+            fd: stream.into_raw_fd(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<fd::OwnedFd> for OwnedFd {
+    fn from(fd: fd::OwnedFd) -> Self {
+        Self {
+            fd: fd.into_raw_fd(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<OwnedFd> for File {
+    fn from(fd: OwnedFd) -> Self {
+        fd::OwnedFd::from(fd).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<OwnedFd> for TcpStream {
+    fn from(fd: OwnedFd) -> Self {
+        fd::OwnedFd::from(fd).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<OwnedFd> for fd::OwnedFd {
+    fn from(fd: OwnedFd) -> Self {
+        // SAFETY: `fd` is open and its ownership is transferred to the new owner.
+        unsafe { fd::FromRawFd::from_raw_fd(fd::IntoRawFd::into_raw_fd(fd)) }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{AsFd, OwnedFd};
+    use crate::posix::fcntl::{AccessMode, Open};
+    use core::ffi::CStr;
+    use std::fs::File;
+    use std::io::Write;
+    use std::os::fd::AsRawFd;
+
+    #[test]
+    fn std_interop() {
+        let path = CStr::from_bytes_with_nul(b"/dev/null\0").unwrap();
+        let fd = Open::new(AccessMode::WriteOnly).path(path).unwrap();
+        let raw = fd.as_raw_fd();
+
+        let mut file = File::from(fd);
+        assert_eq!(file.as_raw_fd(), raw);
+        file.write_all(b"darwin").unwrap();
+        assert_eq!(AsFd::as_fd(&file).as_raw_fd(), raw);
+
+        let fd = OwnedFd::from(file);
+        assert_eq!(fd.as_raw_fd(), raw);
+    }
+}
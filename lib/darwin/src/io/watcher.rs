@@ -0,0 +1,403 @@
+use crate::_sys::sys::event::{
+    kevent, kqueue, EVFILT_VNODE, EV_ADD, EV_CLEAR, NOTE_ATTRIB, NOTE_DELETE, NOTE_EXTEND,
+    NOTE_LINK, NOTE_RENAME, NOTE_REVOKE, NOTE_WRITE,
+};
+use crate::_sys::sys::types::timespec;
+use crate::c::errno::{check, check_retry, Error};
+use crate::io::{AsFd, FromRawFd, OwnedFd};
+use crate::posix::fcntl::{AccessMode, Open};
+use alloc::ffi::CString;
+use alloc::vec::Vec;
+use core::ffi::{c_int, CStr};
+use core::ops::{BitOr, ControlFlow};
+use core::ptr;
+use core::time::Duration;
+
+/// The maximum number of kernel events read by a single call to `kevent(2)`.
+const EVENT_BATCH_LEN: usize = 32;
+
+/// A change to a watched file or directory.
+#[derive(Clone, Copy, Debug)]
+#[repr(u32)]
+pub enum Change {
+    /// The item was deleted (`NOTE_DELETE`).
+    Delete = NOTE_DELETE,
+    /// The item's contents changed, e.g. a file was written to or an entry was added to or removed
+    /// from a directory (`NOTE_WRITE`).
+    Write = NOTE_WRITE,
+    /// The item's size increased (`NOTE_EXTEND`).
+    Extend = NOTE_EXTEND,
+    /// The item's attributes changed (`NOTE_ATTRIB`).
+    Attributes = NOTE_ATTRIB,
+    /// The item's link count changed (`NOTE_LINK`).
+    Link = NOTE_LINK,
+    /// The item was renamed (`NOTE_RENAME`).
+    Rename = NOTE_RENAME,
+    /// Access to the item was revoked, e.g. its volume was unmounted (`NOTE_REVOKE`).
+    Revoke = NOTE_REVOKE,
+}
+
+/// A set of [`Change`]s.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Changes(u32);
+
+/// The changes to a watched item that occurred since the last time it was reported.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Event {
+    id: WatchId,
+    changes: Changes,
+}
+
+/// Identifies an item watched by a [`Watcher`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct WatchId(u64);
+
+/// Monitors a set of files and directories for changes with `kqueue(2)`.
+///
+/// Each watched item is opened for event notification only, so watching an item does not prevent
+/// its volume from being unmounted. Changes that occur between polls are coalesced into a single
+/// [`Event`] per item.
+///
+/// A watch follows the item, not its path: after an item is deleted or renamed, no further events
+/// are reported for the path. Re-watch the path to monitor a replacement item.
+#[derive(Debug)]
+pub struct Watcher {
+    kq: OwnedFd,
+    watches: Vec<Watch>,
+    next_id: u64,
+}
+
+#[derive(Debug)]
+struct Watch {
+    id: WatchId,
+    fd: OwnedFd,
+    path: CString,
+}
+
+impl BitOr for Change {
+    type Output = Changes;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        let lhs: Self::Output = self.into();
+        let rhs: Self::Output = rhs.into();
+        lhs | rhs
+    }
+}
+
+impl BitOr<Changes> for Change {
+    type Output = Changes;
+
+    fn bitor(self, rhs: Changes) -> Self::Output {
+        let lhs: Self::Output = self.into();
+        lhs | rhs
+    }
+}
+
+impl Changes {
+    /// Every kind of [`Change`].
+    pub const ALL: Self = Self(
+        NOTE_DELETE
+            | NOTE_WRITE
+            | NOTE_EXTEND
+            | NOTE_ATTRIB
+            | NOTE_LINK
+            | NOTE_RENAME
+            | NOTE_REVOKE,
+    );
+
+    /// Tests whether the given `change` is set in this change set.
+    #[must_use]
+    pub const fn has(self, change: Change) -> bool {
+        let bit: u32 = change as _;
+        self.0 & bit == bit
+    }
+
+    /// Tests whether the given `changes` are set in this change set.
+    #[must_use]
+    pub const fn has_all(self, changes: Self) -> bool {
+        self.0 & changes.0 == changes.0
+    }
+
+    /// Tests whether the given `changes` are not set in this change set.
+    #[must_use]
+    pub const fn has_none(self, changes: Self) -> bool {
+        self.0 & changes.0 == 0
+    }
+
+    /// Returns `true` if no change is set in this change set.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns a copy of this change set with `change` cleared.
+    #[must_use]
+    pub const fn without(self, change: Change) -> Self {
+        Self(self.0 & !(change as u32))
+    }
+}
+
+impl BitOr for Changes {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOr<Change> for Changes {
+    type Output = Self;
+
+    fn bitor(self, rhs: Change) -> Self::Output {
+        let rhs: Self = rhs.into();
+        self | rhs
+    }
+}
+
+impl From<Change> for Changes {
+    fn from(change: Change) -> Self {
+        Self(change as _)
+    }
+}
+
+impl Event {
+    /// Returns the changes that occurred to the item.
+    #[must_use]
+    pub const fn changes(&self) -> Changes {
+        self.changes
+    }
+
+    /// Returns the identifier of the item that changed.
+    #[must_use]
+    pub const fn id(&self) -> WatchId {
+        self.id
+    }
+}
+
+impl Watcher {
+    /// Creates a watcher that is not watching any items.
+    pub fn new() -> Result<Self, Error> {
+        // SAFETY: The function is always safe to call.
+        let kq = check(unsafe { kqueue() })?;
+        Ok(Self {
+            // SAFETY: kq is opened, the unique owner of the resource, and must be `close(2)`ed.
+            kq: unsafe { OwnedFd::from_raw_fd(kq) },
+            watches: Vec::new(),
+            next_id: 0,
+        })
+    }
+
+    /// Returns the path with which the item identified by `id` was watched, or [`None`] if the item
+    /// is not watched.
+    #[must_use]
+    pub fn path(&self, id: WatchId) -> Option<&CStr> {
+        self.watches
+            .iter()
+            .find(|watch| watch.id == id)
+            .map(|watch| watch.path.as_c_str())
+    }
+
+    /// Waits for changes to the watched items and returns them, coalesced into one [`Event`] per
+    /// item.
+    ///
+    /// If `timeout` is [`None`], waits until a change occurs. Otherwise, returns an empty vector if
+    /// no change occurs before `timeout` elapses.
+    pub fn poll(&mut self, timeout: Option<Duration>) -> Result<Vec<Event>, Error> {
+        let mut events = Vec::new();
+
+        let timeout = timeout.map(|timeout| timespec {
+            sec: timeout.as_secs().try_into().unwrap_or(isize::MAX),
+            nsec: timeout.subsec_nanos().try_into().unwrap_or_default(),
+        });
+        let mut len = self.read_events(timeout.as_ref(), &mut events)?;
+
+        // Drain the changes that are already queued so bursts (e.g., a file written in several
+        // chunks) are reported as one event.
+        let now = timespec { sec: 0, nsec: 0 };
+        while len == EVENT_BATCH_LEN {
+            len = self.read_events(Some(&now), &mut events)?;
+        }
+
+        Ok(events)
+    }
+
+    /// Waits for changes to the watched items and calls `f` with each [`Event`], until `f` returns
+    /// [`ControlFlow::Break`].
+    ///
+    /// `f` may watch or unwatch items, e.g. to re-watch a path after its item is replaced.
+    pub fn run<F>(&mut self, mut f: F) -> Result<(), Error>
+    where
+        F: FnMut(&mut Self, Event) -> ControlFlow<()>,
+    {
+        loop {
+            for event in self.poll(None)? {
+                if f(self, event).is_break() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Stops watching the item identified by `id` and closes its file descriptor. Returns `false`
+    /// if the item is not watched.
+    pub fn unwatch(&mut self, id: WatchId) -> bool {
+        let len = self.watches.len();
+        // Closing the file descriptor removes its events from the kernel queue.
+        self.watches.retain(|watch| watch.id != id);
+        self.watches.len() != len
+    }
+
+    /// Watches the file or directory at `path` for `changes`. Symbolic links are followed.
+    ///
+    /// Changes to a directory's entries are reported as [`Change::Write`] on the directory; the
+    /// entries themselves are not watched.
+    pub fn watch(
+        &mut self,
+        path: impl AsRef<CStr>,
+        changes: impl Into<Changes>,
+    ) -> Result<WatchId, Error> {
+        let path = path.as_ref();
+        let fd = Open::new(AccessMode::ReadOnly)
+            .close_on_exec(true)
+            .event_only(true)
+            .path(path)?;
+
+        let change = kevent {
+            // `open(2)` never returns a negative file descriptor.
+            ident: usize::try_from(fd.fd).unwrap_or_default(),
+            filter: EVFILT_VNODE,
+            // Clear the state after it is retrieved so each change is reported once.
+            flags: EV_ADD | EV_CLEAR,
+            fflags: changes.into().0,
+            data: 0,
+            udata: ptr::null_mut(),
+        };
+
+        // SAFETY: The change list contains one valid event and the event list is empty.
+        let _ = check_retry(|| unsafe {
+            kevent(self.kq.as_fd(), &change, 1, ptr::null_mut(), 0, ptr::null())
+        })?;
+
+        let id = WatchId(self.next_id);
+        self.next_id = self.next_id.wrapping_add(1);
+        self.watches.push(Watch {
+            id,
+            fd,
+            path: path.into(),
+        });
+        Ok(id)
+    }
+
+    /// Reads up to [`EVENT_BATCH_LEN`] kernel events and merges them into `events`. Returns the
+    /// number of kernel events read.
+    fn read_events(
+        &self,
+        timeout: Option<&timespec>,
+        events: &mut Vec<Event>,
+    ) -> Result<usize, Error> {
+        let mut buf = [kevent {
+            ident: 0,
+            filter: 0,
+            flags: 0,
+            fflags: 0,
+            data: 0,
+            udata: ptr::null_mut(),
+        }; EVENT_BATCH_LEN];
+        let timeout: *const timespec = timeout.map_or(ptr::null(), |timeout| timeout);
+
+        // UB: The buffer length is a small constant.
+        let nevents = c_int::try_from(EVENT_BATCH_LEN).unwrap_or(c_int::MAX);
+        // SAFETY: The change list is empty, `buf` can hold `nevents` events, and `timeout` is a
+        // valid pointer or `NULL`.
+        let len = check_retry(|| unsafe {
+            kevent(
+                self.kq.as_fd(),
+                ptr::null(),
+                0,
+                buf.as_mut_ptr(),
+                nevents,
+                timeout,
+            )
+        })?;
+        let len = usize::try_from(len).unwrap_or_default();
+
+        for raw in buf.iter().take(len) {
+            let Some(watch) = self
+                .watches
+                .iter()
+                .find(|watch| usize::try_from(watch.fd.fd).ok() == Some(raw.ident))
+            else {
+                continue;
+            };
+
+            let changes = Changes(raw.fflags & Changes::ALL.0);
+            if let Some(event) = events.iter_mut().find(|event| event.id == watch.id) {
+                event.changes = event.changes | changes;
+            } else {
+                events.push(Event {
+                    id: watch.id,
+                    changes,
+                });
+            }
+        }
+
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Change, Changes, Watcher};
+    use crate::io::AsFd;
+    use crate::posix::unistd::unlink;
+    use crate::tests::TemporaryDirectory;
+    use core::ffi::{c_int, c_void};
+    use core::time::Duration;
+
+    extern "C" {
+        fn write(fildes: c_int, buf: *const c_void, nbyte: usize) -> isize;
+    }
+
+    #[test]
+    fn changes() {
+        let changes = Change::Write | Change::Delete;
+        assert!(changes.has(Change::Write));
+        assert!(!changes.has(Change::Rename));
+        assert!(changes
+            .without(Change::Write)
+            .has_all(Change::Delete.into()));
+        assert!(Changes::default().is_empty());
+    }
+
+    #[test]
+    fn watch() {
+        let directory = TemporaryDirectory::new();
+        let (path, fd) = directory.create_file();
+        let path = path.as_c_str();
+        let mut watcher = Watcher::new().unwrap();
+
+        let id = watcher.watch(path, Changes::ALL).unwrap();
+        assert_eq!(watcher.path(id), Some(path));
+
+        let data = b"watch";
+        // SAFETY: The file descriptor is open for writing and `data` is valid for its length.
+        let written = unsafe { write(fd.as_fd().fd, data.as_ptr().cast(), data.len()) };
+        assert_eq!(written, 5);
+
+        let events = watcher.poll(Some(Duration::from_secs(5))).unwrap();
+        assert!(!events.is_empty());
+        assert!(events.iter().all(|event| event.id() == id));
+        assert!(events
+            .iter()
+            .any(|event| event.changes().has(Change::Write)));
+
+        drop(fd);
+        unlink(path).unwrap();
+        directory.remove();
+
+        assert!(watcher.unwatch(id));
+        assert!(!watcher.unwatch(id));
+        assert_eq!(watcher.path(id), None);
+    }
+}
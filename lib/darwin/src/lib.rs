@@ -6,14 +6,28 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 #[cfg(feature = "experimental")]
 mod _sys;
 
 #[cfg(feature = "experimental")]
 pub mod c;
 #[cfg(feature = "experimental")]
+pub mod copyfile;
+#[cfg(feature = "experimental")]
 pub mod io;
 #[cfg(feature = "experimental")]
+pub mod path;
+#[cfg(feature = "experimental")]
 pub mod posix;
 #[cfg(feature = "experimental")]
 pub mod sys;
+
+#[cfg(all(test, feature = "experimental"))]
+mod tests;
+#[cfg(feature = "experimental")]
+mod unwind;
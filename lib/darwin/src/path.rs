@@ -0,0 +1,357 @@
+//! File system paths as nul-terminated bytes, which can be passed to the system without conversion
+//! and are not assumed to be UTF-8.
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
+#[cfg(feature = "alloc")]
+use alloc::ffi::{CString, NulError};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::borrow::Borrow;
+use core::ffi::CStr;
+#[cfg(feature = "alloc")]
+use core::ops::Deref;
+
+/// The path component separator.
+const SEPARATOR: u8 = b'/';
+
+/// A borrowed file system path: a slice of nul-terminated bytes.
+///
+/// Paths are compared bytewise, so `a/b` and `a//b` are different paths.
+#[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Path {
+    inner: CStr,
+}
+
+/// An owned, mutable file system path: a buffer of nul-terminated bytes.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PathBuf {
+    inner: CString,
+}
+
+impl Path {
+    /// Borrows the nul-terminated string `s` as a path.
+    #[must_use]
+    pub const fn new(s: &CStr) -> &Self {
+        let s: *const CStr = s;
+        // LINT: `Path` is a transparent wrapper around `CStr`, so the pointer metadata is the same.
+        #[allow(clippy::as_conversions)]
+        let path = s as *const Self;
+        // SAFETY: `Path` has the same layout as `CStr` and `path` has the lifetime of `s`.
+        unsafe { &*path }
+    }
+
+    /// Returns the path as a nul-terminated string.
+    #[must_use]
+    pub const fn as_c_str(&self) -> &CStr {
+        &self.inner
+    }
+
+    /// Returns the bytes of the path, without the nul terminator.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.inner.to_bytes()
+    }
+
+    /// Returns the final component of the path, ignoring trailing separators, or [`None`] if the
+    /// path is empty, is the root directory, or ends in `..`.
+    #[must_use]
+    pub fn file_name(&self) -> Option<&[u8]> {
+        let bytes = trim_trailing_separators(self.as_bytes());
+        let start = bytes
+            .iter()
+            .rposition(|&b| b == SEPARATOR)
+            .map_or(0, |i| i.saturating_add(1));
+        let name = bytes.get(start..)?;
+        (!name.is_empty() && name != b"..").then_some(name)
+    }
+
+    /// Returns `true` if the path starts with the root directory.
+    #[must_use]
+    pub fn is_absolute(&self) -> bool {
+        self.as_bytes().first() == Some(&SEPARATOR)
+    }
+
+    /// Returns a new path with `path` appended to this path, separated by `/`.
+    ///
+    /// If `path` is absolute, it replaces this path.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn join(&self, path: impl AsRef<Self>) -> PathBuf {
+        let mut buf = self.to_path_buf();
+        buf.push(path);
+        buf
+    }
+
+    /// Returns the path without its final component, or [`None`] if the path has no final
+    /// component (e.g., it is empty or the root directory).
+    ///
+    /// The parent of a relative path with a single component (e.g., `file`) is the empty path.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn parent(&self) -> Option<PathBuf> {
+        let bytes = trim_trailing_separators(self.as_bytes());
+        if bytes.is_empty() || bytes == [SEPARATOR] {
+            return None;
+        }
+
+        let parent = match bytes.iter().rposition(|&b| b == SEPARATOR) {
+            Some(i) => {
+                let parent = bytes.get(..i).map_or(&[][..], trim_trailing_separators);
+                // Keep the root directory if the final component's parent is the root.
+                if parent.is_empty() {
+                    bytes.get(..1).unwrap_or_default()
+                } else {
+                    parent
+                }
+            }
+            None => &[],
+        };
+        Some(PathBuf::from_bytes_unchecked(parent.to_vec()))
+    }
+
+    /// Returns the path as a string, replacing invalid UTF-8 sequences with U+FFFD.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        self.inner.to_string_lossy()
+    }
+
+    /// Copies the path into a new [`PathBuf`].
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_path_buf(&self) -> PathBuf {
+        PathBuf {
+            inner: self.inner.into(),
+        }
+    }
+}
+
+impl AsRef<CStr> for Path {
+    fn as_ref(&self) -> &CStr {
+        &self.inner
+    }
+}
+
+impl AsRef<Self> for Path {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsRef<Path> for CStr {
+    fn as_ref(&self) -> &Path {
+        Path::new(self)
+    }
+}
+
+impl<'path> From<&'path CStr> for &'path Path {
+    fn from(s: &'path CStr) -> Self {
+        Path::new(s)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ToOwned for Path {
+    type Owned = PathBuf;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_path_buf()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PathBuf {
+    /// Creates an empty path.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a path from `bytes`, which must not contain a nul byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NulError`] if `bytes` contains a nul byte.
+    pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Result<Self, NulError> {
+        CString::new(bytes).map(Self::from)
+    }
+
+    /// Borrows the path.
+    #[must_use]
+    pub fn as_path(&self) -> &Path {
+        Path::new(&self.inner)
+    }
+
+    /// Returns the path as a nul-terminated string.
+    #[must_use]
+    pub fn into_c_string(self) -> CString {
+        self.inner
+    }
+
+    /// Removes the final component of the path. Returns `false`, leaving the path unchanged, if
+    /// the path has no final component.
+    pub fn pop(&mut self) -> bool {
+        self.parent().map(|parent| *self = parent).is_some()
+    }
+
+    /// Appends `path` to this path, separated by `/`.
+    ///
+    /// If `path` is absolute, it replaces this path.
+    pub fn push(&mut self, path: impl AsRef<Path>) {
+        let path = path.as_ref().as_bytes();
+        let mut bytes = core::mem::take(&mut self.inner).into_bytes();
+
+        if path.first() == Some(&SEPARATOR) {
+            bytes.clear();
+        } else if !bytes.is_empty() && bytes.last() != Some(&SEPARATOR) {
+            bytes.push(SEPARATOR);
+        }
+        bytes.extend_from_slice(path);
+
+        *self = Self::from_bytes_unchecked(bytes);
+    }
+
+    /// Creates a path from `bytes`, which are known not to contain a nul byte.
+    fn from_bytes_unchecked(bytes: Vec<u8>) -> Self {
+        // SAFETY: Every caller passes bytes taken from nul-terminated strings, without the
+        // terminator.
+        let inner = unsafe { CString::from_vec_unchecked(bytes) };
+        Self { inner }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<CStr> for PathBuf {
+    fn as_ref(&self) -> &CStr {
+        &self.inner
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<Path> for PathBuf {
+    fn as_ref(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Borrow<Path> for PathBuf {
+    fn borrow(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Deref for PathBuf {
+    type Target = Path;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_path()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<CString> for PathBuf {
+    fn from(inner: CString) -> Self {
+        Self { inner }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&Path> for PathBuf {
+    fn from(path: &Path) -> Self {
+        path.to_path_buf()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<PathBuf> for CString {
+    fn from(path: PathBuf) -> Self {
+        path.inner
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<&std::path::Path> for PathBuf {
+    type Error = NulError;
+
+    fn try_from(path: &std::path::Path) -> Result<Self, Self::Error> {
+        use std::os::unix::ffi::OsStrExt;
+        Self::from_bytes(path.as_os_str().as_bytes())
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<PathBuf> for std::path::PathBuf {
+    fn from(path: PathBuf) -> Self {
+        use std::os::unix::ffi::OsStringExt;
+        std::ffi::OsString::from_vec(path.inner.into_bytes()).into()
+    }
+}
+
+/// Returns `bytes` without trailing separators, unless `bytes` is only separators, in which case
+/// the root directory is returned.
+fn trim_trailing_separators(bytes: &[u8]) -> &[u8] {
+    let end = bytes
+        .iter()
+        .rposition(|&b| b != SEPARATOR)
+        .map_or_else(|| bytes.len().min(1), |i| i.saturating_add(1));
+    bytes.get(..end).unwrap_or(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Path;
+    use core::ffi::CStr;
+
+    fn path(bytes: &[u8]) -> &Path {
+        Path::new(CStr::from_bytes_with_nul(bytes).unwrap())
+    }
+
+    #[test]
+    fn file_name() {
+        assert_eq!(path(b"/usr/lib\0").file_name(), Some(&b"lib"[..]));
+        assert_eq!(path(b"/usr/lib//\0").file_name(), Some(&b"lib"[..]));
+        assert_eq!(path(b"file\0").file_name(), Some(&b"file"[..]));
+        assert_eq!(path(b"/\0").file_name(), None);
+        assert_eq!(path(b"..\0").file_name(), None);
+        assert_eq!(path(b"\0").file_name(), None);
+    }
+
+    #[test]
+    fn is_absolute() {
+        assert!(path(b"/usr\0").is_absolute());
+        assert!(!path(b"usr\0").is_absolute());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn join() {
+        let usr = path(b"/usr\0");
+        assert_eq!(usr.join(path(b"lib\0")).as_bytes(), b"/usr/lib");
+        assert_eq!(usr.join(path(b"/etc\0")).as_bytes(), b"/etc");
+        assert_eq!(path(b"/\0").join(path(b"etc\0")).as_bytes(), b"/etc");
+        assert_eq!(path(b"\0").join(path(b"etc\0")).as_bytes(), b"etc");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn parent() {
+        assert_eq!(path(b"/usr/lib\0").parent().unwrap().as_bytes(), b"/usr");
+        assert_eq!(path(b"/usr/lib/\0").parent().unwrap().as_bytes(), b"/usr");
+        assert_eq!(path(b"/usr\0").parent().unwrap().as_bytes(), b"/");
+        assert_eq!(path(b"file\0").parent().unwrap().as_bytes(), b"");
+        assert!(path(b"/\0").parent().is_none());
+        assert!(path(b"\0").parent().is_none());
+
+        let mut buf = path(b"/usr/lib\0").to_path_buf();
+        assert!(buf.pop());
+        assert!(buf.pop());
+        assert!(!buf.pop());
+        assert_eq!(buf.as_c_str().to_bytes_with_nul(), b"/\0");
+    }
+}
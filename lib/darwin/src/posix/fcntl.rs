@@ -1,8 +1,9 @@
-use crate::_sys::posix::fcntl::{open, O_ACCMODE, O_CLOEXEC, O_RDONLY, O_RDWR, O_WRONLY};
-use crate::c::errno::check_retry;
+use crate::_sys::posix::fcntl::{
+    open, O_ACCMODE, O_CLOEXEC, O_EVTONLY, O_RDONLY, O_RDWR, O_WRONLY,
+};
+use crate::c::errno::{check_retry, Error};
 use crate::io::{FromRawFd, OwnedFd};
 use core::ffi::CStr;
-use core::num::NonZeroI32;
 
 /// Specifies the type of I/O access granted to the file.
 #[derive(Clone, Copy, Debug)]
@@ -40,7 +41,14 @@ impl Open {
         self.set_flag_enabled(O_CLOEXEC, close_on_exec)
     }
 
-    pub fn path(self, path: impl AsRef<CStr>) -> Result<OwnedFd, NonZeroI32> {
+    /// Opens the file only to receive event notifications (e.g., through `kqueue(2)`), which does
+    /// not prevent the volume containing the file from being unmounted.
+    #[must_use]
+    pub const fn event_only(self, event_only: bool) -> Self {
+        self.set_flag_enabled(O_EVTONLY, event_only)
+    }
+
+    pub fn path(self, path: impl AsRef<CStr>) -> Result<OwnedFd, Error> {
         let path = path.as_ref().as_ptr();
         let oflag = self.oflag;
 
@@ -64,7 +72,7 @@ impl Open {
 #[cfg(test)]
 mod tests {
     use super::{AccessMode, Open};
-    use crate::_sys::posix::fcntl::{O_CLOEXEC, O_RDONLY, O_RDWR, O_WRONLY};
+    use crate::_sys::posix::fcntl::{O_CLOEXEC, O_EVTONLY, O_RDONLY, O_RDWR, O_WRONLY};
     use crate::c::errno::Error;
     use core::ffi::CStr;
 
@@ -83,6 +91,9 @@ mod tests {
 
         assert_eq!(o().close_on_exec(true).oflag, O_CLOEXEC);
         assert_eq!(o().close_on_exec(true).close_on_exec(false).oflag, 0);
+
+        assert_eq!(o().event_only(true).oflag, O_EVTONLY);
+        assert_eq!(o().event_only(true).event_only(false).oflag, 0);
     }
 
     #[test]
@@ -90,7 +101,7 @@ mod tests {
         let path = CStr::from_bytes_with_nul(b"/this/path/does/not/exist\0").unwrap();
         let result = Open::new(AccessMode::ReadOnly).path(path);
 
-        assert_eq!(result.unwrap_err().get(), Error::NotFound as _);
+        assert_eq!(result.unwrap_err(), Error::NotFound);
     }
 
     #[test]
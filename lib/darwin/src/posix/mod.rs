@@ -1,2 +1,7 @@
 pub mod fcntl;
+pub mod resource;
+pub mod signal;
+#[cfg(feature = "alloc")]
+pub mod sync;
+pub mod thread;
 pub mod unistd;
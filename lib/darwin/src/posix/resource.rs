@@ -0,0 +1,196 @@
+use crate::_sys::sys::resource::{
+    getrlimit, getrusage, rlimit, rusage, setrlimit, timeval, RLIMIT_AS, RLIMIT_CORE, RLIMIT_CPU,
+    RLIMIT_DATA, RLIMIT_FSIZE, RLIMIT_MEMLOCK, RLIMIT_NOFILE, RLIMIT_NPROC, RLIMIT_STACK,
+    RLIM_INFINITY, RUSAGE_CHILDREN, RUSAGE_SELF,
+};
+use crate::c::errno::{check, Error};
+use core::mem::MaybeUninit;
+use core::time::Duration;
+
+/// A system resource whose consumption by the process can be limited.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(i32)]
+pub enum Resource {
+    /// The maximum amount of CPU time, in seconds, the process may use (`RLIMIT_CPU`).
+    Cpu = RLIMIT_CPU,
+    /// The largest size, in bytes, of a file the process may create (`RLIMIT_FSIZE`).
+    FileSize = RLIMIT_FSIZE,
+    /// The maximum size, in bytes, of the process's data segment (`RLIMIT_DATA`).
+    Data = RLIMIT_DATA,
+    /// The maximum size, in bytes, of the main thread's stack (`RLIMIT_STACK`).
+    Stack = RLIMIT_STACK,
+    /// The largest size, in bytes, of a core file the process may create (`RLIMIT_CORE`).
+    Core = RLIMIT_CORE,
+    /// The maximum size, in bytes, of the process's address space (`RLIMIT_AS`).
+    AddressSpace = RLIMIT_AS,
+    /// The maximum size, in bytes, the process may lock into memory (`RLIMIT_MEMLOCK`).
+    MemoryLock = RLIMIT_MEMLOCK,
+    /// The maximum number of simultaneous processes for the user ID (`RLIMIT_NPROC`).
+    Processes = RLIMIT_NPROC,
+    /// One more than the largest file descriptor the process may open (`RLIMIT_NOFILE`).
+    OpenFiles = RLIMIT_NOFILE,
+}
+
+/// The soft and hard limits on the consumption of a [`Resource`]. A value of [`None`] indicates
+/// there is no limit.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Limit {
+    /// The soft limit, which the system enforces. A process may raise it up to `maximum`.
+    pub current: Option<u64>,
+
+    /// The hard limit, which bounds `current`. Only the super-user may raise it.
+    pub maximum: Option<u64>,
+}
+
+/// The processes whose resource usage is reported by [`usage`].
+#[derive(Clone, Copy, Debug)]
+#[repr(i32)]
+pub enum Who {
+    /// The calling process (`RUSAGE_SELF`).
+    Process = RUSAGE_SELF,
+    /// The terminated and waited-for children of the calling process (`RUSAGE_CHILDREN`).
+    Children = RUSAGE_CHILDREN,
+}
+
+/// The resources consumed by one or more processes.
+#[derive(Clone, Copy, Debug)]
+pub struct Usage {
+    rusage: rusage,
+}
+
+/// Gets the soft and hard limits on the consumption of `resource` by the calling process.
+pub fn get_limit(resource: Resource) -> Result<Limit, Error> {
+    let mut rlimit = rlimit {
+        rlim_cur: 0,
+        rlim_max: 0,
+    };
+
+    // SAFETY: resource is a valid resource and rlimit is a valid buffer that the operating system
+    // will not write outside the bounds of.
+    let _ = check(unsafe { getrlimit(resource as _, &mut rlimit) })?;
+
+    Ok(Limit {
+        current: from_rlim(rlimit.rlim_cur),
+        maximum: from_rlim(rlimit.rlim_max),
+    })
+}
+
+/// Sets the soft and hard limits on the consumption of `resource` by the calling process.
+///
+/// Setting [`Resource::OpenFiles`] above `OPEN_MAX` (as reported by `sysctl kern.maxfilesperproc`)
+/// fails with [`Error::InvalidArgument`], even if the hard limit is unlimited.
+pub fn set_limit(resource: Resource, limit: Limit) -> Result<(), Error> {
+    let rlimit = rlimit {
+        rlim_cur: limit.current.unwrap_or(RLIM_INFINITY),
+        rlim_max: limit.maximum.unwrap_or(RLIM_INFINITY),
+    };
+
+    // SAFETY: resource is a valid resource and rlimit is a valid structure that the operating
+    // system will not write to.
+    let _ = check(unsafe { setrlimit(resource as _, &rlimit) })?;
+    Ok(())
+}
+
+/// Gets the resources consumed by `who`.
+pub fn usage(who: Who) -> Result<Usage, Error> {
+    let mut usage = Usage {
+        // SAFETY: rusage is a scalar structure that is safe to zero-initialize.
+        rusage: unsafe { MaybeUninit::<rusage>::zeroed().assume_init() },
+    };
+
+    // SAFETY: who is a valid value and the buffer is guaranteed to be valid. The operating system
+    // will not write outside the bounds of the buffer.
+    let _ = check(unsafe { getrusage(who as _, &mut usage.rusage) })?;
+
+    Ok(usage)
+}
+
+const fn from_rlim(value: u64) -> Option<u64> {
+    if value == RLIM_INFINITY {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+pub(crate) fn from_timeval(timeval: timeval) -> Duration {
+    let secs = timeval.tv_sec.try_into().unwrap_or_default();
+    let micros: u32 = timeval.tv_usec.try_into().unwrap_or_default();
+    Duration::new(secs, micros.saturating_mul(1_000))
+}
+
+impl Usage {
+    /// The number of times a context switch occurred because a higher priority process became
+    /// runnable or the time slice was exhausted.
+    #[must_use]
+    pub fn involuntary_context_switches(&self) -> u64 {
+        self.rusage.ru_nivcsw.try_into().unwrap_or_default()
+    }
+
+    /// The number of page faults serviced that required I/O.
+    #[must_use]
+    pub fn major_page_faults(&self) -> u64 {
+        self.rusage.ru_majflt.try_into().unwrap_or_default()
+    }
+
+    /// The largest resident set size used, in bytes.
+    #[must_use]
+    pub fn max_resident_set_size(&self) -> u64 {
+        self.rusage.ru_maxrss.try_into().unwrap_or_default()
+    }
+
+    /// The number of page faults serviced without any I/O (e.g., by reclaiming a page).
+    #[must_use]
+    pub fn minor_page_faults(&self) -> u64 {
+        self.rusage.ru_minflt.try_into().unwrap_or_default()
+    }
+
+    /// The total amount of time spent executing in kernel mode.
+    #[must_use]
+    pub fn system_time(&self) -> Duration {
+        from_timeval(self.rusage.ru_stime)
+    }
+
+    /// The total amount of time spent executing in user mode.
+    #[must_use]
+    pub fn user_time(&self) -> Duration {
+        from_timeval(self.rusage.ru_utime)
+    }
+
+    /// The number of times a context switch occurred because the process voluntarily gave up the
+    /// processor (e.g., to wait for a resource).
+    #[must_use]
+    pub fn voluntary_context_switches(&self) -> u64 {
+        self.rusage.ru_nvcsw.try_into().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_limit, set_limit, usage, Resource, Who};
+
+    #[test]
+    fn limit() {
+        let limit = get_limit(Resource::OpenFiles).unwrap();
+        let current = limit.current.unwrap();
+        assert!(current > 3);
+
+        let mut lowered = limit;
+        lowered.current = Some(current - 1);
+        set_limit(Resource::OpenFiles, lowered).unwrap();
+        assert_eq!(get_limit(Resource::OpenFiles).unwrap(), lowered);
+
+        set_limit(Resource::OpenFiles, limit).unwrap();
+        assert_eq!(get_limit(Resource::OpenFiles).unwrap(), limit);
+    }
+
+    #[test]
+    fn usage_self() {
+        let usage = usage(Who::Process).unwrap();
+
+        // The test harness has certainly used at least some memory and CPU time.
+        assert!(usage.max_resident_set_size() > 0);
+        assert!(!(usage.user_time() + usage.system_time()).is_zero());
+    }
+}
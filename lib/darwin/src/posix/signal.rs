@@ -0,0 +1,118 @@
+//! Unix signals.
+
+use crate::_sys::posix::signal::{
+    raise, sigaction, SIGABRT, SIGALRM, SIGBUS, SIGCHLD, SIGCONT, SIGEMT, SIGFPE, SIGHUP, SIGILL,
+    SIGINFO, SIGINT, SIGIO, SIGKILL, SIGPIPE, SIGPROF, SIGQUIT, SIGSEGV, SIGSTOP, SIGSYS, SIGTERM,
+    SIGTRAP, SIGTSTP, SIGTTIN, SIGTTOU, SIGURG, SIGUSR1, SIGUSR2, SIGVTALRM, SIGWINCH, SIGXCPU,
+    SIGXFSZ, SIG_IGN,
+};
+use crate::c::errno::{check, Error};
+use core::ptr;
+
+/// A signal that can be delivered to a process.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(i32)]
+pub enum Signal {
+    /// The controlling terminal hung up (`SIGHUP`).
+    Hangup = SIGHUP,
+    /// The user interrupted the process, e.g. by typing Control-C (`SIGINT`).
+    Interrupt = SIGINT,
+    /// The user requested the process quit and dump core (`SIGQUIT`).
+    Quit = SIGQUIT,
+    /// The process executed an illegal instruction (`SIGILL`).
+    IllegalInstruction = SIGILL,
+    /// The process hit a trace or breakpoint trap (`SIGTRAP`).
+    Trap = SIGTRAP,
+    /// The process aborted, e.g. by calling `abort(3)` (`SIGABRT`).
+    Abort = SIGABRT,
+    /// The process executed an emulator trap (`SIGEMT`).
+    EmulatorTrap = SIGEMT,
+    /// An arithmetic error occurred, e.g. an integer division by zero (`SIGFPE`).
+    FloatingPointException = SIGFPE,
+    /// The process is terminated immediately. It cannot be caught or ignored (`SIGKILL`).
+    Kill = SIGKILL,
+    /// The process accessed memory that does not exist (`SIGBUS`).
+    Bus = SIGBUS,
+    /// The process accessed memory it does not have permission to access (`SIGSEGV`).
+    SegmentationFault = SIGSEGV,
+    /// The process made a non-existent system call (`SIGSYS`).
+    BadSystemCall = SIGSYS,
+    /// The process wrote to a pipe or socket with no reader (`SIGPIPE`).
+    Pipe = SIGPIPE,
+    /// A timer set by `alarm(3)` expired (`SIGALRM`).
+    Alarm = SIGALRM,
+    /// The process was asked to terminate (`SIGTERM`).
+    Terminate = SIGTERM,
+    /// Urgent data is available on a socket (`SIGURG`).
+    Urgent = SIGURG,
+    /// The process is stopped. It cannot be caught or ignored (`SIGSTOP`).
+    Stop = SIGSTOP,
+    /// The user requested the process stop, e.g. by typing Control-Z (`SIGTSTP`).
+    TerminalStop = SIGTSTP,
+    /// The stopped process was continued (`SIGCONT`).
+    Continue = SIGCONT,
+    /// A child process stopped or exited (`SIGCHLD`).
+    Child = SIGCHLD,
+    /// A background process read from its controlling terminal (`SIGTTIN`).
+    TerminalInput = SIGTTIN,
+    /// A background process wrote to its controlling terminal (`SIGTTOU`).
+    TerminalOutput = SIGTTOU,
+    /// I/O is possible on a file descriptor (`SIGIO`).
+    Io = SIGIO,
+    /// The process exceeded its CPU time limit (`SIGXCPU`).
+    CpuLimit = SIGXCPU,
+    /// The process exceeded its file size limit (`SIGXFSZ`).
+    FileSizeLimit = SIGXFSZ,
+    /// A virtual timer expired (`SIGVTALRM`).
+    VirtualAlarm = SIGVTALRM,
+    /// A profiling timer expired (`SIGPROF`).
+    Profile = SIGPROF,
+    /// The size of the controlling terminal's window changed (`SIGWINCH`).
+    WindowChange = SIGWINCH,
+    /// The user requested status, e.g. by typing Control-T (`SIGINFO`).
+    Info = SIGINFO,
+    /// A user-defined signal (`SIGUSR1`).
+    User1 = SIGUSR1,
+    /// A user-defined signal (`SIGUSR2`).
+    User2 = SIGUSR2,
+}
+
+impl Signal {
+    /// Ignores the signal: when it is delivered to the process, the default action (e.g.,
+    /// terminating the process) is not taken.
+    ///
+    /// Ignoring a signal does not prevent it from being observed with `kqueue(2)`'s
+    /// `EVFILT_SIGNAL` filter, which is how dispatch sources observe signals.
+    pub fn ignore(self) -> Result<(), Error> {
+        let action = sigaction {
+            handler: SIG_IGN,
+            mask: 0,
+            flags: 0,
+        };
+
+        // SAFETY: `action` is a valid signal action and the previous action is not requested.
+        let _ = check(unsafe { sigaction(self as _, &action, ptr::null_mut()) })?;
+        Ok(())
+    }
+
+    /// Sends the signal to the calling thread.
+    pub fn raise(self) -> Result<(), Error> {
+        // SAFETY: The function is always safe to call.
+        let _ = check(unsafe { raise(self as _) })?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Signal;
+    use crate::c::errno::Error;
+
+    #[test]
+    fn ignore() {
+        Signal::User2.ignore().unwrap();
+        Signal::User2.raise().unwrap();
+
+        assert_eq!(Signal::Kill.ignore().unwrap_err(), Error::InvalidArgument);
+    }
+}
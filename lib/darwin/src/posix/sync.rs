@@ -0,0 +1,741 @@
+//! Synchronization primitives built on the POSIX threads (`pthread`) interface.
+//!
+//! These types are alternatives to the types in `std::sync` for crates that cannot depend on `std`.
+//! Unlike their `std` counterparts, they do not implement poisoning: a panic while a lock is held
+//! releases the lock when its guard is dropped during unwinding, and the next holder observes the
+//! protected value in whatever state the panicking thread left it.
+//!
+//! A `pthread` primitive must not be moved once it is in use, so each type allocates its primitive
+//! on the heap the first time it is used. This allows the types to be constructed in a `const`
+//! context (e.g., in a `static` item) and moved freely.
+//!
+//! # Panics
+//!
+//! The `pthread` functions only fail due to programming errors (e.g., a thread attempting to
+//! acquire a lock it already holds, or waiting on a [`Condvar`] with two different mutexes). These
+//! failures are reported by panicking.
+
+use crate::_sys::posix::pthread::{
+    pthread_cond_broadcast, pthread_cond_destroy, pthread_cond_init, pthread_cond_signal,
+    pthread_cond_t, pthread_cond_timedwait_relative_np, pthread_cond_wait, pthread_mutex_destroy,
+    pthread_mutex_init, pthread_mutex_lock, pthread_mutex_t, pthread_mutex_trylock,
+    pthread_mutex_unlock, pthread_mutexattr_destroy, pthread_mutexattr_init,
+    pthread_mutexattr_setprotocol, pthread_mutexattr_t, pthread_rwlock_destroy,
+    pthread_rwlock_init, pthread_rwlock_rdlock, pthread_rwlock_t, pthread_rwlock_tryrdlock,
+    pthread_rwlock_trywrlock, pthread_rwlock_unlock, pthread_rwlock_wrlock, PTHREAD_PRIO_INHERIT,
+    PTHREAD_PRIO_NONE,
+};
+use crate::_sys::sys::types::timespec;
+use crate::c::errno::{check_error_number, Error};
+use alloc::boxed::Box;
+use core::cell::UnsafeCell;
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use core::time::Duration;
+
+/// A mutual exclusion primitive useful for protecting shared data.
+pub struct Mutex<T: ?Sized> {
+    raw: AtomicPtr<pthread_mutex_t>,
+    protocol: i32,
+    data: UnsafeCell<T>,
+}
+
+/// An RAII guard that releases the [`Mutex`] when dropped.
+#[must_use = "if unused the Mutex will immediately unlock"]
+pub struct MutexGuard<'mutex, T: ?Sized> {
+    mutex: &'mutex Mutex<T>,
+    // A mutex must be unlocked by the thread that locked it.
+    _not_send: PhantomData<*const ()>,
+}
+
+/// A reader-writer lock that allows any number of readers or at most one writer at any point in
+/// time.
+pub struct RwLock<T: ?Sized> {
+    raw: AtomicPtr<pthread_rwlock_t>,
+    data: UnsafeCell<T>,
+}
+
+/// An RAII guard that releases shared read access of the [`RwLock`] when dropped.
+#[must_use = "if unused the RwLock will immediately unlock"]
+pub struct RwLockReadGuard<'lock, T: ?Sized> {
+    lock: &'lock RwLock<T>,
+    // A lock must be unlocked by the thread that locked it.
+    _not_send: PhantomData<*const ()>,
+}
+
+/// An RAII guard that releases exclusive write access of the [`RwLock`] when dropped.
+#[must_use = "if unused the RwLock will immediately unlock"]
+pub struct RwLockWriteGuard<'lock, T: ?Sized> {
+    lock: &'lock RwLock<T>,
+    // A lock must be unlocked by the thread that locked it.
+    _not_send: PhantomData<*const ()>,
+}
+
+/// A condition variable that blocks a thread until it is notified of an event.
+///
+/// A condition variable must only be used with one [`Mutex`] at a time. Waiting on the same
+/// condition variable with different mutexes concurrently panics.
+pub struct Condvar {
+    raw: AtomicPtr<pthread_cond_t>,
+}
+
+/// Returns the primitive in `slot`, allocating and initializing it with `init` if this is the first
+/// use. If another thread wins the race to initialize the slot, the primitive is destroyed with
+/// `destroy` and the winner's primitive is returned.
+fn get_or_init<T>(
+    slot: &AtomicPtr<T>,
+    init: impl FnOnce(*mut T) -> Result<(), Error>,
+    destroy: unsafe extern "C" fn(*mut T) -> i32,
+) -> *mut T {
+    let raw = slot.load(Ordering::Acquire);
+    if !raw.is_null() {
+        return raw;
+    }
+
+    let new = Box::into_raw(Box::new(MaybeUninit::<T>::uninit())).cast::<T>();
+    if let Err(err) = init(new) {
+        // SAFETY: new was allocated above and was never initialized.
+        drop(unsafe { Box::from_raw(new.cast::<MaybeUninit<T>>()) });
+        panic!("failed to initialize the pthread primitive: {err}");
+    }
+
+    match slot.compare_exchange(ptr::null_mut(), new, Ordering::AcqRel, Ordering::Acquire) {
+        Ok(_) => new,
+        Err(existing) => {
+            // SAFETY: new was initialized above and was never shared with another thread.
+            unsafe { free(new, destroy) };
+            existing
+        }
+    }
+}
+
+/// Destroys and deallocates a primitive allocated by [`get_or_init`].
+///
+/// # Safety
+///
+/// `raw` must be null or have been returned by [`get_or_init`], and it must not be in use.
+unsafe fn free<T>(raw: *mut T, destroy: unsafe extern "C" fn(*mut T) -> i32) {
+    if raw.is_null() {
+        return;
+    }
+
+    // SAFETY: The caller guarantees raw is initialized and is not in use.
+    let _ = unsafe { destroy(raw) };
+
+    // SAFETY: The caller guarantees raw was allocated by get_or_init.
+    drop(unsafe { Box::from_raw(raw.cast::<MaybeUninit<T>>()) });
+}
+
+/// Panics if `result` is a non-zero error number, describing the failed `operation`.
+#[track_caller]
+fn expect(result: i32, operation: &str) {
+    if let Err(err) = check_error_number(result) {
+        panic!("{operation} failed: {err}");
+    }
+}
+
+impl<T> Mutex<T> {
+    /// Constructs a new mutex in an unlocked state.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self {
+            raw: AtomicPtr::new(ptr::null_mut()),
+            protocol: PTHREAD_PRIO_NONE,
+            data: UnsafeCell::new(value),
+        }
+    }
+
+    /// Constructs a new mutex in an unlocked state that uses the priority inheritance protocol: a
+    /// thread holding the mutex runs at the highest priority of the threads waiting to acquire it,
+    /// which avoids priority inversion.
+    #[inline]
+    pub const fn with_priority_inheritance(value: T) -> Self {
+        Self {
+            raw: AtomicPtr::new(ptr::null_mut()),
+            protocol: PTHREAD_PRIO_INHERIT,
+            data: UnsafeCell::new(value),
+        }
+    }
+
+    /// Consumes the mutex and returns the protected value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        let this = ManuallyDrop::new(self);
+
+        // SAFETY: The mutex is owned, so it is not in use.
+        unsafe { free(this.raw.load(Ordering::Relaxed), pthread_mutex_destroy) };
+
+        // SAFETY: The data is moved exactly once, here, because `this` is never dropped.
+        unsafe { ptr::read(&this.data) }.into_inner()
+    }
+}
+
+impl<T: ?Sized> Mutex<T> {
+    /// Returns a mutable reference to the protected value. No locking is required because the
+    /// mutable borrow statically guarantees exclusive access.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+
+    /// Acquires the mutex, blocking the current thread until it is able to do so.
+    ///
+    /// Attempting to acquire the mutex while the current thread holds it deadlocks.
+    #[inline]
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        // SAFETY: The primitive is initialized and is not destroyed while self is borrowed.
+        expect(
+            unsafe { pthread_mutex_lock(self.raw()) },
+            "pthread_mutex_lock",
+        );
+        MutexGuard {
+            mutex: self,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Attempts to acquire the mutex without blocking. Returns [`None`] if the mutex is held.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex could not be initialized.
+    #[inline]
+    pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        // SAFETY: The primitive is initialized and is not destroyed while self is borrowed.
+        match check_error_number(unsafe { pthread_mutex_trylock(self.raw()) }) {
+            Ok(()) => Some(MutexGuard {
+                mutex: self,
+                _not_send: PhantomData,
+            }),
+            Err(Error::ResourceBusy) => None,
+            Err(err) => panic!("pthread_mutex_trylock failed: {err}"),
+        }
+    }
+
+    fn raw(&self) -> *mut pthread_mutex_t {
+        get_or_init(
+            &self.raw,
+            |raw| {
+                let mut attr = MaybeUninit::<pthread_mutexattr_t>::uninit();
+
+                // SAFETY: attr is a valid write destination.
+                check_error_number(unsafe { pthread_mutexattr_init(attr.as_mut_ptr()) })?;
+
+                // SAFETY: attr was initialized above and the function validates its argument.
+                let mut result = check_error_number(unsafe {
+                    pthread_mutexattr_setprotocol(attr.as_mut_ptr(), self.protocol)
+                });
+                if result.is_ok() {
+                    // SAFETY: attr was initialized above and raw is a valid write destination.
+                    result = check_error_number(unsafe { pthread_mutex_init(raw, attr.as_ptr()) });
+                }
+
+                // SAFETY: attr was initialized above and is not used again.
+                let _ = unsafe { pthread_mutexattr_destroy(attr.as_mut_ptr()) };
+                result
+            },
+            pthread_mutex_destroy,
+        )
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for Mutex<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("Mutex");
+        match self.try_lock() {
+            Some(guard) => d.field("data", &&*guard).finish_non_exhaustive(),
+            None => d
+                .field("data", &format_args!("<locked>"))
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+impl<T: Default> Default for Mutex<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: ?Sized> Drop for Mutex<T> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The mutex is being dropped, so it is not in use.
+        unsafe { free(*self.raw.get_mut(), pthread_mutex_destroy) };
+    }
+}
+
+impl<T> From<T> for Mutex<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+// SAFETY: The primitive is heap allocated, so moving the mutex to another thread does not move it.
+unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
+
+// SAFETY: The mutex guarantees exclusive access to the value, which may be accessed from any
+// thread that holds the lock (hence `T: Send`).
+unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
+
+impl<T: ?Sized> Deref for MutexGuard<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: The guard holds the lock, which guarantees exclusive access.
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MutexGuard<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: The guard holds the lock, which guarantees exclusive access.
+        unsafe { &mut *self.mutex.data.get() }
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for MutexGuard<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized> Drop for MutexGuard<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The guard holds the lock, so the primitive is initialized and locked by this
+        // thread.
+        let _ = unsafe { pthread_mutex_unlock(self.mutex.raw.load(Ordering::Relaxed)) };
+    }
+}
+
+// SAFETY: Sharing the guard only shares a reference to the value.
+unsafe impl<T: ?Sized + Sync> Sync for MutexGuard<'_, T> {}
+
+impl<T> RwLock<T> {
+    /// Constructs a new reader-writer lock in an unlocked state.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self {
+            raw: AtomicPtr::new(ptr::null_mut()),
+            data: UnsafeCell::new(value),
+        }
+    }
+
+    /// Consumes the lock and returns the protected value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        let this = ManuallyDrop::new(self);
+
+        // SAFETY: The lock is owned, so it is not in use.
+        unsafe { free(this.raw.load(Ordering::Relaxed), pthread_rwlock_destroy) };
+
+        // SAFETY: The data is moved exactly once, here, because `this` is never dropped.
+        unsafe { ptr::read(&this.data) }.into_inner()
+    }
+}
+
+impl<T: ?Sized> RwLock<T> {
+    /// Returns a mutable reference to the protected value. No locking is required because the
+    /// mutable borrow statically guarantees exclusive access.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+
+    /// Acquires shared read access, blocking the current thread until it is able to do so.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current thread holds write access, or if the maximum number of readers is
+    /// exceeded.
+    #[inline]
+    pub fn read(&self) -> RwLockReadGuard<'_, T> {
+        // SAFETY: The primitive is initialized and is not destroyed while self is borrowed.
+        expect(
+            unsafe { pthread_rwlock_rdlock(self.raw()) },
+            "pthread_rwlock_rdlock",
+        );
+        RwLockReadGuard {
+            lock: self,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Attempts to acquire shared read access without blocking. Returns [`None`] if a writer holds
+    /// the lock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the maximum number of readers is exceeded.
+    #[inline]
+    pub fn try_read(&self) -> Option<RwLockReadGuard<'_, T>> {
+        // SAFETY: The primitive is initialized and is not destroyed while self is borrowed.
+        match check_error_number(unsafe { pthread_rwlock_tryrdlock(self.raw()) }) {
+            Ok(()) => Some(RwLockReadGuard {
+                lock: self,
+                _not_send: PhantomData,
+            }),
+            Err(Error::ResourceBusy) => None,
+            Err(err) => panic!("pthread_rwlock_tryrdlock failed: {err}"),
+        }
+    }
+
+    /// Attempts to acquire exclusive write access without blocking. Returns [`None`] if a reader
+    /// or writer holds the lock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock could not be initialized.
+    #[inline]
+    pub fn try_write(&self) -> Option<RwLockWriteGuard<'_, T>> {
+        // SAFETY: The primitive is initialized and is not destroyed while self is borrowed.
+        match check_error_number(unsafe { pthread_rwlock_trywrlock(self.raw()) }) {
+            Ok(()) => Some(RwLockWriteGuard {
+                lock: self,
+                _not_send: PhantomData,
+            }),
+            Err(Error::ResourceBusy) => None,
+            Err(err) => panic!("pthread_rwlock_trywrlock failed: {err}"),
+        }
+    }
+
+    /// Acquires exclusive write access, blocking the current thread until it is able to do so.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current thread already holds write access.
+    #[inline]
+    pub fn write(&self) -> RwLockWriteGuard<'_, T> {
+        // SAFETY: The primitive is initialized and is not destroyed while self is borrowed.
+        expect(
+            unsafe { pthread_rwlock_wrlock(self.raw()) },
+            "pthread_rwlock_wrlock",
+        );
+        RwLockWriteGuard {
+            lock: self,
+            _not_send: PhantomData,
+        }
+    }
+
+    fn raw(&self) -> *mut pthread_rwlock_t {
+        get_or_init(
+            &self.raw,
+            // SAFETY: raw is a valid write destination.
+            |raw| check_error_number(unsafe { pthread_rwlock_init(raw, ptr::null()) }),
+            pthread_rwlock_destroy,
+        )
+    }
+
+    fn unlock(&self) {
+        // SAFETY: Only called by a guard, so the primitive is initialized and locked by this
+        // thread.
+        let _ = unsafe { pthread_rwlock_unlock(self.raw.load(Ordering::Relaxed)) };
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for RwLock<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("RwLock");
+        match self.try_read() {
+            Some(guard) => d.field("data", &&*guard).finish_non_exhaustive(),
+            None => d
+                .field("data", &format_args!("<locked>"))
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+impl<T: Default> Default for RwLock<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: ?Sized> Drop for RwLock<T> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The lock is being dropped, so it is not in use.
+        unsafe { free(*self.raw.get_mut(), pthread_rwlock_destroy) };
+    }
+}
+
+impl<T> From<T> for RwLock<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+// SAFETY: The primitive is heap allocated, so moving the lock to another thread does not move it.
+unsafe impl<T: ?Sized + Send> Send for RwLock<T> {}
+
+// SAFETY: Readers on multiple threads share a reference to the value (hence `T: Sync`), and a
+// writer may access it from any thread (hence `T: Send`).
+unsafe impl<T: ?Sized + Send + Sync> Sync for RwLock<T> {}
+
+impl<T: ?Sized> Deref for RwLockReadGuard<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: The guard holds shared read access, which guarantees there is no writer.
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for RwLockReadGuard<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockReadGuard<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.lock.unlock();
+    }
+}
+
+// SAFETY: Sharing the guard only shares a reference to the value.
+unsafe impl<T: ?Sized + Sync> Sync for RwLockReadGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for RwLockWriteGuard<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: The guard holds exclusive write access.
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for RwLockWriteGuard<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: The guard holds exclusive write access.
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for RwLockWriteGuard<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockWriteGuard<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.lock.unlock();
+    }
+}
+
+// SAFETY: Sharing the guard only shares a reference to the value.
+unsafe impl<T: ?Sized + Sync> Sync for RwLockWriteGuard<'_, T> {}
+
+impl Condvar {
+    /// Constructs a new condition variable.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            raw: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Wakes all threads blocked on this condition variable.
+    #[inline]
+    pub fn notify_all(&self) {
+        // SAFETY: The primitive is initialized and is not destroyed while self is borrowed.
+        expect(
+            unsafe { pthread_cond_broadcast(self.raw()) },
+            "pthread_cond_broadcast",
+        );
+    }
+
+    /// Wakes one thread blocked on this condition variable, if any.
+    #[inline]
+    pub fn notify_one(&self) {
+        // SAFETY: The primitive is initialized and is not destroyed while self is borrowed.
+        expect(
+            unsafe { pthread_cond_signal(self.raw()) },
+            "pthread_cond_signal",
+        );
+    }
+
+    /// Atomically releases the mutex held by `guard` and blocks the current thread until this
+    /// condition variable is notified, then reacquires the mutex.
+    ///
+    /// Spurious wakeups are possible, so the caller must recheck its condition in a loop.
+    ///
+    /// # Panics
+    ///
+    /// Panics if another thread is concurrently waiting with a different mutex.
+    #[inline]
+    pub fn wait<'mutex, T: ?Sized>(&self, guard: MutexGuard<'mutex, T>) -> MutexGuard<'mutex, T> {
+        let mutex = guard.mutex.raw.load(Ordering::Relaxed);
+
+        // SAFETY: Both primitives are initialized, and the guard guarantees the mutex is locked by
+        // this thread. The mutex is locked again when the function returns.
+        expect(
+            unsafe { pthread_cond_wait(self.raw(), mutex) },
+            "pthread_cond_wait",
+        );
+        guard
+    }
+
+    /// Like [`Self::wait`], but returns after `timeout` elapses if the condition variable is not
+    /// notified. The returned `bool` is `true` if the wait timed out.
+    ///
+    /// # Panics
+    ///
+    /// Panics if another thread is concurrently waiting with a different mutex.
+    #[inline]
+    pub fn wait_timeout<'mutex, T: ?Sized>(
+        &self,
+        guard: MutexGuard<'mutex, T>,
+        timeout: Duration,
+    ) -> (MutexGuard<'mutex, T>, bool) {
+        let mutex = guard.mutex.raw.load(Ordering::Relaxed);
+        let timeout = timespec {
+            sec: timeout.as_secs().try_into().unwrap_or(isize::MAX),
+            nsec: timeout.subsec_nanos().try_into().unwrap_or_default(),
+        };
+
+        // SAFETY: Both primitives are initialized, and the guard guarantees the mutex is locked by
+        // this thread. The mutex is locked again when the function returns.
+        let result = unsafe { pthread_cond_timedwait_relative_np(self.raw(), mutex, &timeout) };
+        match check_error_number(result) {
+            Ok(()) => (guard, false),
+            Err(Error::TimedOut) => (guard, true),
+            Err(err) => panic!("pthread_cond_timedwait_relative_np failed: {err}"),
+        }
+    }
+
+    fn raw(&self) -> *mut pthread_cond_t {
+        get_or_init(
+            &self.raw,
+            // SAFETY: raw is a valid write destination.
+            |raw| check_error_number(unsafe { pthread_cond_init(raw, ptr::null()) }),
+            pthread_cond_destroy,
+        )
+    }
+}
+
+impl Debug for Condvar {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Condvar").finish_non_exhaustive()
+    }
+}
+
+impl Default for Condvar {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Condvar {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The condition variable is being dropped, so it is not in use.
+        unsafe { free(*self.raw.get_mut(), pthread_cond_destroy) };
+    }
+}
+
+// SAFETY: The primitive is heap allocated, so moving the condition variable to another thread does
+// not move it.
+unsafe impl Send for Condvar {}
+
+// SAFETY: The `pthread` functions synchronize access to the primitive.
+unsafe impl Sync for Condvar {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Condvar, Mutex, RwLock};
+    use crate::posix::thread::spawn;
+    use alloc::sync::Arc;
+    use core::time::Duration;
+
+    #[test]
+    fn condvar() {
+        static PAIR: (Mutex<bool>, Condvar) = (Mutex::new(false), Condvar::new());
+
+        let handle = spawn(|| {
+            *PAIR.0.lock() = true;
+            PAIR.1.notify_one();
+        })
+        .unwrap();
+
+        let mut ready = PAIR.0.lock();
+        while !*ready {
+            ready = PAIR.1.wait(ready);
+        }
+        drop(ready);
+        handle.join().unwrap();
+
+        let (guard, timed_out) = PAIR.1.wait_timeout(PAIR.0.lock(), Duration::from_millis(1));
+        assert!(*guard);
+        assert!(timed_out);
+    }
+
+    #[test]
+    fn mutex() {
+        let mutex = Arc::new(Mutex::with_priority_inheritance(0_u32));
+
+        let handles = (0..4_u8)
+            .map(|_| {
+                let mutex = Arc::clone(&mutex);
+                spawn(move || {
+                    for _ in 0..1000_u16 {
+                        *mutex.lock() += 1;
+                    }
+                })
+                .unwrap()
+            })
+            .collect::<alloc::vec::Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let guard = mutex.lock();
+        assert_eq!(*guard, 4000);
+        assert!(mutex.try_lock().is_none());
+        drop(guard);
+
+        let mutex = Arc::into_inner(mutex).unwrap();
+        assert_eq!(mutex.into_inner(), 4000);
+    }
+
+    #[test]
+    fn rwlock() {
+        let mut lock = RwLock::new(1_u32);
+
+        let read1 = lock.read();
+        let read2 = lock.try_read().unwrap();
+        assert_eq!(*read1 + *read2, 2);
+        assert!(lock.try_write().is_none());
+        drop((read1, read2));
+
+        *lock.write() += 1;
+        assert!(lock.try_write().is_some());
+        *lock.get_mut() += 1;
+        assert_eq!(lock.into_inner(), 3);
+    }
+}
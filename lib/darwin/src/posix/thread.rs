@@ -0,0 +1,415 @@
+//! Threads of execution built on the POSIX threads (`pthread`) interface.
+//!
+//! A panic in a thread's closure cannot unwind through the `pthread` start routine, so it aborts
+//! the process.
+
+#[cfg(feature = "alloc")]
+use crate::_sys::posix::pthread::{
+    pthread_attr_destroy, pthread_attr_init, pthread_attr_set_qos_class_np,
+    pthread_attr_setdetachstate, pthread_attr_setstacksize, pthread_attr_t, pthread_create,
+    pthread_detach, pthread_join, pthread_t, PTHREAD_CREATE_DETACHED, PTHREAD_CREATE_JOINABLE,
+};
+use crate::_sys::posix::pthread::{
+    pthread_getname_np, pthread_self, pthread_set_qos_class_self_np, pthread_setname_np,
+};
+use crate::c::errno::{check_error_number, Error};
+use crate::sys::qos::Class;
+#[cfg(feature = "alloc")]
+use crate::unwind::abort_on_unwind;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, ffi::CString, sync::Arc};
+#[cfg(feature = "alloc")]
+use core::cell::UnsafeCell;
+#[cfg(feature = "alloc")]
+use core::ffi::c_void;
+use core::ffi::CStr;
+#[cfg(feature = "alloc")]
+use core::mem::{ManuallyDrop, MaybeUninit};
+#[cfg(feature = "alloc")]
+use core::ptr;
+
+/// Configures the attributes of a new thread before it is spawned.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default)]
+#[must_use]
+pub struct Builder {
+    name: Option<CString>,
+    qos_class: Option<(Class, i32)>,
+    stack_size: Option<usize>,
+}
+
+/// An owned permission to join on a thread (block on its termination) and obtain its result.
+///
+/// Dropping a `JoinHandle<T>` detaches the thread, after which it can no longer be joined.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct JoinHandle<T> {
+    thread: pthread_t,
+    packet: Arc<Packet<T>>,
+}
+
+/// Shares the thread's result with its [`JoinHandle`].
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+struct Packet<T> {
+    result: UnsafeCell<Option<T>>,
+}
+
+/// Owns the state passed to the `pthread` start routine.
+#[cfg(feature = "alloc")]
+struct Main<F, T> {
+    f: F,
+    name: Option<CString>,
+    packet: Arc<Packet<T>>,
+}
+
+/// Owns an initialized `pthread_attr_t`, destroying it when dropped.
+#[cfg(feature = "alloc")]
+struct Attributes(pthread_attr_t);
+
+/// Gets the name of the calling thread into `buf` and returns the nul-terminated name.
+///
+/// # Errors
+///
+/// Returns [`Error::ResultOutOfRange`] if `buf` is too small to hold the name and its nul
+/// terminator.
+#[inline]
+pub fn name(buf: &mut [u8]) -> Result<&CStr, Error> {
+    // SAFETY: buf is a mutable slice, thus its range is guaranteed to be a valid write destination.
+    // The system function never overruns the buffer and always nul terminates the output.
+    check_error_number(unsafe {
+        pthread_getname_np(pthread_self(), buf.as_mut_ptr().cast(), buf.len())
+    })?;
+    CStr::from_bytes_until_nul(buf).map_err(|_| Error::ResultOutOfRange)
+}
+
+/// Sets the name of the calling thread, which is visible in debuggers and crash reports.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidFilename`] if `name` is longer than 63 bytes.
+#[inline]
+pub fn set_name(name: impl AsRef<CStr>) -> Result<(), Error> {
+    // SAFETY: name is guaranteed to be a valid, nul-terminated C string.
+    check_error_number(unsafe { pthread_setname_np(name.as_ref().as_ptr()) })
+}
+
+/// Sets the quality of service class of the calling thread. `relative_priority` is an offset in the
+/// range `-15..=0` from the maximum scheduling priority of `class`.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidArgument`] if `relative_priority` is out of range, or
+/// [`Error::NotPermitted`] if the calling thread is a work queue thread whose quality of service
+/// class is managed by the system.
+#[inline]
+pub fn set_qos_class(class: Class, relative_priority: i32) -> Result<(), Error> {
+    // SAFETY: The function validates its arguments and only affects the calling thread.
+    check_error_number(unsafe { pthread_set_qos_class_self_np(class.into(), relative_priority) })
+}
+
+/// Spawns a new joinable thread with the default attributes that calls `f`.
+///
+/// # Errors
+///
+/// Returns [`Error::WouldBlock`] if the system lacks the resources to create the thread.
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn spawn<F, T>(f: F) -> Result<JoinHandle<T>, Error>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    Builder::new().spawn(f)
+}
+
+#[cfg(feature = "alloc")]
+impl Builder {
+    /// Constructs a new `Builder` with the default thread attributes.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name the new thread assigns itself before calling its closure.
+    #[inline]
+    pub fn name(mut self, name: CString) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the quality of service class of the new thread. `relative_priority` is an offset in the
+    /// range `-15..=0` from the maximum scheduling priority of `class`.
+    #[inline]
+    pub const fn qos_class(mut self, class: Class, relative_priority: i32) -> Self {
+        self.qos_class = Some((class, relative_priority));
+        self
+    }
+
+    /// Sets the size, in bytes, of the new thread's stack. The size must be a multiple of the
+    /// system page size and at least `PTHREAD_STACK_MIN` (16 KiB).
+    #[inline]
+    pub const fn stack_size(mut self, size: usize) -> Self {
+        self.stack_size = Some(size);
+        self
+    }
+
+    /// Spawns a new joinable thread that calls `f`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if an attribute is invalid, or [`Error::WouldBlock`] if
+    /// the system lacks the resources to create the thread.
+    pub fn spawn<F, T>(self, f: F) -> Result<JoinHandle<T>, Error>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let packet = Arc::new(Packet {
+            result: UnsafeCell::new(None),
+        });
+        let thread = self.create(f, PTHREAD_CREATE_JOINABLE, Arc::clone(&packet))?;
+        Ok(JoinHandle { thread, packet })
+    }
+
+    /// Spawns a new detached thread that calls `f`. The thread's resources are reclaimed by the
+    /// system when it terminates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if an attribute is invalid, or [`Error::WouldBlock`] if
+    /// the system lacks the resources to create the thread.
+    pub fn spawn_detached<F>(self, f: F) -> Result<(), Error>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let packet = Arc::new(Packet {
+            result: UnsafeCell::new(None),
+        });
+        let _ = self.create(f, PTHREAD_CREATE_DETACHED, packet)?;
+        Ok(())
+    }
+
+    fn create<F, T>(
+        self,
+        f: F,
+        detach_state: i32,
+        packet: Arc<Packet<T>>,
+    ) -> Result<pthread_t, Error>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let mut attr = Attributes::new()?;
+        attr.set_detach_state(detach_state)?;
+        if let Some((class, relative_priority)) = self.qos_class {
+            attr.set_qos_class(class, relative_priority)?;
+        }
+        if let Some(size) = self.stack_size {
+            attr.set_stack_size(size)?;
+        }
+
+        let main = Box::into_raw(Box::new(Main {
+            f,
+            name: self.name,
+            packet,
+        }));
+
+        let mut thread = ptr::null_mut();
+
+        // SAFETY: thread is a valid write destination and attr is initialized. Ownership of main
+        // is transferred to the new thread by start::<F, T> if the call succeeds.
+        let result = check_error_number(unsafe {
+            pthread_create(&mut thread, &attr.0, start::<F, T>, main.cast())
+        });
+
+        if result.is_err() {
+            // SAFETY: The thread was not created, so ownership of main was not transferred.
+            drop(unsafe { Box::from_raw(main) });
+        }
+        result.map(|()| thread)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> JoinHandle<T> {
+    /// Detaches the thread, allowing the system to reclaim its resources when it terminates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoSuchProcess`] if the thread no longer exists.
+    #[inline]
+    pub fn detach(self) -> Result<(), Error> {
+        let this = ManuallyDrop::new(self);
+
+        // SAFETY: The handle's packet is dropped exactly once, here, because `this` is never
+        // dropped.
+        drop(unsafe { ptr::read(&this.packet) });
+
+        // SAFETY: The thread was created joinable and has been neither joined nor detached.
+        check_error_number(unsafe { pthread_detach(this.thread) })
+    }
+
+    /// Blocks until the thread terminates and returns the value returned by its closure.
+    ///
+    /// If the thread cannot be joined, it is detached so the system reclaims its resources when it
+    /// terminates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Deadlock`] if the thread attempts to join itself, or [`Error::NoData`] if
+    /// the thread terminated without storing its closure's result.
+    pub fn join(self) -> Result<T, Error> {
+        let this = ManuallyDrop::new(self);
+
+        // SAFETY: The thread was created joinable and has been neither joined nor detached.
+        let result = check_error_number(unsafe { pthread_join(this.thread, ptr::null_mut()) });
+
+        // SAFETY: The handle's packet is moved exactly once, here, because `this` is never
+        // dropped.
+        let packet = unsafe { ptr::read(&this.packet) };
+        if let Err(err) = result {
+            // SAFETY: The thread was not joined, and has not been detached.
+            let _ = unsafe { pthread_detach(this.thread) };
+            return Err(err);
+        }
+
+        // The thread dropped its reference to the packet before it terminated, and the memory
+        // effects of the thread are visible after it is joined.
+        Arc::into_inner(packet)
+            .and_then(|packet| packet.result.into_inner())
+            .ok_or(Error::NoData)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Drop for JoinHandle<T> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The thread was created joinable and has been neither joined nor detached.
+        let _ = unsafe { pthread_detach(self.thread) };
+    }
+}
+
+// SAFETY: A `pthread_t` may be joined or detached from any thread, and the result is sent from the
+// spawned thread to the joining thread (hence `T: Send`).
+#[cfg(feature = "alloc")]
+unsafe impl<T> Send for JoinHandle<T> where T: Send {}
+
+// SAFETY: A `JoinHandle<T>` has no methods that take a shared reference.
+#[cfg(feature = "alloc")]
+unsafe impl<T> Sync for JoinHandle<T> {}
+
+// SAFETY: The result is written exactly once by the spawned thread, and is only read by the joining
+// thread after the spawned thread terminates.
+#[cfg(feature = "alloc")]
+unsafe impl<T> Sync for Packet<T> where T: Send {}
+
+#[cfg(feature = "alloc")]
+impl Attributes {
+    fn new() -> Result<Self, Error> {
+        let mut attr = MaybeUninit::uninit();
+
+        // SAFETY: attr is a valid write destination.
+        check_error_number(unsafe { pthread_attr_init(attr.as_mut_ptr()) })?;
+
+        // SAFETY: pthread_attr_init succeeded, so attr is initialized.
+        Ok(Self(unsafe { attr.assume_init() }))
+    }
+
+    fn set_detach_state(&mut self, detach_state: i32) -> Result<(), Error> {
+        // SAFETY: self.0 is initialized and the function validates its argument.
+        check_error_number(unsafe { pthread_attr_setdetachstate(&mut self.0, detach_state) })
+    }
+
+    fn set_qos_class(&mut self, class: Class, relative_priority: i32) -> Result<(), Error> {
+        // SAFETY: self.0 is initialized and the function validates its arguments.
+        check_error_number(unsafe {
+            pthread_attr_set_qos_class_np(&mut self.0, class.into(), relative_priority)
+        })
+    }
+
+    fn set_stack_size(&mut self, size: usize) -> Result<(), Error> {
+        // SAFETY: self.0 is initialized and the function validates its argument.
+        check_error_number(unsafe { pthread_attr_setstacksize(&mut self.0, size) })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Drop for Attributes {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: self.0 is initialized and is not used again.
+        let _ = unsafe { pthread_attr_destroy(&mut self.0) };
+    }
+}
+
+#[cfg(feature = "alloc")]
+extern "C" fn start<F, T>(main: *mut c_void) -> *mut c_void
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    // SAFETY: Builder::create transferred ownership of main to this thread.
+    let main = unsafe { Box::from_raw(main.cast::<Main<F, T>>()) };
+    let Main { f, name, packet } = *main;
+
+    if let Some(name) = name {
+        // The name is advisory, so failing to set it is not fatal.
+        let _ = set_name(name);
+    }
+
+    let result = abort_on_unwind(f);
+
+    // SAFETY: This is the only write to the result, and the joining thread only reads it after
+    // this thread terminates.
+    unsafe { *packet.result.get() = Some(result) };
+    ptr::null_mut()
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{name, set_qos_class, spawn, Builder};
+    use crate::sys::qos::Class;
+    use alloc::ffi::CString;
+    use core::ffi::CStr;
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    #[test]
+    fn builder() {
+        let name_str = CStr::from_bytes_with_nul(b"darwin.thread.test\0").unwrap();
+
+        let handle = Builder::new()
+            .name(CString::from(name_str))
+            .qos_class(Class::Utility, -1)
+            .stack_size(1 << 20)
+            .spawn(move || {
+                let mut buf = [0; 64];
+                name(&mut buf).unwrap() == name_str
+            })
+            .unwrap();
+        assert!(handle.join().unwrap());
+    }
+
+    #[test]
+    fn join() {
+        let handle = spawn(|| {
+            set_qos_class(Class::Background, 0).unwrap();
+            41 + 1
+        })
+        .unwrap();
+        assert_eq!(handle.join().unwrap(), 42);
+    }
+
+    #[test]
+    fn detach() {
+        static RAN: AtomicBool = AtomicBool::new(false);
+
+        let handle = spawn(|| RAN.store(true, Ordering::Release)).unwrap();
+        handle.detach().unwrap();
+
+        while !RAN.load(Ordering::Acquire) {
+            core::hint::spin_loop();
+        }
+    }
+}
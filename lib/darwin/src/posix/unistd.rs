@@ -1,11 +1,11 @@
 use crate::_sys::posix::unistd::{
-    self, confstr, mkdtemp, mkstemp, rmdir, _CS_DARWIN_USER_TEMP_DIR,
+    self, confstr, gethostname, mkdtemp, mkstemp, rmdir, _CS_DARWIN_USER_TEMP_DIR,
 };
 use crate::c::errno::{self, check, Error};
 use crate::io::{FromRawFd, OwnedFd};
 use crate::posix::fcntl::Open;
 use core::ffi::{c_char, CStr};
-use core::num::{NonZeroI32, NonZeroUsize};
+use core::num::NonZeroUsize;
 use core::ptr;
 
 #[derive(Clone, Copy, Debug)]
@@ -23,7 +23,7 @@ impl ConfigurationString {
     ///      hold the entire string value, including the nul terminator.
     ///    * `None`: The variable name is valid but does not have a defined value.
     /// * `Err(_)`: The call was not successful and failed due to the provided reason.
-    pub fn get(self, buf: Option<&mut [u8]>) -> Result<Option<NonZeroUsize>, NonZeroI32> {
+    pub fn get(self, buf: Option<&mut [u8]>) -> Result<Option<NonZeroUsize>, Error> {
         let (ptr, len) = buf.map_or((ptr::null_mut(), 0), |buf| (buf.as_mut_ptr(), buf.len()));
 
         // Clear the current error code. This must occur prior to calling the C function to
@@ -35,7 +35,7 @@ impl ConfigurationString {
         // always nul terminates the output.
         match NonZeroUsize::new(unsafe { confstr(self as _, ptr.cast(), len) }) {
             // confstr(3) returned 0. There was either an error or there is no entry.
-            None => errno::get().map(Err).transpose(),
+            None => Error::last().map(Err).transpose(),
             // A non-zero result is always the capacity required for the full nul terminated string.
             cap => Ok(cap),
         }
@@ -56,7 +56,7 @@ impl ConfigurationString {
 /// # Panics
 ///
 /// Panics if `template` is not nul-terminated or does not end with one or more `X`s.
-pub fn create_unique_directory_and_open(template: &mut [u8]) -> Result<OwnedFd, NonZeroI32> {
+pub fn create_unique_directory_and_open(template: &mut [u8]) -> Result<OwnedFd, Error> {
     let _ = create_unique_retry_driver(template, |template| {
         // SAFETY: template is guaranteed to be a valid mutable buffer. create_unique_retry_driver
         // verifies the buffer is nul-terminated. The system function will only overwrite bytes
@@ -68,9 +68,7 @@ pub fn create_unique_directory_and_open(template: &mut [u8]) -> Result<OwnedFd,
         }
     })?;
 
-    let path = CStr::from_bytes_with_nul(template)
-        .ok()
-        .ok_or_else(|| NonZeroI32::new(Error::IllegalByteSequence as _).unwrap())?;
+    let path = CStr::from_bytes_with_nul(template).map_err(|_| Error::IllegalByteSequence)?;
     Open::default().path(path)
 }
 
@@ -87,7 +85,7 @@ pub fn create_unique_directory_and_open(template: &mut [u8]) -> Result<OwnedFd,
 /// # Panics
 ///
 /// Panics if `template` is not nul-terminated or does not end with one or more `X`s.
-pub fn create_unique_file_and_open(template: &mut [u8]) -> Result<OwnedFd, NonZeroI32> {
+pub fn create_unique_file_and_open(template: &mut [u8]) -> Result<OwnedFd, Error> {
     create_unique_retry_driver(template, |template| {
         // SAFETY: template is guaranteed to be a valid mutable buffer. create_unique_retry_driver
         // verifies the buffer is nul-terminated. The system function will only overwrite bytes
@@ -101,7 +99,7 @@ pub fn create_unique_file_and_open(template: &mut [u8]) -> Result<OwnedFd, NonZe
 fn create_unique_retry_driver(
     template: &mut [u8],
     mut mktemp: impl FnMut(*mut c_char) -> i32,
-) -> Result<i32, NonZeroI32> {
+) -> Result<i32, Error> {
     let mut iter = template.iter().rev();
     assert!(*iter.next().unwrap() == 0);
 
@@ -110,7 +108,7 @@ fn create_unique_retry_driver(
 
     loop {
         match check(mktemp(template.as_mut_ptr().cast())) {
-            Err(e) if e.get() == Error::Interrupted as _ => {
+            Err(e) if e.is_interrupted() => {
                 // template is in an undefined state. Restore the placeholders and retry.
                 template[placeholder_range.clone()].fill(b'X');
             }
@@ -119,7 +117,20 @@ fn create_unique_retry_driver(
     }
 }
 
-pub fn remove_directory(path: impl AsRef<CStr>) -> Result<(), NonZeroI32> {
+/// Writes the host name of the computer, followed by a nul terminator, into the caller's `buf`fer.
+/// Returns a [`CStr`] borrowing the written bytes.
+///
+/// A host name is at most 255 bytes long, so a 256-byte buffer can hold any host name.
+pub fn host_name(buf: &mut [u8]) -> Result<&CStr, Error> {
+    // SAFETY: buf is a mutable slice, thus its range is guaranteed to be a valid write destination.
+    // The system function never overruns the buffer.
+    let _ = check(unsafe { gethostname(buf.as_mut_ptr().cast(), buf.len()) })?;
+
+    // The name is not nul-terminated if it was truncated to fit the buffer.
+    CStr::from_bytes_until_nul(buf).map_err(|_| Error::InvalidFilename)
+}
+
+pub fn remove_directory(path: impl AsRef<CStr>) -> Result<(), Error> {
     let path = path.as_ref().as_ptr();
     // It is not possible to recover from `rmdir(2)` errors as the directory removal may have
     // actually succeeded. Retrying may remove a directory created after the first call failed.
@@ -130,7 +141,7 @@ pub fn remove_directory(path: impl AsRef<CStr>) -> Result<(), NonZeroI32> {
     Ok(())
 }
 
-pub fn unlink(path: impl AsRef<CStr>) -> Result<(), NonZeroI32> {
+pub fn unlink(path: impl AsRef<CStr>) -> Result<(), Error> {
     let path = path.as_ref().as_ptr();
     // It is not possible to recover from `unlink(2)` errors as the unlink may have actually
     // succeeded. Retrying may unlink a file created after the first call failed.
@@ -144,8 +155,8 @@ pub fn unlink(path: impl AsRef<CStr>) -> Result<(), NonZeroI32> {
 #[cfg(test)]
 mod tests {
     use super::{
-        create_unique_directory_and_open, create_unique_file_and_open, remove_directory, unlink,
-        ConfigurationString,
+        create_unique_directory_and_open, create_unique_file_and_open, host_name, remove_directory,
+        unlink, ConfigurationString,
     };
     use crate::c::errno::Error;
     use crate::sys::stat::Metadata;
@@ -177,10 +188,7 @@ mod tests {
     #[test]
     fn bad_name() {
         let name: ConfigurationString = unsafe { mem::transmute(0) };
-        assert_eq!(
-            name.get(None).unwrap_err().get(),
-            Error::InvalidArgument as _
-        );
+        assert_eq!(name.get(None).unwrap_err(), Error::InvalidArgument);
     }
 
     #[test]
@@ -236,6 +244,18 @@ mod tests {
         unlink(path).unwrap();
     }
 
+    // host_name()
+
+    #[test]
+    fn host_name_buffer() {
+        let mut buf = [0_u8; 256];
+        let name = host_name(&mut buf).unwrap();
+        assert!(!name.is_empty());
+
+        let mut buf = [0_u8; 0];
+        assert_eq!(host_name(&mut buf).unwrap_err(), Error::InvalidFilename);
+    }
+
     // Utilities
 
     const TEMPLATE: &[u8; 11] = b"temp.XXXXXX";
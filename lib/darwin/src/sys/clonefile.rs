@@ -1,8 +1,9 @@
-use crate::_sys::sys::clonefile::{fclonefileat, CLONE_ACL, CLONE_NOFOLLOW, CLONE_NOOWNERCOPY};
-use crate::c::errno::check;
+use crate::_sys::sys::clonefile::{
+    clonefile, fclonefileat, CLONE_ACL, CLONE_NOFOLLOW, CLONE_NOOWNERCOPY,
+};
+use crate::c::errno::{check, Error};
 use crate::io::AsFd;
 use core::ffi::CStr;
-use core::num::NonZeroI32;
 
 #[allow(missing_copy_implementations)]
 #[derive(Debug, Default)]
@@ -31,7 +32,7 @@ impl Clone {
         source: &impl AsFd,
         destination_directory: &impl AsFd,
         destination_file_name: impl AsRef<CStr>,
-    ) -> Result<(), NonZeroI32> {
+    ) -> Result<(), Error> {
         let srcfd = source.as_fd();
         let dst_dirfd = destination_directory.as_fd();
         let dst = destination_file_name.as_ref().as_ptr();
@@ -44,6 +45,25 @@ impl Clone {
         Ok(())
     }
 
+    /// Clones the file, directory, or symbolic link at `source` to `destination` with
+    /// copy-on-write. Directories are cloned recursively. The destination must not exist, and
+    /// both paths must be on the same APFS volume.
+    pub fn path(
+        self,
+        source: impl AsRef<CStr>,
+        destination: impl AsRef<CStr>,
+    ) -> Result<(), Error> {
+        let src = source.as_ref().as_ptr();
+        let dst = destination.as_ref().as_ptr();
+        let flags = self.flags;
+
+        // SAFETY: src and dst are guaranteed to be valid, nul-terminated C-style strings, the
+        // system function will not write to the strings, and flags is guaranteed to be a valid
+        // combination.
+        let _ = check(unsafe { clonefile(src, dst, flags) })?;
+        Ok(())
+    }
+
     const fn set_flag_enabled(mut self, flag: u32, enable: bool) -> Self {
         if enable {
             self.flags |= flag;
@@ -58,12 +78,10 @@ impl Clone {
 mod tests {
     use super::Clone;
     use crate::posix::fcntl::Open;
-    use crate::posix::unistd::{
-        create_unique_directory_and_open, remove_directory, unlink, ConfigurationString,
-    };
+    use crate::posix::unistd::unlink;
     use crate::sys::stat::Metadata;
+    use crate::tests::TemporaryDirectory;
     use core::ffi::CStr;
-    use core::mem;
 
     #[test]
     fn test_clone_fd() {
@@ -73,33 +91,15 @@ mod tests {
         .unwrap();
         let source = Open::default().path(source_path).unwrap();
 
-        let mut buf: [u8; 512] = unsafe { mem::zeroed() };
-        let len = ConfigurationString::TemporaryDirectory
-            .get(Some(&mut buf))
-            .unwrap()
-            .unwrap()
-            .get()
-            -1 /* nul */;
-
-        let template = b"rust-darwin-XXXXXX";
-        let template_end = len + template.len();
-        buf[len..template_end].copy_from_slice(template);
-
-        let destination_directory =
-            create_unique_directory_and_open(&mut buf[..=template_end]).unwrap();
+        let destination_directory = TemporaryDirectory::new();
         let destination_file_name = CStr::from_bytes_with_nul(b"Info.plist\0").unwrap();
 
         Clone::default()
-            .fd(&source, &destination_directory, destination_file_name)
+            .fd(&source, destination_directory.fd(), destination_file_name)
             .unwrap();
 
-        let file_name = destination_file_name.to_bytes();
-        let file_name_end = template_end + 1 + file_name.len();
-        buf[template_end] = b'/';
-        buf[(template_end + 1)..file_name_end].copy_from_slice(file_name);
-
-        let file_path = CStr::from_bytes_with_nul(&buf[..=file_name_end]).unwrap();
-        let cloned = Open::default().path(file_path).unwrap();
+        let file_path = destination_directory.join(destination_file_name.to_bytes());
+        let cloned = Open::default().path(&file_path).unwrap();
 
         let source_metadata = Metadata::from_fd(&source).unwrap();
         let cloned_metadata = Metadata::from_fd(&cloned).unwrap();
@@ -111,9 +111,6 @@ mod tests {
         );
 
         unlink(file_path).unwrap();
-
-        buf[template_end] = 0;
-        let directory_path = CStr::from_bytes_with_nul(&buf[..=template_end]).unwrap();
-        remove_directory(directory_path).unwrap();
+        destination_directory.remove();
     }
 }
@@ -0,0 +1,127 @@
+//! Memory-mapped files.
+
+use crate::_sys::sys::mman::{mmap, munmap, MAP_FAILED, MAP_FILE, MAP_PRIVATE, PROT_READ};
+use crate::c::errno::Error;
+use crate::io::AsFd;
+use crate::posix::fcntl::{AccessMode, Open};
+use crate::sys::stat::Metadata;
+use core::ffi::{c_void, CStr};
+use core::ops::Deref;
+use core::ptr::{self, NonNull};
+use core::slice;
+
+/// A read-only, private mapping of a file's contents into memory (`mmap(2)`).
+///
+/// The pages are loaded lazily as they are accessed and can be evicted by the system without being
+/// written to swap, so a mapping of a large file is not resident in memory. The file is unmapped
+/// when the mapping is dropped.
+///
+/// The mapping reflects changes made to the file by other processes until a page is read. Reading
+/// beyond the end of a file that was truncated after it was mapped raises `SIGBUS`.
+#[derive(Debug)]
+pub struct Mapping {
+    addr: NonNull<u8>,
+    len: usize,
+}
+
+impl Mapping {
+    /// Maps the entire contents of the file open as `fd`.
+    pub fn from_fd(fd: &impl AsFd) -> Result<Self, Error> {
+        let len = Metadata::from_fd(fd)?.len();
+        let len = usize::try_from(len).map_err(|_| Error::FileTooLarge)?;
+        if len == 0 {
+            // `mmap(2)` fails with `EINVAL` for a zero-length mapping.
+            return Ok(Self {
+                addr: NonNull::dangling(),
+                len,
+            });
+        }
+
+        // SAFETY: The file descriptor is valid and the system chooses the address of the mapping.
+        let addr = unsafe {
+            mmap(
+                ptr::null_mut(),
+                len,
+                PROT_READ,
+                MAP_FILE | MAP_PRIVATE,
+                fd.as_fd().fd,
+                0,
+            )
+        };
+        if addr == MAP_FAILED {
+            // UB: `mmap(2)` sets `errno` when it fails.
+            return Err(Error::last().unwrap_or(Error::InvalidArgument));
+        }
+
+        Ok(Self {
+            addr: NonNull::new(addr.cast()).ok_or(Error::InvalidArgument)?,
+            len,
+        })
+    }
+
+    /// Maps the entire contents of the file at `path`.
+    ///
+    /// The file is closed after it is mapped; the mapping remains valid.
+    pub fn from_path(path: impl AsRef<CStr>) -> Result<Self, Error> {
+        let fd = Open::new(AccessMode::ReadOnly)
+            .close_on_exec(true)
+            .path(path)?;
+        Self::from_fd(&fd)
+    }
+
+    /// Returns the mapped contents of the file.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        // SAFETY: The mapping is readable for `len` bytes until it is dropped.
+        unsafe { slice::from_raw_parts(self.addr.as_ptr(), self.len) }
+    }
+}
+
+impl AsRef<[u8]> for Mapping {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Deref for Mapping {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_bytes()
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        if self.len == 0 {
+            return;
+        }
+
+        let addr: *mut c_void = self.addr.as_ptr().cast();
+        // SAFETY: The mapping was created by `mmap(2)` with the same address and length, and is not
+        // used again. `munmap(2)` only fails for invalid arguments.
+        let _ = unsafe { munmap(addr, self.len) };
+    }
+}
+
+// SAFETY: The mapping is read-only and is not tied to the thread that created it.
+unsafe impl Send for Mapping {}
+
+// SAFETY: See above.
+unsafe impl Sync for Mapping {}
+
+#[cfg(test)]
+mod tests {
+    use super::Mapping;
+    use core::ffi::CStr;
+
+    #[test]
+    fn from_path() {
+        let path = CStr::from_bytes_with_nul(b"/etc/hosts\0").unwrap();
+        let mapping = Mapping::from_path(path).unwrap();
+        assert!(!mapping.is_empty());
+
+        let empty = Mapping::from_path(CStr::from_bytes_with_nul(b"/dev/null\0").unwrap()).unwrap();
+        assert!(empty.is_empty());
+    }
+}
@@ -1,3 +1,6 @@
 pub mod clonefile;
+pub mod mman;
 pub mod qos;
 pub mod stat;
+pub mod time;
+pub mod utsname;
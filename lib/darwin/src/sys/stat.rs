@@ -1,14 +1,63 @@
-use crate::_sys::sys::stat::{fstat, stat, ALLPERMS, DEFFILEMODE};
+use crate::_sys::posix::fcntl::AT_SYMLINK_NOFOLLOW;
+use crate::_sys::sys::stat::{
+    chflags, chmod, fchflags, fchmod, fstat, fstatat, lstat, stat, ALLPERMS, DEFFILEMODE,
+    SF_APPEND, SF_ARCHIVED, SF_DATALESS, SF_FIRMLINK, SF_IMMUTABLE, SF_NOUNLINK, SF_RESTRICTED,
+    UF_APPEND, UF_COMPRESSED, UF_DATAVAULT, UF_HIDDEN, UF_IMMUTABLE, UF_NODUMP, UF_OPAQUE,
+    UF_TRACKED,
+};
 use crate::_sys::sys::types::{
-    S_IFBLK, S_IFCHR, S_IFDIR, S_IFIFO, S_IFLNK, S_IFMT, S_IFREG, S_IFSOCK, S_IRGRP, S_IROTH,
-    S_IRUSR, S_ISGID, S_ISUID, S_ISVTX, S_IWGRP, S_IWOTH, S_IWUSR, S_IXGRP, S_IXOTH, S_IXUSR,
+    timespec, S_IFBLK, S_IFCHR, S_IFDIR, S_IFIFO, S_IFLNK, S_IFMT, S_IFREG, S_IFSOCK, S_IRGRP,
+    S_IROTH, S_IRUSR, S_ISGID, S_ISUID, S_ISVTX, S_IWGRP, S_IWOTH, S_IWUSR, S_IXGRP, S_IXOTH,
+    S_IXUSR,
 };
-use crate::c::errno::check_retry;
+use crate::c::errno::{check_retry, Error};
 use crate::io::AsFd;
+use core::ffi::CStr;
 use core::mem::MaybeUninit;
-use core::num::NonZeroI32;
 use core::ops::BitOr;
 
+/// A user or system flag that can be set on a file with [`set_flags()`].
+#[derive(Clone, Copy, Debug)]
+#[repr(u32)]
+pub enum Flag {
+    /// Do not dump the file (`UF_NODUMP`).
+    NoDump = UF_NODUMP,
+    /// The file may not be changed (`UF_IMMUTABLE`).
+    UserImmutable = UF_IMMUTABLE,
+    /// Writes to the file may only append (`UF_APPEND`).
+    UserAppend = UF_APPEND,
+    /// The directory is opaque when viewed through a union mount (`UF_OPAQUE`).
+    Opaque = UF_OPAQUE,
+    /// The file is compressed (`UF_COMPRESSED`).
+    Compressed = UF_COMPRESSED,
+    /// Renames and deletes of the file are tracked (`UF_TRACKED`).
+    Tracked = UF_TRACKED,
+    /// Access to the file requires an entitlement (`UF_DATAVAULT`).
+    DataVault = UF_DATAVAULT,
+    /// The file should not be displayed in a GUI (`UF_HIDDEN`).
+    Hidden = UF_HIDDEN,
+
+    /// The file is archived (`SF_ARCHIVED`).
+    SystemArchived = SF_ARCHIVED,
+    /// The file may not be changed (`SF_IMMUTABLE`).
+    SystemImmutable = SF_IMMUTABLE,
+    /// Writes to the file may only append (`SF_APPEND`).
+    SystemAppend = SF_APPEND,
+    /// The file is protected by System Integrity Protection (`SF_RESTRICTED`).
+    Restricted = SF_RESTRICTED,
+    /// The file may not be removed or renamed (`SF_NOUNLINK`).
+    NoUnlink = SF_NOUNLINK,
+    /// The file is a firmlink (`SF_FIRMLINK`).
+    Firmlink = SF_FIRMLINK,
+    /// The file is a placeholder whose data is not materialized (`SF_DATALESS`).
+    Dataless = SF_DATALESS,
+}
+
+/// A set of user and system [`Flag`]s.
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct Flags(u32);
+
 /// Information about a file.
 #[derive(Clone, Copy, Debug)]
 pub struct Metadata {
@@ -44,33 +93,244 @@ pub enum Permission {
 #[repr(transparent)]
 pub struct Permissions(u16);
 
+/// A point in time, with nanosecond precision, relative to the Unix epoch.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Timestamp {
+    seconds: i64,
+    nanoseconds: u32,
+}
+
+/// Replaces the flags of the file at `path` with `flags`. Symbolic links are followed.
+///
+/// Only the super-user may change the system flags.
+pub fn set_flags(path: impl AsRef<CStr>, flags: Flags) -> Result<(), Error> {
+    let path = path.as_ref().as_ptr();
+
+    // SAFETY: path is guaranteed to be a valid, nul-terminated C-style string and chflags() will
+    // not write to path.
+    let _ = check_retry(|| unsafe { chflags(path, flags.0) })?;
+    Ok(())
+}
+
+/// Replaces the flags of the file referenced by `fd` with `flags`.
+///
+/// Only the super-user may change the system flags.
+pub fn set_flags_fd(fd: &impl AsFd, flags: Flags) -> Result<(), Error> {
+    // SAFETY: The file descriptor is guaranteed to be valid.
+    let _ = check_retry(|| unsafe { fchflags(fd.as_fd(), flags.0) })?;
+    Ok(())
+}
+
+/// Replaces the permissions of the file at `path` with `permissions`. Symbolic links are followed.
+pub fn set_permissions(path: impl AsRef<CStr>, permissions: Permissions) -> Result<(), Error> {
+    let path = path.as_ref().as_ptr();
+
+    // SAFETY: path is guaranteed to be a valid, nul-terminated C-style string and chmod() will not
+    // write to path.
+    let _ = check_retry(|| unsafe { chmod(path, permissions.0) })?;
+    Ok(())
+}
+
+/// Replaces the permissions of the file referenced by `fd` with `permissions`.
+pub fn set_permissions_fd(fd: &impl AsFd, permissions: Permissions) -> Result<(), Error> {
+    // SAFETY: The file descriptor is guaranteed to be valid.
+    let _ = check_retry(|| unsafe { fchmod(fd.as_fd(), permissions.0) })?;
+    Ok(())
+}
+
+impl BitOr for Flag {
+    type Output = Flags;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        let lhs: Self::Output = self.into();
+        let rhs: Self::Output = rhs.into();
+        lhs | rhs
+    }
+}
+
+impl BitOr<Flags> for Flag {
+    type Output = Flags;
+
+    fn bitor(self, rhs: Flags) -> Self::Output {
+        let lhs: Self::Output = self.into();
+        lhs | rhs
+    }
+}
+
+impl Flags {
+    /// Tests whether the given `flag` is set in this flag set.
+    #[must_use]
+    pub const fn has(self, flag: Flag) -> bool {
+        let bit: u32 = flag as _;
+        self.0 & bit == bit
+    }
+
+    /// Tests whether the given `flags` are set in this flag set.
+    #[must_use]
+    pub const fn has_all(self, flags: Self) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+
+    /// Tests whether the given `flags` are not set in this flag set.
+    #[must_use]
+    pub const fn has_none(self, flags: Self) -> bool {
+        self.0 & flags.0 == 0
+    }
+
+    /// Returns a copy of this flag set with `flag` cleared.
+    #[must_use]
+    pub const fn without(self, flag: Flag) -> Self {
+        Self(self.0 & !(flag as u32))
+    }
+
+    /// Returns the raw `st_flags` value, which may include flags not known to this crate.
+    #[inline]
+    #[must_use]
+    pub const fn into_raw(self) -> u32 {
+        self.0
+    }
+}
+
+impl BitOr for Flags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOr<Flag> for Flags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Flag) -> Self::Output {
+        let rhs: Self = rhs.into();
+        self | rhs
+    }
+}
+
+impl From<Flag> for Flags {
+    fn from(flag: Flag) -> Self {
+        Self(flag as _)
+    }
+}
+
 #[allow(clippy::len_without_is_empty)] // not a container type
 impl Metadata {
-    pub fn from_fd(fd: &impl AsFd) -> Result<Self, NonZeroI32> {
+    /// Queries the metadata of the file referenced by `fd`.
+    pub fn from_fd(fd: &impl AsFd) -> Result<Self, Error> {
+        Self::query(|buf| {
+            // SAFETY: The file descriptor and the buffer are guaranteed to be valid. The operating
+            // system will not write outside the bounds of the buffer.
+            unsafe { fstat(fd.as_fd(), buf) }
+        })
+    }
+
+    /// Queries the metadata of the file at `path`. If `path` names a symbolic link, the link is
+    /// followed.
+    pub fn from_path(path: impl AsRef<CStr>) -> Result<Self, Error> {
+        let path = path.as_ref().as_ptr();
+
+        Self::query(|buf| {
+            // SAFETY: path is guaranteed to be a valid, nul-terminated C-style string and the
+            // buffer is guaranteed to be valid. The operating system will not write to path or
+            // outside the bounds of the buffer.
+            unsafe { stat(path, buf) }
+        })
+    }
+
+    /// Queries the metadata of the file at `path`. If `path` names a symbolic link, the metadata
+    /// describes the link itself.
+    pub fn from_path_no_follow(path: impl AsRef<CStr>) -> Result<Self, Error> {
+        let path = path.as_ref().as_ptr();
+
+        Self::query(|buf| {
+            // SAFETY: path is guaranteed to be a valid, nul-terminated C-style string and the
+            // buffer is guaranteed to be valid. The operating system will not write to path or
+            // outside the bounds of the buffer.
+            unsafe { lstat(path, buf) }
+        })
+    }
+
+    /// Queries the metadata of the file at `path`, which is resolved relative to `directory` if it
+    /// is not absolute. If `follow` is `false` and `path` names a symbolic link, the metadata
+    /// describes the link itself.
+    pub fn from_path_at(
+        directory: &impl AsFd,
+        path: impl AsRef<CStr>,
+        follow: bool,
+    ) -> Result<Self, Error> {
+        let path = path.as_ref().as_ptr();
+        let flag = if follow { 0 } else { AT_SYMLINK_NOFOLLOW };
+
+        Self::query(|buf| {
+            // SAFETY: The file descriptor and the buffer are guaranteed to be valid, and path is
+            // guaranteed to be a valid, nul-terminated C-style string. The operating system will
+            // not write to path or outside the bounds of the buffer.
+            unsafe { fstatat(directory.as_fd(), path, buf, flag) }
+        })
+    }
+
+    fn query(mut f: impl FnMut(&mut stat) -> i32) -> Result<Self, Error> {
         let mut metadata = Self {
             // SAFETY: stat is a scalar structure that is safe to zero-initialize.
             stat: unsafe { MaybeUninit::<stat>::zeroed().assume_init() },
         };
 
-        // Note: EINTR is not explicitly listed as an error condition for fstat(2), but POSIX
-        // section 2.3 does not prohibit implementations from returning additional error codes.
-
-        // SAFETY: The file descriptor and the buffer are guaranteed to be valid. The operating
-        // system will not write outside the bounds of the buffer.
-        let _ = check_retry(|| unsafe { fstat(fd.as_fd(), &mut metadata.stat) })?;
+        // Note: EINTR is not explicitly listed as an error condition for the stat(2) family, but
+        // POSIX section 2.3 does not prohibit implementations from returning additional error
+        // codes.
+        let _ = check_retry(|| f(&mut metadata.stat))?;
 
         Ok(metadata)
     }
 
+    /// The time the file data was last accessed.
+    #[must_use]
+    pub fn accessed(&self) -> Timestamp {
+        Timestamp::from_timespec(self.stat.atimespec)
+    }
+
+    /// The time the file was created.
+    #[must_use]
+    pub fn created(&self) -> Timestamp {
+        Timestamp::from_timespec(self.stat.birthtimespec)
+    }
+
+    /// The user and system flags set on the file.
+    #[must_use]
+    pub const fn flags(&self) -> Flags {
+        Flags(self.stat.flags)
+    }
+
+    /// The size of the file, in bytes.
     #[must_use]
     pub fn len(&self) -> u64 {
         self.stat.size.try_into().unwrap_or_default()
     }
 
+    /// The time the file status (e.g. permissions, flags, or link count) was last changed.
+    #[must_use]
+    pub fn metadata_changed(&self) -> Timestamp {
+        Timestamp::from_timespec(self.stat.ctimespec)
+    }
+
+    /// The type of the file and its [`Permissions`].
     #[must_use]
     pub const fn mode(&self) -> Mode {
         Mode(self.stat.mode)
     }
+
+    /// The time the file data was last modified.
+    #[must_use]
+    pub fn modified(&self) -> Timestamp {
+        Timestamp::from_timespec(self.stat.mtimespec)
+    }
+
+    /// The access rights granted to the file.
+    #[must_use]
+    pub const fn permissions(&self) -> Permissions {
+        self.mode().permissions()
+    }
 }
 
 impl Mode {
@@ -192,6 +452,29 @@ impl BitOr<Permission> for Permissions {
     }
 }
 
+impl Timestamp {
+    fn from_timespec(timespec: timespec) -> Self {
+        Self {
+            seconds: timespec.sec.try_into().unwrap_or_default(),
+            nanoseconds: timespec.nsec.try_into().unwrap_or_default(),
+        }
+    }
+
+    /// The number of whole seconds since the Unix epoch. Negative values precede the epoch.
+    #[inline]
+    #[must_use]
+    pub const fn seconds(self) -> i64 {
+        self.seconds
+    }
+
+    /// The number of nanoseconds elapsed since [`Self::seconds()`], in the range `0..1_000_000_000`.
+    #[inline]
+    #[must_use]
+    pub const fn subsec_nanoseconds(self) -> u32 {
+        self.nanoseconds
+    }
+}
+
 impl Default for Permissions {
     fn default() -> Self {
         Self(DEFFILEMODE)
@@ -206,10 +489,63 @@ impl From<Permission> for Permissions {
 
 #[cfg(test)]
 mod tests {
-    use super::{Metadata, Permission};
+    use super::{set_flags_fd, set_permissions, Flag, Metadata, Permission};
     use crate::posix::fcntl::Open;
+    use crate::posix::unistd::unlink;
+    use crate::tests::TemporaryDirectory;
     use core::ffi::CStr;
 
+    #[test]
+    fn lstat_tmp() {
+        let path = CStr::from_bytes_with_nul(b"/tmp\0").unwrap();
+
+        assert!(Metadata::from_path(path).unwrap().mode().is_dir());
+        assert!(Metadata::from_path_no_follow(path)
+            .unwrap()
+            .mode()
+            .is_symbolic_link());
+
+        let root = Open::default()
+            .path(CStr::from_bytes_with_nul(b"/\0").unwrap())
+            .unwrap();
+        let relative = CStr::from_bytes_with_nul(b"tmp\0").unwrap();
+
+        assert!(Metadata::from_path_at(&root, relative, true)
+            .unwrap()
+            .mode()
+            .is_dir());
+        assert!(Metadata::from_path_at(&root, relative, false)
+            .unwrap()
+            .mode()
+            .is_symbolic_link());
+    }
+
+    #[test]
+    fn set_flags_and_permissions() {
+        use Permission::{UserRead, UserWrite};
+
+        let directory = TemporaryDirectory::new();
+        let (path, fd) = directory.create_file();
+        let path = path.as_c_str();
+
+        let metadata = Metadata::from_fd(&fd).unwrap();
+        assert!(!metadata.flags().has(Flag::Hidden));
+        assert!(metadata.created() <= metadata.modified());
+        assert!(metadata.modified().subsec_nanoseconds() < 1_000_000_000);
+
+        set_flags_fd(&fd, metadata.flags() | Flag::Hidden).unwrap();
+        set_permissions(path, UserRead.into()).unwrap();
+
+        let metadata = Metadata::from_path(path).unwrap();
+        assert!(metadata.flags().has(Flag::Hidden));
+        assert!(metadata.permissions().has(UserRead));
+        assert!(metadata.permissions().has_none(UserWrite.into()));
+
+        drop(fd);
+        unlink(path).unwrap();
+        directory.remove();
+    }
+
     #[test]
     fn stat_bin_sh() {
         use Permission::{
@@ -0,0 +1,37 @@
+//! The time of day (`gettimeofday(2)`).
+
+use crate::_sys::sys::resource::timeval;
+use crate::_sys::sys::time::gettimeofday;
+use crate::c::errno::{check, Error};
+use crate::posix::resource::from_timeval;
+use core::ptr;
+use core::time::Duration;
+
+/// Returns the time since the Unix epoch, with microsecond precision.
+///
+/// [`Clock::Realtime`] reads the same clock with nanosecond precision.
+///
+/// [`Clock::Realtime`]: crate::c::time::Clock::Realtime
+pub fn time_of_day() -> Result<Duration, Error> {
+    let mut timeval = timeval {
+        tv_sec: 0,
+        tv_usec: 0,
+    };
+
+    // SAFETY: The buffer is guaranteed to be valid and the obsolete time zone argument may be
+    // `NULL`. The operating system will not write outside the bounds of the buffer.
+    let _ = check(unsafe { gettimeofday(&mut timeval, ptr::null_mut()) })?;
+
+    Ok(from_timeval(timeval))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::time_of_day;
+
+    #[test]
+    fn since_epoch() {
+        // 2001-01-01T00:00:00Z, the Core Foundation reference date.
+        assert!(time_of_day().unwrap().as_secs() > 978_307_200);
+    }
+}
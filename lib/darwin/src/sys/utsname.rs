@@ -0,0 +1,83 @@
+//! Identification of the operating system and hardware (`uname(3)`).
+
+use crate::_sys::sys::utsname::{uname as sys_uname, utsname, _SYS_NAMELEN};
+use crate::c::errno::{check, Error};
+use core::ffi::{c_char, CStr};
+use core::mem::MaybeUninit;
+use core::slice;
+
+/// The names that identify the running operating system and the hardware it runs on.
+#[derive(Clone, Copy, Debug)]
+pub struct SystemName {
+    name: utsname,
+}
+
+impl SystemName {
+    /// The name of the hardware type (e.g., `arm64`).
+    #[must_use]
+    pub fn machine(&self) -> &CStr {
+        to_c_str(&self.name.machine)
+    }
+
+    /// The name of the computer on the network. This is the same as the host name (see
+    /// [`host_name()`]).
+    ///
+    /// [`host_name()`]: crate::posix::unistd::host_name
+    #[must_use]
+    pub fn node_name(&self) -> &CStr {
+        to_c_str(&self.name.nodename)
+    }
+
+    /// The release level of the operating system (e.g., `23.4.0`).
+    #[must_use]
+    pub fn release(&self) -> &CStr {
+        to_c_str(&self.name.release)
+    }
+
+    /// The name of the operating system (e.g., `Darwin`).
+    #[must_use]
+    pub fn system_name(&self) -> &CStr {
+        to_c_str(&self.name.sysname)
+    }
+
+    /// The version of the operating system, which includes the kernel's build information.
+    #[must_use]
+    pub fn version(&self) -> &CStr {
+        to_c_str(&self.name.version)
+    }
+}
+
+/// Queries the names that identify the running operating system and the hardware it runs on.
+pub fn uname() -> Result<SystemName, Error> {
+    let mut system_name = SystemName {
+        // SAFETY: utsname is a scalar structure that is safe to zero-initialize.
+        name: unsafe { MaybeUninit::<utsname>::zeroed().assume_init() },
+    };
+
+    // SAFETY: The buffer is guaranteed to be valid. The operating system will not write outside
+    // the bounds of the buffer.
+    let _ = check(unsafe { sys_uname(&mut system_name.name) })?;
+
+    Ok(system_name)
+}
+
+/// Borrows the nul-terminated string in `field`, or an empty string if `field` is not terminated.
+fn to_c_str(field: &[c_char; _SYS_NAMELEN]) -> &CStr {
+    // SAFETY: `c_char` has the same size and alignment as `u8`, and the slice borrows `field`.
+    let bytes = unsafe { slice::from_raw_parts(field.as_ptr().cast::<u8>(), field.len()) };
+    CStr::from_bytes_until_nul(bytes).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::uname;
+
+    #[test]
+    fn system_name() {
+        let name = uname().unwrap();
+        assert_eq!(name.system_name().to_bytes(), b"Darwin");
+        assert!(!name.machine().is_empty());
+        assert!(!name.release().is_empty());
+        assert!(!name.version().is_empty());
+    }
+}
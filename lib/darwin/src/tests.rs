@@ -0,0 +1,98 @@
+//! Fixtures shared by the crate's tests.
+
+use crate::io::OwnedFd;
+use crate::posix::unistd::{
+    create_unique_directory_and_open, create_unique_file_and_open, remove_directory,
+    ConfigurationString,
+};
+use core::ffi::CStr;
+
+/// The capacity of a [`Path`], including the nul terminator.
+const PATH_CAPACITY: usize = 512;
+
+/// A nul-terminated path stored inline, so the fixtures do not require the `alloc` feature.
+pub(crate) struct Path {
+    buf: [u8; PATH_CAPACITY],
+    len: usize,
+}
+
+/// A uniquely named directory in the user's temporary directory.
+///
+/// Tests must remove the entries they create in the directory before calling
+/// [`TemporaryDirectory::remove`].
+pub(crate) struct TemporaryDirectory {
+    path: Path,
+    fd: OwnedFd,
+}
+
+impl Path {
+    /// Returns the path as a C string.
+    pub(crate) fn as_c_str(&self) -> &CStr {
+        CStr::from_bytes_with_nul(&self.buf[..=self.len]).unwrap()
+    }
+}
+
+impl AsRef<CStr> for Path {
+    fn as_ref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl TemporaryDirectory {
+    /// Creates a new, empty directory.
+    pub(crate) fn new() -> Self {
+        let mut buf = [0_u8; PATH_CAPACITY];
+        // The temporary directory always ends with a path separator.
+        let len = ConfigurationString::TemporaryDirectory
+            .get(Some(&mut buf))
+            .unwrap()
+            .unwrap()
+            .get()
+            - 1 /* nul */;
+
+        let template = b"rust-darwin-XXXXXX";
+        let template_end = len + template.len();
+        buf[len..template_end].copy_from_slice(template);
+        let fd = create_unique_directory_and_open(&mut buf[..=template_end]).unwrap();
+
+        Self {
+            path: Path {
+                buf,
+                len: template_end,
+            },
+            fd,
+        }
+    }
+
+    /// Creates a new, empty file with a unique name in the directory, and returns its path and a
+    /// read-write file descriptor.
+    pub(crate) fn create_file(&self) -> (Path, OwnedFd) {
+        let mut path = self.join(b"file.XXXXXX");
+        let fd = create_unique_file_and_open(&mut path.buf[..=path.len]).unwrap();
+        (path, fd)
+    }
+
+    /// Returns an open file descriptor for the directory.
+    pub(crate) const fn fd(&self) -> &OwnedFd {
+        &self.fd
+    }
+
+    /// Returns the path of the entry `name` in the directory, which need not exist.
+    pub(crate) fn join(&self, name: &[u8]) -> Path {
+        let mut path = Path {
+            buf: self.path.buf,
+            len: self.path.len + 1 + name.len(),
+        };
+        path.buf[self.path.len] = b'/';
+        path.buf[self.path.len + 1..path.len].copy_from_slice(name);
+        path.buf[path.len] = 0;
+        path
+    }
+
+    /// Removes the directory, which must be empty.
+    pub(crate) fn remove(self) {
+        let Self { path, fd } = self;
+        drop(fd);
+        remove_directory(path).unwrap();
+    }
+}
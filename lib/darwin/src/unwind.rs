@@ -0,0 +1,26 @@
+//! Support for calling Rust closures from C.
+
+use crate::_sys::c::stdlib::abort;
+
+/// Calls `f`, aborting the process if it panics.
+///
+/// Unwinding out of an `extern "C"` function is undefined behavior, so callbacks invoked by C code
+/// run their closures through this function.
+pub(crate) fn abort_on_unwind<F, T>(f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    struct AbortOnUnwind;
+
+    impl Drop for AbortOnUnwind {
+        fn drop(&mut self) {
+            // SAFETY: The function has no preconditions.
+            unsafe { abort() }
+        }
+    }
+
+    let guard = AbortOnUnwind;
+    let result = f();
+    core::mem::forget(guard);
+    result
+}
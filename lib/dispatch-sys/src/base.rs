@@ -1,3 +1,9 @@
 pub use core::ffi::c_void;
 
 pub type dispatch_function_t = extern "C" fn(*mut c_void);
+
+/// An Objective-C block pointer.
+///
+/// These bindings do not construct blocks. Prefer the `_f` variants of functions that accept a
+/// block, which accept a [`dispatch_function_t`] and context pointer instead.
+pub type dispatch_block_t = *mut c_void;
@@ -0,0 +1,66 @@
+use crate::{c_void, dispatch_block_t, dispatch_queue_t};
+use c_ffi::opaque_type;
+use core::ptr;
+
+opaque_type!(dispatch_data_s);
+
+pub type dispatch_data_t = *mut dispatch_data_s;
+
+/// The default destructor for dispatch data objects. Used at data object creation to indicate
+/// that the supplied buffer needs to be copied into internal storage managed by the system.
+pub const DISPATCH_DATA_DESTRUCTOR_DEFAULT: dispatch_block_t = ptr::null_mut();
+
+extern "C" {
+    /// The singleton dispatch data object representing a zero-length memory region. Use
+    /// [`dispatch_data_empty`] to obtain a pointer to it.
+    pub static _dispatch_data_empty: dispatch_data_s;
+
+    /// The destructor for dispatch data objects created from a malloc'd buffer
+    /// (`DISPATCH_DATA_DESTRUCTOR_FREE`).
+    pub static _dispatch_data_destructor_free: dispatch_block_t;
+
+    /// The destructor for dispatch data objects that have been created from buffers that require
+    /// deallocation with `munmap(2)` (`DISPATCH_DATA_DESTRUCTOR_MUNMAP`).
+    pub static _dispatch_data_destructor_munmap: dispatch_block_t;
+
+    pub fn dispatch_data_create(
+        buffer: *const c_void,
+        size: usize,
+        queue: dispatch_queue_t,
+        destructor: dispatch_block_t,
+    ) -> dispatch_data_t;
+
+    pub fn dispatch_data_get_size(data: dispatch_data_t) -> usize;
+
+    pub fn dispatch_data_create_map(
+        data: dispatch_data_t,
+        buffer_ptr: *mut *const c_void,
+        size_ptr: *mut usize,
+    ) -> dispatch_data_t;
+    pub fn dispatch_data_create_concat(
+        data1: dispatch_data_t,
+        data2: dispatch_data_t,
+    ) -> dispatch_data_t;
+    pub fn dispatch_data_create_subrange(
+        data: dispatch_data_t,
+        offset: usize,
+        length: usize,
+    ) -> dispatch_data_t;
+    pub fn dispatch_data_copy_region(
+        data: dispatch_data_t,
+        location: usize,
+        offset_ptr: *mut usize,
+    ) -> dispatch_data_t;
+}
+
+/// Returns the singleton dispatch data object representing a zero-length memory region
+/// (`dispatch_data_empty`).
+#[inline]
+#[must_use]
+pub fn dispatch_data_empty() -> dispatch_data_t {
+    // LINT: Taking the address of an extern static only became safe in Rust 1.82.
+    #[allow(unused_unsafe)]
+    // SAFETY: Only the address of the static is taken; the static is not read.
+    let data = unsafe { ptr::addr_of!(_dispatch_data_empty) };
+    data.cast_mut()
+}
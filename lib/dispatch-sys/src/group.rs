@@ -0,0 +1,31 @@
+use crate::{c_void, dispatch_function_t, dispatch_queue_t, dispatch_time_t};
+use c_ffi::opaque_type;
+
+opaque_type!(dispatch_group_s);
+
+pub type dispatch_group_t = *mut dispatch_group_s;
+
+extern "C" {
+    pub fn dispatch_group_create() -> dispatch_group_t;
+
+    pub fn dispatch_group_async_f(
+        group: dispatch_group_t,
+        queue: dispatch_queue_t,
+        context: *mut c_void,
+        work: dispatch_function_t,
+    );
+
+    /// Returns zero on success (all blocks associated with the group completed within the
+    /// specified timeout) or non-zero on error (i.e. timed out).
+    pub fn dispatch_group_wait(group: dispatch_group_t, timeout: dispatch_time_t) -> isize;
+
+    pub fn dispatch_group_notify_f(
+        group: dispatch_group_t,
+        queue: dispatch_queue_t,
+        context: *mut c_void,
+        work: dispatch_function_t,
+    );
+
+    pub fn dispatch_group_enter(group: dispatch_group_t);
+    pub fn dispatch_group_leave(group: dispatch_group_t);
+}
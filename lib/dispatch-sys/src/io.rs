@@ -0,0 +1,94 @@
+use crate::{dispatch_block_t, dispatch_queue_t};
+use c_ffi::opaque_type;
+use core::ffi::{c_char, c_int, c_ulong};
+
+/// Native file descriptor type for the platform.
+pub type dispatch_fd_t = c_int;
+
+opaque_type!(dispatch_io_s);
+
+pub type dispatch_io_t = *mut dispatch_io_s;
+
+/// The type of a dispatch I/O channel.
+pub type dispatch_io_type_t = c_ulong;
+
+/// A dispatch I/O channel representing a stream of bytes.
+pub const DISPATCH_IO_STREAM: dispatch_io_type_t = 0;
+/// A dispatch I/O channel representing a random access file.
+pub const DISPATCH_IO_RANDOM: dispatch_io_type_t = 1;
+
+/// The type of flags you can set on a [`dispatch_io_close`] call.
+pub type dispatch_io_close_flags_t = c_ulong;
+
+/// Stop outstanding operations on a channel when the channel is closed.
+pub const DISPATCH_IO_STOP: dispatch_io_close_flags_t = 0x1;
+
+/// Type of flags to set on [`dispatch_io_set_interval`].
+pub type dispatch_io_interval_flags_t = c_ulong;
+
+/// Enqueue I/O handlers at a channel's interval setting even if the amount of data ready to be
+/// delivered is inferior to the low water mark (or zero).
+pub const DISPATCH_IO_STRICT_INTERVAL: dispatch_io_interval_flags_t = 0x1;
+
+extern "C" {
+    pub fn dispatch_read(
+        fd: dispatch_fd_t,
+        length: usize,
+        queue: dispatch_queue_t,
+        handler: dispatch_block_t,
+    );
+    pub fn dispatch_write(
+        fd: dispatch_fd_t,
+        data: crate::dispatch_data_t,
+        queue: dispatch_queue_t,
+        handler: dispatch_block_t,
+    );
+
+    pub fn dispatch_io_create(
+        r#type: dispatch_io_type_t,
+        fd: dispatch_fd_t,
+        queue: dispatch_queue_t,
+        cleanup_handler: dispatch_block_t,
+    ) -> dispatch_io_t;
+    pub fn dispatch_io_create_with_path(
+        r#type: dispatch_io_type_t,
+        path: *const c_char,
+        oflag: c_int,
+        mode: u16,
+        queue: dispatch_queue_t,
+        cleanup_handler: dispatch_block_t,
+    ) -> dispatch_io_t;
+    pub fn dispatch_io_create_with_io(
+        r#type: dispatch_io_type_t,
+        io: dispatch_io_t,
+        queue: dispatch_queue_t,
+        cleanup_handler: dispatch_block_t,
+    ) -> dispatch_io_t;
+
+    pub fn dispatch_io_read(
+        channel: dispatch_io_t,
+        offset: i64,
+        length: usize,
+        queue: dispatch_queue_t,
+        io_handler: dispatch_block_t,
+    );
+    pub fn dispatch_io_write(
+        channel: dispatch_io_t,
+        offset: i64,
+        data: crate::dispatch_data_t,
+        queue: dispatch_queue_t,
+        io_handler: dispatch_block_t,
+    );
+
+    pub fn dispatch_io_close(channel: dispatch_io_t, flags: dispatch_io_close_flags_t);
+    pub fn dispatch_io_barrier(channel: dispatch_io_t, barrier: dispatch_block_t);
+    pub fn dispatch_io_get_descriptor(channel: dispatch_io_t) -> dispatch_fd_t;
+
+    pub fn dispatch_io_set_high_water(channel: dispatch_io_t, high_water: usize);
+    pub fn dispatch_io_set_low_water(channel: dispatch_io_t, low_water: usize);
+    pub fn dispatch_io_set_interval(
+        channel: dispatch_io_t,
+        interval: u64,
+        flags: dispatch_io_interval_flags_t,
+    );
+}
@@ -3,12 +3,35 @@
 //! The Clang module definition can be found in the iOS and macOS SDKs included with Xcode at
 //! `$SDKROOT/usr/include/dispatch/module.modulemap`. These FFI bindings are derived from
 //! [libdispatch-1462.0.4](https://github.com/apple-oss-distributions/libdispatch/tree/libdispatch-1462.0.4).
+//!
+//! Functions that accept an Objective-C block are declared only where libdispatch does not provide
+//! an `_f` variant that accepts a function pointer and context.
 
 #![no_std]
 #![allow(clippy::undocumented_unsafe_blocks, missing_docs, non_camel_case_types)]
 
 mod base;
+mod data;
+mod group;
+mod io;
+mod object;
 mod once;
+mod queue;
+mod semaphore;
+mod source;
+mod time;
+mod workloop;
+
+pub mod weak;
 
 pub use base::*;
+pub use data::*;
+pub use group::*;
+pub use io::*;
+pub use object::*;
 pub use once::*;
+pub use queue::*;
+pub use semaphore::*;
+pub use source::*;
+pub use time::*;
+pub use workloop::*;
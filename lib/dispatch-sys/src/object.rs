@@ -0,0 +1,34 @@
+use crate::{c_void, dispatch_function_t, dispatch_qos_class_t};
+use availability::availability;
+use c_ffi::opaque_type;
+use core::ffi::c_int;
+
+opaque_type!(
+    /// The base type of all dispatch objects.
+    ///
+    /// Functions that accept a `dispatch_object_t` accept any dispatch object type (e.g.,
+    /// [`dispatch_queue_t`](crate::dispatch_queue_t)) cast to this type.
+    dispatch_object_s
+);
+
+pub type dispatch_object_t = *mut dispatch_object_s;
+
+extern "C" {
+    pub fn dispatch_retain(object: dispatch_object_t);
+    pub fn dispatch_release(object: dispatch_object_t);
+
+    pub fn dispatch_get_context(object: dispatch_object_t) -> *mut c_void;
+    pub fn dispatch_set_context(object: dispatch_object_t, context: *mut c_void);
+    pub fn dispatch_set_finalizer_f(object: dispatch_object_t, finalizer: dispatch_function_t);
+
+    pub fn dispatch_activate(object: dispatch_object_t);
+    pub fn dispatch_suspend(object: dispatch_object_t);
+    pub fn dispatch_resume(object: dispatch_object_t);
+
+    #[availability(macos = "10.14", ios = "12.0", tvos = "12.0", watchos = "5.0")]
+    pub fn dispatch_set_qos_class_floor(
+        object: dispatch_object_t,
+        qos_class: dispatch_qos_class_t,
+        relative_priority: c_int,
+    );
+}
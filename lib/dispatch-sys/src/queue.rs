@@ -0,0 +1,179 @@
+use crate::{c_void, dispatch_function_t, dispatch_object_t, dispatch_time_t};
+use availability::availability;
+use c_ffi::opaque_type;
+use core::ffi::{c_char, c_int, c_long, c_ulong};
+use core::ptr;
+
+opaque_type!(dispatch_queue_s);
+
+pub type dispatch_queue_t = *mut dispatch_queue_s;
+pub type dispatch_queue_global_t = dispatch_queue_t;
+pub type dispatch_queue_serial_t = dispatch_queue_t;
+pub type dispatch_queue_main_t = dispatch_queue_t;
+pub type dispatch_queue_concurrent_t = dispatch_queue_t;
+
+opaque_type!(dispatch_queue_attr_s);
+
+pub type dispatch_queue_attr_t = *mut dispatch_queue_attr_s;
+
+/// A `qos_class_t` value, as defined by `<sys/qos.h>`.
+pub type dispatch_qos_class_t = u32;
+
+pub type dispatch_queue_priority_t = c_long;
+
+pub const DISPATCH_QUEUE_PRIORITY_HIGH: dispatch_queue_priority_t = 2;
+pub const DISPATCH_QUEUE_PRIORITY_DEFAULT: dispatch_queue_priority_t = 0;
+pub const DISPATCH_QUEUE_PRIORITY_LOW: dispatch_queue_priority_t = -2;
+pub const DISPATCH_QUEUE_PRIORITY_BACKGROUND: dispatch_queue_priority_t = -32_768;
+
+pub type dispatch_autorelease_frequency_t = c_ulong;
+
+pub const DISPATCH_AUTORELEASE_FREQUENCY_INHERIT: dispatch_autorelease_frequency_t = 0;
+pub const DISPATCH_AUTORELEASE_FREQUENCY_WORK_ITEM: dispatch_autorelease_frequency_t = 1;
+pub const DISPATCH_AUTORELEASE_FREQUENCY_NEVER: dispatch_autorelease_frequency_t = 2;
+
+/// Constant to pass to [`dispatch_apply_f`] to request that the system automatically use worker
+/// threads that match the configuration of the current thread as closely as possible.
+pub const DISPATCH_APPLY_AUTO: dispatch_queue_t = ptr::null_mut();
+
+/// Constant to pass to [`dispatch_queue_create_with_target`], [`dispatch_set_target_queue`] and
+/// `dispatch_source_create` to set the target queue to the default target queue.
+pub const DISPATCH_TARGET_QUEUE_DEFAULT: dispatch_queue_t = ptr::null_mut();
+
+/// An attribute that can be used to create a dispatch queue that invokes blocks serially in FIFO
+/// order.
+pub const DISPATCH_QUEUE_SERIAL: dispatch_queue_attr_t = ptr::null_mut();
+
+/// Constant to pass to [`dispatch_queue_get_label`] to retrieve the label of the current queue.
+pub const DISPATCH_CURRENT_QUEUE_LABEL: dispatch_queue_t = ptr::null_mut();
+
+extern "C" {
+    /// The main queue. Use [`dispatch_get_main_queue`] to obtain a pointer to it.
+    pub static _dispatch_main_q: dispatch_queue_s;
+
+    /// The attribute of concurrent queues. Use [`dispatch_queue_attr_concurrent`] to obtain a
+    /// pointer to it.
+    pub static _dispatch_queue_attr_concurrent: dispatch_queue_attr_s;
+
+    pub fn dispatch_async_f(
+        queue: dispatch_queue_t,
+        context: *mut c_void,
+        work: dispatch_function_t,
+    );
+    pub fn dispatch_sync_f(
+        queue: dispatch_queue_t,
+        context: *mut c_void,
+        work: dispatch_function_t,
+    );
+    #[availability(macos = "10.14", ios = "12.0", tvos = "12.0", watchos = "5.0")]
+    pub fn dispatch_async_and_wait_f(
+        queue: dispatch_queue_t,
+        context: *mut c_void,
+        work: dispatch_function_t,
+    );
+    pub fn dispatch_apply_f(
+        iterations: usize,
+        queue: dispatch_queue_t,
+        context: *mut c_void,
+        work: extern "C" fn(context: *mut c_void, iteration: usize),
+    );
+
+    pub fn dispatch_get_global_queue(identifier: isize, flags: usize) -> dispatch_queue_global_t;
+
+    pub fn dispatch_queue_attr_make_initially_inactive(
+        attr: dispatch_queue_attr_t,
+    ) -> dispatch_queue_attr_t;
+    pub fn dispatch_queue_attr_make_with_autorelease_frequency(
+        attr: dispatch_queue_attr_t,
+        frequency: dispatch_autorelease_frequency_t,
+    ) -> dispatch_queue_attr_t;
+    pub fn dispatch_queue_attr_make_with_qos_class(
+        attr: dispatch_queue_attr_t,
+        qos_class: dispatch_qos_class_t,
+        relative_priority: c_int,
+    ) -> dispatch_queue_attr_t;
+
+    #[link_name = "dispatch_queue_create_with_target$V2"]
+    pub fn dispatch_queue_create_with_target(
+        label: *const c_char,
+        attr: dispatch_queue_attr_t,
+        target: dispatch_queue_t,
+    ) -> dispatch_queue_t;
+    pub fn dispatch_queue_create(
+        label: *const c_char,
+        attr: dispatch_queue_attr_t,
+    ) -> dispatch_queue_t;
+
+    pub fn dispatch_queue_get_label(queue: dispatch_queue_t) -> *const c_char;
+    pub fn dispatch_queue_get_qos_class(
+        queue: dispatch_queue_t,
+        relative_priority_ptr: *mut c_int,
+    ) -> dispatch_qos_class_t;
+
+    pub fn dispatch_set_target_queue(object: dispatch_object_t, queue: dispatch_queue_t);
+
+    pub fn dispatch_main() -> !;
+
+    pub fn dispatch_after_f(
+        when: dispatch_time_t,
+        queue: dispatch_queue_t,
+        context: *mut c_void,
+        work: dispatch_function_t,
+    );
+
+    pub fn dispatch_barrier_async_f(
+        queue: dispatch_queue_t,
+        context: *mut c_void,
+        work: dispatch_function_t,
+    );
+    pub fn dispatch_barrier_sync_f(
+        queue: dispatch_queue_t,
+        context: *mut c_void,
+        work: dispatch_function_t,
+    );
+    #[availability(macos = "10.14", ios = "12.0", tvos = "12.0", watchos = "5.0")]
+    pub fn dispatch_barrier_async_and_wait_f(
+        queue: dispatch_queue_t,
+        context: *mut c_void,
+        work: dispatch_function_t,
+    );
+
+    pub fn dispatch_queue_set_specific(
+        queue: dispatch_queue_t,
+        key: *const c_void,
+        context: *mut c_void,
+        destructor: Option<dispatch_function_t>,
+    );
+    pub fn dispatch_queue_get_specific(queue: dispatch_queue_t, key: *const c_void) -> *mut c_void;
+    pub fn dispatch_get_specific(key: *const c_void) -> *mut c_void;
+
+    #[link_name = "dispatch_assert_queue$V2"]
+    pub fn dispatch_assert_queue(queue: dispatch_queue_t);
+    pub fn dispatch_assert_queue_barrier(queue: dispatch_queue_t);
+    #[link_name = "dispatch_assert_queue_not$V2"]
+    pub fn dispatch_assert_queue_not(queue: dispatch_queue_t);
+}
+
+/// Returns the default queue that is bound to the main thread.
+#[inline]
+#[must_use]
+pub fn dispatch_get_main_queue() -> dispatch_queue_main_t {
+    // LINT: Taking the address of an extern static only became safe in Rust 1.82.
+    #[allow(unused_unsafe)]
+    // SAFETY: Only the address of the static is taken; the static is not read.
+    let queue = unsafe { ptr::addr_of!(_dispatch_main_q) };
+    queue.cast_mut()
+}
+
+/// Returns the attribute that can be used to create a dispatch queue that may invoke blocks
+/// concurrently and supports barrier blocks submitted with the dispatch barrier API
+/// (`DISPATCH_QUEUE_CONCURRENT`).
+#[inline]
+#[must_use]
+pub fn dispatch_queue_attr_concurrent() -> dispatch_queue_attr_t {
+    // LINT: Taking the address of an extern static only became safe in Rust 1.82.
+    #[allow(unused_unsafe)]
+    // SAFETY: Only the address of the static is taken; the static is not read.
+    let attr = unsafe { ptr::addr_of!(_dispatch_queue_attr_concurrent) };
+    attr.cast_mut()
+}
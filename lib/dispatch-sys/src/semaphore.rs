@@ -0,0 +1,20 @@
+use crate::dispatch_time_t;
+use c_ffi::opaque_type;
+
+opaque_type!(dispatch_semaphore_s);
+
+pub type dispatch_semaphore_t = *mut dispatch_semaphore_s;
+
+extern "C" {
+    /// Passing zero for the value is useful for when two threads need to reconcile the completion
+    /// of a particular event. Passing a value greater than zero is useful for managing a finite
+    /// pool of resources, where the pool size is equal to the value. Returns `NULL` if the value
+    /// is less than zero.
+    pub fn dispatch_semaphore_create(value: isize) -> dispatch_semaphore_t;
+
+    /// Returns zero on success, or non-zero if the timeout occurred.
+    pub fn dispatch_semaphore_wait(dsema: dispatch_semaphore_t, timeout: dispatch_time_t) -> isize;
+
+    /// Returns non-zero if a thread is woken. Otherwise, zero is returned.
+    pub fn dispatch_semaphore_signal(dsema: dispatch_semaphore_t) -> isize;
+}
@@ -0,0 +1,181 @@
+use crate::{dispatch_function_t, dispatch_queue_t, dispatch_time_t};
+use c_ffi::opaque_type;
+use core::ffi::c_ulong;
+
+opaque_type!(dispatch_source_s);
+
+pub type dispatch_source_t = *mut dispatch_source_s;
+
+opaque_type!(dispatch_source_type_s);
+
+/// Constants of this type represent the class of low-level system object that is being monitored
+/// by the dispatch source.
+pub type dispatch_source_type_t = *const dispatch_source_type_s;
+
+pub type dispatch_source_mach_send_flags_t = c_ulong;
+
+/// The receive right corresponding to the given send right was destroyed.
+pub const DISPATCH_MACH_SEND_DEAD: dispatch_source_mach_send_flags_t = 0x1;
+
+pub type dispatch_source_mach_recv_flags_t = c_ulong;
+
+pub type dispatch_source_memorypressure_flags_t = c_ulong;
+
+/// The system memory pressure condition has returned to normal.
+pub const DISPATCH_MEMORYPRESSURE_NORMAL: dispatch_source_memorypressure_flags_t = 0x01;
+/// The system memory pressure condition has changed to warning.
+pub const DISPATCH_MEMORYPRESSURE_WARN: dispatch_source_memorypressure_flags_t = 0x02;
+/// The system memory pressure condition has changed to critical.
+pub const DISPATCH_MEMORYPRESSURE_CRITICAL: dispatch_source_memorypressure_flags_t = 0x04;
+
+pub type dispatch_source_proc_flags_t = c_ulong;
+
+/// The process has exited (perhaps cleanly, perhaps not).
+pub const DISPATCH_PROC_EXIT: dispatch_source_proc_flags_t = 0x8000_0000;
+/// The process has created one or more child processes.
+pub const DISPATCH_PROC_FORK: dispatch_source_proc_flags_t = 0x4000_0000;
+/// The process has become another executable image via `exec*()` or `posix_spawn*()`.
+pub const DISPATCH_PROC_EXEC: dispatch_source_proc_flags_t = 0x2000_0000;
+/// A Unix signal was delivered to the process.
+pub const DISPATCH_PROC_SIGNAL: dispatch_source_proc_flags_t = 0x0800_0000;
+
+pub type dispatch_source_vnode_flags_t = c_ulong;
+
+/// The filesystem object was deleted from the namespace.
+pub const DISPATCH_VNODE_DELETE: dispatch_source_vnode_flags_t = 0x1;
+/// The filesystem object data changed.
+pub const DISPATCH_VNODE_WRITE: dispatch_source_vnode_flags_t = 0x2;
+/// The filesystem object changed in size.
+pub const DISPATCH_VNODE_EXTEND: dispatch_source_vnode_flags_t = 0x4;
+/// The filesystem object metadata changed.
+pub const DISPATCH_VNODE_ATTRIB: dispatch_source_vnode_flags_t = 0x8;
+/// The filesystem object link count changed.
+pub const DISPATCH_VNODE_LINK: dispatch_source_vnode_flags_t = 0x10;
+/// The filesystem object was renamed in the namespace.
+pub const DISPATCH_VNODE_RENAME: dispatch_source_vnode_flags_t = 0x20;
+/// The filesystem object was revoked.
+pub const DISPATCH_VNODE_REVOKE: dispatch_source_vnode_flags_t = 0x40;
+/// The filesystem object was unlocked.
+pub const DISPATCH_VNODE_FUNLOCK: dispatch_source_vnode_flags_t = 0x100;
+
+pub type dispatch_source_timer_flags_t = c_ulong;
+
+/// Specifies that the system should make a best effort to strictly observe the leeway value
+/// specified for the timer via [`dispatch_source_set_timer`].
+pub const DISPATCH_TIMER_STRICT: dispatch_source_timer_flags_t = 0x1;
+
+extern "C" {
+    pub static _dispatch_source_type_data_add: dispatch_source_type_s;
+    pub static _dispatch_source_type_data_or: dispatch_source_type_s;
+    pub static _dispatch_source_type_data_replace: dispatch_source_type_s;
+    pub static _dispatch_source_type_mach_send: dispatch_source_type_s;
+    pub static _dispatch_source_type_mach_recv: dispatch_source_type_s;
+    pub static _dispatch_source_type_memorypressure: dispatch_source_type_s;
+    pub static _dispatch_source_type_proc: dispatch_source_type_s;
+    pub static _dispatch_source_type_read: dispatch_source_type_s;
+    pub static _dispatch_source_type_signal: dispatch_source_type_s;
+    pub static _dispatch_source_type_timer: dispatch_source_type_s;
+    pub static _dispatch_source_type_vnode: dispatch_source_type_s;
+    pub static _dispatch_source_type_write: dispatch_source_type_s;
+
+    pub fn dispatch_source_create(
+        r#type: dispatch_source_type_t,
+        handle: usize,
+        mask: usize,
+        queue: dispatch_queue_t,
+    ) -> dispatch_source_t;
+
+    pub fn dispatch_source_set_event_handler_f(
+        source: dispatch_source_t,
+        handler: Option<dispatch_function_t>,
+    );
+    pub fn dispatch_source_set_cancel_handler_f(
+        source: dispatch_source_t,
+        handler: Option<dispatch_function_t>,
+    );
+    pub fn dispatch_source_set_registration_handler_f(
+        source: dispatch_source_t,
+        handler: Option<dispatch_function_t>,
+    );
+
+    pub fn dispatch_source_cancel(source: dispatch_source_t);
+    pub fn dispatch_source_testcancel(source: dispatch_source_t) -> isize;
+
+    pub fn dispatch_source_get_handle(source: dispatch_source_t) -> usize;
+    pub fn dispatch_source_get_mask(source: dispatch_source_t) -> usize;
+    pub fn dispatch_source_get_data(source: dispatch_source_t) -> usize;
+    pub fn dispatch_source_merge_data(source: dispatch_source_t, value: usize);
+
+    pub fn dispatch_source_set_timer(
+        source: dispatch_source_t,
+        start: dispatch_time_t,
+        interval: u64,
+        leeway: u64,
+    );
+}
+
+/// Defines a function returning the [`dispatch_source_type_t`] of a `_dispatch_source_type_*`
+/// static, which C code refers to with the corresponding `DISPATCH_SOURCE_TYPE_*` macro.
+macro_rules! source_type {
+    ($(#[$doc:meta])* $name:ident => $static:ident) => {
+        $(#[$doc])*
+        #[inline]
+        #[must_use]
+        pub fn $name() -> dispatch_source_type_t {
+            // LINT: Taking the address of an extern static only became safe in Rust 1.82.
+            #[allow(unused_unsafe)]
+            // SAFETY: Only the address of the static is taken; the static is not read.
+            let source_type = unsafe { core::ptr::addr_of!($static) };
+            source_type
+        }
+    };
+}
+
+source_type!(
+    /// `DISPATCH_SOURCE_TYPE_DATA_ADD`
+    dispatch_source_type_data_add => _dispatch_source_type_data_add
+);
+source_type!(
+    /// `DISPATCH_SOURCE_TYPE_DATA_OR`
+    dispatch_source_type_data_or => _dispatch_source_type_data_or
+);
+source_type!(
+    /// `DISPATCH_SOURCE_TYPE_DATA_REPLACE`
+    dispatch_source_type_data_replace => _dispatch_source_type_data_replace
+);
+source_type!(
+    /// `DISPATCH_SOURCE_TYPE_MACH_SEND`
+    dispatch_source_type_mach_send => _dispatch_source_type_mach_send
+);
+source_type!(
+    /// `DISPATCH_SOURCE_TYPE_MACH_RECV`
+    dispatch_source_type_mach_recv => _dispatch_source_type_mach_recv
+);
+source_type!(
+    /// `DISPATCH_SOURCE_TYPE_MEMORYPRESSURE`
+    dispatch_source_type_memorypressure => _dispatch_source_type_memorypressure
+);
+source_type!(
+    /// `DISPATCH_SOURCE_TYPE_PROC`
+    dispatch_source_type_proc => _dispatch_source_type_proc
+);
+source_type!(
+    /// `DISPATCH_SOURCE_TYPE_READ`
+    dispatch_source_type_read => _dispatch_source_type_read
+);
+source_type!(
+    /// `DISPATCH_SOURCE_TYPE_SIGNAL`
+    dispatch_source_type_signal => _dispatch_source_type_signal
+);
+source_type!(
+    /// `DISPATCH_SOURCE_TYPE_TIMER`
+    dispatch_source_type_timer => _dispatch_source_type_timer
+);
+source_type!(
+    /// `DISPATCH_SOURCE_TYPE_VNODE`
+    dispatch_source_type_vnode => _dispatch_source_type_vnode
+);
+source_type!(
+    /// `DISPATCH_SOURCE_TYPE_WRITE`
+    dispatch_source_type_write => _dispatch_source_type_write
+);
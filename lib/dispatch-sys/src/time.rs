@@ -0,0 +1,31 @@
+use core::ffi::c_long;
+
+pub const NSEC_PER_SEC: u64 = 1_000_000_000;
+pub const NSEC_PER_MSEC: u64 = 1_000_000;
+pub const USEC_PER_SEC: u64 = 1_000_000;
+pub const NSEC_PER_USEC: u64 = 1_000;
+
+/// A somewhat abstract representation of time; where zero means "now" and `DISPATCH_TIME_FOREVER`
+/// means "infinity" and every value in between is an opaque encoding.
+pub type dispatch_time_t = u64;
+
+pub const DISPATCH_WALLTIME_NOW: dispatch_time_t = !1;
+pub const DISPATCH_TIME_NOW: dispatch_time_t = 0;
+pub const DISPATCH_TIME_FOREVER: dispatch_time_t = !0;
+
+/// A time value with nanosecond precision, as defined by `<sys/_types/_timespec.h>`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(C)]
+pub struct timespec {
+    pub tv_sec: c_long,
+    pub tv_nsec: c_long,
+}
+
+extern "C" {
+    /// Create a [`dispatch_time_t`] relative to the current value of the default or wall time
+    /// clock, or modify an existing [`dispatch_time_t`].
+    pub fn dispatch_time(when: dispatch_time_t, delta: i64) -> dispatch_time_t;
+
+    /// Create a [`dispatch_time_t`] using the wall clock.
+    pub fn dispatch_walltime(when: *const timespec, delta: i64) -> dispatch_time_t;
+}
@@ -0,0 +1,37 @@
+//! Bindings that are resolved at run time, for binaries deployed to OS versions that predate them.
+//!
+//! Each binding gated by a deployment target feature is also declared here as a
+//! [`WeakFn`](c_ffi::WeakFn), which is available regardless of the crate's features. Use
+//! `is_available()` to test whether the running OS provides the function.
+
+use crate::{
+    dispatch_autorelease_frequency_t, dispatch_function_t, dispatch_object_t, dispatch_qos_class_t,
+    dispatch_queue_t, dispatch_workloop_t,
+};
+use core::ffi::{c_char, c_int, c_void};
+
+c_ffi::weak_extern! {
+    pub fn dispatch_async_and_wait_f(
+        queue: dispatch_queue_t,
+        context: *mut c_void,
+        work: dispatch_function_t,
+    );
+    pub fn dispatch_barrier_async_and_wait_f(
+        queue: dispatch_queue_t,
+        context: *mut c_void,
+        work: dispatch_function_t,
+    );
+
+    pub fn dispatch_set_qos_class_floor(
+        object: dispatch_object_t,
+        qos_class: dispatch_qos_class_t,
+        relative_priority: c_int,
+    );
+
+    pub fn dispatch_workloop_create(label: *const c_char) -> dispatch_workloop_t;
+    pub fn dispatch_workloop_create_inactive(label: *const c_char) -> dispatch_workloop_t;
+    pub fn dispatch_workloop_set_autorelease_frequency(
+        workloop: dispatch_workloop_t,
+        frequency: dispatch_autorelease_frequency_t,
+    );
+}
@@ -0,0 +1,20 @@
+use crate::{dispatch_autorelease_frequency_t, dispatch_queue_t};
+use availability::availability;
+use core::ffi::c_char;
+
+/// A dispatch workloop is a flavor of `dispatch_queue_t` that is a priority ordered queue (using
+/// the QOS class of the submitted workitems as the ordering).
+pub type dispatch_workloop_t = dispatch_queue_t;
+
+extern "C" {
+    #[availability(macos = "10.14", ios = "12.0", tvos = "12.0", watchos = "5.0")]
+    pub fn dispatch_workloop_create(label: *const c_char) -> dispatch_workloop_t;
+    #[availability(macos = "10.14", ios = "12.0", tvos = "12.0", watchos = "5.0")]
+    pub fn dispatch_workloop_create_inactive(label: *const c_char) -> dispatch_workloop_t;
+
+    #[availability(macos = "10.14", ios = "12.0", tvos = "12.0", watchos = "5.0")]
+    pub fn dispatch_workloop_set_autorelease_frequency(
+        workloop: dispatch_workloop_t,
+        frequency: dispatch_autorelease_frequency_t,
+    );
+}
@@ -0,0 +1,82 @@
+//! A minimal implementation of the Apple blocks ABI, for the libdispatch functions that only accept
+//! a block (i.e., have no `_f` variant that accepts a function pointer and context).
+//!
+//! Only blocks that are called exactly once and capture a single value that can be moved bitwise
+//! are supported. The block is created on the stack; the callee copies it to the heap with
+//! `Block_copy`, which moves the captured value with `memmove`, so the value must not be dropped by
+//! the caller.
+
+use core::ffi::{c_int, c_ulong, c_void};
+use core::mem::{size_of, ManuallyDrop};
+use core::ptr;
+
+extern "C" {
+    /// The class of blocks that are allocated on the stack.
+    static _NSConcreteStackBlock: [*const c_void; 32];
+}
+
+/// A block that captures a value of type `T` and drops it when called.
+#[repr(C)]
+pub(crate) struct DropBlock<T> {
+    isa: *const c_void,
+    flags: c_int,
+    reserved: c_int,
+    invoke: unsafe extern "C" fn(*mut Self),
+    descriptor: *const Descriptor,
+    value: ManuallyDrop<T>,
+}
+
+#[repr(C)]
+struct Descriptor {
+    reserved: c_ulong,
+    size: c_ulong,
+}
+
+impl<T> DropBlock<T>
+where
+    T: Send + 'static,
+{
+    /// The descriptor for every block that captures a `T`. The block has no copy or dispose
+    /// helpers, so `Block_copy` moves the captured value bitwise and `Block_release` does not drop
+    /// it.
+    const DESCRIPTOR: Descriptor = Descriptor {
+        reserved: 0,
+        // UB: The size of a type always fits in `c_ulong`, which is the same width as `usize` on
+        // all Apple platforms.
+        #[allow(clippy::as_conversions)]
+        size: size_of::<Self>() as c_ulong,
+    };
+
+    /// Creates a block that drops `value` when it is called.
+    ///
+    /// The block must be passed to a function that copies it and calls the copy exactly once.
+    /// Otherwise, `value` is leaked.
+    #[inline]
+    pub(crate) fn new(value: T) -> Self {
+        // LINT: Taking the address of an extern static only became safe in Rust 1.82.
+        #[allow(unused_unsafe)]
+        // SAFETY: Only the address of the static is taken; the static is not read.
+        let isa = unsafe { ptr::addr_of!(_NSConcreteStackBlock) };
+        Self {
+            isa: isa.cast(),
+            flags: 0,
+            reserved: 0,
+            invoke: invoke_drop::<T>,
+            descriptor: &Self::DESCRIPTOR,
+            value: ManuallyDrop::new(value),
+        }
+    }
+
+    /// Returns the block as a pointer for an argument of type `dispatch_block_t`.
+    #[inline]
+    pub(crate) fn as_raw(&mut self) -> *mut c_void {
+        let block: *mut Self = self;
+        block.cast()
+    }
+}
+
+unsafe extern "C" fn invoke_drop<T>(block: *mut DropBlock<T>) {
+    // SAFETY: The block is a copy of a [`DropBlock<T>`] created by [`DropBlock::new`] and, because the block
+    // is called exactly once, the value has not already been dropped.
+    unsafe { ManuallyDrop::drop(&mut (*block).value) };
+}
@@ -0,0 +1,175 @@
+//! The policy for Rust closures invoked by libdispatch.
+//!
+//! A panic must never unwind out of a function called by libdispatch, so every closure that crosses
+//! the FFI boundary is invoked through [`invoke`]:
+//!
+//! * With the `std` feature, the panic is caught at the boundary and its payload is forwarded to the
+//!   hook registered with [`set_panic_hook`]. If no hook is registered, the process aborts.
+//! * Without the `std` feature, the panic cannot be caught and the process aborts.
+//!
+//! In both cases the panic message has already been reported by the panic handler when the process
+//! aborts.
+//!
+//! The quality-of-service class and activity of the submitting thread are captured by libdispatch
+//! when the work item is submitted and are restored around the invocation, so the closure (and the
+//! panic hook) execute in the submitter's context without any additional bookkeeping here.
+
+#[cfg(any(feature = "experimental", feature = "std"))]
+extern crate alloc;
+
+#[cfg(any(feature = "experimental", feature = "std"))]
+use alloc::boxed::Box;
+#[cfg(feature = "experimental")]
+use core::ffi::c_void;
+#[cfg(feature = "experimental")]
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use core::{any::Any, panic::AssertUnwindSafe};
+#[cfg(feature = "std")]
+use std::{
+    panic,
+    sync::{PoisonError, RwLock},
+};
+
+/// A function that receives the payload of a panic raised by a closure invoked by libdispatch.
+///
+/// The hook is called on the thread that executed the closure. If the hook itself panics, the
+/// process aborts.
+#[cfg(feature = "std")]
+pub type PanicHook = fn(Box<dyn Any + Send + 'static>);
+
+#[cfg(feature = "std")]
+static PANIC_HOOK: RwLock<Option<PanicHook>> = RwLock::new(None);
+
+/// Registers a hook to receive the payload of panics raised by closures invoked by libdispatch,
+/// replacing the previously registered hook.
+///
+/// If `hook` is [`None`], a panic aborts the process, which is the default behavior.
+///
+/// When the hook returns, the work item is considered complete. For example, a [`Once`] whose
+/// closure panicked does not call a closure again.
+///
+/// [`Once`]: crate::Once
+#[cfg(feature = "std")]
+#[inline]
+pub fn set_panic_hook(hook: Option<PanicHook>) {
+    *PANIC_HOOK.write().unwrap_or_else(PoisonError::into_inner) = hook;
+}
+
+/// Calls `f`, ensuring a panic does not unwind into the caller.
+///
+/// This **must** be used to call any closure from a function invoked by libdispatch.
+pub(crate) fn invoke<F>(f: F)
+where
+    F: FnOnce(),
+{
+    #[cfg(feature = "std")]
+    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(f)) {
+        let hook = *PANIC_HOOK.read().unwrap_or_else(PoisonError::into_inner);
+        match hook {
+            Some(hook) => abort_on_unwind(|| hook(payload)),
+            None => std::process::abort(),
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    abort_on_unwind(f);
+}
+
+/// Calls `f`, aborting the process if it panics.
+fn abort_on_unwind<F>(f: F)
+where
+    F: FnOnce(),
+{
+    struct AbortOnUnwind;
+
+    impl Drop for AbortOnUnwind {
+        fn drop(&mut self) {
+            // PANIC: This is only reached while unwinding, and a panic during unwinding aborts the
+            // process, which is the only way to abort without `std`.
+            panic!("a closure invoked by libdispatch panicked");
+        }
+    }
+
+    let guard = AbortOnUnwind;
+    f();
+    core::mem::forget(guard);
+}
+
+/// A closure boxed into an opaque context pointer that is passed through libdispatch to
+/// [`ClosureContext::invoke`].
+#[cfg(feature = "experimental")]
+pub(crate) struct ClosureContext<F>(PhantomData<F>);
+
+#[cfg(feature = "experimental")]
+impl<F> ClosureContext<F>
+where
+    F: FnOnce() + Send + 'static,
+{
+    /// Moves `f` to the heap and returns an opaque pointer to be passed as the context argument of
+    /// [`Self::invoke`].
+    pub(crate) fn into_raw(f: F) -> *mut c_void {
+        Box::into_raw(Box::new(f)).cast()
+    }
+
+    /// Consumes and invokes a closure created by [`Self::into_raw`].
+    ///
+    /// This is intended to be passed to libdispatch as the function pointer argument of a `_f`
+    /// function whose context argument was returned by [`Self::into_raw`], which must be invoked at
+    /// most once.
+    pub(crate) extern "C" fn invoke(context: *mut c_void) {
+        // SAFETY: libdispatch passes the context pointer returned by [`Self::into_raw`] to this
+        // function at most once.
+        let f = unsafe { Box::<F>::from_raw(context.cast()) };
+        invoke(*f);
+    }
+}
+
+/// Calls `f` with `hook` registered as the panic hook, restoring the default behavior afterwards.
+///
+/// The panic hook is global, so tests that register one are serialized to avoid observing each
+/// other's hooks.
+#[cfg(all(test, feature = "std"))]
+pub(crate) fn with_panic_hook<F, T>(hook: PanicHook, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    use std::sync::Mutex;
+
+    /// Restores the default behavior when dropped, even if `f` unwinds.
+    struct Restore;
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            set_panic_hook(None);
+        }
+    }
+
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    let _guard = LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    set_panic_hook(Some(hook));
+    let _restore = Restore;
+    f()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{with_panic_hook, PanicHook};
+    use crate::Once;
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    #[test]
+    fn panic_hook() {
+        static PANICKED: AtomicBool = AtomicBool::new(false);
+        static ONCE: Once = Once::new();
+
+        let hook: PanicHook = |payload| {
+            assert_eq!(payload.downcast_ref::<&str>(), Some(&"expected"));
+            PANICKED.store(true, Ordering::Release);
+        };
+        with_panic_hook(hook, || ONCE.call_once(|| panic!("expected")));
+
+        assert!(PANICKED.load(Ordering::Acquire));
+    }
+}
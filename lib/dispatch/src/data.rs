@@ -0,0 +1,178 @@
+//! Dispatch data objects, which manage immutable, possibly discontiguous memory regions.
+
+extern crate alloc;
+
+use crate::block::DropBlock;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+use core::ptr::{self, NonNull};
+use dispatch_sys as sys;
+use retain_release::ffi::ForeignFunctionInterface;
+use retain_release::sync::Arc;
+
+/// An immutable region of memory managed by libdispatch.
+///
+/// Data objects created from a [`Vec<u8>`] or [`Box<[u8]>`] take ownership of the allocation
+/// instead of copying it, so large buffers can be passed to the system without a copy. The
+/// allocation is dropped on a global queue once the system no longer needs it.
+#[repr(C)]
+pub struct Data([u8; 0]);
+
+impl Data {
+    /// Returns the empty data object.
+    #[inline]
+    #[must_use]
+    pub fn empty() -> Arc<Self> {
+        let data = sys::dispatch_data_empty();
+        // SAFETY: The empty data object is a valid, immortal dispatch data object.
+        unsafe { Self::from_borrowed_ptr(NonNull::new_unchecked(data)) }
+    }
+
+    /// Creates a data object with a copy of `bytes`.
+    ///
+    /// Returns [`None`] if the data object could not be created.
+    #[inline]
+    #[must_use]
+    pub fn copy_from_slice(bytes: &[u8]) -> Option<Arc<Self>> {
+        // SAFETY: `bytes` is valid for its length, and the default destructor copies it.
+        let data = unsafe {
+            sys::dispatch_data_create(
+                bytes.as_ptr().cast(),
+                bytes.len(),
+                ptr::null_mut(),
+                sys::DISPATCH_DATA_DESTRUCTOR_DEFAULT,
+            )
+        };
+        // SAFETY: The data object was just created, so it has a retain that must be released.
+        unsafe { Self::try_from_owned_ptr(data.cast_const()) }
+    }
+
+    /// Creates a data object that takes ownership of `bytes` without copying them.
+    ///
+    /// Returns [`None`] if the data object could not be created.
+    #[inline]
+    #[must_use]
+    pub fn from_boxed_slice(bytes: Box<[u8]>) -> Option<Arc<Self>> {
+        let (buffer, len) = (bytes.as_ptr(), bytes.len());
+        // SAFETY: The boxed slice's heap allocation does not move when the box is moved.
+        unsafe { Self::from_owner(buffer, len, bytes) }
+    }
+
+    /// Creates a data object that takes ownership of the initialized elements of `bytes` without
+    /// copying them.
+    ///
+    /// The vector's spare capacity is retained until the data object is destroyed. Call
+    /// [`Vec::shrink_to_fit`] first if the excess is significant.
+    ///
+    /// Returns [`None`] if the data object could not be created.
+    #[inline]
+    #[must_use]
+    pub fn from_vec(bytes: Vec<u8>) -> Option<Arc<Self>> {
+        let (buffer, len) = (bytes.as_ptr(), bytes.len());
+        // SAFETY: The vector's heap allocation does not move when the vector is moved.
+        unsafe { Self::from_owner(buffer, len, bytes) }
+    }
+
+    /// Returns `true` if the data object has a length of zero.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of bytes in the data object's memory regions.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        // SAFETY: `self` is a valid data object.
+        unsafe { sys::dispatch_data_get_size(self.as_raw()) }
+    }
+
+    const fn as_raw(&self) -> sys::dispatch_data_t {
+        let data: *const Self = self;
+        data.cast_mut().cast()
+    }
+
+    /// Creates a data object over the `len` bytes at `buffer`, which are owned by `owner`.
+    ///
+    /// # Safety
+    ///
+    /// `buffer` must be valid for reads of `len` bytes until `owner` is dropped, including after
+    /// `owner` is moved.
+    unsafe fn from_owner<T>(buffer: *const u8, len: usize, owner: T) -> Option<Arc<Self>>
+    where
+        T: Send + 'static,
+    {
+        let mut destructor = DropBlock::new(owner);
+        // SAFETY: The caller asserts `buffer` is valid while `owner` is alive. libdispatch copies
+        // the destructor block and calls the copy exactly once, on a global queue, when the buffer
+        // is no longer used (immediately if `len` is zero).
+        let data = unsafe {
+            sys::dispatch_data_create(buffer.cast(), len, ptr::null_mut(), destructor.as_raw())
+        };
+        // SAFETY: The data object was just created, so it has a retain that must be released.
+        unsafe { Self::try_from_owned_ptr(data.cast_const()) }
+    }
+}
+
+impl Debug for Data {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Data").field("len", &self.len()).finish()
+    }
+}
+
+impl Drop for Data {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The reference is guaranteed to be a valid pointer.
+        unsafe { sys::dispatch_release(self.as_raw().cast()) };
+    }
+}
+
+impl ForeignFunctionInterface for Data {
+    type Raw = sys::dispatch_data_s;
+
+    #[inline]
+    unsafe fn from_borrowed_ptr(ptr: NonNull<Self::Raw>) -> Arc<Self>
+    where
+        Self: Sized,
+    {
+        // SAFETY: The caller asserts `ptr` is a valid data object.
+        unsafe { sys::dispatch_retain(ptr.as_ptr().cast()) };
+        // SAFETY: The retain is balanced by [`Self::release`].
+        unsafe { Arc::from_owned_ptr(ptr) }
+    }
+
+    #[inline]
+    unsafe fn release(this: &mut Self) {
+        // SAFETY: The caller asserts `this` has a retain that must be released.
+        unsafe { sys::dispatch_release(this.as_raw().cast()) };
+    }
+}
+
+// SAFETY: Dispatch data objects are immutable and thread safe.
+unsafe impl Send for Data {}
+
+// SAFETY: See above.
+unsafe impl Sync for Data {}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::Data;
+    use alloc::vec;
+
+    #[test]
+    fn create() {
+        assert!(Data::empty().is_empty());
+        assert_eq!(Data::copy_from_slice(b"copy").unwrap().len(), 4);
+        assert_eq!(Data::from_vec(vec![0; 4096]).unwrap().len(), 4096);
+        assert!(Data::from_vec(vec![]).unwrap().is_empty());
+
+        let bytes = vec![1, 2, 3].into_boxed_slice();
+        assert_eq!(Data::from_boxed_slice(bytes).unwrap().len(), 3);
+    }
+}
@@ -0,0 +1,272 @@
+//! A bridge between dispatch queues and Rust `async` code.
+//!
+//! [`Queue::run_async`] offloads a blocking closure onto a queue and returns a [`Future`] that
+//! resolves to its result, so async code can use GCD without an executor crate. [`Queue::spawn`]
+//! runs a future on a queue: each time the future's waker is woken, the future is polled again by a
+//! work item submitted to the same queue.
+
+extern crate alloc;
+
+use crate::Queue;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::mem;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
+use darwin::posix::sync::Mutex;
+use dispatch_sys as sys;
+
+/// A future that resolves to the value returned by a closure submitted with [`Queue::run_async`].
+///
+/// The closure runs whether or not the future is polled. Dropping the future discards the value.
+#[must_use = "futures do nothing unless polled"]
+pub struct RunAsync<T> {
+    shared: Arc<Mutex<State<T>>>,
+}
+
+enum State<T> {
+    /// The closure has not returned. Holds the waker of the most recent poll, if any.
+    Pending(Option<Waker>),
+    /// The closure returned a value that has not been taken by a poll.
+    Ready(T),
+    /// The closure panicked.
+    Abandoned,
+    /// The value was taken by a poll.
+    Complete,
+}
+
+/// Publishes the closure's result to its [`RunAsync`], or marks it abandoned if dropped without a
+/// result (i.e., the closure panicked).
+struct Completion<T>(Arc<Mutex<State<T>>>);
+
+/// A future spawned with [`Queue::spawn`] and the queue on which it is polled.
+struct Task {
+    queue: sys::dispatch_queue_t,
+    future: Mutex<Option<Pin<Box<dyn Future<Output = ()> + Send + 'static>>>>,
+    scheduled: AtomicBool,
+}
+
+impl Queue {
+    /// Submits `f` for asynchronous execution on the queue and returns a future that resolves to
+    /// its return value.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the panic is handled as described by [`set_panic_hook`] and polling the
+    /// future panics.
+    ///
+    /// [`set_panic_hook`]: crate::set_panic_hook
+    #[inline]
+    pub fn run_async<F, T>(&self, f: F) -> RunAsync<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let shared = Arc::new(Mutex::new(State::Pending(None)));
+        let completion = Completion(Arc::clone(&shared));
+        self.dispatch_fn_once(move || completion.complete(f()));
+        RunAsync { shared }
+    }
+
+    /// Runs `future` to completion on the queue.
+    ///
+    /// The future is first polled by a work item submitted to the queue, and is polled again on the
+    /// queue each time it is woken. The queue is retained until the future completes or its waker
+    /// is dropped.
+    ///
+    /// If polling the future panics, the panic is handled as described by [`set_panic_hook`] and
+    /// the future is polled again if it is woken.
+    ///
+    /// [`set_panic_hook`]: crate::set_panic_hook
+    #[inline]
+    pub fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let queue: *const _ = self;
+        let queue = queue.cast_mut().cast();
+        // SAFETY: The reference is guaranteed to be a valid pointer. The retain is balanced by the
+        // task's [`Drop`] implementation.
+        unsafe { sys::dispatch_retain(queue) };
+
+        let task = Arc::new(Task {
+            queue: queue.cast(),
+            future: Mutex::new(Some(Box::pin(future))),
+            scheduled: AtomicBool::new(false),
+        });
+        task.schedule();
+    }
+}
+
+impl<T> Debug for RunAsync<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunAsync").finish_non_exhaustive()
+    }
+}
+
+impl<T> Future for RunAsync<T> {
+    type Output = T;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.lock();
+        match mem::replace(&mut *state, State::Complete) {
+            State::Pending(_) => {
+                *state = State::Pending(Some(cx.waker().clone()));
+                Poll::Pending
+            }
+            State::Ready(value) => Poll::Ready(value),
+            State::Abandoned => {
+                *state = State::Abandoned;
+                panic!("the closure submitted with Queue::run_async panicked");
+            }
+            State::Complete => panic!("RunAsync polled after completion"),
+        }
+    }
+}
+
+impl<T> Completion<T> {
+    fn complete(self, value: T) {
+        self.finish(State::Ready(value));
+    }
+
+    fn finish(&self, result: State<T>) {
+        let waker = {
+            let mut state = self.0.lock();
+            match *state {
+                State::Pending(ref mut waker) => {
+                    let waker = waker.take();
+                    *state = result;
+                    waker
+                }
+                State::Ready(_) | State::Abandoned | State::Complete => None,
+            }
+        };
+
+        // Wake outside of the lock so the waker may poll the future immediately.
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Drop for Completion<T> {
+    fn drop(&mut self) {
+        // Only has an effect if the closure panicked; otherwise the state is already `Ready`.
+        self.finish(State::Abandoned);
+    }
+}
+
+impl Task {
+    /// Submits a work item to poll the future, unless one is already pending.
+    fn schedule(self: Arc<Self>) {
+        if self.scheduled.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        // SAFETY: The queue was retained when the task was created and is released when it is
+        // dropped.
+        let queue = unsafe { &*self.queue.cast::<Queue>() };
+        queue.dispatch_fn_once(move || self.run());
+    }
+
+    fn run(self: Arc<Self>) {
+        // Clear the flag before polling so a wake during the poll schedules another poll.
+        self.scheduled.store(false, Ordering::Release);
+
+        let waker = Waker::from(Arc::clone(&self));
+        let mut cx = Context::from_waker(&waker);
+
+        let mut future = self.future.lock();
+        if let Some(pinned) = future.as_mut() {
+            if pinned.as_mut().poll(&mut cx).is_ready() {
+                *future = None;
+            }
+        }
+    }
+}
+
+impl Drop for Task {
+    fn drop(&mut self) {
+        // SAFETY: The queue was retained when the task was created.
+        unsafe { sys::dispatch_release(self.queue.cast()) };
+    }
+}
+
+// SAFETY: Dispatch queues are thread safe, and the future is `Send` and only accessed through the
+// mutex.
+unsafe impl Send for Task {}
+
+// SAFETY: See above.
+unsafe impl Sync for Task {}
+
+impl Wake for Task {
+    #[inline]
+    fn wake(self: Arc<Self>) {
+        self.schedule();
+    }
+
+    #[inline]
+    fn wake_by_ref(self: &Arc<Self>) {
+        Arc::clone(self).schedule();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Arc, Wake};
+    use crate::Queue;
+    use core::future::Future;
+    use core::pin::pin;
+    use core::sync::atomic::{AtomicBool, Ordering};
+    use core::task::{Context, Poll, Waker};
+
+    struct Flag(AtomicBool);
+
+    impl Wake for Flag {
+        fn wake(self: Arc<Self>) {
+            self.0.store(true, Ordering::Release);
+        }
+    }
+
+    /// Polls `future` on the current thread, spinning until its waker is woken.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let flag = Arc::new(Flag(AtomicBool::new(true)));
+        let waker = Waker::from(Arc::clone(&flag));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+
+        loop {
+            while !flag.0.swap(false, Ordering::AcqRel) {
+                core::hint::spin_loop();
+            }
+            if let Poll::Ready(value) = future.as_mut().poll(&mut cx) {
+                return value;
+            }
+        }
+    }
+
+    #[test]
+    fn run_async() {
+        assert_eq!(block_on(Queue::global().run_async(|| 41_i32 + 1)), 42);
+    }
+
+    #[test]
+    fn spawn() {
+        static DONE: AtomicBool = AtomicBool::new(false);
+
+        Queue::global().spawn(async {
+            let value = Queue::global().run_async(|| 21_i32 * 2).await;
+            DONE.store(value == 42, Ordering::Release);
+        });
+
+        while !DONE.load(Ordering::Acquire) {
+            core::hint::spin_loop();
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::Once;
+use core::cell::UnsafeCell;
+use core::fmt::{self, Debug, Formatter};
+use core::mem;
+use core::ops::Deref;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// A value that is initialized on first access by a closure guarded by [`dispatch_once_f`].
+///
+/// Unlike [`LazyStatic`], the initializer may be any [`FnOnce`] type, access to the value is
+/// available without forcing initialization (via [`Lazy::get`]), and a panic in the initializer
+/// poisons the instance.
+///
+/// Like [`Once`], a `Lazy<T, F>` must be stored as a [`static` item][static-item]. The results of
+/// using a `Lazy<T, F>` with automatic or dynamic storage are undefined.
+///
+/// A `Lazy<T, F>`'s initialization closure may refer to another `Lazy<T, F>`, but a deadlock will
+/// occur if it refers to itself or if the initialization closures are mutually recursive.
+///
+/// # Poisoning
+///
+/// A panic cannot unwind through [`dispatch_once_f`]. Without the `std` feature, a panic in the
+/// initialization closure aborts the process. With the `std` feature, the panic is forwarded to the
+/// hook registered with [`set_panic_hook`] (or aborts if there is none). If the hook returns, the
+/// `Lazy<T, F>` is poisoned: the closure is never called again and every subsequent access panics.
+///
+/// [`dispatch_once_f`]: dispatch_sys::dispatch_once_f
+/// [`LazyStatic`]: crate::LazyStatic
+/// [`set_panic_hook`]: crate::set_panic_hook
+/// [static-item]: https://doc.rust-lang.org/reference/items/static-items.html
+///
+/// # Examples
+///
+/// ```
+/// # use dispatch::Lazy;
+/// static NAMES: Lazy<[String; 2]> = Lazy::new(|| ["NSObject".to_owned(), "NSString".to_owned()]);
+///
+/// assert_eq!(NAMES[1], "NSString");
+/// ```
+pub struct Lazy<T, F = fn() -> T> {
+    sentinel: Once,
+    state: UnsafeCell<State<T, F>>,
+    initialized: AtomicBool,
+}
+
+enum State<T, F> {
+    Uninitialized(F),
+    Initialized(T),
+    Poisoned,
+}
+
+impl<T, F> Lazy<T, F>
+where
+    F: FnOnce() -> T,
+{
+    /// Constructs a new `Lazy<T, F>` that will call `initialize` to obtain its value on the first
+    /// access.
+    #[inline]
+    pub const fn new(initialize: F) -> Self {
+        Self {
+            sentinel: Once::new(),
+            state: UnsafeCell::new(State::Uninitialized(initialize)),
+            initialized: AtomicBool::new(false),
+        }
+    }
+
+    /// Forces the evaluation of this lazy value and returns a reference to the result. This is
+    /// equivalent to the [`Deref`] implementation, but is explicit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the initialization closure panicked (see [Poisoning](Self#poisoning)).
+    #[inline]
+    pub fn force(this: &Self) -> &T {
+        this.sentinel.call_once(|| this.initialize());
+
+        // SAFETY: [`Self::initialize`] is the only place a mutable reference to `self.state` is
+        // obtained through a shared reference, and [`Once::call_once`] guarantees it is no longer
+        // executing and its effects are visible to this thread.
+        match *unsafe { &*this.state.get() } {
+            State::Initialized(ref value) => value,
+            State::Poisoned => panic!("Lazy instance has previously been poisoned"),
+            // SAFETY: [`Self::initialize`] always replaces the initialization closure.
+            State::Uninitialized(_) => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+
+    fn initialize(&self) {
+        // SAFETY: [`dispatch_once_f`] guarantees that this executes exclusively and only once.
+        let state = unsafe { &mut *self.state.get() };
+
+        // Leave the poisoned state in place while the closure runs so a panic (which is handled
+        // by `crate::context::invoke`) poisons the instance.
+        if let State::Uninitialized(initialize) = mem::replace(state, State::Poisoned) {
+            *state = State::Initialized(initialize());
+            self.initialized.store(true, Ordering::Release);
+        }
+    }
+}
+
+impl<T, F> Lazy<T, F> {
+    /// Returns a reference to the value if it has been initialized, or [`None`] otherwise. This
+    /// never calls the initialization closure.
+    #[inline]
+    pub fn get(this: &Self) -> Option<&T> {
+        if !this.initialized.load(Ordering::Acquire) {
+            return None;
+        }
+
+        // SAFETY: `initialized` is set after the value is written and is never cleared, so the
+        // state is no longer mutated through a shared reference.
+        match *unsafe { &*this.state.get() } {
+            State::Initialized(ref value) => Some(value),
+            State::Uninitialized(_) | State::Poisoned => None,
+        }
+    }
+}
+
+impl<T, F> Debug for Lazy<T, F>
+where
+    T: Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_tuple("Lazy");
+        match Self::get(self) {
+            Some(value) => d.field(value).finish(),
+            None => d.field(&format_args!("<uninit>")).finish(),
+        }
+    }
+}
+
+impl<T, F> Deref for Lazy<T, F>
+where
+    F: FnOnce() -> T,
+{
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        Self::force(self)
+    }
+}
+
+// SAFETY: Sending a `Lazy<T, F>` to another thread moves the closure or the value with it.
+unsafe impl<T, F> Send for Lazy<T, F>
+where
+    T: Send,
+    F: Send,
+{
+}
+
+// SAFETY: The use of [`UnsafeCell`] inhibits automatic implementation of [`Sync`]. The state is
+// only mutated through a shared reference in [`Lazy::initialize`], which [`dispatch_once_f`]
+// guarantees runs exclusively and at most once, possibly on another thread (hence `F: Send`). From
+// there, it's safe to share a reference to the value (hence `T: Sync`), which may have been created
+// on another thread (hence `T: Send`).
+unsafe impl<T, F> Sync for Lazy<T, F>
+where
+    T: Send + Sync,
+    F: Send,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Lazy;
+    use core::sync::atomic::{AtomicIsize, Ordering};
+
+    #[test]
+    fn initialize_once() {
+        static VALUE: AtomicIsize = AtomicIsize::new(0);
+
+        static LAZY: Lazy<isize> = Lazy::new(|| {
+            let magic = 41;
+
+            let _ = VALUE.fetch_add(magic, Ordering::AcqRel);
+            magic
+        });
+
+        assert_eq!(Lazy::get(&LAZY), None);
+        assert_eq!(VALUE.load(Ordering::Acquire), 0);
+        assert_eq!(*LAZY, 41);
+        assert_eq!(*LAZY, 41);
+        assert_eq!(Lazy::get(&LAZY), Some(&41));
+        assert_eq!(VALUE.load(Ordering::Acquire), 41);
+    }
+
+    #[test]
+    fn not_copy() {
+        #[derive(Debug, Eq, PartialEq)]
+        struct Pair(isize, isize);
+
+        static LAZY: Lazy<Pair> = Lazy::new(|| Pair(13, 41));
+
+        assert_eq!(Lazy::force(&LAZY), &Pair(13, 41));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn poisoned() {
+        extern crate std;
+
+        use crate::context::with_panic_hook;
+
+        static LAZY: Lazy<isize> = Lazy::new(|| panic!("expected"));
+
+        let result = with_panic_hook(|_| {}, || std::panic::catch_unwind(|| *LAZY));
+
+        drop(result.expect_err("expected a panic"));
+        assert_eq!(Lazy::get(&LAZY), None);
+    }
+}
@@ -10,6 +10,17 @@
 
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "experimental")]
+mod block;
+mod context;
+#[cfg(feature = "experimental")]
+mod data;
+#[cfg(feature = "futures")]
+mod future;
+mod lazy;
 mod lazy_static;
 #[cfg(feature = "experimental")]
 mod object;
@@ -17,11 +28,22 @@ mod once;
 #[cfg(feature = "experimental")]
 mod queue;
 #[cfg(feature = "experimental")]
-mod sys;
+pub mod signals;
+#[cfg(feature = "experimental")]
+mod source;
 
+#[cfg(feature = "std")]
+pub use context::{set_panic_hook, PanicHook};
+#[cfg(feature = "experimental")]
+pub use data::Data;
+#[cfg(feature = "futures")]
+pub use future::RunAsync;
+pub use lazy::*;
 pub use lazy_static::*;
 #[cfg(feature = "experimental")]
 pub use object::Object;
 pub use once::*;
 #[cfg(feature = "experimental")]
-pub use queue::Queue;
+pub use queue::{assert_not_queue, assert_queue, assert_queue_barrier, main, Queue};
+#[cfg(feature = "experimental")]
+pub use source::{MemoryPressureFlags, ProcessFlags, Source, VnodeFlags};
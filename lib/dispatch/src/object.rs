@@ -1,2 +1,101 @@
+use crate::Queue;
+use availability::availability;
+use darwin::sys::qos;
+use dispatch_sys as sys;
+
+/// Operations common to all dispatch object types.
 #[allow(drop_bounds)] // All dispatch objects must be dropped (released).
-pub trait Object: Drop {}
+pub trait Object: Drop {
+    /// Activates an object that was created in the inactive state, allowing it to begin invoking
+    /// work items.
+    ///
+    /// Activating an active object has no effect.
+    #[inline]
+    fn activate(&self)
+    where
+        Self: Sized,
+    {
+        // SAFETY: `self` is a valid dispatch object.
+        unsafe { sys::dispatch_activate(as_raw(self)) };
+    }
+
+    /// Suspends the invocation of work items on the object.
+    ///
+    /// Suspension is not immediate: work items that are already executing run to completion.
+    /// Every call to [`Object::suspend`] must be balanced by a call to [`Object::resume`] before
+    /// the object is released.
+    #[inline]
+    fn suspend(&self)
+    where
+        Self: Sized,
+    {
+        // SAFETY: `self` is a valid dispatch object.
+        unsafe { sys::dispatch_suspend(as_raw(self)) };
+    }
+
+    /// Resumes the invocation of work items on the object after a call to [`Object::suspend`].
+    ///
+    /// Resuming an object that is not suspended (or inactive) is a programming error and crashes
+    /// the process.
+    #[inline]
+    fn resume(&self)
+    where
+        Self: Sized,
+    {
+        // SAFETY: `self` is a valid dispatch object. libdispatch traps on unbalanced resumes.
+        unsafe { sys::dispatch_resume(as_raw(self)) };
+    }
+
+    /// Sets the queue on which the object's work items (or handlers) are executed.
+    ///
+    /// The target queue of an object must not be changed after the object has been activated.
+    #[inline]
+    fn set_target_queue(&self, queue: &Queue)
+    where
+        Self: Sized,
+    {
+        let queue: *const _ = queue;
+        // SAFETY: `self` and `queue` are valid dispatch objects.
+        unsafe { sys::dispatch_set_target_queue(as_raw(self), queue.cast_mut().cast()) };
+    }
+
+    /// Sets a closure to be called on the object's target queue after the last reference to the
+    /// object is released.
+    ///
+    /// The closure replaces (and drops) a finalizer that was previously set through this method.
+    ///
+    /// The object's context may be shared with other state owned by the bindings (e.g., a source's
+    /// handlers), and [`dispatch_get_context`] and [`dispatch_set_context`] cannot be updated
+    /// atomically. Therefore, each type stores the finalizer in its own state, which it must update
+    /// atomically as this method may be called concurrently.
+    ///
+    /// [`dispatch_get_context`]: dispatch_sys::dispatch_get_context
+    /// [`dispatch_set_context`]: dispatch_sys::dispatch_set_context
+    fn set_finalizer<F>(&self, f: F)
+    where
+        Self: Sized,
+        F: FnOnce() + Send + 'static;
+
+    /// Sets the minimum quality-of-service class with which the object's work items are executed.
+    ///
+    /// `relative_priority` is an offset in `-15..=0` from the maximum supported scheduler priority
+    /// for `qos`.
+    #[availability(macos = "10.14", ios = "12.0", tvos = "12.0", watchos = "5.0")]
+    #[inline]
+    fn set_qos_class_floor(&self, qos: qos::Class, relative_priority: i32)
+    where
+        Self: Sized,
+    {
+        // SAFETY: `self` is a valid dispatch object and `qos` is a valid QoS class. libdispatch
+        // ignores out of range relative priorities.
+        unsafe { sys::dispatch_set_qos_class_floor(as_raw(self), qos.into(), relative_priority) };
+    }
+}
+
+const fn as_raw<T>(object: &T) -> sys::dispatch_object_t
+where
+    T: Object,
+{
+    let object: *const T = object;
+    object.cast_mut().cast()
+}
@@ -1,8 +1,15 @@
-use core::mem::swap;
 use core::sync::atomic::AtomicIsize;
+#[cfg(feature = "dispatch_once_inline_fastpath")]
+use core::sync::atomic::Ordering;
 use dispatch_sys::{c_void, dispatch_once_f};
 
-/// Provides thread-safe, one-time execution of a function using [`dispatch_once_f`].
+/// The value of the predicate after the first invocation through a sentinel has completed.
+#[cfg(feature = "dispatch_once_inline_fastpath")]
+const DONE: isize = !0;
+
+/// Provides thread-safe, one-time execution of a closure or function using [`dispatch_once_f`].
+///
+/// `Once` is a `no_std` alternative to `std::sync::Once` that is backed by libdispatch.
 ///
 /// A `Once` must stored as a [`static` item][static-item]. The results of using a `Once` with
 /// automatic or dynamic storage are undefined.
@@ -17,7 +24,7 @@ use dispatch_sys::{c_void, dispatch_once_f};
 ///
 /// fn start_operation(op: Operation) {
 ///     static INITIALIZE: Once = Once::new();
-///     INITIALIZE.dispatch_once(subsystem_initialize);
+///     INITIALIZE.call_once(subsystem_initialize);
 ///
 ///     subsystem_start_operation(op);
 /// }
@@ -27,11 +34,6 @@ use dispatch_sys::{c_void, dispatch_once_f};
 #[derive(Debug)]
 pub struct Once(AtomicIsize);
 
-struct UserCallback<T> {
-    context: Option<T>,
-    function: fn(T),
-}
-
 impl Once {
     /// Constructs a new sentinel to guarantee, at most, one-time execution of a function.
     #[inline]
@@ -40,6 +42,74 @@ impl Once {
         Self(AtomicIsize::new(0))
     }
 
+    /// If this is the first invocation through this sentinel, then the closure `f` is called
+    /// synchronously. Otherwise, no operation takes place and `f` is dropped.
+    ///
+    /// All memory effects of `f` *happen before* this method returns on any thread, including
+    /// threads that concurrently block waiting for the first invocation to complete. Calling
+    /// `call_once` on the same sentinel from within `f` results in a deadlock.
+    ///
+    /// A panic cannot unwind through [`dispatch_once_f`], so if `f` panics, the process aborts or
+    /// the panic is forwarded to the hook registered with `set_panic_hook` (which requires the
+    /// `std` feature). Unlike [`std::sync::Once`], there is no poisoning.
+    ///
+    /// [`std::sync::Once`]: https://doc.rust-lang.org/std/sync/struct.Once.html
+    #[allow(clippy::inline_always)]
+    #[inline(always)]
+    pub fn call_once<F>(&self, f: F)
+    where
+        F: FnOnce(),
+    {
+        let mut f = Some(f);
+        let context: *mut _ = &mut f;
+
+        // SAFETY: The `context` pointer is a function-local and used correctly in
+        // [`Self::call_once_callback`], though this must be verified through code inspection.
+        unsafe {
+            dispatch_once_f(
+                self.0.as_ptr(),
+                context.cast(),
+                Self::call_once_callback::<F>,
+            );
+        }
+    }
+
+    /// Returns `true` if the first invocation through this sentinel has completed.
+    ///
+    /// If this returns `true`, all memory effects of the invoked function *happen before* the
+    /// return. A `false` return value may be stale by the time it is observed, as another thread
+    /// may complete the invocation concurrently.
+    ///
+    /// This is only available if the target supports reading the predicate inline (see the
+    /// `dispatch_once_inline_fastpath` feature).
+    #[cfg(feature = "dispatch_once_inline_fastpath")]
+    #[allow(clippy::inline_always)]
+    #[inline(always)]
+    #[must_use]
+    pub fn is_completed(&self) -> bool {
+        // [`dispatch_once_f`] marks the predicate done with release semantics only after the
+        // function's memory effects are visible to every thread, so an acquire load suffices.
+        self.predicate() == DONE
+    }
+
+    extern "C" fn call_once_callback<F>(context: *mut c_void)
+    where
+        F: FnOnce(),
+    {
+        let context: *mut Option<F> = context.cast();
+        // SAFETY: [`Self::call_once`] passes a reference to `f` as an opaque pointer. This function
+        // is invoked synchronously so the pointer is guaranteed to be valid, but code inspection is
+        // required to validate the pointers are of the same type.
+        let f = unsafe { &mut *context };
+
+        // SAFETY: [`Self::call_once`] always initializes `f` to [`Some`], and [`dispatch_once_f`]
+        // invokes this function at most once. Taking ownership of the closure leaves [`None`] so
+        // the closure is not dropped a second time at the end of [`Self::call_once`].
+        let f = unsafe { f.take().unwrap_unchecked() };
+
+        crate::context::invoke(f);
+    }
+
     /// If this is the first function invocation through this sentinel, then `function` is called
     /// synchronously. Otherwise, no operation takes place.
     ///
@@ -47,7 +117,7 @@ impl Once {
     #[allow(clippy::inline_always)]
     #[inline(always)]
     pub fn dispatch_once(&self, function: fn()) {
-        self.dispatch_once_with_context(function, |function| function());
+        self.call_once(function);
     }
 
     /// If this is the first function invocation through this sentinel, then `function` is called
@@ -61,44 +131,7 @@ impl Once {
     #[allow(clippy::inline_always)]
     #[inline(always)]
     pub fn dispatch_once_with_context<T>(&self, context: T, function: fn(T)) {
-        let mut user_callback = UserCallback {
-            context: Some(context),
-            function,
-        };
-        let context: *mut _ = &mut user_callback;
-
-        // SAFETY: The `context` pointer is a function-local and used correctly in
-        // [`Self::dispatch_once_with_context_callback`], though this must be verified through code
-        // inspection.
-        unsafe {
-            dispatch_once_f(
-                self.0.as_ptr(),
-                context.cast(),
-                Self::dispatch_once_with_context_callback::<T>,
-            );
-        }
-    }
-
-    extern "C" fn dispatch_once_with_context_callback<T>(context: *mut c_void) {
-        let context: *mut UserCallback<T> = context.cast();
-        // SAFETY: [`Self::dispatch_once_with_context`] passes a reference to `context` as an opaque
-        // pointer. This function is invoked synchronously so the pointer is guaranteed to be valid,
-        // but code inspection is required to validate the pointers are of the same type.
-        let user_callback = unsafe { &mut *context };
-
-        let mut context = None;
-        swap(&mut context, &mut user_callback.context);
-
-        // SAFETY: [`Self::dispatch_once_with_context`] always initializes the `context` field of
-        // [`UserCallback<T>`] to [`Some`]. The field is an [`Option<T>`] so this function can take
-        // ownership of the user context by swapping the field with [`None`]. Then, the user context
-        // is moved into the user callback function, where it will be dropped. If
-        // [`Self::dispatch_once_with_context`] is called more than one time, the user context will
-        // be dropped at the end of that function because the `context` field will retain its
-        // [`Some`] value.
-        let context = unsafe { context.unwrap_unchecked() };
-
-        (user_callback.function)(context);
+        self.call_once(move || function(context));
     }
 
     /// Gets a boolean value indicating whether the first function invocation this sentinel is
@@ -122,7 +155,7 @@ impl Once {
         // function invocation occurred to determine what clean up work is necessary. So, use
         // acquire semantics, like the implementation of [`dispatch_once_f`], to ensure the latest
         // value is visible to this thread.
-        self.0.load(core::sync::atomic::Ordering::Acquire) == 0
+        self.predicate() == 0
     }
 
     /// Gets a boolean value indicating whether the first function invocation this sentinel is
@@ -133,7 +166,15 @@ impl Once {
     /// This function cannot be used safely because it can race with other threads.
     #[cfg(feature = "dispatch_once_inline_fastpath")]
     pub(crate) unsafe fn pending_unsafe(&self) -> bool {
-        self.0.load(core::sync::atomic::Ordering::Acquire) == 0
+        self.predicate() == 0
+    }
+
+    /// Reads the predicate with the acquire semantics used by [`dispatch_once_f`].
+    #[cfg(feature = "dispatch_once_inline_fastpath")]
+    #[allow(clippy::inline_always)]
+    #[inline(always)]
+    fn predicate(&self) -> isize {
+        self.0.load(Ordering::Acquire)
     }
 }
 
@@ -165,4 +206,22 @@ mod tests {
 
         assert_eq!(VALUE.load(Ordering::Acquire), 13);
     }
+
+    #[test]
+    fn call_once() {
+        static INITIALIZE: Once = Once::new();
+        static VALUE: AtomicIsize = AtomicIsize::new(0);
+
+        assert_eq!(VALUE.load(Ordering::Acquire), 0);
+
+        let value = 13;
+        #[cfg(feature = "dispatch_once_inline_fastpath")]
+        assert!(!INITIALIZE.is_completed());
+        INITIALIZE.call_once(|| VALUE.store(value, Ordering::Release));
+        #[cfg(feature = "dispatch_once_inline_fastpath")]
+        assert!(INITIALIZE.is_completed());
+        INITIALIZE.call_once(|| VALUE.store(41, Ordering::Release));
+
+        assert_eq!(VALUE.load(Ordering::Acquire), 13);
+    }
 }
@@ -1,14 +1,18 @@
-extern crate alloc;
-
-use crate::{sys, Object};
-use alloc::boxed::Box;
+use crate::context::ClosureContext;
+use crate::Object;
 use core::ffi::{c_char, c_void, CStr};
 use core::fmt::{self, Debug, Formatter};
+use core::ptr;
 use darwin::sys::qos;
+use dispatch_sys as sys;
 
 #[repr(C)]
 pub struct Queue([u8; 0]);
 
+/// The queue-specific key that identifies a queue to [`Queue::is_current`]. Only its address is
+/// meaningful.
+static IDENTITY_KEY: u8 = 0;
+
 impl Queue {
     #[must_use]
     pub fn global() -> &'static Self {
@@ -26,37 +30,145 @@ impl Queue {
         unsafe { queue.as_ref() }.unwrap()
     }
 
+    /// Returns the serial queue associated with the application's main thread.
+    ///
+    /// Work items submitted to the main queue execute on the main thread, one at a time, but only
+    /// while the main thread services the queue. The queue is serviced by exactly one of:
+    ///
+    /// * [`main`](crate::main), which parks the main thread and never returns.
+    /// * The main thread's run loop (e.g., `CFRunLoopRun`, `NSApplicationMain`, or
+    ///   `UIApplicationMain`), which drains the main queue on each iteration in its common modes.
+    ///
+    /// Applications with a run loop, such as GUI applications, **must not** call
+    /// [`main`](crate::main); the run loop already drains the main queue.
     #[must_use]
     pub fn main() -> &'static Self {
-        let queue: *const _ = unsafe { &sys::_dispatch_main_q };
-        let queue = queue.cast();
+        let queue = sys::dispatch_get_main_queue().cast_const().cast();
         // SAFETY: The pointer is owned by the system and valid for the lifetime of the process.
         unsafe { &*queue }
     }
 
+    /// Returns the label assigned to the queue when it was created, which may be empty.
+    #[inline]
+    #[must_use]
+    pub fn label(&self) -> &CStr {
+        // SAFETY: The reference is guaranteed to be a valid pointer.
+        let label = unsafe { sys::dispatch_queue_get_label(self.as_raw()) };
+        if label.is_null() {
+            return <&CStr>::default();
+        }
+        // SAFETY: The label is a valid C-style string that lives as long as the queue.
+        unsafe { CStr::from_ptr(label) }
+    }
+
+    /// Returns `true` if the current work item is executing on this queue, or on a queue that
+    /// targets this queue.
+    ///
+    /// Unlike the deprecated `dispatch_get_current_queue`, the queue is identified by queue-specific
+    /// data, which is inherited through the target queue hierarchy. Global queues do not support
+    /// queue-specific data, so this function always returns `false` for a global queue.
+    #[inline]
+    #[must_use]
+    pub fn is_current(&self) -> bool {
+        if self.is_global() {
+            return false;
+        }
+
+        let key: *const u8 = &IDENTITY_KEY;
+        let key = key.cast();
+        let queue = self.as_raw();
+        // SAFETY: The reference is guaranteed to be a valid pointer, and the key is a static
+        // address that is never freed.
+        unsafe {
+            if sys::dispatch_queue_get_specific(queue, key).is_null() {
+                // The context is the queue itself, which outlives its own specific data, so no
+                // destructor is necessary.
+                sys::dispatch_queue_set_specific(queue, key, queue.cast(), None);
+            }
+            sys::dispatch_get_specific(key) == queue.cast()
+        }
+    }
+
     pub fn dispatch_fn_once<F>(&self, f: F)
     where
         F: FnOnce() + Send + 'static,
     {
         let queue: *const _ = self;
         let queue = (queue as *mut Self).cast();
-        let context = Box::into_raw(Box::new(f)).cast();
+        let context = ClosureContext::into_raw(f);
         // SAFETY: The reference is guaranteed to be a valid pointer, the context is guaranteed to
-        // be a valid pointer, and Self::call_boxed_fn_once::<F> has the correct signature.
-        unsafe { sys::dispatch_async_f(queue, context, Self::call_boxed_fn_once::<F>) }
+        // be a valid pointer, and ClosureContext::<F>::invoke has the correct signature.
+        unsafe { sys::dispatch_async_f(queue, context, ClosureContext::<F>::invoke) }
     }
 
-    extern "C" fn call_boxed_fn_once<F>(context: *mut c_void)
-    where
-        F: FnOnce() + Send + 'static,
-    {
-        // SAFETY: This is called by dispatch_fn_once(), which only ever passes a boxed `F` as the
-        // context parameter.
-        let f = unsafe { Box::<F>::from_raw(context.cast()) };
-        (*f)();
+    /// Returns the queue as a pointer for use with the system's API.
+    const fn as_raw(&self) -> sys::dispatch_queue_t {
+        let queue: *const Self = self;
+        queue.cast_mut().cast()
+    }
+
+    /// Returns `true` if the queue is one of the global concurrent queues.
+    fn is_global(&self) -> bool {
+        [
+            qos::Class::UserInteractive,
+            qos::Class::UserInitiated,
+            qos::Class::Default,
+            qos::Class::Utility,
+            qos::Class::Background,
+        ]
+        .into_iter()
+        .any(|qos| ptr::eq(self, Self::global_with_qos(qos)))
     }
 }
 
+/// Verifies that the current work item is executing on `queue`, or on a queue that targets it.
+///
+/// If it is not, the process is terminated with a diagnostic. Use this function to enforce the
+/// threading contract of code that must only be called from a particular queue.
+#[inline]
+pub fn assert_queue(queue: &Queue) {
+    // SAFETY: The reference is guaranteed to be a valid pointer.
+    unsafe { sys::dispatch_assert_queue(queue.as_raw()) };
+}
+
+/// Verifies that the current work item is executing on `queue` as a barrier, i.e., that no other
+/// work item is executing on `queue` concurrently.
+///
+/// If it is not, the process is terminated with a diagnostic. On a serial queue, this is equivalent
+/// to [`assert_queue`].
+#[inline]
+pub fn assert_queue_barrier(queue: &Queue) {
+    // SAFETY: The reference is guaranteed to be a valid pointer.
+    unsafe { sys::dispatch_assert_queue_barrier(queue.as_raw()) };
+}
+
+/// Verifies that the current work item is not executing on `queue`, nor on a queue that targets
+/// it.
+///
+/// If it is, the process is terminated with a diagnostic. Use this function to detect code that
+/// would deadlock by synchronously dispatching to `queue`.
+#[inline]
+pub fn assert_not_queue(queue: &Queue) {
+    // SAFETY: The reference is guaranteed to be a valid pointer.
+    unsafe { sys::dispatch_assert_queue_not(queue.as_raw()) };
+}
+
+/// Executes work items submitted to the main queue and never returns.
+///
+/// This function parks the main thread to service the [main queue](Queue::main). It should be
+/// called by applications that do not have a run loop on the main thread (e.g., command line tools
+/// and daemons) after their initial setup is complete. The process exits when a work item calls
+/// `exit`.
+///
+/// Do not call this function in an application that runs the main thread's run loop (e.g., via
+/// `CFRunLoopRun`). The run loop drains the main queue itself, and the run loop would never run
+/// again after this function is called.
+#[inline]
+pub fn main() -> ! {
+    // SAFETY: The function takes no arguments and is always safe to call.
+    unsafe { sys::dispatch_main() }
+}
+
 impl Debug for Queue {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         #[link(name = "objc")]
@@ -87,11 +199,20 @@ impl Drop for Queue {
     }
 }
 
-impl Object for Queue {}
+impl Object for Queue {
+    /// Drops `f` without calling it, as the global and main queues are never deallocated.
+    #[inline]
+    fn set_finalizer<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        drop(f);
+    }
+}
 
 #[cfg(test)]
 mod tests {
-    use super::{qos, Queue};
+    use super::{assert_not_queue, qos, Queue};
     use core::sync::atomic::{AtomicBool, Ordering};
 
     #[test]
@@ -131,6 +252,20 @@ mod tests {
         assert_ne!(queue5, queue6);
     }
 
+    #[test]
+    fn test_label() {
+        assert_eq!(Queue::main().label().to_bytes(), b"com.apple.main-thread");
+        assert!(!Queue::global().label().to_bytes().is_empty());
+    }
+
+    #[test]
+    fn test_is_current() {
+        // Tests are not run on the main queue.
+        assert!(!Queue::main().is_current());
+        assert!(!Queue::global().is_current());
+        assert_not_queue(Queue::main());
+    }
+
     #[test]
     fn test_dispatch_async() {
         extern "C" {
@@ -0,0 +1,94 @@
+//! Handling Unix signals on a dispatch queue.
+//!
+//! A signal handler installed with `sigaction(2)` runs asynchronously on an arbitrary thread and
+//! may only call async-signal-safe functions. A dispatch source instead observes the signal and
+//! submits an ordinary closure to a queue. But the source only observes the signal: unless the
+//! signal is ignored, its default action (e.g., terminating the process on `SIGTERM`) is taken
+//! before the closure has a chance to run. [`watch`] ignores each signal before monitoring it.
+
+extern crate alloc;
+
+use crate::{Object, Queue, Source};
+use alloc::sync::Arc as StdArc;
+use alloc::vec::Vec;
+use darwin::c::errno::Error;
+use darwin::posix::signal::Signal;
+use retain_release::sync::Arc;
+
+/// Ignores each signal in `signals` and monitors its delivery to the process.
+///
+/// `handler` is submitted to `queue` with the signal and the number of times it was delivered since
+/// the handler was last invoked for that signal. The returned sources are active; cancel them to
+/// stop monitoring. The signals remain ignored.
+///
+/// # Errors
+///
+/// Returns an [`Error`] if a signal cannot be ignored (i.e., [`Signal::Kill`] or [`Signal::Stop`])
+/// or if a source could not be created. The sources created before the error occurred are
+/// cancelled, but their signals remain ignored.
+#[inline]
+pub fn watch<F>(signals: &[Signal], queue: &Queue, handler: F) -> Result<Vec<Arc<Source>>, Error>
+where
+    F: Fn(Signal, usize) + Send + Sync + 'static,
+{
+    let handler = StdArc::new(handler);
+
+    let mut sources = Vec::with_capacity(signals.len());
+    for &signal in signals {
+        let source = signal.ignore().and_then(|()| {
+            let handler = StdArc::clone(&handler);
+            Source::signal(signal, queue, move |count| handler(signal, count))
+                .ok_or(Error::InvalidArgument)
+        });
+
+        match source {
+            Ok(source) => {
+                source.activate();
+                sources.push(source);
+            }
+            Err(error) => {
+                for source in sources {
+                    source.cancel();
+                }
+                return Err(error);
+            }
+        }
+    }
+
+    Ok(sources)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::watch;
+    use crate::Queue;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use darwin::c::errno::Error;
+    use darwin::posix::signal::Signal;
+
+    #[test]
+    fn raise() {
+        static DELIVERED: AtomicUsize = AtomicUsize::new(0);
+
+        let sources = watch(&[Signal::User1], Queue::global(), |signal, count| {
+            assert_eq!(signal, Signal::User1);
+            let _ = DELIVERED.fetch_add(count, Ordering::AcqRel);
+        })
+        .unwrap();
+
+        Signal::User1.raise().unwrap();
+        while DELIVERED.load(Ordering::Acquire) == 0 {
+            core::hint::spin_loop();
+        }
+
+        for source in sources {
+            source.cancel();
+        }
+    }
+
+    #[test]
+    fn uncatchable() {
+        let error = watch(&[Signal::Kill], Queue::global(), |_, _| {}).unwrap_err();
+        assert_eq!(error, Error::InvalidArgument);
+    }
+}
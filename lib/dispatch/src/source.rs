@@ -0,0 +1,574 @@
+//! Dispatch sources, which monitor low-level system objects and submit an event handler to a queue
+//! when an event occurs.
+
+extern crate alloc;
+
+use crate::context;
+use crate::{Object, Queue};
+use alloc::boxed::Box;
+use core::ffi::{c_int, c_ulong, c_void};
+use core::fmt::{self, Debug, Formatter};
+use core::ops::BitOr;
+use core::ptr::{self, NonNull};
+use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+use darwin::posix::signal::Signal;
+use dispatch_sys as sys;
+use retain_release::ffi::ForeignFunctionInterface;
+use retain_release::sync::Arc;
+
+type CancelHandler = Box<dyn FnOnce() + Send + 'static>;
+type Finalizer = Box<dyn FnOnce() + Send + 'static>;
+
+/// Monitors a low-level system object and submits its event handler to a queue when an event
+/// occurs.
+///
+/// Sources are created inactive so they can be configured (e.g., with
+/// [`Source::set_cancel_handler`]) before any event is delivered. Call [`Object::activate`] to
+/// begin monitoring. Events that occur while the event handler is executing are coalesced and
+/// delivered together in the next invocation.
+///
+/// libdispatch does not allow an inactive source to be deallocated, so releasing the last reference
+/// to a source that was never activated cancels and then activates it.
+#[repr(C)]
+pub struct Source([u8; 0]);
+
+/// The memory pressure conditions monitored by [`Source::memory_pressure`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct MemoryPressureFlags(sys::dispatch_source_memorypressure_flags_t);
+
+/// The process events monitored by [`Source::process`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ProcessFlags(sys::dispatch_source_proc_flags_t);
+
+/// The file system object events monitored by [`Source::vnode`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct VnodeFlags(sys::dispatch_source_vnode_flags_t);
+
+/// The state shared by every source, stored in the source's context. The first field of every
+/// [`State<F>`], so it may be accessed without knowing the event handler's type.
+struct Shared {
+    source: sys::dispatch_source_t,
+    cancel_handler: AtomicPtr<CancelHandler>,
+    finalizer: AtomicPtr<Finalizer>,
+    references: AtomicUsize,
+    activated: AtomicBool,
+}
+
+/// The context of a source with an event handler of type `F`.
+#[repr(C)]
+struct State<F> {
+    shared: Shared,
+    event_handler: F,
+}
+
+impl Source {
+    /// Creates a source that monitors the system's memory pressure for the conditions in `mask`.
+    ///
+    /// `handler` is submitted to `queue` with the conditions that occurred. The system's memory
+    /// pressure returns to normal when [`MemoryPressureFlags::NORMAL`] is delivered, so a handler
+    /// that reduces its memory use on [`MemoryPressureFlags::WARN`] or
+    /// [`MemoryPressureFlags::CRITICAL`] should also monitor it.
+    ///
+    /// Returns [`None`] if the source could not be created.
+    #[inline]
+    #[must_use]
+    pub fn memory_pressure<F>(
+        mask: MemoryPressureFlags,
+        queue: &Queue,
+        handler: F,
+    ) -> Option<Arc<Self>>
+    where
+        F: Fn(MemoryPressureFlags) + Send + Sync + 'static,
+    {
+        Self::new(
+            sys::dispatch_source_type_memorypressure(),
+            0,
+            mask.0,
+            queue,
+            move |data| {
+                handler(MemoryPressureFlags(data));
+            },
+        )
+    }
+
+    /// Creates a source that monitors the process `pid` for the events in `mask`.
+    ///
+    /// `handler` is submitted to `queue` with the events that occurred. After the process exits,
+    /// no further events are delivered; the source should be cancelled.
+    ///
+    /// Returns [`None`] if the source could not be created (e.g., `pid` does not exist).
+    #[inline]
+    #[must_use]
+    pub fn process<F>(pid: i32, mask: ProcessFlags, queue: &Queue, handler: F) -> Option<Arc<Self>>
+    where
+        F: Fn(ProcessFlags) + Send + Sync + 'static,
+    {
+        let pid = usize::try_from(pid).ok()?;
+        Self::new(
+            sys::dispatch_source_type_proc(),
+            pid,
+            mask.0,
+            queue,
+            move |data| {
+                handler(ProcessFlags(data));
+            },
+        )
+    }
+
+    /// Creates a source that monitors the delivery of `signal` to the process.
+    ///
+    /// `handler` is submitted to `queue` with the number of times the signal was delivered since
+    /// the handler was last invoked. The source observes the signal alongside any handler installed
+    /// with `sigaction(2)`; unless the signal is ignored, its default action (e.g., terminating the
+    /// process) is still taken. [`signals::watch`] ignores the signals it monitors.
+    ///
+    /// Returns [`None`] if the source could not be created.
+    ///
+    /// [`signals::watch`]: crate::signals::watch
+    #[inline]
+    #[must_use]
+    pub fn signal<F>(signal: Signal, queue: &Queue, handler: F) -> Option<Arc<Self>>
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        // LINT: `Signal` is represented by its signal number.
+        #[allow(clippy::as_conversions)]
+        let signal = signal as i32;
+        let signal = usize::try_from(signal).ok()?;
+        Self::new(
+            sys::dispatch_source_type_signal(),
+            signal,
+            0,
+            queue,
+            move |data| {
+                // UB: `c_ulong` is the same width as `usize` on all Apple platforms.
+                #[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+                handler(data as usize);
+            },
+        )
+    }
+
+    /// Creates a source that monitors the file system object open as `fd` for the events in
+    /// `mask`.
+    ///
+    /// `handler` is submitted to `queue` with the events that occurred. The source does not take
+    /// ownership of `fd`, which must remain open until the source's cancel handler is called. The
+    /// cancel handler is the appropriate place to close it.
+    ///
+    /// Returns [`None`] if the source could not be created (e.g., `fd` is not a valid descriptor).
+    #[inline]
+    #[must_use]
+    pub fn vnode<F>(fd: c_int, mask: VnodeFlags, queue: &Queue, handler: F) -> Option<Arc<Self>>
+    where
+        F: Fn(VnodeFlags) + Send + Sync + 'static,
+    {
+        let fd = usize::try_from(fd).ok()?;
+        Self::new(
+            sys::dispatch_source_type_vnode(),
+            fd,
+            mask.0,
+            queue,
+            move |data| {
+                handler(VnodeFlags(data));
+            },
+        )
+    }
+
+    fn new<F>(
+        source_type: sys::dispatch_source_type_t,
+        handle: usize,
+        mask: c_ulong,
+        queue: &Queue,
+        handler: F,
+    ) -> Option<Arc<Self>>
+    where
+        F: Fn(c_ulong) + Send + Sync + 'static,
+    {
+        let queue: *const _ = queue;
+        // UB: `c_ulong` is the same width as `usize` on all Apple platforms.
+        #[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+        let mask = mask as usize;
+
+        // SAFETY: `source_type` is a valid source type and `queue` is a valid queue. libdispatch
+        // returns `NULL` if `handle` or `mask` are invalid for the source type.
+        let source = unsafe {
+            sys::dispatch_source_create(source_type, handle, mask, queue.cast_mut().cast())
+        };
+        if source.is_null() {
+            return None;
+        }
+
+        let state = Box::into_raw(Box::new(State {
+            shared: Shared {
+                source,
+                cancel_handler: AtomicPtr::new(ptr::null_mut()),
+                finalizer: AtomicPtr::new(ptr::null_mut()),
+                references: AtomicUsize::new(1),
+                activated: AtomicBool::new(false),
+            },
+            event_handler: handler,
+        }));
+
+        let object = source.cast();
+        // SAFETY: `object` is a valid, inactive source and `state` is a valid pointer.
+        unsafe { sys::dispatch_set_context(object, state.cast()) };
+        // SAFETY: `object` is a valid source and [`finalize`] reclaims `state` when the source is
+        // deallocated.
+        unsafe { sys::dispatch_set_finalizer_f(object, finalize::<F>) };
+        // SAFETY: `source` is a valid, inactive source whose context is a [`State<F>`].
+        unsafe { sys::dispatch_source_set_event_handler_f(source, Some(handle_event::<F>)) };
+
+        // SAFETY: The source was just created, so it has a retain that must be released.
+        unsafe { Self::try_from_owned_ptr(source.cast_const().cast()) }
+    }
+
+    /// Asynchronously cancels the source, preventing any further invocation of its event handler.
+    ///
+    /// Cancellation does not interrupt an event handler that is already executing. The cancel
+    /// handler is submitted to the source's queue once the event handler has returned and the
+    /// system has released its resources for the monitored object.
+    #[inline]
+    pub fn cancel(&self) {
+        // SAFETY: `self` is a valid source.
+        unsafe { sys::dispatch_source_cancel(self.as_raw()) };
+    }
+
+    /// Returns `true` if the source has been cancelled.
+    #[inline]
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        // SAFETY: `self` is a valid source.
+        unsafe { sys::dispatch_source_testcancel(self.as_raw()) != 0 }
+    }
+
+    /// Sets a closure to be submitted to the source's queue once the source is cancelled.
+    ///
+    /// The closure replaces (and drops) a cancel handler that was previously set through this
+    /// method. It is called at most once.
+    #[inline]
+    pub fn set_cancel_handler<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let handler: CancelHandler = Box::new(f);
+        let previous = self
+            .shared()
+            .cancel_handler
+            .swap(Box::into_raw(Box::new(handler)), Ordering::AcqRel);
+        if !previous.is_null() {
+            // SAFETY: The pointer was created by [`Box::into_raw`] above on an earlier call, and
+            // was swapped out so it cannot be taken by [`handle_cancel`].
+            drop(unsafe { Box::from_raw(previous) });
+        }
+
+        // SAFETY: `self` is a valid source, and [`handle_cancel`] takes the handler from the source's
+        // context.
+        unsafe { sys::dispatch_source_set_cancel_handler_f(self.as_raw(), Some(handle_cancel)) };
+    }
+
+    const fn as_raw(&self) -> sys::dispatch_source_t {
+        let source: *const Self = self;
+        source.cast_mut().cast()
+    }
+
+    fn shared(&self) -> &Shared {
+        // SAFETY: `self` is a valid source.
+        let context = unsafe { sys::dispatch_get_context(self.as_raw().cast()) };
+        // SAFETY: The context is set to a [`State<F>`], whose first field is [`Shared`], when the
+        // source is created, and is only reclaimed when the source is deallocated.
+        unsafe { &*context.cast::<Shared>() }
+    }
+}
+
+impl Debug for Source {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let source: *const Self = self;
+        f.debug_tuple("Source").field(&source).finish()
+    }
+}
+
+impl Drop for Source {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The reference is guaranteed to be a valid pointer.
+        unsafe { sys::dispatch_release(self.as_raw().cast()) };
+    }
+}
+
+impl ForeignFunctionInterface for Source {
+    type Raw = sys::dispatch_source_s;
+
+    #[inline]
+    unsafe fn from_borrowed_ptr(ptr: NonNull<Self::Raw>) -> Arc<Self>
+    where
+        Self: Sized,
+    {
+        // SAFETY: The caller asserts `ptr` is a valid source.
+        unsafe { sys::dispatch_retain(ptr.as_ptr().cast()) };
+        // SAFETY: The caller asserts `ptr` is a valid source.
+        let source = unsafe { ptr.cast::<Self>().as_ref() };
+        let _ = source.shared().references.fetch_add(1, Ordering::Relaxed);
+        // SAFETY: The retain is balanced by [`Self::release`].
+        unsafe { Arc::from_owned_ptr(ptr) }
+    }
+
+    #[inline]
+    unsafe fn release(this: &mut Self) {
+        let shared = this.shared();
+        if shared.references.fetch_sub(1, Ordering::AcqRel) == 1
+            && !shared.activated.load(Ordering::Acquire)
+        {
+            // libdispatch crashes if an inactive source is deallocated. Cancelling the source first
+            // ensures its event handler is never invoked once it is activated.
+            this.cancel();
+            // SAFETY: `this` is a valid source.
+            unsafe { sys::dispatch_activate(this.as_raw().cast()) };
+        }
+
+        // SAFETY: The caller asserts `this` has a retain that must be released.
+        unsafe { sys::dispatch_release(this.as_raw().cast()) };
+    }
+}
+
+impl Object for Source {
+    #[inline]
+    fn activate(&self) {
+        self.shared().activated.store(true, Ordering::Release);
+        // SAFETY: `self` is a valid source.
+        unsafe { sys::dispatch_activate(self.as_raw().cast()) };
+    }
+
+    /// Sets a closure to be called on the source's target queue after the last reference to the
+    /// source is released.
+    ///
+    /// The closure replaces (and drops) a finalizer that was previously set through this method.
+    #[inline]
+    fn set_finalizer<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        // The source's context holds its handlers, so the finalizer is stored alongside them.
+        let finalizer: Finalizer = Box::new(f);
+        let previous = self
+            .shared()
+            .finalizer
+            .swap(Box::into_raw(Box::new(finalizer)), Ordering::AcqRel);
+        if !previous.is_null() {
+            // SAFETY: The pointer was created by [`Box::into_raw`] above on an earlier call, and
+            // was swapped out so it cannot be taken by [`finalize`].
+            drop(unsafe { Box::from_raw(previous) });
+        }
+    }
+}
+
+// SAFETY: Dispatch sources are thread safe, and the handlers stored in the context are `Send` and
+// `Sync`.
+unsafe impl Send for Source {}
+
+// SAFETY: See above.
+unsafe impl Sync for Source {}
+
+macro_rules! impl_flags {
+    ($ty:ident) => {
+        impl $ty {
+            /// Tests whether all of the flags in `other` are in this set.
+            #[inline]
+            #[must_use]
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Returns `true` if the set has no flags.
+            #[inline]
+            #[must_use]
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl BitOr for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(self, rhs: Self) -> Self::Output {
+                Self(self.0 | rhs.0)
+            }
+        }
+    };
+}
+
+impl MemoryPressureFlags {
+    /// The system's memory pressure has returned to normal.
+    pub const NORMAL: Self = Self(sys::DISPATCH_MEMORYPRESSURE_NORMAL);
+
+    /// The system's memory pressure is elevated. Release caches and other memory that is cheap to
+    /// recreate.
+    pub const WARN: Self = Self(sys::DISPATCH_MEMORYPRESSURE_WARN);
+
+    /// The system's memory pressure is critical. Release as much memory as possible.
+    pub const CRITICAL: Self = Self(sys::DISPATCH_MEMORYPRESSURE_CRITICAL);
+
+    /// All memory pressure conditions.
+    pub const ALL: Self = Self(
+        sys::DISPATCH_MEMORYPRESSURE_NORMAL
+            | sys::DISPATCH_MEMORYPRESSURE_WARN
+            | sys::DISPATCH_MEMORYPRESSURE_CRITICAL,
+    );
+}
+
+impl_flags!(MemoryPressureFlags);
+
+impl ProcessFlags {
+    /// The process exited.
+    pub const EXIT: Self = Self(sys::DISPATCH_PROC_EXIT);
+
+    /// The process created one or more child processes.
+    pub const FORK: Self = Self(sys::DISPATCH_PROC_FORK);
+
+    /// The process became another executable image with a call to `exec` or `posix_spawn`.
+    pub const EXEC: Self = Self(sys::DISPATCH_PROC_EXEC);
+
+    /// A Unix signal was delivered to the process.
+    pub const SIGNAL: Self = Self(sys::DISPATCH_PROC_SIGNAL);
+}
+
+impl_flags!(ProcessFlags);
+
+impl VnodeFlags {
+    /// The file system object was deleted.
+    pub const DELETE: Self = Self(sys::DISPATCH_VNODE_DELETE);
+
+    /// The file system object's data changed.
+    pub const WRITE: Self = Self(sys::DISPATCH_VNODE_WRITE);
+
+    /// The file system object's size changed.
+    pub const EXTEND: Self = Self(sys::DISPATCH_VNODE_EXTEND);
+
+    /// The file system object's metadata changed.
+    pub const ATTRIB: Self = Self(sys::DISPATCH_VNODE_ATTRIB);
+
+    /// The file system object's link count changed.
+    pub const LINK: Self = Self(sys::DISPATCH_VNODE_LINK);
+
+    /// The file system object was renamed.
+    pub const RENAME: Self = Self(sys::DISPATCH_VNODE_RENAME);
+
+    /// The file system object was revoked.
+    pub const REVOKE: Self = Self(sys::DISPATCH_VNODE_REVOKE);
+
+    /// The file system object was unlocked.
+    pub const FUNLOCK: Self = Self(sys::DISPATCH_VNODE_FUNLOCK);
+}
+
+impl_flags!(VnodeFlags);
+
+extern "C" fn handle_event<F>(context: *mut c_void)
+where
+    F: Fn(c_ulong),
+{
+    // SAFETY: The context was set to a [`State<F>`] when the source was created, and is only
+    // reclaimed when the source is deallocated, after its last event handler returns.
+    let state = unsafe { &*context.cast::<State<F>>() };
+    // SAFETY: The source is valid while its event handler executes.
+    let data = unsafe { sys::dispatch_source_get_data(state.shared.source) };
+    // UB: `c_ulong` is the same width as `usize` on all Apple platforms.
+    #[allow(clippy::as_conversions)]
+    let data = data as c_ulong;
+    context::invoke(|| (state.event_handler)(data));
+}
+
+extern "C" fn handle_cancel(context: *mut c_void) {
+    // SAFETY: The context is a [`State<F>`], whose first field is [`Shared`]. The cancel handler
+    // is called before the source is deallocated.
+    let shared = unsafe { &*context.cast::<Shared>() };
+    let handler = shared
+        .cancel_handler
+        .swap(ptr::null_mut(), Ordering::AcqRel);
+    if !handler.is_null() {
+        // SAFETY: The pointer was created by [`Box::into_raw`] in [`Source::set_cancel_handler`]
+        // and was swapped out, so it is consumed exactly once.
+        let handler = unsafe { Box::from_raw(handler) };
+        context::invoke(*handler);
+    }
+}
+
+extern "C" fn finalize<F>(context: *mut c_void) {
+    // SAFETY: The context was created by [`Box::into_raw`] when the source was created, and
+    // libdispatch calls the finalizer exactly once, after the source's last reference is released.
+    let state = unsafe { Box::from_raw(context.cast::<State<F>>()) };
+
+    let cancel_handler = state.shared.cancel_handler.load(Ordering::Acquire);
+    if !cancel_handler.is_null() {
+        // SAFETY: The cancel handler was never called, so the pointer was not consumed.
+        drop(unsafe { Box::from_raw(cancel_handler) });
+    }
+
+    let finalizer = state.shared.finalizer.load(Ordering::Acquire);
+    drop(state);
+    if !finalizer.is_null() {
+        // SAFETY: The pointer was created by [`Box::into_raw`] in [`Object::set_finalizer`], and
+        // the finalizer is called exactly once.
+        let finalizer = unsafe { Box::from_raw(finalizer) };
+        context::invoke(*finalizer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MemoryPressureFlags, ProcessFlags, Source, VnodeFlags};
+    use crate::{Object, Queue};
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    #[test]
+    fn cancel_handler() {
+        static CANCELLED: AtomicBool = AtomicBool::new(false);
+
+        let source = Source::memory_pressure(MemoryPressureFlags::ALL, Queue::global(), |_| {})
+            .expect("memory pressure source");
+        source.set_cancel_handler(|| CANCELLED.store(true, Ordering::Release));
+        source.activate();
+        source.cancel();
+        assert!(source.is_cancelled());
+
+        while !CANCELLED.load(Ordering::Acquire) {
+            core::hint::spin_loop();
+        }
+    }
+
+    #[test]
+    fn drop_inactive() {
+        static CANCELLED: AtomicBool = AtomicBool::new(false);
+
+        let source = Source::memory_pressure(MemoryPressureFlags::ALL, Queue::global(), |_| {})
+            .expect("memory pressure source");
+        source.set_cancel_handler(|| CANCELLED.store(true, Ordering::Release));
+        drop(source.clone());
+        drop(source);
+
+        while !CANCELLED.load(Ordering::Acquire) {
+            core::hint::spin_loop();
+        }
+    }
+
+    #[test]
+    fn invalid_handles() {
+        let queue = Queue::global();
+        // Negative handles are rejected before they are passed to libdispatch.
+        assert!(Source::process(-1, ProcessFlags::EXIT, queue, |_| {}).is_none());
+        assert!(Source::vnode(-1, VnodeFlags::WRITE, queue, |_| {}).is_none());
+        // libdispatch does not create process sources for a zero handle.
+        assert!(Source::process(0, ProcessFlags::EXIT, queue, |_| {}).is_none());
+    }
+
+    #[test]
+    fn flags() {
+        let mask = VnodeFlags::WRITE | VnodeFlags::DELETE;
+        assert!(mask.contains(VnodeFlags::WRITE));
+        assert!(!mask.contains(VnodeFlags::RENAME));
+        assert!(VnodeFlags::default().is_empty());
+    }
+}
@@ -1,7 +0,0 @@
-#![allow(non_camel_case_types)]
-
-mod object;
-mod queue;
-
-pub(crate) use object::*;
-pub(crate) use queue::*;
@@ -17,7 +17,11 @@ pub mod activity;
 #[cfg(feature = "experimental")]
 pub mod log;
 #[cfg(feature = "experimental")]
+pub mod proc;
+#[cfg(feature = "experimental")]
 pub mod trace_base;
+#[cfg(feature = "experimental")]
+pub mod transaction;
 
 #[cfg(feature = "experimental")]
 pub use macros::paste;
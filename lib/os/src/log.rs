@@ -1,10 +1,12 @@
 use crate::sys::log::{
     _os_log_debug_impl, _os_log_default, _os_log_disabled, _os_log_error_impl, _os_log_fault_impl,
-    _os_log_impl, os_log_t, os_log_type_enabled, os_log_type_t, OS_LOG_TYPE_DEBUG,
+    _os_log_impl, os_log_create, os_log_t, os_log_type_enabled, os_log_type_t, OS_LOG_TYPE_DEBUG,
     OS_LOG_TYPE_DEFAULT, OS_LOG_TYPE_ERROR, OS_LOG_TYPE_FAULT, OS_LOG_TYPE_INFO,
 };
+use crate::sys::object::{os_release, os_retain};
 use crate::sys::trace_base::__dso_handle;
 use crate::trace_base::LogString;
+use core::ffi::CStr;
 use core::fmt::{self, Debug, Formatter};
 use core::mem::size_of;
 
@@ -60,18 +62,47 @@ where
 #[repr(C, packed)]
 pub struct Items<A, B>(A, B);
 
+/// A log object, which sends messages to the logging system.
+///
+/// `Log` owns a reference to the log object and releases it when dropped, so it is [`Clone`] but
+/// not [`Copy`].
 #[allow(missing_copy_implementations, missing_debug_implementations)]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct Log(os_log_t);
 
+type LogFn = unsafe extern "C" fn(*const u32, os_log_t, os_log_type_t, LogString, *const u8, u32);
+
 struct Parameters {
-    log: os_log_t,
-    func: unsafe extern "C" fn(*const u32, os_log_t, os_log_type_t, LogString, *const u8, u32),
+    log: Log,
+    func: LogFn,
     ty: os_log_type_t,
     format: LogString,
 }
 
+/// The type of a log message, which determines whether the message is captured and how long it is
+/// retained.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum Type {
+    /// Messages about conditions that might result in a failure, which are captured by default.
+    Default = OS_LOG_TYPE_DEFAULT,
+    /// Messages that might be helpful, but not essential, for troubleshooting problems.
+    Info = OS_LOG_TYPE_INFO,
+    /// Messages that are useful during development while actively debugging, which are not
+    /// captured by default.
+    Debug = OS_LOG_TYPE_DEBUG,
+    /// Messages about errors that occurred during execution.
+    Error = OS_LOG_TYPE_ERROR,
+    /// Messages about faults, i.e., bugs, that occurred during execution.
+    Fault = OS_LOG_TYPE_FAULT,
+}
+
+// SAFETY: Log objects are immutable, thread safe, and reference counted.
+unsafe impl Send for Log {}
+// SAFETY: Log objects are immutable, thread safe, and reference counted.
+unsafe impl Sync for Log {}
+
 // Item Descriptor Flags
 const PRIVATE: u8 = 1 << 0;
 const PUBLIC: u8 = 1 << 1;
@@ -128,7 +159,7 @@ impl<T> Builder<T> {
         } = self.parameters;
 
         // SAFETY: This matches the canonical mechanics of `<os/log.h>`.
-        unsafe { (func)(dso, log, ty, format, buf, size) };
+        unsafe { (func)(dso, log.0, ty, format, buf, size) };
     }
 }
 
@@ -181,72 +212,96 @@ where
 }
 
 impl Log {
+    /// Creates a log object that tags its messages with `subsystem` (a reverse DNS identifier such
+    /// as `com.example.app`) and `category` (a component of the subsystem), which may be used to
+    /// filter messages and to configure logging behavior.
+    #[must_use]
+    pub fn create(subsystem: impl AsRef<CStr>, category: impl AsRef<CStr>) -> Self {
+        let subsystem = subsystem.as_ref().as_ptr();
+        let category = category.as_ref().as_ptr();
+
+        // SAFETY: subsystem and category are guaranteed to be valid, nul-terminated C-style
+        // strings, which the system function copies.
+        let log = unsafe { os_log_create(subsystem, category) };
+        if log.is_null() {
+            Self::disabled()
+        } else {
+            Self(log)
+        }
+    }
+
+    /// Returns a log object that discards all messages.
     #[must_use]
     pub fn disabled() -> Self {
         // SAFETY: This matches the canonical mechanics of `<os/log.h>`.
         Self(unsafe { &_os_log_disabled })
     }
 
+    /// Returns `true` if messages of type `ty` are captured by this log object under its current
+    /// configuration. Check this before performing expensive work to produce a message.
     #[must_use]
-    pub fn log(self, format: LogString) -> Option<Builder<()>> {
-        Self::with_parameters(Parameters {
-            log: self.0,
-            func: _os_log_impl,
-            ty: OS_LOG_TYPE_DEFAULT,
-            format,
-        })
+    pub fn is_type_enabled(&self, ty: Type) -> bool {
+        // SAFETY: This matches the canonical mechanics of `<os/log.h>`.
+        unsafe { os_log_type_enabled(self.0, ty as os_log_type_t) }
     }
 
+    /// Returns a builder for a default message with `format`, or [`None`] if messages of type
+    /// [`Type::Default`] are not enabled.
     #[must_use]
-    pub fn info(self, format: LogString) -> Option<Builder<()>> {
-        Self::with_parameters(Parameters {
-            log: self.0,
-            func: _os_log_impl,
-            ty: OS_LOG_TYPE_INFO,
-            format,
-        })
+    pub fn log(&self, format: LogString) -> Option<Builder<()>> {
+        self.with_parameters(_os_log_impl, Type::Default, format)
     }
 
+    /// Returns a builder for an info message with `format`, or [`None`] if messages of type
+    /// [`Type::Info`] are not enabled.
     #[must_use]
-    pub fn debug(self, format: LogString) -> Option<Builder<()>> {
-        Self::with_parameters(Parameters {
-            log: self.0,
-            func: _os_log_debug_impl,
-            ty: OS_LOG_TYPE_DEBUG,
-            format,
-        })
+    pub fn info(&self, format: LogString) -> Option<Builder<()>> {
+        self.with_parameters(_os_log_impl, Type::Info, format)
     }
 
+    /// Returns a builder for a debug message with `format`, or [`None`] if messages of type
+    /// [`Type::Debug`] are not enabled.
     #[must_use]
-    pub fn error(self, format: LogString) -> Option<Builder<()>> {
-        Self::with_parameters(Parameters {
-            log: self.0,
-            func: _os_log_error_impl,
-            ty: OS_LOG_TYPE_ERROR,
-            format,
-        })
+    pub fn debug(&self, format: LogString) -> Option<Builder<()>> {
+        self.with_parameters(_os_log_debug_impl, Type::Debug, format)
     }
 
+    /// Returns a builder for an error message with `format`, or [`None`] if messages of type
+    /// [`Type::Error`] are not enabled.
     #[must_use]
-    pub fn fault(self, format: LogString) -> Option<Builder<()>> {
-        Self::with_parameters(Parameters {
-            log: self.0,
-            func: _os_log_fault_impl,
-            ty: OS_LOG_TYPE_FAULT,
-            format,
+    pub fn error(&self, format: LogString) -> Option<Builder<()>> {
+        self.with_parameters(_os_log_error_impl, Type::Error, format)
+    }
+
+    /// Returns a builder for a fault message with `format`, or [`None`] if messages of type
+    /// [`Type::Fault`] are not enabled.
+    #[must_use]
+    pub fn fault(&self, format: LogString) -> Option<Builder<()>> {
+        self.with_parameters(_os_log_fault_impl, Type::Fault, format)
+    }
+
+    fn with_parameters(&self, func: LogFn, ty: Type, format: LogString) -> Option<Builder<()>> {
+        self.is_type_enabled(ty).then(|| Builder {
+            parameters: Parameters {
+                log: self.clone(),
+                func,
+                ty: ty as os_log_type_t,
+                format,
+            },
+            buffer: AlignedBuffer::new(),
         })
     }
+}
 
-    fn with_parameters(parameters: Parameters) -> Option<Builder<()>> {
-        // SAFETY: This matches the canonical mechanics of `<os/log.h>`.
-        if unsafe { os_log_type_enabled(parameters.log, parameters.ty) } {
-            Some(Builder {
-                parameters,
-                buffer: AlignedBuffer::new(),
-            })
-        } else {
-            None
-        }
+impl Clone for Log {
+    fn clone(&self) -> Self {
+        // SAFETY: The log object is valid for the lifetime of `self`. Retaining the default and
+        // disabled log objects has no effect.
+        Self(
+            unsafe { os_retain(self.0.cast_mut().cast()) }
+                .cast_const()
+                .cast(),
+        )
     }
 }
 
@@ -257,6 +312,14 @@ impl Default for Log {
     }
 }
 
+impl Drop for Log {
+    fn drop(&mut self) {
+        // SAFETY: `Log` owns a reference to the log object. Releasing the default and disabled log
+        // objects has no effect.
+        unsafe { os_release(self.0.cast_mut().cast()) };
+    }
+}
+
 macro_rules! builder_scalar_item {
     ($ty:ty) => {
         impl<T> BuilderItem<$ty> for Builder<T> {
@@ -278,8 +341,9 @@ builder_scalar_item!(u32);
 
 #[cfg(test)]
 mod test {
-    use super::{AlignedBuffer, BuilderItem, Log, Privacy};
+    use super::{AlignedBuffer, BuilderItem, Log, Privacy, Type};
     use crate::{log, log_debug, log_error, log_fault, log_info};
+    use core::ffi::CStr;
     use core::slice;
 
     log_string!(static UNUSED = b"");
@@ -330,4 +394,18 @@ mod test {
         log_error!(Log::default(), b"minus one = %d; mask = %x", a, b);
         log_fault!(Log::default(), b"minus one = %d; mask = %x", a, b);
     }
+
+    #[test]
+    fn create() {
+        let subsystem = CStr::from_bytes_with_nul(b"com.example.os\0").unwrap();
+        let category = CStr::from_bytes_with_nul(b"test\0").unwrap();
+        let log = Log::create(subsystem, category);
+
+        assert!(log.is_type_enabled(Type::Fault));
+        assert!(!Log::disabled().is_type_enabled(Type::Fault));
+
+        let clone = log.clone();
+        drop(log);
+        log_error!(clone, b"mask = %x", 0x8000_0015_u32);
+    }
 }
@@ -0,0 +1,19 @@
+#[cfg(not(target_os = "macos"))]
+use crate::sys::proc::os_proc_available_memory;
+#[cfg(not(target_os = "macos"))]
+use availability::availability;
+
+/// Returns the number of bytes the process may allocate before it reaches its memory limit and
+/// becomes eligible for termination by the jetsam subsystem.
+///
+/// The function is unavailable on macOS, which does not enforce a per-process memory limit. The
+/// limit can change over the lifetime of the process, so the value should be queried as needed
+/// rather than cached.
+#[availability(ios = "13.0", tvos = "13.0", visionos = "1.0", watchos = "6.0")]
+#[cfg(not(target_os = "macos"))]
+#[inline]
+#[must_use]
+pub fn available_memory() -> usize {
+    // SAFETY: The function has no preconditions.
+    unsafe { os_proc_available_memory() }
+}
@@ -1,6 +1,7 @@
 #![allow(non_camel_case_types)]
 
 use crate::trace_base::LogString;
+use core::ffi::c_char;
 
 pub(crate) type os_log_t = *const usize;
 
@@ -18,6 +19,8 @@ pub(crate) const OS_LOG_TYPE_ERROR: os_log_type_t = 0x10;
 pub(crate) const OS_LOG_TYPE_FAULT: os_log_type_t = 0x11;
 
 extern "C" {
+    pub(crate) fn os_log_create(subsystem: *const c_char, category: *const c_char) -> os_log_t;
+
     pub(crate) fn os_log_type_enabled(oslog: os_log_t, ty: os_log_type_t) -> bool;
 
     pub(crate) fn _os_log_impl(
@@ -1,3 +1,6 @@
 pub(crate) mod activity;
 pub(crate) mod log;
+pub(crate) mod object;
+pub(crate) mod proc;
 pub(crate) mod trace_base;
+pub(crate) mod transaction;
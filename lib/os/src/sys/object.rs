@@ -0,0 +1,6 @@
+use core::ffi::c_void;
+
+extern "C" {
+    pub(crate) fn os_release(object: *mut c_void);
+    pub(crate) fn os_retain(object: *mut c_void) -> *mut c_void;
+}
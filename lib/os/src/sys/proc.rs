@@ -0,0 +1,8 @@
+#![cfg(not(target_os = "macos"))]
+
+use availability::availability;
+
+extern "C" {
+    #[availability(ios = "13.0", tvos = "13.0", visionos = "1.0", watchos = "6.0")]
+    pub(crate) fn os_proc_available_memory() -> usize;
+}
@@ -0,0 +1,9 @@
+#![allow(non_camel_case_types)]
+
+use core::ffi::{c_char, c_void};
+
+pub(crate) type os_transaction_t = *mut c_void;
+
+extern "C" {
+    pub(crate) fn os_transaction_create(description: *const c_char) -> os_transaction_t;
+}
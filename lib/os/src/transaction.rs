@@ -0,0 +1,57 @@
+use crate::sys::object::os_release;
+use crate::sys::transaction::{os_transaction_create, os_transaction_t};
+use core::ffi::CStr;
+
+/// An outstanding unit of work that prevents a launchd-managed process from exiting when idle.
+///
+/// While any `Transaction` is alive, launchd treats the process as dirty and will not terminate it
+/// to reclaim resources (e.g., for a daemon with `EnableTransactions` set). The transaction ends
+/// when the value is dropped.
+#[allow(missing_copy_implementations)]
+#[derive(Debug)]
+pub struct Transaction(os_transaction_t);
+
+// SAFETY: A transaction is an immutable, reference counted object that may be released from any
+// thread.
+unsafe impl Send for Transaction {}
+// SAFETY: `Transaction` exposes no methods that access the underlying object.
+unsafe impl Sync for Transaction {}
+
+impl Transaction {
+    /// Begins a transaction. The `description` identifies the work in diagnostic output (e.g.,
+    /// `launchctl dumpstate`).
+    ///
+    /// Returns [`None`] if the transaction could not be created.
+    #[inline]
+    #[must_use]
+    pub fn new(description: impl AsRef<CStr>) -> Option<Self> {
+        let description = description.as_ref().as_ptr();
+
+        // SAFETY: description is guaranteed to be a valid, nul-terminated C-style string, which the
+        // system function copies.
+        let transaction = unsafe { os_transaction_create(description) };
+        (!transaction.is_null()).then_some(Self(transaction))
+    }
+}
+
+impl Drop for Transaction {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: `Transaction` is the owner of the object returned by `os_transaction_create`,
+        // which must be released exactly once to end the transaction.
+        unsafe { os_release(self.0) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Transaction;
+    use core::ffi::CStr;
+
+    #[test]
+    fn transaction() {
+        let description = CStr::from_bytes_with_nul(b"rust-os-transaction\0").unwrap();
+        let transaction = Transaction::new(description).unwrap();
+        drop(transaction);
+    }
+}
@@ -0,0 +1,383 @@
+//! Collections of shared object instances that do not allocate for small numbers of items.
+//!
+//! Bindings frequently need to keep a handful of objects alive for the duration of a foreign
+//! function call (e.g., the objects referenced by an array of pointers passed to the call). A
+//! [`SmallArcVec<T, N>`] stores up to `N` [`Arc<T>`]s inline, so these temporaries do not require a
+//! heap allocation, even when the `alloc` feature is disabled. An [`ArcSlice<T>`] views a slice of
+//! [`Arc<T>`]s as the array of object instance pointers the foreign function expects.
+//!
+//! ```
+//! # #[repr(C)]
+//! # struct ForeignType(u8);
+//! # struct RustBindings;
+//! #
+//! # impl retain_release::ffi::ForeignFunctionInterface for RustBindings {
+//! #     type Raw = ForeignType;
+//! #
+//! #     unsafe fn from_borrowed_ptr(ptr: core::ptr::NonNull<Self::Raw>) -> Arc<Self>
+//! #     where
+//! #         Self: Sized
+//! #     { todo!() }
+//! #
+//! #     unsafe fn release(this: &mut Self) { todo!() }
+//! # }
+//! #
+//! use retain_release::collections::SmallArcVec;
+//! use retain_release::sync::Arc;
+//!
+//! fn keep_alive(objects: impl IntoIterator<Item = Arc<RustBindings>>) {
+//!     let mut alive = SmallArcVec::<RustBindings, 4>::new();
+//!     for object in objects {
+//!         if alive.try_push(object).is_err() {
+//!             break;
+//!         }
+//!     }
+//!     // `alive` derefs to `[Arc<RustBindings>]` and releases its objects when dropped.
+//!     let ptrs = alive.as_arc_slice().as_ptr();
+//! #   let _ = ptrs;
+//! }
+//! ```
+//!
+//! [`Arc<T>`]: crate::sync::Arc
+
+use crate::ffi::ForeignFunctionInterface;
+use crate::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+use core::mem::MaybeUninit;
+use core::ops::Deref;
+use core::{ptr, slice};
+
+/// A slice of [`Arc<T>`]s that can be passed to a foreign function as an array of object instance
+/// pointers.
+///
+/// An [`Arc<T>`] has the same layout as the object instance pointer it manages, so the slice's
+/// items are also a valid array of `*const T::Raw` for as long as the slice is borrowed.
+#[repr(transparent)]
+pub struct ArcSlice<T>([Arc<T>])
+where
+    T: ForeignFunctionInterface;
+
+impl<T> ArcSlice<T>
+where
+    T: ForeignFunctionInterface,
+{
+    /// Views `items` as an `ArcSlice<T>`.
+    #[inline]
+    #[must_use]
+    pub const fn from_slice(items: &[Arc<T>]) -> &Self {
+        // LINT: An as conversion is the only way to cast a pointer to an unsized type.
+        #[allow(clippy::as_conversions)]
+        let ptr = ptr::addr_of!(*items) as *const Self;
+        // SAFETY: `ArcSlice<T>` is a transparent wrapper around `[Arc<T>]`.
+        unsafe { &*ptr }
+    }
+
+    /// Returns a pointer to the slice's array of object instance pointers.
+    ///
+    /// The pointers remain valid for as long as the slice is borrowed, which keeps the objects
+    /// alive.
+    #[inline]
+    #[must_use]
+    pub const fn as_ptr(&self) -> *const *const T::Raw {
+        // The smart pointer is a transparent wrapper around the object instance pointer.
+        self.0.as_ptr().cast()
+    }
+}
+
+impl<T> AsRef<[Arc<T>]> for ArcSlice<T>
+where
+    T: ForeignFunctionInterface,
+{
+    #[inline]
+    fn as_ref(&self) -> &[Arc<T>] {
+        &self.0
+    }
+}
+
+impl<T> Debug for ArcSlice<T>
+where
+    T: ForeignFunctionInterface + Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.0).finish()
+    }
+}
+
+impl<T> Deref for ArcSlice<T>
+where
+    T: ForeignFunctionInterface,
+{
+    type Target = [Arc<T>];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A vector of [`Arc<T>`]s that stores up to `N` items inline.
+///
+/// Pushing an item onto a full vector fails with [`SmallArcVec::try_push`]. If the `alloc` feature
+/// is enabled, `SmallArcVec::push` instead moves the items to the heap ("spills"), after which
+/// the vector grows like a `Vec`.
+pub struct SmallArcVec<T, const N: usize>
+where
+    T: ForeignFunctionInterface,
+{
+    storage: Storage<T, N>,
+}
+
+enum Storage<T, const N: usize>
+where
+    T: ForeignFunctionInterface,
+{
+    /// The first `len` items are initialized.
+    Inline {
+        items: [MaybeUninit<Arc<T>>; N],
+        len: usize,
+    },
+    #[cfg(feature = "alloc")]
+    Heap(Vec<Arc<T>>),
+}
+
+impl<T, const N: usize> SmallArcVec<T, N>
+where
+    T: ForeignFunctionInterface,
+{
+    /// Constructs a new, empty vector that can hold `N` items without allocating.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            storage: Storage::Inline {
+                // SAFETY: An array of [`MaybeUninit`] does not require initialization.
+                items: unsafe { MaybeUninit::<[MaybeUninit<Arc<T>>; N]>::uninit().assume_init() },
+                len: 0,
+            },
+        }
+    }
+
+    /// Returns the vector's items as an [`ArcSlice<T>`].
+    #[inline]
+    #[must_use]
+    pub fn as_arc_slice(&self) -> &ArcSlice<T> {
+        ArcSlice::from_slice(self.as_slice())
+    }
+
+    /// Returns the slice of the vector's items.
+    #[inline]
+    #[must_use]
+    // LINT: The function can only be `const` when the `alloc` feature is disabled.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn as_slice(&self) -> &[Arc<T>] {
+        match self.storage {
+            Storage::Inline { ref items, len } => {
+                // SAFETY: The first `len` items are initialized, and [`MaybeUninit<Arc<T>>`] has
+                // the same layout as [`Arc<T>`].
+                unsafe { slice::from_raw_parts(items.as_ptr().cast::<Arc<T>>(), len) }
+            }
+            #[cfg(feature = "alloc")]
+            Storage::Heap(ref items) => items,
+        }
+    }
+
+    /// Returns the number of items the vector can hold without allocating.
+    #[inline]
+    #[must_use]
+    // LINT: The function can only be `const` when the `alloc` feature is disabled.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn capacity(&self) -> usize {
+        match self.storage {
+            Storage::Inline { .. } => N,
+            #[cfg(feature = "alloc")]
+            Storage::Heap(ref items) => items.capacity(),
+        }
+    }
+
+    /// Removes and releases all the items in the vector.
+    ///
+    /// If the vector spilled to the heap, its allocation is retained.
+    #[inline]
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
+
+    /// Returns `true` if the vector's items are stored on the heap.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[must_use]
+    pub const fn is_spilled(&self) -> bool {
+        matches!(self.storage, Storage::Heap(_))
+    }
+
+    /// Removes the last item from the vector and returns it, or [`None`] if the vector is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<Arc<T>> {
+        match self.storage {
+            Storage::Inline {
+                ref mut items,
+                ref mut len,
+            } => {
+                *len = len.checked_sub(1)?;
+                let item = items.get(*len)?;
+                // SAFETY: The item was initialized, and decrementing `len` transfers its ownership
+                // to the caller.
+                Some(unsafe { item.assume_init_read() })
+            }
+            #[cfg(feature = "alloc")]
+            Storage::Heap(ref mut items) => items.pop(),
+        }
+    }
+
+    /// Appends `item` to the end of the vector, spilling the items to the heap if the vector is
+    /// full.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn push(&mut self, item: Arc<T>) {
+        if let Err(item) = self.try_push(item) {
+            let mut spilled = Vec::with_capacity(N.saturating_mul(2).max(1));
+            while let Some(item) = self.pop() {
+                spilled.push(item);
+            }
+            spilled.reverse();
+            spilled.push(item);
+            self.storage = Storage::Heap(spilled);
+        }
+    }
+
+    /// Appends `item` to the end of the vector, or returns it if the vector is stored inline and
+    /// full.
+    ///
+    /// # Errors
+    ///
+    /// Returns `item` if the vector already holds `N` items inline.
+    #[inline]
+    pub fn try_push(&mut self, item: Arc<T>) -> Result<(), Arc<T>> {
+        match self.storage {
+            Storage::Inline {
+                ref mut items,
+                ref mut len,
+            } => {
+                let Some(slot) = items.get_mut(*len) else {
+                    return Err(item);
+                };
+                let _ = slot.write(item);
+                // UB: `len` is less than `N`, so this cannot overflow.
+                *len = len.wrapping_add(1);
+                Ok(())
+            }
+            #[cfg(feature = "alloc")]
+            Storage::Heap(ref mut items) => {
+                items.push(item);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> AsRef<[Arc<T>]> for SmallArcVec<T, N>
+where
+    T: ForeignFunctionInterface,
+{
+    #[inline]
+    fn as_ref(&self) -> &[Arc<T>] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> Clone for SmallArcVec<T, N>
+where
+    T: ForeignFunctionInterface,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut clone = Self::new();
+        for item in self.as_slice() {
+            #[cfg(feature = "alloc")]
+            clone.push(Arc::clone(item));
+            // Without the `alloc` feature, `self` holds at most `N` items, so pushing its items onto
+            // an empty vector with the same inline capacity always succeeds.
+            #[cfg(not(feature = "alloc"))]
+            drop(clone.try_push(Arc::clone(item)));
+        }
+        clone
+    }
+}
+
+impl<T, const N: usize> Debug for SmallArcVec<T, N>
+where
+    T: ForeignFunctionInterface + Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T, const N: usize> Default for SmallArcVec<T, N>
+where
+    T: ForeignFunctionInterface,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Deref for SmallArcVec<T, N>
+where
+    T: ForeignFunctionInterface,
+{
+    type Target = [Arc<T>];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> Drop for SmallArcVec<T, N>
+where
+    T: ForeignFunctionInterface,
+{
+    #[inline]
+    fn drop(&mut self) {
+        // A spilled vector's items are released when the `Vec` is dropped.
+        self.clear();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> Extend<Arc<T>> for SmallArcVec<T, N>
+where
+    T: ForeignFunctionInterface,
+{
+    #[inline]
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Arc<T>>,
+    {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> FromIterator<Arc<T>> for SmallArcVec<T, N>
+where
+    T: ForeignFunctionInterface,
+{
+    #[inline]
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Arc<T>>,
+    {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
@@ -3,6 +3,7 @@
 
 use crate::boxed::Box;
 use crate::sync::Arc;
+use core::fmt::{self, Display, Formatter};
 use core::ptr::NonNull;
 
 /// A trait for use in bridging the between a foreign function interface with reference counting
@@ -245,3 +246,168 @@ pub trait ForeignFunctionInterface {
         ptr.cast()
     }
 }
+
+/// An extension of [`ForeignFunctionInterface`] whose constructors report why a raw object instance
+/// pointer could not be placed in a smart pointer.
+///
+/// The [`ForeignFunctionInterface`] constructors only `NULL`-check the pointer and return [`None`]
+/// on failure. If the foreign interface can identify the type of an object instance at run time
+/// (e.g., Core Foundation's `CFGetTypeID`), implement [`is_instance`] so the constructors of this
+/// trait also reject object instances of the wrong type.
+///
+/// This trait **should not** be used by crates utilizing Rust API bindings; it's intended only for
+/// crates *implementing* Rust API bindings.
+///
+/// [`is_instance`]: Self::is_instance
+pub trait TryFromForeign: ForeignFunctionInterface {
+    /// Returns `true` if the raw object instance pointer points to an instance of the foreign type
+    /// bound to `Self`.
+    ///
+    /// The default implementation cannot check the type and always returns `true`.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to a valid object instance of the foreign interface, though not
+    /// necessarily an instance of [`Raw`](ForeignFunctionInterface::Raw).
+    #[inline]
+    #[must_use]
+    unsafe fn is_instance(ptr: NonNull<Self::Raw>) -> bool {
+        let _ = ptr;
+        true
+    }
+
+    /// `NULL`-checks and type-checks the newly created but shared raw object instance pointer and
+    /// places the instance in an [`Arc<T>`].
+    ///
+    /// Ownership is transferred by the caller even if the type check fails, in which case the
+    /// object is released with [`release`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryFromForeignError::Null`] if the pointer is `NULL`, or
+    /// [`TryFromForeignError::TypeMismatch`] if [`is_instance`] returns `false`.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must meet the safety requirements of
+    /// [`ForeignFunctionInterface::try_from_owned_ptr`], except it may point to an object instance
+    /// of another type if [`is_instance`] is implemented and [`release`] can release it.
+    ///
+    /// [`is_instance`]: Self::is_instance
+    /// [`release`]: ForeignFunctionInterface::release
+    #[inline]
+    unsafe fn try_from_owned(ptr: *const Self::Raw) -> Result<Arc<Self>, TryFromForeignError>
+    where
+        Self: Sized,
+    {
+        let ptr = NonNull::new(ptr.cast_mut()).ok_or(TryFromForeignError::Null)?;
+        // SAFETY: Caller asserts `ptr` meets all safety requirements.
+        unsafe { check_owned::<Self>(ptr) }?;
+        // SAFETY: Caller asserts `ptr` meets all safety requirements.
+        Ok(unsafe { Self::from_owned_ptr(ptr) })
+    }
+
+    /// `NULL`-checks and type-checks the newly created, unique raw object instance pointer and
+    /// places the instance in a [`Box<T>`].
+    ///
+    /// Ownership is transferred by the caller even if the type check fails, in which case the
+    /// object is released with [`release`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryFromForeignError::Null`] if the pointer is `NULL`, or
+    /// [`TryFromForeignError::TypeMismatch`] if [`is_instance`] returns `false`.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must meet the safety requirements of
+    /// [`ForeignFunctionInterface::try_from_owned_mut_ptr`], except it may point to an object
+    /// instance of another type if [`is_instance`] is implemented and [`release`] can release it.
+    ///
+    /// [`is_instance`]: Self::is_instance
+    /// [`release`]: ForeignFunctionInterface::release
+    #[inline]
+    unsafe fn try_from_owned_mut(ptr: *mut Self::Raw) -> Result<Box<Self>, TryFromForeignError>
+    where
+        Self: Sized,
+    {
+        let ptr = NonNull::new(ptr).ok_or(TryFromForeignError::Null)?;
+        // SAFETY: Caller asserts `ptr` meets all safety requirements.
+        unsafe { check_owned::<Self>(ptr) }?;
+        // SAFETY: Caller asserts `ptr` meets all safety requirements.
+        Ok(unsafe { Self::from_owned_mut_ptr(ptr) })
+    }
+
+    /// `NULL`-checks and type-checks a "borrowed" raw object instance pointer, adds a reference
+    /// count, and places the instance in an [`Arc<T>`].
+    ///
+    /// The object is only retained if both checks pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryFromForeignError::Null`] if the pointer is `NULL`, or
+    /// [`TryFromForeignError::TypeMismatch`] if [`is_instance`] returns `false`.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must meet the safety requirements of
+    /// [`ForeignFunctionInterface::try_from_borrowed_ptr`], except it may point to an object
+    /// instance of another type if [`is_instance`] is implemented.
+    ///
+    /// [`is_instance`]: Self::is_instance
+    #[inline]
+    unsafe fn try_from_borrowed(ptr: *const Self::Raw) -> Result<Arc<Self>, TryFromForeignError>
+    where
+        Self: Sized,
+    {
+        let ptr = NonNull::new(ptr.cast_mut()).ok_or(TryFromForeignError::Null)?;
+        // SAFETY: Caller asserts `ptr` meets all safety requirements.
+        if !unsafe { Self::is_instance(ptr) } {
+            return Err(TryFromForeignError::TypeMismatch);
+        }
+        // SAFETY: Caller asserts `ptr` meets all safety requirements.
+        Ok(unsafe { Self::from_borrowed_ptr(ptr) })
+    }
+}
+
+/// Type-checks an owned raw object instance pointer, releasing the object if the check fails.
+///
+/// # Safety
+///
+/// The pointer must meet the safety requirements of [`TryFromForeign::try_from_owned`].
+unsafe fn check_owned<T>(ptr: NonNull<T::Raw>) -> Result<(), TryFromForeignError>
+where
+    T: TryFromForeign,
+{
+    // SAFETY: Caller asserts `ptr` meets all safety requirements.
+    if unsafe { T::is_instance(ptr) } {
+        return Ok(());
+    }
+
+    // SAFETY: Caller transferred ownership of the object instance, which must be balanced, and
+    // asserts [`release`] can release it.
+    unsafe { T::release(ptr.cast().as_mut()) };
+    Err(TryFromForeignError::TypeMismatch)
+}
+
+/// The error type returned when a raw object instance pointer cannot be placed in a smart pointer.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum TryFromForeignError {
+    /// The pointer is `NULL`.
+    Null,
+    /// The pointer points to an object instance of another type.
+    TypeMismatch,
+}
+
+impl Display for TryFromForeignError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Null => write!(f, "object instance pointer is NULL"),
+            Self::TypeMismatch => write!(f, "object instance is not of the expected type"),
+        }
+    }
+}
+
+impl std::error::Error for TryFromForeignError {}
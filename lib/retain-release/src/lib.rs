@@ -161,6 +161,10 @@
 //! [`release`]: crate::ffi::ForeignFunctionInterface::release
 //! [`try_from_owned_mut_ptr`]: crate::ffi::ForeignFunctionInterface::try_from_owned_mut_ptr
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod collections;
 pub mod ffi;
 mod rc;
 
@@ -21,6 +21,7 @@ use core::ptr::NonNull;
 /// generally obtain a mutable reference to something inside an `Arc<T>`.
 ///
 /// [`clone`]: Clone::clone
+#[repr(transparent)]
 pub struct Arc<T>(NonNull<T>)
 where
     T: ForeignFunctionInterface;